@@ -19,13 +19,77 @@ fn main() {
     return;
   }
 
+  if cfg!(feature = "mock") {
+    println!("Run with `mock` enabled, not linking SDL2.");
+    return;
+  }
+
+  if cfg!(feature = "dynamic_load") {
+    if cfg!(feature = "static_bundled_build") {
+      panic!("The `dynamic_load` feature can't be combined with `static_bundled_build`, there's no library to load at runtime.");
+    }
+    if cfg!(feature = "delay_load") {
+      panic!("The `dynamic_load` and `delay_load` features can't be combined, pick one.");
+    }
+    println!("Run with `dynamic_load` enabled, not linking SDL2.");
+    let target_os = env::var("CARGO_CFG_TARGET_OS").unwrap();
+    if target_os == "linux" {
+      // `dlopen` is in libc since glibc 2.34, but older versions need this.
+      println!("cargo:rustc-link-lib=dl");
+    }
+    return;
+  }
+
+  if cfg!(feature = "sdl3") {
+    do_sdl3();
+    return;
+  }
+
   #[cfg(feature = "static_bundled_build")]
   {
+    if let Some((feature, lib)) = extension_libs().next() {
+      panic!("The `{feature}` feature can't be combined with `static_bundled_build`, there's no bundled {lib} source to build.");
+    }
+    if cfg!(feature = "delay_load") {
+      panic!("The `delay_load` feature can't be combined with `static_bundled_build`, there's no DLL to delay.");
+    }
+    if cfg!(feature = "static_link") {
+      println!("The `static_bundled_build` feature always links statically, `static_link` has no extra effect.");
+    }
+    if cfg!(feature = "vcpkg") {
+      panic!("The `vcpkg` feature can't be combined with `static_bundled_build`, pick one place to get SDL2 from.");
+    }
+    if cfg!(feature = "prebuilt") {
+      panic!("The `prebuilt` feature can't be combined with `static_bundled_build`, pick one place to get SDL2 from.");
+    }
     do_static_bundled();
   }
   #[cfg(not(feature = "static_bundled_build"))]
   {
+    if link_static() && cfg!(feature = "delay_load") {
+      panic!("The `delay_load` feature can't be combined with a static link, there's no DLL to delay.");
+    }
+    println!("cargo:rerun-if-env-changed=FERMIUM_SDL2_LIB_DIR");
+    println!("cargo:rerun-if-env-changed=FERMIUM_SDL2_INCLUDE_DIR");
+    if let Some(lib_dir) = env::var_os("FERMIUM_SDL2_LIB_DIR") {
+      do_env_override(std::path::Path::new(&lib_dir));
+      if !link_static() {
+        expose_runtime_lib();
+      }
+      return;
+    }
+    if cfg!(feature = "vcpkg") && cfg!(feature = "prebuilt") {
+      panic!("The `vcpkg` and `prebuilt` features can't be combined, pick one place to get SDL2 from.");
+    }
+    #[cfg(feature = "prebuilt")]
+    do_prebuilt();
+    #[cfg(all(feature = "vcpkg", not(feature = "prebuilt")))]
+    do_vcpkg();
+    #[cfg(not(any(feature = "vcpkg", feature = "prebuilt")))]
     do_dynamic_system();
+    if !link_static() {
+      expose_runtime_lib();
+    }
   }
 }
 
@@ -85,20 +149,15 @@ fn do_static_bundled() {
     build_output_path.join("lib").display()
   );
 
+  link_sdl2_test(None);
   if target.contains("windows") {
+    // The bundled build has always linked SDL2main, with or without the
+    // `sdl2main` feature. Its `main` is only pulled in when the binary doesn't
+    // have one of its own. With MinGW this must come after `mingw32` in the
+    // link order, which rustc's own target spec already puts first.
     println!("cargo:rustc-link-lib=static=SDL2main");
     println!("cargo:rustc-link-lib=static=SDL2");
-    println!("cargo:rustc-link-lib=user32");
-    println!("cargo:rustc-link-lib=gdi32");
-    println!("cargo:rustc-link-lib=winmm");
-    println!("cargo:rustc-link-lib=imm32");
-    println!("cargo:rustc-link-lib=ole32");
-    println!("cargo:rustc-link-lib=oleaut32");
-    println!("cargo:rustc-link-lib=version");
-    println!("cargo:rustc-link-lib=uuid");
-    println!("cargo:rustc-link-lib=advapi32");
-    println!("cargo:rustc-link-lib=setupapi");
-    println!("cargo:rustc-link-lib=shell32");
+    link_windows_system_libs();
   } else {
     let sdl2_cfg_cmd = format!(
       "{}",
@@ -125,7 +184,11 @@ fn do_static_bundled() {
   }
 }
 
-#[cfg(not(feature = "static_bundled_build"))]
+#[cfg(not(any(
+  feature = "static_bundled_build",
+  feature = "vcpkg",
+  feature = "prebuilt"
+)))]
 fn do_dynamic_system() {
   println!("Performing dynamically-linked build against the system SDL2.");
 
@@ -147,11 +210,33 @@ fn do_dynamic_system() {
   let target_vendor = env::var("CARGO_CFG_TARGET_VENDOR").unwrap();
   println!("target_vendor:{}", target_vendor);
 
+  let static_link = link_static();
+  if static_link {
+    println!("Linking the system SDL2 statically.");
+  }
+
   if target_os.contains("windows") {
     if target == "x86_64-pc-windows-msvc" {
       let devel_path =
         std::path::Path::new(&cargo_manifest_dir).join("SDL2-2.26.5-devel");
 
+      if static_link {
+        // SDL's CMake build names the static lib `SDL2-static.lib`, so that it
+        // can sit next to the `SDL2.lib` import lib.
+        if !devel_path.join("SDL2-static.lib").exists() {
+          panic!("The `static_link` feature needs `SDL2-static.lib` in `{}`. Build SDL2 with `-DSDL_STATIC=ON` and copy it there, or use the `static_bundled_build` feature instead.", devel_path.display());
+        }
+        println!("cargo:rustc-link-search={}", devel_path.display());
+        if cfg!(feature = "sdl2main") {
+          println!("cargo:rustc-link-lib=static=SDL2main");
+        }
+        link_sdl2_test(Some(&devel_path));
+        println!("cargo:rustc-link-lib=static=SDL2-static");
+        link_windows_system_libs();
+        link_extension_libs(Some(&devel_path));
+        return;
+      }
+
       // Copy the DLL file into the executable out directory. When distributing
       // your executable, you might need to provide the SDL2 DLL as well, unless
       // it's already installed via other means (eg: steam provided it, or
@@ -163,29 +248,688 @@ fn do_dynamic_system() {
       std::fs::copy(dll_from, dll_to).unwrap();
 
       println!("cargo:rustc-link-search={}", devel_path.display());
+      if cfg!(feature = "sdl2main") {
+        // SDL2main is only ever a static lib. The bundled developer files
+        // don't include it, so it has to be dropped in next to `SDL2.lib`
+        // (it's in SDL's official `SDL2-devel-2.26.5-VC.zip` download).
+        if !devel_path.join("SDL2main.lib").exists() {
+          panic!("The `sdl2main` feature needs `SDL2main.lib` in `{}`. Copy it from SDL's official VC development files, or use the `static_bundled_build` feature instead.", devel_path.display());
+        }
+        println!("cargo:rustc-link-lib=static=SDL2main");
+        // SDL2main's `WinMain` uses `CommandLineToArgvW`.
+        println!("cargo:rustc-link-lib=shell32");
+      }
+      link_sdl2_test(Some(&devel_path));
       println!("cargo:rustc-link-lib=SDL2");
+      link_extension_libs(Some(&devel_path));
+
+      if cfg!(feature = "delay_load") {
+        link_delay_load();
+      }
     } else {
       panic!("On windows, dynamic_system builds are only supported for `x86_64-pc-windows-msvc`. Consider the `static_bundled_build` feature instead.");
     }
   } else {
-    let sd2_config_linking = std::process::Command::new("sdl2-config")
-      .arg("--libs")
-      .output()
+    // pkg-config knows about cross-compile sysroots (`PKG_CONFIG_SYSROOT_DIR`)
+    // and layouts where each library has its own prefix (NixOS, Yocto SDKs),
+    // so we ask it first. If it isn't installed, or doesn't know about SDL2,
+    // then we fall back to `sdl2-config`.
+    for var in [
+      "PKG_CONFIG",
+      "PKG_CONFIG_PATH",
+      "PKG_CONFIG_LIBDIR",
+      "PKG_CONFIG_SYSROOT_DIR",
+    ] {
+      println!("cargo:rerun-if-env-changed={var}");
+    }
+    let pkg_config_args: &[&str] = if static_link {
+      &["--libs", "--static", "sdl2"]
+    } else {
+      &["--libs", "sdl2"]
+    };
+    let sd2_config_linking_stdout = if let Some(pkg_config_linking_stdout) =
+      run_pkg_config(pkg_config_args)
+    {
+      println!("pkg_config_linking_stdout: {}", pkg_config_linking_stdout);
+      if let Some(cflags) = run_pkg_config(&["--cflags-only-I", "sdl2"]) {
+        emit_include_dirs(&cflags);
+      }
+      pkg_config_linking_stdout
+    } else {
+      // With `--static-libs` we get all of the system libraries that SDL2
+      // itself links to (`-lm`, `-liconv`, the macOS frameworks, etc), which a
+      // static link needs to be given too.
+      let link_style_arg: &str =
+        if static_link { "--static-libs" } else { "--libs" };
+      let sd2_config_linking = std::process::Command::new("sdl2-config")
+        .arg(link_style_arg)
+        .output()
+        .unwrap_or_else(|_| {
+          panic!(
+            "Couldn't run `pkg-config` or `sdl2-config {}`, is SDL2 properly installed?",
+            link_style_arg
+          )
+        });
+      assert!(sd2_config_linking.status.success());
+
+      let sd2_config_linking_stdout: String =
+        String::from_utf8_lossy(&sd2_config_linking.stdout).into_owned();
+      println!("sd2_config_linking_stdout: {}", sd2_config_linking_stdout);
+      assert!(sd2_config_linking_stdout.len() > 0);
+
+      if let Ok(cflags) =
+        std::process::Command::new("sdl2-config").arg("--cflags").output()
+      {
+        emit_include_dirs(&String::from_utf8_lossy(&cflags.stdout));
+      }
+      sd2_config_linking_stdout
+    };
+
+    process_sdl2_config_output(sd2_config_linking_stdout);
+    link_sdl2_test(None);
+    if static_link {
+      println!("cargo:rustc-link-lib=static=SDL2");
+    } else {
+      println!("cargo:rustc-link-lib=SDL2");
+    }
+    link_extension_libs(None);
+  }
+}
+
+/// If SDL2 should be linked statically.
+///
+/// This is the `static_link` feature, unless `FERMIUM_SDL2_LINK_KIND` is set
+/// to `static` or `dylib`.
+#[allow(dead_code)]
+fn link_static() -> bool {
+  println!("cargo:rerun-if-env-changed=FERMIUM_SDL2_LINK_KIND");
+  match env::var("FERMIUM_SDL2_LINK_KIND").as_deref() {
+    Ok("static") => true,
+    Ok("dylib") | Ok("dynamic") => false,
+    Ok(other) => panic!(
+      "`FERMIUM_SDL2_LINK_KIND` must be `static` or `dylib`, not `{other}`."
+    ),
+    Err(_) => cfg!(feature = "static_link"),
+  }
+}
+
+/// Links the SDL2 in `FERMIUM_SDL2_LIB_DIR`, skipping all other discovery.
+///
+/// This is for using a custom build of SDL2 (a patched fork, a debug build,
+/// etc) without having to install it.
+#[allow(dead_code)]
+fn do_env_override(lib_dir: &std::path::Path) {
+  println!("Using the SDL2 in `FERMIUM_SDL2_LIB_DIR`: {}", lib_dir.display());
+
+  let target = env::var("TARGET").expect("Could not read `TARGET`!");
+  println!("target:{}", target);
+
+  let static_link = link_static();
+  println!("cargo:rustc-link-search=native={}", lib_dir.display());
+  if let Some(include_dir) = env::var_os("FERMIUM_SDL2_INCLUDE_DIR") {
+    println!("cargo:include={}", std::path::Path::new(&include_dir).display());
+  }
+  if cfg!(feature = "sdl2main") {
+    println!("cargo:rustc-link-lib=static=SDL2main");
+    if target.contains("windows") {
+      println!("cargo:rustc-link-lib=shell32");
+    }
+  }
+  link_sdl2_test(target.ends_with("windows-msvc").then_some(lib_dir));
+
+  if target.contains("windows") {
+    if static_link {
+      // A CMake build of SDL2 on MSVC names the static lib `SDL2-static.lib`,
+      // but other builds just call it `SDL2`.
+      if lib_dir.join("SDL2-static.lib").exists() {
+        println!("cargo:rustc-link-lib=static=SDL2-static");
+      } else {
+        println!("cargo:rustc-link-lib=static=SDL2");
+      }
+      link_windows_system_libs();
+    } else {
+      // Same as with the bundled developer files, copy the DLL into the out
+      // directory. A CMake build puts it in `bin`, next to `lib`.
+      let out_dir = env::var("OUT_DIR").unwrap();
+      let dll_to = std::path::Path::new(&out_dir).join("SDL2.dll");
+      let bin_dir = lib_dir.join("..").join("bin");
+      for dll_from in [lib_dir.join("SDL2.dll"), bin_dir.join("SDL2.dll")] {
+        if dll_from.exists() {
+          println!("dll_from:{}", dll_from.display());
+          std::fs::copy(dll_from, &dll_to).unwrap();
+          break;
+        }
+      }
+      println!("cargo:rustc-link-lib=SDL2");
+      if cfg!(feature = "delay_load") {
+        link_delay_load();
+      }
+    }
+  } else if static_link {
+    // A static SDL2 also needs all of the system libraries that it depends
+    // on. We get those from the build's own `sdl2.pc` file, if it has one.
+    let pc_dir = lib_dir.join("pkgconfig");
+    let pc_output = std::process::Command::new(
+      env::var("PKG_CONFIG").unwrap_or_else(|_| "pkg-config".into()),
+    )
+    .args(["--libs", "--static", "sdl2"])
+    .env("PKG_CONFIG_PATH", &pc_dir)
+    .output();
+    match pc_output {
+      Ok(output) if output.status.success() => {
+        process_sdl2_config_output(
+          String::from_utf8_lossy(&output.stdout).into_owned(),
+        );
+      }
+      _ => println!("There's no `sdl2.pc` in `{}`, the libraries that SDL2 depends on must be linked some other way.", pc_dir.display()),
+    }
+    println!("cargo:rustc-link-lib=static=SDL2");
+  } else {
+    println!("cargo:rustc-link-lib=SDL2");
+  }
+
+  if target.ends_with("windows-msvc") {
+    link_extension_libs(Some(lib_dir));
+  } else {
+    link_extension_libs(None);
+  }
+}
+
+/// Passes the path of the SDL2 runtime library along as `DEP_SDL2_DLL`, and
+/// with the `copy_dll` feature also copies it next to the build's outputs.
+///
+/// This only finds the library on Windows (where all the ways of linking copy
+/// `SDL2.dll` into `OUT_DIR`) and macOS (where it's in SDL2's `libdir`).
+#[allow(dead_code)]
+fn expose_runtime_lib() {
+  let out_dir = env::var("OUT_DIR").unwrap();
+  let target_os = env::var("CARGO_CFG_TARGET_OS").unwrap();
+  let lib_path = if target_os == "windows" {
+    Some(std::path::Path::new(&out_dir).join("SDL2.dll"))
+  } else if target_os == "macos" {
+    let lib_dir = env::var("FERMIUM_SDL2_LIB_DIR")
+      .ok()
+      .or_else(|| {
+        run_pkg_config(&["--variable=libdir", "sdl2"])
+          .map(|libdir| libdir.trim().to_string())
+      })
+      .or_else(|| {
+        let prefix =
+          std::process::Command::new("sdl2-config").arg("--prefix").output();
+        prefix.ok().map(|prefix| {
+          format!("{}/lib", String::from_utf8_lossy(&prefix.stdout).trim())
+        })
+      });
+    lib_dir
+      .map(|lib_dir| std::path::Path::new(&lib_dir).join("libSDL2-2.0.0.dylib"))
+  } else {
+    None
+  };
+  let lib_path = match lib_path {
+    Some(lib_path) if lib_path.exists() => lib_path,
+    _ => {
+      if cfg!(feature = "copy_dll") {
+        println!("cargo:warning=The `copy_dll` feature couldn't find the SDL2 runtime library for this target, so nothing was copied.");
+      }
+      return;
+    }
+  };
+  println!("cargo:dll={}", lib_path.display());
+
+  if cfg!(feature = "copy_dll") {
+    // `OUT_DIR` is `target/<profile>/build/fermium-<hash>/out`. Binaries go in
+    // `target/<profile>`, while tests and examples go in the `deps` and
+    // `examples` folders within that.
+    let profile_dir =
+      std::path::Path::new(&out_dir).ancestors().nth(3).unwrap();
+    let file_name = lib_path.file_name().unwrap();
+    for dir in [
+      profile_dir.to_path_buf(),
+      profile_dir.join("deps"),
+      profile_dir.join("examples"),
+    ] {
+      std::fs::create_dir_all(&dir).unwrap();
+      let lib_to = dir.join(file_name);
+      println!("lib_to:{}", lib_to.display());
+      std::fs::copy(&lib_path, lib_to).unwrap();
+    }
+  }
+}
+
+/// Runs `pkg-config` (or whatever `PKG_CONFIG` names) with the given args.
+///
+/// **Returns:** The output, or `None` if it couldn't run or didn't succeed.
+#[allow(dead_code)]
+fn run_pkg_config(args: &[&str]) -> Option<String> {
+  let pkg_config =
+    env::var("PKG_CONFIG").unwrap_or_else(|_| "pkg-config".into());
+  let output =
+    std::process::Command::new(pkg_config).args(args).output().ok()?;
+  if output.status.success() {
+    Some(String::from_utf8_lossy(&output.stdout).into_owned())
+  } else {
+    None
+  }
+}
+
+/// Passes the `-I` dirs of some cflags along to dependents as
+/// `DEP_SDL2_INCLUDE` (a `PATH`-style list), for any that build C code against
+/// the same SDL2 headers.
+#[allow(dead_code)]
+fn emit_include_dirs(cflags: &str) {
+  let dirs: Vec<&str> = cflags
+    .split_whitespace()
+    .filter_map(|term| term.strip_prefix("-I"))
+    .collect();
+  if let Ok(dirs) = env::join_paths(dirs) {
+    if !dirs.is_empty() {
+      println!("cargo:include={}", dirs.to_string_lossy());
+    }
+  }
+}
+
+/// Delay-loads `SDL2.dll`, for the `delay_load` feature.
+#[allow(dead_code)]
+fn link_delay_load() {
+  // `delayimp` is an ordinary lib, so it carries over to whatever executable
+  // we end up in. The `/DELAYLOAD` flag itself is a link arg, and cargo only
+  // applies those to this package's own targets, so we also pass the DLL name
+  // along as `DEP_SDL2_DELAYLOAD` for the final binary's build script to use.
+  println!("cargo:rustc-link-lib=delayimp");
+  println!("cargo:rustc-link-arg=/DELAYLOAD:SDL2.dll");
+  println!("cargo:delayload=SDL2.dll");
+}
+
+#[cfg(all(
+  feature = "vcpkg",
+  not(any(feature = "static_bundled_build", feature = "prebuilt"))
+))]
+fn do_vcpkg() {
+  println!("Finding SDL2 with vcpkg.");
+
+  let target = env::var("TARGET").expect("Could not read `TARGET`!");
+  println!("target:{}", target);
+
+  let target_arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap();
+  println!("target_arch:{}", target_arch);
+
+  let static_link = link_static();
+  let mut cfg = vcpkg::Config::new();
+  cfg.emit_includes(true).copy_dlls(true);
+
+  // Left to itself the vcpkg crate picks `*-windows-static-md` on MSVC, which
+  // isn't what you'd expect when `static_link` is off. So unless the triplet
+  // was given with `VCPKGRS_TRIPLET` we pick one based on our own features.
+  // On other targets the vcpkg crate's choice is fine.
+  println!("cargo:rerun-if-env-changed=VCPKGRS_TRIPLET");
+  if target.ends_with("windows-msvc")
+    && env::var_os("VCPKGRS_TRIPLET").is_none()
+  {
+    let arch = match target_arch.as_str() {
+      "x86_64" => "x64",
+      "x86" => "x86",
+      "aarch64" => "arm64",
+      arch => panic!("vcpkg has no Windows triplet for `{arch}`, set `VCPKGRS_TRIPLET` to pick one."),
+    };
+    let crt_static = env::var("CARGO_CFG_TARGET_FEATURE")
+      .map(|features| features.split(',').any(|f| f == "crt-static"))
+      .unwrap_or(false);
+    let triplet = match (static_link, crt_static) {
+      (false, _) => format!("{arch}-windows"),
+      (true, true) => format!("{arch}-windows-static"),
+      (true, false) => format!("{arch}-windows-static-md"),
+    };
+    println!("vcpkg triplet:{}", triplet);
+    cfg.target_triplet(triplet);
+  }
+
+  // The vcpkg crate prints all the link lines itself.
+  let sdl2 = cfg.find_package("sdl2").unwrap_or_else(|e| {
+    panic!("Couldn't find SDL2 with vcpkg (`vcpkg install sdl2`): {e}")
+  });
+  println!("vcpkg_triplet:{}", sdl2.vcpkg_triplet);
+  if sdl2.is_static && target.contains("windows") {
+    link_windows_system_libs();
+  }
+  if cfg!(feature = "sdl2main") {
+    // vcpkg puts `SDL2main` in `manual-link`, so that it's not linked to every
+    // program that uses SDL2.
+    for link_path in &sdl2.link_paths {
+      println!(
+        "cargo:rustc-link-search=native={}",
+        link_path.join("manual-link").display()
+      );
+    }
+    println!("cargo:rustc-link-lib=static=SDL2main");
+    if target.contains("windows") {
+      println!("cargo:rustc-link-lib=shell32");
+    }
+  }
+  link_sdl2_test(None);
+
+  let mut dlls = sdl2.found_dlls;
+  for (feature, lib) in extension_libs() {
+    // The ports are named like `sdl2-image`.
+    let port = lib.to_ascii_lowercase().replace('_', "-");
+    let found = cfg.find_package(&port).unwrap_or_else(|e| {
+      panic!("The `{feature}` feature needs {lib}, but vcpkg couldn't find it (`vcpkg install {port}`): {e}")
+    });
+    dlls.extend(found.found_dlls);
+  }
+
+  if cfg!(feature = "delay_load") {
+    if sdl2.is_static {
+      panic!("The `delay_load` feature can't be used with a static vcpkg triplet, there's no DLL to delay.");
+    }
+    link_delay_load();
+  }
+
+  // The DLLs are copied into `OUT_DIR`, but that's not where the final binary
+  // goes, so we also pass their paths along as `DEP_SDL2_VCPKG_DLLS` (a
+  // `PATH`-style list) for the final binary's build script to copy.
+  if !dlls.is_empty() {
+    let dlls = env::join_paths(&dlls).unwrap();
+    println!("cargo:vcpkg_dlls={}", dlls.to_string_lossy());
+  }
+}
+
+/// The version of SDL2's official development archives that `prebuilt` uses.
+#[allow(dead_code)]
+const PREBUILT_VERSION: &str = "2.26.5";
+
+/// The SHA-256 of each of the [`PREBUILT_VERSION`] development archives, as
+/// `(archive name, hash)`, from SDL's releases page.
+///
+/// Every download is checked against this. `FERMIUM_SDL2_PREBUILT_SHA256`
+/// overrides it (eg: for a mirror that repackages the files), and an archive
+/// that isn't listed here can only be used with that set.
+#[cfg(all(feature = "prebuilt", not(feature = "static_bundled_build")))]
+const PREBUILT_SHA256: &[(&str, &str)] = &[
+  // ("SDL2-devel-2.26.5-VC.zip", "<sha-256 from the release page>"),
+  // ("SDL2-devel-2.26.5-mingw.tar.gz", "<sha-256 from the release page>"),
+];
+
+#[cfg(all(feature = "prebuilt", not(feature = "static_bundled_build")))]
+fn do_prebuilt() {
+  use sha2::{Digest, Sha256};
+  use std::path::{Path, PathBuf};
+  use std::process::Command;
+
+  println!("Linking SDL2's official prebuilt development files.");
+
+  let out_dir = env::var("OUT_DIR").unwrap();
+  println!("out_dir:{}", out_dir);
+
+  let target = env::var("TARGET").expect("Could not read `TARGET`!");
+  println!("target:{}", target);
+
+  let target_arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap();
+  println!("target_arch:{}", target_arch);
+
+  let target_env = env::var("CARGO_CFG_TARGET_ENV").unwrap();
+  println!("target_env:{}", target_env);
+
+  if !target.contains("windows") {
+    panic!("The `prebuilt` feature is only for Windows targets, SDL2 doesn't publish development archives for other platforms.");
+  }
+  let msvc = target_env == "msvc";
+  let archive_name = if msvc {
+    format!("SDL2-devel-{PREBUILT_VERSION}-VC.zip")
+  } else {
+    format!("SDL2-devel-{PREBUILT_VERSION}-mingw.tar.gz")
+  };
+
+  // Find the expected SHA-256 before downloading anything, so a missing hash
+  // fails right away. The env var overrides the pinned hash.
+  let expected = match env::var("FERMIUM_SDL2_PREBUILT_SHA256") {
+    Ok(expected) => expected.trim().to_ascii_lowercase(),
+    Err(_) => {
+      let pinned =
+        PREBUILT_SHA256.iter().find(|(name, _)| *name == archive_name);
+      match pinned {
+        Some((_, hash)) => hash.to_string(),
+        None => panic!("There's no known SHA-256 for `{archive_name}`. Set `FERMIUM_SDL2_PREBUILT_SHA256` to the hash from SDL's releases page."),
+      }
+    }
+  };
+
+  // The archives are kept outside of `OUT_DIR` (in the target directory, by
+  // default), so that `cargo clean -p fermium` or a changed feature set doesn't
+  // mean downloading them again.
+  for var in [
+    "FERMIUM_SDL2_PREBUILT_URL",
+    "FERMIUM_SDL2_PREBUILT_CACHE",
+    "FERMIUM_SDL2_PREBUILT_SHA256",
+  ] {
+    println!("cargo:rerun-if-env-changed={var}");
+  }
+  let cache_dir = match env::var_os("FERMIUM_SDL2_PREBUILT_CACHE") {
+    Some(dir) => PathBuf::from(dir),
+    // `OUT_DIR` is `target/<profile>/build/fermium-<hash>/out`
+    None => {
+      Path::new(&out_dir).ancestors().nth(4).unwrap().join("fermium-prebuilt")
+    }
+  };
+  std::fs::create_dir_all(&cache_dir).unwrap();
+  let archive_path = cache_dir.join(&archive_name);
+
+  if !archive_path.exists() {
+    if env::var_os("CARGO_NET_OFFLINE").is_some() {
+      panic!("The `prebuilt` feature needs `{archive_name}`, but cargo is offline. Download it from SDL's releases page into `{}`.", cache_dir.display());
+    }
+    let url = match env::var("FERMIUM_SDL2_PREBUILT_URL") {
+      Ok(base) => format!("{}/{archive_name}", base.trim_end_matches('/')),
+      Err(_) => format!("https://github.com/libsdl-org/SDL/releases/download/release-{PREBUILT_VERSION}/{archive_name}"),
+    };
+    // `curl` ships with Windows 10 and later. Download to a temp name first
+    // so that an interrupted download never looks like a cached archive.
+    let partial_path = cache_dir.join(format!("{archive_name}.part"));
+    let status = Command::new("curl")
+      .args(["--fail", "--location", "--silent", "--show-error", "--output"])
+      .arg(&partial_path)
+      .arg(&url)
+      .status()
+      .unwrap_or_else(|_| panic!("Couldn't run `curl` to download `{url}`."));
+    if !status.success() {
+      panic!("Couldn't download `{url}`.");
+    }
+    std::fs::rename(&partial_path, &archive_path).unwrap();
+  }
+
+  // Check the archive (downloaded or cached) against the expected hash.
+  let bytes = std::fs::read(&archive_path).unwrap();
+  let actual = format!("{:x}", Sha256::digest(&bytes));
+  if expected != actual {
+    panic!("`{}` has SHA-256 `{actual}`, but `{expected}` was expected. Delete it to download it again.", archive_path.display());
+  }
+
+  // `tar` also ships with Windows 10 and later, and it can unpack zip files.
+  let extract_dir = cache_dir.join(archive_name.replace('.', "_"));
+  let sdl_dir = extract_dir.join(format!("SDL2-{PREBUILT_VERSION}"));
+  if !sdl_dir.exists() {
+    std::fs::create_dir_all(&extract_dir).unwrap();
+    let status = Command::new("tar")
+      .arg("-xf")
+      .arg(&archive_path)
+      .arg("-C")
+      .arg(&extract_dir)
+      .status()
       .unwrap_or_else(|_| {
-        panic!("Couldn't run `sdl2-config --libs`, is SDL2 properly installed?")
+        panic!("Couldn't run `tar` to unpack `{archive_name}`.")
       });
-    assert!(sd2_config_linking.status.success());
+    if !status.success() {
+      panic!("Couldn't unpack `{}`.", archive_path.display());
+    }
+  }
 
-    let sd2_config_linking_stdout: String =
-      String::from_utf8_lossy(&sd2_config_linking.stdout).into_owned();
-    println!("sd2_config_linking_stdout: {}", sd2_config_linking_stdout);
-    assert!(sd2_config_linking_stdout.len() > 0);
+  let (lib_dir, bin_dir) = if msvc {
+    let arch = match target_arch.as_str() {
+      "x86_64" => "x64",
+      "x86" => "x86",
+      arch => {
+        panic!("SDL2's VC development files don't have `{arch}` libraries.")
+      }
+    };
+    let dir = sdl_dir.join("lib").join(arch);
+    (dir.clone(), dir)
+  } else {
+    let triple = match target_arch.as_str() {
+      "x86_64" => "x86_64-w64-mingw32",
+      "x86" => "i686-w64-mingw32",
+      arch => {
+        panic!("SDL2's mingw development files don't have `{arch}` libraries.")
+      }
+    };
+    (sdl_dir.join(triple).join("lib"), sdl_dir.join(triple).join("bin"))
+  };
+  println!("cargo:rustc-link-search=native={}", lib_dir.display());
+  println!(
+    "cargo:include={}",
+    if msvc {
+      sdl_dir.join("include")
+    } else {
+      lib_dir.join("..").join("include").join("SDL2")
+    }
+    .display()
+  );
 
-    process_sdl2_config_output(sd2_config_linking_stdout);
+  if cfg!(feature = "sdl2main") {
+    println!("cargo:rustc-link-lib=static=SDL2main");
+    println!("cargo:rustc-link-lib=shell32");
+  }
+  link_sdl2_test(msvc.then_some(lib_dir.as_path()));
+  if link_static() {
+    if msvc {
+      panic!("SDL2's VC development files don't have a static library, use the `static_bundled_build` feature instead.");
+    }
+    println!("cargo:rustc-link-lib=static=SDL2");
+    link_windows_system_libs();
+  } else {
+    // Same as with the bundled developer files, copy the DLL into the out
+    // directory.
+    let dll_to = Path::new(&out_dir).join("SDL2.dll");
+    std::fs::copy(bin_dir.join("SDL2.dll"), dll_to).unwrap();
     println!("cargo:rustc-link-lib=SDL2");
+    if cfg!(feature = "delay_load") {
+      link_delay_load();
+    }
+  }
+  if msvc {
+    link_extension_libs(Some(&lib_dir));
+  } else {
+    link_extension_libs(None);
   }
 }
 
+/// The Windows system libraries that a static SDL2 depends on.
+///
+/// This matches the `EXTRA_LIBS` that SDL's own CMake build uses on Windows.
+#[allow(dead_code)]
+fn link_windows_system_libs() {
+  for lib in [
+    "user32", "gdi32", "winmm", "imm32", "ole32", "oleaut32", "version",
+    "uuid", "advapi32", "setupapi", "shell32",
+  ] {
+    println!("cargo:rustc-link-lib={lib}");
+  }
+}
+
+/// Links SDL's test library (`SDL2_test`) when the `sdltest` feature is on.
+///
+/// Like SDL2main this is only ever a static lib, so it must come before SDL2
+/// in the link order. SDL's official VC development files name it
+/// `SDL2test.lib` rather than `SDL2_test.lib`, so when a Windows MSVC
+/// `devel_path` is given either name is accepted there.
+#[allow(dead_code)]
+fn link_sdl2_test(devel_path: Option<&std::path::Path>) {
+  if !cfg!(feature = "sdltest") {
+    return;
+  }
+  match devel_path {
+    Some(devel_path) if devel_path.join("SDL2test.lib").exists() => {
+      println!("cargo:rustc-link-lib=static=SDL2test");
+    }
+    Some(devel_path) if !devel_path.join("SDL2_test.lib").exists() => {
+      panic!("The `sdltest` feature needs `SDL2test.lib` in `{}`. Copy it from SDL's official VC development files, or use the `static_bundled_build` feature instead.", devel_path.display());
+    }
+    _ => println!("cargo:rustc-link-lib=static=SDL2_test"),
+  }
+}
+
+/// The `(feature, library)` pairs of the SDL2 extension libraries that are
+/// enabled.
+fn extension_libs() -> impl Iterator<Item = (&'static str, &'static str)> {
+  [
+    (cfg!(feature = "image"), "image", "SDL2_image"),
+    (cfg!(feature = "mixer"), "mixer", "SDL2_mixer"),
+    (cfg!(feature = "ttf"), "ttf", "SDL2_ttf"),
+    (cfg!(feature = "net"), "net", "SDL2_net"),
+    (cfg!(feature = "gfx"), "gfx", "SDL2_gfx"),
+  ]
+  .into_iter()
+  .filter(|(enabled, _, _)| *enabled)
+  .map(|(_, feature, lib)| (feature, lib))
+}
+
+/// Links the extension libraries (SDL2_image, etc) of any enabled features.
+///
+/// These are never bundled, so they always link dynamically. On Windows MSVC
+/// the `.lib` and `.dll` files must be placed in the developer files folder
+/// (which is passed as `devel_path`), next to SDL2's own files.
+#[allow(dead_code)]
+fn link_extension_libs(devel_path: Option<&std::path::Path>) {
+  for (feature, lib) in extension_libs() {
+    if let Some(devel_path) = devel_path {
+      if !devel_path.join(format!("{lib}.lib")).exists() {
+        panic!("The `{feature}` feature needs `{lib}.lib` and `{lib}.dll` in `{}`. Copy them from {lib}'s official VC development files.", devel_path.display());
+      }
+      // Same as with `SDL2.dll`, copy the DLL into the out directory.
+      let dll_name = format!("{lib}.dll");
+      let out_dir = env::var("OUT_DIR").unwrap();
+      let dll_to = std::path::Path::new(&out_dir).join(&dll_name);
+      std::fs::copy(devel_path.join(&dll_name), dll_to).unwrap();
+    } else if let Some(pkg_config_linking_stdout) =
+      run_pkg_config(&["--libs", lib])
+    {
+      // This gets us the library's own search path, which isn't always the
+      // same as SDL2's.
+      process_sdl2_config_output(pkg_config_linking_stdout);
+    }
+    println!("cargo:rustc-link-lib={lib}");
+  }
+}
+
+fn do_sdl3() {
+  println!("Performing dynamically-linked build against the system SDL3.");
+
+  if cfg!(feature = "static_bundled_build") {
+    panic!("The `sdl3` feature can't be combined with `static_bundled_build`, there's no bundled SDL3 source.");
+  }
+
+  // SDL3 doesn't ship an `sdl3-config` script, it only provides a pkg-config
+  // file, so that's what we use here.
+  let pkg_config_linking = std::process::Command::new("pkg-config")
+    .arg("--libs")
+    .arg("sdl3")
+    .output()
+    .unwrap_or_else(|_| {
+      panic!("Couldn't run `pkg-config --libs sdl3`, is pkg-config installed?")
+    });
+  if !pkg_config_linking.status.success() {
+    panic!(
+      "`pkg-config --libs sdl3` failed, is SDL3 properly installed?\n{}",
+      String::from_utf8_lossy(&pkg_config_linking.stderr)
+    );
+  }
+
+  let pkg_config_linking_stdout: String =
+    String::from_utf8_lossy(&pkg_config_linking.stdout).into_owned();
+  println!("pkg_config_linking_stdout: {}", pkg_config_linking_stdout);
+  assert!(pkg_config_linking_stdout.len() > 0);
+
+  process_sdl2_config_output(pkg_config_linking_stdout);
+}
+
 /// THE CALLER HAS TO HANDLE THE LINK TO SDL2 ITSELF (static or dynamic).
 fn process_sdl2_config_output(sd2_config_linking_stdout: String) {
   for term in sd2_config_linking_stdout.split_whitespace() {
@@ -0,0 +1,214 @@
+//! Assertions that report through SDL's assertion handler.
+//!
+//! In C these are all macros. Here the breakpoint is an inline function and
+//! the assertions themselves are the [`sdl_assert!`](crate::sdl_assert) and
+//! [`sdl_assert_release!`](crate::sdl_assert_release) macros, which keep a
+//! static [`SDL_AssertData`] for each call site just like the C versions.
+
+use crate::{c_char, c_int, c_uint, c_void};
+
+// makes rustdoc link properly!
+#[allow(unused)]
+use crate::*;
+
+/// The response that an assertion handler gives for a failed assertion.
+///
+/// See the `SDL_ASSERTION_*` constants.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct SDL_AssertState(pub c_int);
+
+/// Retry the assert immediately.
+pub const SDL_ASSERTION_RETRY: SDL_AssertState = SDL_AssertState(0);
+/// Make the debugger trigger a breakpoint.
+pub const SDL_ASSERTION_BREAK: SDL_AssertState = SDL_AssertState(1);
+/// Terminate the program.
+pub const SDL_ASSERTION_ABORT: SDL_AssertState = SDL_AssertState(2);
+/// Ignore the assert.
+pub const SDL_ASSERTION_IGNORE: SDL_AssertState = SDL_AssertState(3);
+/// Ignore the assert from now on.
+pub const SDL_ASSERTION_ALWAYS_IGNORE: SDL_AssertState = SDL_AssertState(4);
+
+/// Info about a single assertion site.
+///
+/// Each assertion macro keeps one of these in a `static`, and SDL links the
+/// ones that have failed together into the list returned by
+/// [`SDL_GetAssertionReport`]. Other than the `condition`, SDL fills in all of
+/// the fields itself the first time the assertion fails.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct SDL_AssertData {
+  /// Non-zero if the assertion has been set to always be ignored.
+  pub always_ignore: c_int,
+  /// How many times the assertion has failed.
+  pub trigger_count: c_uint,
+  /// The text of the condition, null terminated.
+  pub condition: *const c_char,
+  /// The file that the assertion is in, null terminated.
+  pub filename: *const c_char,
+  /// The line that the assertion is on.
+  pub linenum: c_int,
+  /// The function that the assertion is in, null terminated.
+  pub function: *const c_char,
+  /// The next failed assertion in the report, or null.
+  pub next: *const SDL_AssertData,
+}
+impl Default for SDL_AssertData {
+  #[inline]
+  fn default() -> Self {
+    unsafe { core::mem::zeroed() }
+  }
+}
+
+/// A callback that fires when an SDL assertion fails.
+///
+/// * `data` the [`SDL_AssertData`] of the assertion that failed.
+/// * `userdata` what was passed as `userdata` to [`SDL_SetAssertionHandler`].
+///
+/// **Returns:** How the failure should be handled.
+pub type SDL_AssertionHandler = Option<
+  unsafe extern "C" fn(
+    data: *const SDL_AssertData,
+    userdata: *mut c_void,
+  ) -> SDL_AssertState,
+>;
+
+extern "C" {
+  /// Reports a failed assertion to the current assertion handler.
+  ///
+  /// Never call this directly, use the assertion macros.
+  pub fn SDL_ReportAssertion(
+    data: *mut SDL_AssertData, func: *const c_char, file: *const c_char,
+    line: c_int,
+  ) -> SDL_AssertState;
+
+  /// Set an application-defined assertion handler.
+  ///
+  /// If the application doesn't provide this, SDL will try to do the right
+  /// thing, popping up a system-specific GUI dialog, and probably minimizing
+  /// any fullscreen windows.
+  ///
+  /// This callback may fire from any thread, but it runs wrapped in a mutex,
+  /// so it will only fire from one thread at a time.
+  ///
+  /// This callback is NOT reset to SDL's internal handler upon [`SDL_Quit`].
+  ///
+  /// * `handler` the function to call when an assertion fails, or `None` for
+  ///   the default handler.
+  /// * `userdata` a pointer that is passed to `handler`.
+  pub fn SDL_SetAssertionHandler(
+    handler: SDL_AssertionHandler, userdata: *mut c_void,
+  );
+
+  /// Get the default assertion handler.
+  ///
+  /// This is the handler that SDL uses when [`SDL_SetAssertionHandler`] hasn't
+  /// been used to provide a different function.
+  pub fn SDL_GetDefaultAssertionHandler() -> SDL_AssertionHandler;
+
+  /// Get the current assertion handler.
+  ///
+  /// * `puserdata` is filled with the `userdata` pointer that was passed to
+  ///   [`SDL_SetAssertionHandler`]. This is always null for the default
+  ///   handler. You can pass null if you don't care about the userdata.
+  pub fn SDL_GetAssertionHandler(
+    puserdata: *mut *mut c_void,
+  ) -> SDL_AssertionHandler;
+
+  /// Get a list of all assertion failures.
+  ///
+  /// This gets all assertions triggered since the last call to
+  /// [`SDL_ResetAssertionReport`], or the start of the program. Walk the list
+  /// by following the `next` field of each entry.
+  ///
+  /// **Returns:** The first failed assertion, or null if the list is empty.
+  /// This memory should not be modified or freed by the application.
+  pub fn SDL_GetAssertionReport() -> *const SDL_AssertData;
+
+  /// Clear the list of all assertion failures.
+  ///
+  /// Any previously-triggered assertions will also be reset to a
+  /// `trigger_count` of zero, and their `always_ignore` state will be false.
+  pub fn SDL_ResetAssertionReport();
+}
+
+/// Makes an attached debugger stop at the current location.
+///
+/// This is `int3` on x86, `brk` on AArch64, and `bkpt` on ARM. On other
+/// targets this does nothing, just like SDL's fallback.
+///
+/// If no debugger is attached the OS will usually kill the process.
+#[inline(always)]
+pub fn SDL_TriggerBreakpoint() {
+  #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+  unsafe {
+    core::arch::asm!("int3", options(nomem, nostack));
+  }
+  #[cfg(target_arch = "aarch64")]
+  unsafe {
+    core::arch::asm!("brk #22", options(nomem, nostack));
+  }
+  #[cfg(target_arch = "arm")]
+  unsafe {
+    core::arch::asm!("bkpt #22", options(nomem, nostack));
+  }
+}
+
+/// Like `SDL_assert`: checks the condition in debug builds only.
+///
+/// With `debug_assertions` off the condition is still type checked but is
+/// never evaluated, so it must not have side effects you rely on.
+///
+/// See [`sdl_assert_release!`](crate::sdl_assert_release) for the details of
+/// what happens when the condition fails.
+#[macro_export]
+macro_rules! sdl_assert {
+  ($condition:expr $(,)?) => {
+    if cfg!(debug_assertions) {
+      $crate::sdl_assert_release!($condition);
+    }
+  };
+}
+
+/// Like `SDL_assert_release`: checks the condition in all builds.
+///
+/// When the condition is false the failure is passed to
+/// [`SDL_ReportAssertion`](crate::assert::SDL_ReportAssertion), which calls
+/// the current assertion handler. Depending on what the handler returns, the
+/// condition is checked again, a breakpoint is triggered, or execution just
+/// continues. (The "abort" response is handled inside of SDL.)
+///
+/// The "function" reported to SDL is the module path of the call site, since
+/// Rust has no way to name the current function.
+#[macro_export]
+macro_rules! sdl_assert_release {
+  ($condition:expr $(,)?) => {
+    while !($condition) {
+      static mut SDL_ASSERT_DATA: $crate::assert::SDL_AssertData =
+        $crate::assert::SDL_AssertData {
+          always_ignore: 0,
+          trigger_count: 0,
+          condition: concat!(stringify!($condition), "\0").as_ptr().cast(),
+          filename: ::core::ptr::null(),
+          linenum: 0,
+          function: ::core::ptr::null(),
+          next: ::core::ptr::null(),
+        };
+      let sdl_assert_state = unsafe {
+        $crate::assert::SDL_ReportAssertion(
+          ::core::ptr::addr_of_mut!(SDL_ASSERT_DATA),
+          concat!(module_path!(), "\0").as_ptr().cast(),
+          concat!(file!(), "\0").as_ptr().cast(),
+          line!() as $crate::c_int,
+        )
+      };
+      if sdl_assert_state == $crate::assert::SDL_ASSERTION_RETRY {
+        continue;
+      } else if sdl_assert_state == $crate::assert::SDL_ASSERTION_BREAK {
+        $crate::assert::SDL_TriggerBreakpoint();
+      }
+      break;
+    }
+  };
+}
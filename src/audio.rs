@@ -1,6 +1,9 @@
 //! Audio playback.
 
-use crate::{c_char, c_int, c_void, rwops::*, stdinc::*};
+use crate::{
+  c_char, c_int, c_void, error::*, hints::*, rwops::*, stdinc::*,
+  SDL_InitSubSystem, SDL_QuitSubSystem, SDL_INIT_AUDIO,
+};
 
 /// Audio format flags.
 ///
@@ -19,32 +22,60 @@ use crate::{c_char, c_int, c_void, rwops::*, stdinc::*};
 /// ```
 /// (Unspecified bits are always zero.)
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_AudioFormat(pub u16);
 
-/// Pits per sample. eg: i16 = 16, f32 = 32.
+/// The bits of an [`SDL_AudioFormat`] that hold the sample bit size.
+pub const SDL_AUDIO_MASK_BITSIZE: u16 = 0xFF;
+/// The bit of an [`SDL_AudioFormat`] that's set for float samples.
+pub const SDL_AUDIO_MASK_DATATYPE: u16 = 1 << 8;
+/// The bit of an [`SDL_AudioFormat`] that's set for big-endian samples.
+pub const SDL_AUDIO_MASK_ENDIAN: u16 = 1 << 12;
+/// The bit of an [`SDL_AudioFormat`] that's set for signed samples.
+pub const SDL_AUDIO_MASK_SIGNED: u16 = 1 << 15;
+
+/// Builds an audio format value out of its parts.
+///
+/// This is the layout that all of the `AUDIO_*` constants follow, eg:
+/// `SDL_DEFINE_AUDIO_FORMAT(true, false, false, 16)` is [`AUDIO_S16LSB`].
+/// (SDL2 has no macro for this, the name is the one SDL3 uses.)
+#[inline]
+#[must_use]
+pub const fn SDL_DEFINE_AUDIO_FORMAT(
+  signed: bool, bigendian: bool, float: bool, size: u8,
+) -> SDL_AudioFormat {
+  SDL_AudioFormat(
+    (signed as u16) << 15
+      | (bigendian as u16) << 12
+      | (float as u16) << 8
+      | (size as u16 & SDL_AUDIO_MASK_BITSIZE),
+  )
+}
+
+/// Bits per sample. eg: i16 = 16, f32 = 32.
 #[inline]
 #[must_use]
 pub const fn SDL_AUDIO_BITSIZE(af: SDL_AudioFormat) -> u16 {
-  af.0 & 0xFF
+  af.0 & SDL_AUDIO_MASK_BITSIZE
 }
 /// If the sample type is a floating type.
 #[inline]
 #[must_use]
 pub const fn SDL_AUDIO_ISFLOAT(af: SDL_AudioFormat) -> bool {
-  af.0 & (1 << 8) != 0
+  af.0 & SDL_AUDIO_MASK_DATATYPE != 0
 }
 /// If the samples are big-endian.
 #[inline]
 #[must_use]
 pub const fn SDL_AUDIO_ISBIGENDIAN(af: SDL_AudioFormat) -> bool {
-  af.0 & (1 << 12) != 0
+  af.0 & SDL_AUDIO_MASK_ENDIAN != 0
 }
 /// If the samples are signed values.
 #[inline]
 #[must_use]
 pub const fn SDL_AUDIO_ISSIGNED(af: SDL_AudioFormat) -> bool {
-  af.0 & (1 << 15) != 0
+  af.0 & SDL_AUDIO_MASK_SIGNED != 0
 }
 /// If the samples are an int type.
 #[inline]
@@ -72,9 +103,9 @@ pub const AUDIO_S8: SDL_AudioFormat = SDL_AudioFormat(0x8008);
 pub const AUDIO_U16LSB: SDL_AudioFormat = SDL_AudioFormat(0x0010);
 /// Signed 16-bit samples
 pub const AUDIO_S16LSB: SDL_AudioFormat = SDL_AudioFormat(0x8010);
-/// As [`AUDIO_S16LSB`], but big-endian byte order
+/// As [`AUDIO_U16LSB`], but big-endian byte order
 pub const AUDIO_U16MSB: SDL_AudioFormat = SDL_AudioFormat(0x1010);
-/// As [`AUDIO_U16MSB`], but big-endian byte order
+/// As [`AUDIO_S16LSB`], but big-endian byte order
 pub const AUDIO_S16MSB: SDL_AudioFormat = SDL_AudioFormat(0x9010);
 /// Alias for [`AUDIO_U16LSB`]
 pub const AUDIO_U16: SDL_AudioFormat = AUDIO_U16LSB;
@@ -82,7 +113,7 @@ pub const AUDIO_U16: SDL_AudioFormat = AUDIO_U16LSB;
 pub const AUDIO_S16: SDL_AudioFormat = AUDIO_S16LSB;
 /// 32-bit integer samples
 pub const AUDIO_S32LSB: SDL_AudioFormat = SDL_AudioFormat(0x8020);
-/// As [`AUDIO_S32MSB`], but big-endian byte order
+/// As [`AUDIO_S32LSB`], but big-endian byte order
 pub const AUDIO_S32MSB: SDL_AudioFormat = SDL_AudioFormat(0x9020);
 /// Alias for [`AUDIO_S32LSB`];
 pub const AUDIO_S32: SDL_AudioFormat = AUDIO_S32LSB;
@@ -105,6 +136,22 @@ pub const AUDIO_S32SYS: SDL_AudioFormat =
 pub const AUDIO_F32SYS: SDL_AudioFormat =
   if cfg!(target_endian = "little") { AUDIO_F32LSB } else { AUDIO_F32MSB };
 
+/// Allows [`SDL_OpenAudioDevice`] to give a different `freq` than requested.
+pub const SDL_AUDIO_ALLOW_FREQUENCY_CHANGE: c_int = 0x00000001;
+/// Allows [`SDL_OpenAudioDevice`] to give a different `format` than requested.
+pub const SDL_AUDIO_ALLOW_FORMAT_CHANGE: c_int = 0x00000002;
+/// Allows [`SDL_OpenAudioDevice`] to give a different `channels` than
+/// requested.
+pub const SDL_AUDIO_ALLOW_CHANNELS_CHANGE: c_int = 0x00000004;
+/// Allows [`SDL_OpenAudioDevice`] to give a different `samples` than
+/// requested.
+pub const SDL_AUDIO_ALLOW_SAMPLES_CHANGE: c_int = 0x00000008;
+/// Allows [`SDL_OpenAudioDevice`] to change any of the spec.
+pub const SDL_AUDIO_ALLOW_ANY_CHANGE: c_int = SDL_AUDIO_ALLOW_FREQUENCY_CHANGE
+  | SDL_AUDIO_ALLOW_FORMAT_CHANGE
+  | SDL_AUDIO_ALLOW_CHANNELS_CHANGE
+  | SDL_AUDIO_ALLOW_SAMPLES_CHANGE;
+
 /// This function is called when the audio device needs more data.
 ///
 /// * `userdata` An application-specific parameter saved in the `SDL_AudioSpec`
@@ -138,6 +185,15 @@ pub type SDL_AudioCallback = Option<
 /// 7:  FL FR FC LFE BC SL SR       (6.1 surround)
 /// 8:  FL FR FC LFE BL BR SL SR    (7.1 surround)
 /// ```
+///
+/// The `Default` spec is 48kHz, native-endian `f32`, stereo, with 1024 sample
+/// frames per buffer, and no callback (so you use [`SDL_QueueAudio`]). Change
+/// just the parts you care about with struct update syntax:
+/// ```
+/// # use fermium::prelude::*;
+/// let desired = SDL_AudioSpec { freq: 44100, ..Default::default() };
+/// ```
+#[derive(Debug, Clone, Copy)]
 #[repr(C)]
 pub struct SDL_AudioSpec {
   /// DSP frequency -- samples per second
@@ -160,6 +216,22 @@ pub struct SDL_AudioSpec {
   /// Userdata passed to callback (ignored for NULL callbacks).
   pub userdata: *mut c_void,
 }
+impl Default for SDL_AudioSpec {
+  #[inline]
+  fn default() -> Self {
+    Self {
+      freq: 48000,
+      format: AUDIO_F32SYS,
+      channels: 2,
+      silence: 0,
+      samples: 1024,
+      padding: 0,
+      size: 0,
+      callback: None,
+      userdata: core::ptr::null_mut(),
+    }
+  }
+}
 
 /// Filter function used by [`SDL_AudioCVT`]
 pub type SDL_AudioFilter =
@@ -215,6 +287,7 @@ pub struct SDL_AudioDeviceID(pub u32);
 
 /// The status of an audio device.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_AudioStatus(pub u32);
 #[allow(missing_docs)]
@@ -231,6 +304,11 @@ pub const SDL_AUDIO_PAUSED: SDL_AudioStatus = SDL_AudioStatus(2);
 ///   when it doesn't have the complete buffer available.
 /// * it can handle incoming data in any variable size.
 /// * You push data as you have it, and pull it when you need it.
+///
+/// Make a stream with [`SDL_NewAudioStream`], push data in with
+/// [`SDL_AudioStreamPut`], and pull converted data out with
+/// [`SDL_AudioStreamGet`]. Free it with [`SDL_FreeAudioStream`] when you're
+/// done.
 #[allow(unused)]
 #[repr(transparent)]
 pub struct SDL_AudioStream(c_void);
@@ -245,7 +323,17 @@ extern "C" {
   /// The name of an audio driver. See [`SDL_GetAudioDriver`]
   pub fn SDL_GetAudioDriver(index: c_int) -> *const c_char;
 
-  // skipped because they're mostly internal use: SDL_AudioInit / SDL_AudioQuit
+  /// Initializes the audio subsystem with a particular driver.
+  ///
+  /// Mostly for internal use, you'd usually call [`SDL_InitSubSystem`] with
+  /// [`SDL_INIT_AUDIO`] instead (and pick the driver with
+  /// [`SDL_HINT_AUDIODRIVER`]).
+  ///
+  /// **Returns:** 0 on success, or negative on error.
+  pub fn SDL_AudioInit(driver_name: *const c_char) -> c_int;
+
+  /// Shuts down the audio subsystem started with [`SDL_AudioInit`].
+  pub fn SDL_AudioQuit();
 
   /// The name of the current audio driver (or null if no driver is
   /// initialized).
@@ -473,7 +561,12 @@ extern "C" {
   /// * `dst_channels` The number of channels of the desired audio output
   /// * `dst_rate` The sampling rate of the desired audio output
   ///
-  /// **Return:** 0 on success, or -1 on error.
+  /// **Return:** The new stream, or NULL on error (call [`SDL_GetErrorMsg`]
+  /// for more information).
+  ///
+  /// See Also: [`SDL_AudioStreamPut`], [`SDL_AudioStreamGet`],
+  /// [`SDL_AudioStreamAvailable`], [`SDL_AudioStreamFlush`],
+  /// [`SDL_AudioStreamClear`], [`SDL_FreeAudioStream`]
   pub fn SDL_NewAudioStream(
     src_format: SDL_AudioFormat, src_channels: Uint8, src_rate: c_int,
     dst_format: SDL_AudioFormat, dst_channels: Uint8, dst_rate: c_int,
@@ -743,9 +836,44 @@ extern "C" {
   /// * **Returns:** 0 on success, nonzero on error
   ///
   /// See Also: [SDL_GetNumAudioDevices]
+  #[cfg(feature = "sdl2_2_0_16")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_16")))]
   pub fn SDL_GetAudioDeviceSpec(
     index: c_int, iscapture: c_int, spec: *mut SDL_AudioSpec,
   ) -> c_int;
+
+  /// Get the name and preferred format of the default audio device.
+  ///
+  /// Some (but not all!) platforms have an isolated mechanism to get
+  /// information about the "default" device. This can actually be a
+  /// completely different device that's not in the list you get from
+  /// [`SDL_GetAudioDeviceSpec`]. It can even be a network address! (This is
+  /// discussed in [`SDL_OpenAudioDevice`].)
+  ///
+  /// As a result, this call is not guaranteed to be performant, as it can
+  /// query the sound server directly every time, unlike the other query
+  /// functions. You should call this function sparingly!
+  ///
+  /// `spec` will be filled with the sample rate, sample format, and channel
+  /// count, if a default device exists on the system. If `name` is provided,
+  /// it will be filled with either a dynamically-allocated UTF-8 string or
+  /// NULL.
+  ///
+  /// * `name` A pointer to be filled with the name of the default device (can
+  ///   be NULL). Please call [`SDL_free`] when you are done with this pointer!
+  /// * `spec` The [`SDL_AudioSpec`] to be initialized by this function.
+  /// * `iscapture` non-zero to query the default recording device, zero to
+  ///   query the default output device.
+  ///
+  /// **Returns:** 0 on success, nonzero on error
+  ///
+  /// See Also: [`SDL_GetAudioDeviceName`], [`SDL_GetAudioDeviceSpec`],
+  /// [`SDL_OpenAudioDevice`]
+  #[cfg(feature = "sdl2_2_24")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_24")))]
+  pub fn SDL_GetDefaultAudioInfo(
+    name: *mut *mut c_char, spec: *mut SDL_AudioSpec, iscapture: c_int,
+  ) -> c_int;
 }
 
 /// Loads a WAV file.
@@ -765,3 +893,103 @@ pub unsafe fn SDL_LoadWAV(
     audio_len,
   )
 }
+
+/// Starts the audio subsystem with a driver that doesn't need a sound card.
+///
+/// This is meant for running tests on a CI machine. It sets
+/// [`SDL_HINT_AUDIODRIVER`], initializes [`SDL_INIT_AUDIO`], and then checks
+/// that SDL really did pick the requested driver.
+///
+/// * When `disk` is false the "dummy" driver is used, which just throws away
+///   all output (and records silence).
+/// * When `disk` is true the "disk" driver is used, which writes all output to
+///   a file instead. The file is named by the `SDL_DISKAUDIOFILE` environment
+///   variable, or "sdlaudio.raw" by default.
+///
+/// The hint is only read by SDL 2.0.22 or later. With an older SDL, set the
+/// `SDL_AUDIODRIVER` environment variable instead, and this will still do the
+/// check.
+///
+/// **Returns:** 0 on success, or negative on error (call [`SDL_GetErrorMsg`]).
+pub unsafe fn init_headless_audio(disk: bool) -> c_int {
+  let driver: &[u8] = if disk { b"disk\0" } else { b"dummy\0" };
+  SDL_SetHint(SDL_HINT_AUDIODRIVER.as_ptr().cast(), driver.as_ptr().cast());
+  if SDL_InitSubSystem(SDL_INIT_AUDIO) < 0 {
+    return -1;
+  }
+  let current = SDL_GetCurrentAudioDriver();
+  if !current.is_null()
+    && core::ffi::CStr::from_ptr(current).to_bytes_with_nul() == driver
+  {
+    return 0;
+  }
+  SDL_QuitSubSystem(SDL_INIT_AUDIO);
+  SDL_SetError(b"Couldn't start a headless audio driver\0".as_ptr().cast())
+}
+
+/// Opens an audio device, giving back the device and the spec it really has.
+///
+/// This is [`SDL_OpenAudioDevice`] with the output spec returned rather than
+/// written through a pointer. When `allowed_changes` is 0 SDL converts between
+/// your `desired` spec and the hardware behind the scenes, so the obtained spec
+/// will match what you asked for. Otherwise, use the obtained spec for your
+/// data. See the `SDL_AUDIO_ALLOW_*` constants.
+///
+/// * `device` is a device name from [`SDL_GetAudioDeviceName`], or null for
+///   the default device.
+///
+/// ## Safety
+/// * The audio subsystem must be initialized.
+/// * If `desired` has a callback, it must be sound to call with `userdata`
+///   from SDL's audio thread until the device is closed.
+pub unsafe fn open_audio_device(
+  device: *const c_char, iscapture: bool, desired: &SDL_AudioSpec,
+  allowed_changes: c_int,
+) -> Result<(SDL_AudioDeviceID, SDL_AudioSpec), SdlError> {
+  let mut obtained = SDL_AudioSpec::default();
+  let id = SDL_OpenAudioDevice(
+    device,
+    iscapture as c_int,
+    desired,
+    &mut obtained,
+    allowed_changes,
+  );
+  if id.0 == 0 {
+    Err(SdlError::get())
+  } else {
+    Ok((id, obtained))
+  }
+}
+
+/// Gets the name and preferred spec of the default audio device.
+///
+/// This is [`SDL_GetDefaultAudioInfo`] with the name copied into a `String`
+/// (and SDL's copy freed for you). The name is `None` if the platform doesn't
+/// report one.
+///
+/// * `iscapture` picks the default recording device rather than the default
+///   output device.
+#[cfg(all(feature = "alloc", feature = "sdl2_2_24"))]
+#[cfg_attr(docs_rs, doc(cfg(all(feature = "alloc", feature = "sdl2_2_24"))))]
+pub fn default_audio_info(
+  iscapture: bool,
+) -> Result<(Option<alloc::string::String>, SDL_AudioSpec), SdlError> {
+  let mut name: *mut c_char = core::ptr::null_mut();
+  let mut spec = SDL_AudioSpec::default();
+  unsafe {
+    if SDL_GetDefaultAudioInfo(&mut name, &mut spec, iscapture as c_int) != 0 {
+      return Err(SdlError::get());
+    }
+    let name = if name.is_null() {
+      None
+    } else {
+      let s = alloc::string::String::from_utf8_lossy(
+        core::ffi::CStr::from_ptr(name).to_bytes(),
+      )
+      .into_owned();
+      SDL_free(name.cast());
+      Some(s)
+    };
+    Ok((name, spec))
+  }
+}
@@ -4,6 +4,7 @@
 ///
 /// See `SDL_BLENDMODE_*` for examples.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_BlendMode(pub u32);
 
@@ -43,6 +44,7 @@ pub const SDL_BLENDMODE_INVALID: SDL_BlendMode = SDL_BlendMode(0x7FFFFFFF);
 ///
 /// See `SDL_BLENDOPERATION_*` for examples.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_BlendOperation(pub u32);
 
@@ -65,6 +67,7 @@ pub const SDL_BLENDOPERATION_MAXIMUM: SDL_BlendOperation =
 ///
 /// See the `SDL_BLENDFACTOR_*` constants for values of this type.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_BlendFactor(pub u32);
 
@@ -2,6 +2,11 @@
 
 use crate::{c_char, c_int, stdinc::*};
 
+#[cfg(feature = "alloc")]
+use crate::error::*;
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
 extern "C" {
   /// Put UTF-8 text into the clipboard
   pub fn SDL_SetClipboardText(text: *const c_char) -> c_int;
@@ -13,3 +18,57 @@ extern "C" {
   /// string that is non-empty.
   pub fn SDL_HasClipboardText() -> SDL_bool;
 }
+
+/// Gets the clipboard's text as a `String`.
+///
+/// This copies the text out of SDL's buffer and then frees that buffer with
+/// [`SDL_free`], so there's nothing for you to clean up. Any invalid UTF-8 is
+/// replaced with `U+FFFD`.
+///
+/// **Returns:** `None` if the clipboard has no text, or if the text couldn't
+/// be read (eg: the video subsystem isn't initialized).
+#[cfg(feature = "alloc")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "alloc")))]
+#[must_use]
+pub fn get_clipboard_text() -> Option<String> {
+  let p = unsafe { SDL_GetClipboardText() };
+  if p.is_null() {
+    return None;
+  }
+  let text = unsafe { core::ffi::CStr::from_ptr(p) };
+  let out = if text.to_bytes().is_empty() {
+    None
+  } else {
+    Some(String::from_utf8_lossy(text.to_bytes()).into_owned())
+  };
+  unsafe { SDL_free(p.cast()) };
+  out
+}
+
+/// Puts text into the clipboard.
+///
+/// The null terminator that SDL needs is added for you.
+///
+/// ## Failure
+/// * The text can't contain a null byte, since SDL would cut the text off
+///   there.
+/// * SDL can fail to set the clipboard (eg: the video subsystem isn't
+///   initialized).
+#[cfg(feature = "alloc")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "alloc")))]
+pub fn set_clipboard_text(text: &str) -> Result<(), SdlError> {
+  unsafe {
+    if text.as_bytes().contains(&0) {
+      SDL_SetError(b"Clipboard text contains a null byte\0".as_ptr().cast());
+      return Err(SdlError::get());
+    }
+    let mut buf = Vec::with_capacity(text.len() + 1);
+    buf.extend_from_slice(text.as_bytes());
+    buf.push(0_u8);
+    if SDL_SetClipboardText(buf.as_ptr().cast()) < 0 {
+      Err(SdlError::get())
+    } else {
+      Ok(())
+    }
+  }
+}
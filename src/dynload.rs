@@ -0,0 +1,2510 @@
+//! Loading SDL2 at runtime, instead of linking to it.
+//!
+//! With the `dynamic_load` feature on, the build script doesn't link SDL2 at
+//! all. Instead you call [`SdlApi::load`] (or [`SdlApi::load_default`]) to
+//! open the SDL2 shared library with the OS loader, and then call SDL through
+//! the function pointers of the [`SdlApi`]. If SDL2 isn't installed, loading
+//! just returns an error, so your program can show a friendly message instead
+//! of failing to start. Dropping the `SdlApi` unloads the library, so you can
+//! also load a different build of SDL2 afterwards.
+//!
+//! This is the same idea as SDL's own "dynapi", where the real functions are
+//! reached through a table of pointers.
+//!
+//! The `SdlApi` has a field for every SDL2 function that the crate binds, for
+//! the features that are enabled (the satellite libraries, like `image` and
+//! `ttf`, aren't included, since they're separate libraries). This follows the
+//! version features too, so loading an SDL2 that's older than the version
+//! feature you picked fails with [`DynLoadError::MissingSymbol`]. Any function
+//! that the crate doesn't bind can be looked up with [`SdlApi::get`]. The
+//! normal bindings in the rest of the crate are *not* linked in this mode, so
+//! calling them directly will be a link error.
+
+use core::{ffi::CStr, fmt, ptr::NonNull};
+
+use crate::{c_void, prelude::*};
+
+/// The names tried by [`SdlApi::load_default`], in order.
+#[cfg(target_os = "windows")]
+pub const SDL2_LIBRARY_NAMES: &[&[u8]] = &[b"SDL2.dll\0"];
+/// The names tried by [`SdlApi::load_default`], in order.
+#[cfg(target_vendor = "apple")]
+pub const SDL2_LIBRARY_NAMES: &[&[u8]] =
+  &[b"libSDL2-2.0.0.dylib\0", b"libSDL2.dylib\0", b"SDL2.framework/SDL2\0"];
+/// The names tried by [`SdlApi::load_default`], in order.
+#[cfg(not(any(target_os = "windows", target_vendor = "apple")))]
+pub const SDL2_LIBRARY_NAMES: &[&[u8]] =
+  &[b"libSDL2-2.0.so.0\0", b"libSDL2-2.0.so\0", b"libSDL2.so\0"];
+
+/// An error from loading SDL2 at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DynLoadError {
+  /// The library couldn't be opened (it's not installed, or not on the
+  /// library search path).
+  LibraryNotFound,
+  /// The library opened, but it doesn't have this function (it's probably an
+  /// older version of SDL2).
+  MissingSymbol(&'static str),
+}
+impl fmt::Display for DynLoadError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::LibraryNotFound => f.write_str("couldn't load the SDL2 library"),
+      Self::MissingSymbol(name) => {
+        write!(f, "the SDL2 library doesn't have `{name}`")
+      }
+    }
+  }
+}
+
+/// Just enough of the OS's library loader to open SDL2 with.
+mod os {
+  use crate::{c_char, c_int, c_void};
+
+  #[cfg(target_os = "windows")]
+  extern "system" {
+    fn LoadLibraryA(lpLibFileName: *const c_char) -> *mut c_void;
+    fn GetProcAddress(
+      hModule: *mut c_void, lpProcName: *const c_char,
+    ) -> *mut c_void;
+    fn FreeLibrary(hLibModule: *mut c_void) -> c_int;
+  }
+  #[cfg(target_os = "windows")]
+  pub unsafe fn open(name: *const c_char) -> *mut c_void {
+    LoadLibraryA(name)
+  }
+  #[cfg(target_os = "windows")]
+  pub unsafe fn sym(lib: *mut c_void, name: *const c_char) -> *mut c_void {
+    GetProcAddress(lib, name)
+  }
+  #[cfg(target_os = "windows")]
+  pub unsafe fn close(lib: *mut c_void) {
+    FreeLibrary(lib);
+  }
+
+  #[cfg(not(target_os = "windows"))]
+  extern "C" {
+    fn dlopen(filename: *const c_char, flags: c_int) -> *mut c_void;
+    fn dlsym(handle: *mut c_void, symbol: *const c_char) -> *mut c_void;
+    fn dlclose(handle: *mut c_void) -> c_int;
+  }
+  // This is the same value on every unix we support.
+  #[cfg(not(target_os = "windows"))]
+  const RTLD_NOW: c_int = 2;
+  #[cfg(not(target_os = "windows"))]
+  pub unsafe fn open(name: *const c_char) -> *mut c_void {
+    dlopen(name, RTLD_NOW)
+  }
+  #[cfg(not(target_os = "windows"))]
+  pub unsafe fn sym(lib: *mut c_void, name: *const c_char) -> *mut c_void {
+    dlsym(lib, name)
+  }
+  #[cfg(not(target_os = "windows"))]
+  pub unsafe fn close(lib: *mut c_void) {
+    dlclose(lib);
+  }
+}
+
+macro_rules! sdl_api {
+  ($(
+    $(#[doc = $doc:literal])*
+    $(#[cfg($cfg:meta)])*
+    fn $name:ident($($args:tt)*) $(-> $ret:ty)?;
+  )*) => {
+    /// A loaded SDL2 library, with pointers to its functions.
+    ///
+    /// Each field is named and typed like the normal binding of the same
+    /// function, so `api.SDL_Init` is called just like
+    /// [`SDL_Init`](crate::SDL_Init). The exceptions are [`SDL_BlitSurface`]
+    /// and [`SDL_BlitScaled`], which are under the names that SDL2 exports
+    /// them as (`SDL_UpperBlit` and `SDL_UpperBlitScaled`).
+    pub struct SdlApi {
+      lib: NonNull<c_void>,
+      $(
+        $(#[doc = $doc])*
+        $(#[cfg($cfg)])*
+        pub $name: unsafe extern "C" fn($($args)*) $(-> $ret)?,
+      )*
+    }
+    impl SdlApi {
+      /// Opens the SDL2 library at `path`, and looks up all of the functions.
+      ///
+      /// `path` is passed to the OS loader (`LoadLibraryA` or `dlopen`), so
+      /// it can be a full path or just a file name to search for.
+      ///
+      /// ## Safety
+      /// * Loading a library runs its initialization code, which can do
+      ///   anything. The library must be a real build of SDL2, or the function
+      ///   pointers won't match what they claim to be.
+      pub unsafe fn load(path: &CStr) -> Result<Self, DynLoadError> {
+        let lib = NonNull::new(os::open(path.as_ptr()))
+          .ok_or(DynLoadError::LibraryNotFound)?;
+        Ok(Self {
+          $(
+            $(#[cfg($cfg)])*
+            $name: {
+              let p = os::sym(
+                lib.as_ptr(),
+                concat!(stringify!($name), "\0").as_ptr().cast(),
+              );
+              if p.is_null() {
+                os::close(lib.as_ptr());
+                return Err(DynLoadError::MissingSymbol(stringify!($name)));
+              }
+              core::mem::transmute::<
+                *mut c_void,
+                unsafe extern "C" fn($($args)*) $(-> $ret)?,
+              >(p)
+            },
+          )*
+          lib,
+        })
+      }
+    }
+  };
+}
+
+sdl_api! {
+  // init and main
+  /// See [`SDL_Init`]
+  fn SDL_Init(flags: SDL_InitFlags) -> c_int;
+  /// See [`SDL_InitSubSystem`]
+  fn SDL_InitSubSystem(flags: SDL_InitFlags) -> c_int;
+  /// See [`SDL_QuitSubSystem`]
+  fn SDL_QuitSubSystem(flags: SDL_InitFlags) -> c_int;
+  /// See [`SDL_WasInit`]
+  fn SDL_WasInit(flags: SDL_InitFlags) -> c_int;
+  /// See [`SDL_Quit`]
+  fn SDL_Quit();
+  /// See [`SDL_SetMainReady`]
+  fn SDL_SetMainReady();
+  /// See [`SDL_UIKitRunApp`]
+  #[cfg(any(target_os = "ios", target_os = "tvos", doc))]
+  fn SDL_UIKitRunApp(
+    argc: c_int, argv: *mut *mut c_char, mainFunction: SDL_main_func,
+  ) -> c_int;
+  /// See [`SDL_RegisterApp`]
+  #[cfg(any(target_os = "windows", doc))]
+  fn SDL_RegisterApp(
+    name: *const c_char, style: u32, hInst: *mut c_void,
+  ) -> c_int;
+  /// See [`SDL_UnregisterApp`]
+  #[cfg(any(target_os = "windows", doc))]
+  fn SDL_UnregisterApp();
+
+  // assert
+  /// See [`SDL_ReportAssertion`]
+  fn SDL_ReportAssertion(
+    data: *mut SDL_AssertData, func: *const c_char, file: *const c_char,
+    line: c_int,
+  ) -> SDL_AssertState;
+  /// See [`SDL_SetAssertionHandler`]
+  fn SDL_SetAssertionHandler(
+    handler: SDL_AssertionHandler, userdata: *mut c_void,
+  );
+  /// See [`SDL_GetDefaultAssertionHandler`]
+  fn SDL_GetDefaultAssertionHandler() -> SDL_AssertionHandler;
+  /// See [`SDL_GetAssertionHandler`]
+  fn SDL_GetAssertionHandler(
+    puserdata: *mut *mut c_void,
+  ) -> SDL_AssertionHandler;
+  /// See [`SDL_GetAssertionReport`]
+  fn SDL_GetAssertionReport() -> *const SDL_AssertData;
+  /// See [`SDL_ResetAssertionReport`]
+  fn SDL_ResetAssertionReport();
+
+  // audio
+  /// See [`SDL_GetNumAudioDrivers`]
+  #[cfg(feature = "audio")]
+  fn SDL_GetNumAudioDrivers() -> c_int;
+  /// See [`SDL_GetAudioDriver`]
+  #[cfg(feature = "audio")]
+  fn SDL_GetAudioDriver(index: c_int) -> *const c_char;
+  /// See [`SDL_AudioInit`]
+  #[cfg(feature = "audio")]
+  fn SDL_AudioInit(driver_name: *const c_char) -> c_int;
+  /// See [`SDL_AudioQuit`]
+  #[cfg(feature = "audio")]
+  fn SDL_AudioQuit();
+  /// See [`SDL_GetCurrentAudioDriver`]
+  #[cfg(feature = "audio")]
+  fn SDL_GetCurrentAudioDriver() -> *const c_char;
+  /// See [`SDL_OpenAudio`]
+  #[cfg(feature = "audio")]
+  fn SDL_OpenAudio(
+    desired: *mut SDL_AudioSpec, obtained: *mut SDL_AudioSpec,
+  ) -> c_int;
+  /// See [`SDL_GetNumAudioDevices`]
+  #[cfg(feature = "audio")]
+  fn SDL_GetNumAudioDevices(iscapture: c_int) -> c_int;
+  /// See [`SDL_GetAudioDeviceName`]
+  #[cfg(feature = "audio")]
+  fn SDL_GetAudioDeviceName(index: c_int, iscapture: c_int) -> *const c_char;
+  /// See [`SDL_OpenAudioDevice`]
+  #[cfg(feature = "audio")]
+  fn SDL_OpenAudioDevice(
+    device: *const c_char, iscapture: c_int, desired: *const SDL_AudioSpec,
+    obtained: *mut SDL_AudioSpec, allowed_changes: c_int,
+  ) -> SDL_AudioDeviceID;
+  /// See [`SDL_GetAudioStatus`]
+  #[cfg(feature = "audio")]
+  fn SDL_GetAudioStatus() -> SDL_AudioStatus;
+  /// See [`SDL_GetAudioDeviceStatus`]
+  #[cfg(feature = "audio")]
+  fn SDL_GetAudioDeviceStatus(dev: SDL_AudioDeviceID) -> SDL_AudioStatus;
+  /// See [`SDL_PauseAudio`]
+  #[cfg(feature = "audio")]
+  fn SDL_PauseAudio(pause_on: c_int);
+  /// See [`SDL_PauseAudioDevice`]
+  #[cfg(feature = "audio")]
+  fn SDL_PauseAudioDevice(dev: SDL_AudioDeviceID, pause_on: c_int);
+  /// See [`SDL_LoadWAV_RW`]
+  #[cfg(feature = "audio")]
+  fn SDL_LoadWAV_RW(
+    src: *mut SDL_RWops, freesrc: c_int, spec: *mut SDL_AudioSpec,
+    audio_buf: *mut *mut Uint8, audio_len: *mut Uint32,
+  ) -> *mut SDL_AudioSpec;
+  /// See [`SDL_FreeWAV`]
+  #[cfg(feature = "audio")]
+  fn SDL_FreeWAV(audio_buf: *mut Uint8);
+  /// See [`SDL_BuildAudioCVT`]
+  #[cfg(feature = "audio")]
+  fn SDL_BuildAudioCVT(
+    cvt: *mut SDL_AudioCVT, src_format: SDL_AudioFormat, src_channels: Uint8,
+    src_rate: c_int, dst_format: SDL_AudioFormat, dst_channels: Uint8,
+    dst_rate: c_int,
+  ) -> c_int;
+  /// See [`SDL_ConvertAudio`]
+  #[cfg(feature = "audio")]
+  fn SDL_ConvertAudio(cvt: *mut SDL_AudioCVT) -> c_int;
+  /// See [`SDL_NewAudioStream`]
+  #[cfg(feature = "audio")]
+  fn SDL_NewAudioStream(
+    src_format: SDL_AudioFormat, src_channels: Uint8, src_rate: c_int,
+    dst_format: SDL_AudioFormat, dst_channels: Uint8, dst_rate: c_int,
+  ) -> *mut SDL_AudioStream;
+  /// See [`SDL_AudioStreamPut`]
+  #[cfg(feature = "audio")]
+  fn SDL_AudioStreamPut(
+    stream: *mut SDL_AudioStream, buf: *const c_void, len: c_int,
+  ) -> c_int;
+  /// See [`SDL_AudioStreamGet`]
+  #[cfg(feature = "audio")]
+  fn SDL_AudioStreamGet(
+    stream: *mut SDL_AudioStream, buf: *mut c_void, len: c_int,
+  ) -> c_int;
+  /// See [`SDL_AudioStreamAvailable`]
+  #[cfg(feature = "audio")]
+  fn SDL_AudioStreamAvailable(stream: *mut SDL_AudioStream) -> c_int;
+  /// See [`SDL_AudioStreamFlush`]
+  #[cfg(feature = "audio")]
+  fn SDL_AudioStreamFlush(stream: *mut SDL_AudioStream) -> c_int;
+  /// See [`SDL_AudioStreamClear`]
+  #[cfg(feature = "audio")]
+  fn SDL_AudioStreamClear(stream: *mut SDL_AudioStream);
+  /// See [`SDL_FreeAudioStream`]
+  #[cfg(feature = "audio")]
+  fn SDL_FreeAudioStream(stream: *mut SDL_AudioStream);
+  /// See [`SDL_MixAudio`]
+  #[cfg(feature = "audio")]
+  fn SDL_MixAudio(
+    dst: *mut Uint8, src: *const Uint8, len: Uint32, volume: c_int,
+  );
+  /// See [`SDL_MixAudioFormat`]
+  #[cfg(feature = "audio")]
+  fn SDL_MixAudioFormat(
+    dst: *mut Uint8, src: *const Uint8, format: SDL_AudioFormat, len: Uint32,
+    volume: c_int,
+  );
+  /// See [`SDL_QueueAudio`]
+  #[cfg(feature = "audio")]
+  fn SDL_QueueAudio(
+    dev: SDL_AudioDeviceID, data: *const c_void, len: Uint32,
+  ) -> c_int;
+  /// See [`SDL_DequeueAudio`]
+  #[cfg(feature = "audio")]
+  fn SDL_DequeueAudio(
+    dev: SDL_AudioDeviceID, data: *mut c_void, len: Uint32,
+  ) -> Uint32;
+  /// See [`SDL_GetQueuedAudioSize`]
+  #[cfg(feature = "audio")]
+  fn SDL_GetQueuedAudioSize(dev: SDL_AudioDeviceID) -> Uint32;
+  /// See [`SDL_ClearQueuedAudio`]
+  #[cfg(feature = "audio")]
+  fn SDL_ClearQueuedAudio(dev: SDL_AudioDeviceID);
+  /// See [`SDL_LockAudio`]
+  #[cfg(feature = "audio")]
+  fn SDL_LockAudio();
+  /// See [`SDL_LockAudioDevice`]
+  #[cfg(feature = "audio")]
+  fn SDL_LockAudioDevice(dev: SDL_AudioDeviceID);
+  /// See [`SDL_UnlockAudio`]
+  #[cfg(feature = "audio")]
+  fn SDL_UnlockAudio();
+  /// See [`SDL_UnlockAudioDevice`]
+  #[cfg(feature = "audio")]
+  fn SDL_UnlockAudioDevice(dev: SDL_AudioDeviceID);
+  /// See [`SDL_CloseAudio`]
+  #[cfg(feature = "audio")]
+  fn SDL_CloseAudio();
+  /// See [`SDL_CloseAudioDevice`]
+  #[cfg(feature = "audio")]
+  fn SDL_CloseAudioDevice(dev: SDL_AudioDeviceID);
+  /// See [`SDL_GetAudioDeviceSpec`]
+  #[cfg(all(feature = "audio", feature = "sdl2_2_0_16"))]
+  fn SDL_GetAudioDeviceSpec(
+    index: c_int, iscapture: c_int, spec: *mut SDL_AudioSpec,
+  ) -> c_int;
+  /// See [`SDL_GetDefaultAudioInfo`]
+  #[cfg(all(feature = "audio", feature = "sdl2_2_24"))]
+  fn SDL_GetDefaultAudioInfo(
+    name: *mut *mut c_char, spec: *mut SDL_AudioSpec, iscapture: c_int,
+  ) -> c_int;
+
+  // blendmode
+  /// See [`SDL_ComposeCustomBlendMode`]
+  fn SDL_ComposeCustomBlendMode(
+    srcColorFactor: SDL_BlendFactor, dstColorFactor: SDL_BlendFactor,
+    colorOperation: SDL_BlendOperation, srcAlphaFactor: SDL_BlendFactor,
+    dstAlphaFactor: SDL_BlendFactor, alphaOperation: SDL_BlendOperation,
+  ) -> SDL_BlendMode;
+
+  // clipboard
+  /// See [`SDL_SetClipboardText`]
+  fn SDL_SetClipboardText(text: *const c_char) -> c_int;
+  /// See [`SDL_GetClipboardText`]
+  fn SDL_GetClipboardText() -> *mut c_char;
+  /// See [`SDL_HasClipboardText`]
+  fn SDL_HasClipboardText() -> SDL_bool;
+
+  // cpuinfo
+  /// See [`SDL_GetCPUCount`]
+  fn SDL_GetCPUCount() -> c_int;
+  /// See [`SDL_GetCPUCacheLineSize`]
+  fn SDL_GetCPUCacheLineSize() -> c_int;
+  /// See [`SDL_GetSystemRAM`]
+  fn SDL_GetSystemRAM() -> c_int;
+
+  // error
+  /// See [`SDL_SetError`]
+  fn SDL_SetError(fmt: *const c_char, ...) -> c_int;
+  /// See [`SDL_GetErrorMsg`]
+  fn SDL_GetErrorMsg(errstr: *mut c_char, maxlen: c_int) -> *mut c_char;
+  /// See [`SDL_ClearError`]
+  fn SDL_ClearError();
+
+  // events
+  /// See [`SDL_PumpEvents`]
+  fn SDL_PumpEvents();
+  /// See [`SDL_PeepEvents`]
+  fn SDL_PeepEvents(
+    events: *mut SDL_Event, numevents: c_int, action: SDL_eventaction,
+    minType: SDL_EventType, maxType: SDL_EventType,
+  ) -> c_int;
+  /// See [`SDL_HasEvent`]
+  fn SDL_HasEvent(type_: SDL_EventType) -> SDL_bool;
+  /// See [`SDL_HasEvents`]
+  fn SDL_HasEvents(minType: SDL_EventType, maxType: SDL_EventType) -> SDL_bool;
+  /// See [`SDL_FlushEvent`]
+  fn SDL_FlushEvent(type_: SDL_EventType);
+  /// See [`SDL_FlushEvents`]
+  fn SDL_FlushEvents(minType: SDL_EventType, maxType: SDL_EventType);
+  /// See [`SDL_PollEvent`]
+  fn SDL_PollEvent(event: *mut SDL_Event) -> c_int;
+  /// See [`SDL_WaitEvent`]
+  fn SDL_WaitEvent(event: *mut SDL_Event) -> c_int;
+  /// See [`SDL_WaitEventTimeout`]
+  fn SDL_WaitEventTimeout(event: *mut SDL_Event, timeout: c_int) -> c_int;
+  /// See [`SDL_PushEvent`]
+  fn SDL_PushEvent(event: *mut SDL_Event) -> c_int;
+  /// See [`SDL_SetEventFilter`]
+  fn SDL_SetEventFilter(filter: SDL_EventFilter, userdata: *mut c_void);
+  /// See [`SDL_GetEventFilter`]
+  fn SDL_GetEventFilter(
+    filter: *mut SDL_EventFilter, userdata: *mut *mut c_void,
+  ) -> SDL_bool;
+  /// See [`SDL_AddEventWatch`]
+  fn SDL_AddEventWatch(filter: SDL_EventFilter, userdata: *mut c_void);
+  /// See [`SDL_DelEventWatch`]
+  fn SDL_DelEventWatch(filter: SDL_EventFilter, userdata: *mut c_void);
+  /// See [`SDL_FilterEvents`]
+  fn SDL_FilterEvents(filter: SDL_EventFilter, userdata: *mut c_void);
+  /// See [`SDL_EventState`]
+  fn SDL_EventState(type_: SDL_EventType, state: c_int) -> Uint8;
+  /// See [`SDL_RegisterEvents`]
+  fn SDL_RegisterEvents(numevents: c_int) -> Uint32;
+
+  // filesystem
+  /// See [`SDL_GetBasePath`]
+  fn SDL_GetBasePath() -> *mut c_char;
+  /// See [`SDL_GetPrefPath`]
+  fn SDL_GetPrefPath(org: *const c_char, app: *const c_char) -> *mut c_char;
+
+  // gamecontroller
+  /// See [`SDL_GameControllerAddMappingsFromRW`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerAddMappingsFromRW(
+    rw: *mut SDL_RWops, freerw: c_int,
+  ) -> c_int;
+  /// See [`SDL_GameControllerAddMapping`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerAddMapping(mappingString: *const c_char) -> c_int;
+  /// See [`SDL_GameControllerNumMappings`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerNumMappings() -> c_int;
+  /// See [`SDL_GameControllerMappingForIndex`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerMappingForIndex(mapping_index: c_int) -> *mut c_char;
+  /// See [`SDL_GameControllerMappingForGUID`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerMappingForGUID(guid: SDL_JoystickGUID) -> *mut c_char;
+  /// See [`SDL_GameControllerMapping`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerMapping(
+    gamecontroller: *mut SDL_GameController,
+  ) -> *mut c_char;
+  /// See [`SDL_IsGameController`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_IsGameController(joystick_index: c_int) -> SDL_bool;
+  /// See [`SDL_GameControllerNameForIndex`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerNameForIndex(joystick_index: c_int) -> *const c_char;
+  /// See [`SDL_GameControllerTypeForIndex`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerTypeForIndex(
+    joystick_index: c_int,
+  ) -> SDL_GameControllerType;
+  /// See [`SDL_GameControllerMappingForDeviceIndex`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerMappingForDeviceIndex(
+    joystick_index: c_int,
+  ) -> *mut c_char;
+  /// See [`SDL_GameControllerOpen`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerOpen(joystick_index: c_int) -> *mut SDL_GameController;
+  /// See [`SDL_GameControllerFromInstanceID`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerFromInstanceID(
+    joyid: SDL_JoystickID,
+  ) -> *mut SDL_GameController;
+  /// See [`SDL_GameControllerFromPlayerIndex`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerFromPlayerIndex(
+    player_index: c_int,
+  ) -> *mut SDL_GameController;
+  /// See [`SDL_GameControllerName`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerName(
+    gamecontroller: *mut SDL_GameController,
+  ) -> *const c_char;
+  /// See [`SDL_GameControllerGetType`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerGetType(
+    gamecontroller: *mut SDL_GameController,
+  ) -> SDL_GameControllerType;
+  /// See [`SDL_GameControllerGetPlayerIndex`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerGetPlayerIndex(
+    gamecontroller: *mut SDL_GameController,
+  ) -> c_int;
+  /// See [`SDL_GameControllerSetPlayerIndex`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerSetPlayerIndex(
+    gamecontroller: *mut SDL_GameController, player_index: c_int,
+  );
+  /// See [`SDL_GameControllerGetVendor`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerGetVendor(
+    gamecontroller: *mut SDL_GameController,
+  ) -> Uint16;
+  /// See [`SDL_GameControllerGetProduct`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerGetProduct(
+    gamecontroller: *mut SDL_GameController,
+  ) -> Uint16;
+  /// See [`SDL_GameControllerGetProductVersion`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerGetProductVersion(
+    gamecontroller: *mut SDL_GameController,
+  ) -> Uint16;
+  /// See [`SDL_GameControllerGetSerial`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerGetSerial(
+    gamecontroller: *mut SDL_GameController,
+  ) -> *const c_char;
+  /// See [`SDL_GameControllerGetAttached`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerGetAttached(
+    gamecontroller: *mut SDL_GameController,
+  ) -> SDL_bool;
+  /// See [`SDL_GameControllerGetJoystick`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerGetJoystick(
+    gamecontroller: *mut SDL_GameController,
+  ) -> *mut SDL_Joystick;
+  /// See [`SDL_GameControllerEventState`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerEventState(state: c_int) -> c_int;
+  /// See [`SDL_GameControllerUpdate`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerUpdate();
+  /// See [`SDL_GameControllerGetAxisFromString`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerGetAxisFromString(
+    pchString: *const c_char,
+  ) -> SDL_GameControllerAxis;
+  /// See [`SDL_GameControllerGetStringForAxis`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerGetStringForAxis(
+    axis: SDL_GameControllerAxis,
+  ) -> *const c_char;
+  /// See [`SDL_GameControllerGetBindForAxis`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerGetBindForAxis(
+    gamecontroller: *mut SDL_GameController, axis: SDL_GameControllerAxis,
+  ) -> SDL_GameControllerButtonBind;
+  /// See [`SDL_GameControllerHasAxis`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerHasAxis(
+    gamecontroller: *mut SDL_GameController, axis: SDL_GameControllerAxis,
+  ) -> SDL_bool;
+  /// See [`SDL_GameControllerGetAxis`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerGetAxis(
+    gamecontroller: *mut SDL_GameController, axis: SDL_GameControllerAxis,
+  ) -> Sint16;
+  /// See [`SDL_GameControllerGetButtonFromString`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerGetButtonFromString(
+    pchString: *const c_char,
+  ) -> SDL_GameControllerButton;
+  /// See [`SDL_GameControllerGetStringForButton`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerGetStringForButton(
+    button: SDL_GameControllerButton,
+  ) -> *const c_char;
+  /// See [`SDL_GameControllerGetBindForButton`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerGetBindForButton(
+    gamecontroller: *mut SDL_GameController, button: SDL_GameControllerButton,
+  ) -> SDL_GameControllerButtonBind;
+  /// See [`SDL_GameControllerHasButton`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerHasButton(
+    gamecontroller: *mut SDL_GameController, button: SDL_GameControllerButton,
+  ) -> SDL_bool;
+  /// See [`SDL_GameControllerGetButton`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerGetButton(
+    gamecontroller: *mut SDL_GameController, button: SDL_GameControllerButton,
+  ) -> Uint8;
+  /// See [`SDL_GameControllerGetNumTouchpads`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerGetNumTouchpads(
+    gamecontroller: *mut SDL_GameController,
+  ) -> c_int;
+  /// See [`SDL_GameControllerGetNumTouchpadFingers`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerGetNumTouchpadFingers(
+    gamecontroller: *mut SDL_GameController, touchpad: c_int,
+  ) -> c_int;
+  /// See [`SDL_GameControllerGetTouchpadFinger`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerGetTouchpadFinger(
+    gamecontroller: *mut SDL_GameController, touchpad: c_int, finger: c_int,
+    state: *mut Uint8, x: *mut c_float, y: *mut c_float,
+    pressure: *mut c_float,
+  ) -> c_int;
+  /// See [`SDL_GameControllerHasSensor`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerHasSensor(
+    gamecontroller: *mut SDL_GameController, type_: SDL_SensorType,
+  ) -> SDL_bool;
+  /// See [`SDL_GameControllerSetSensorEnabled`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerSetSensorEnabled(
+    gamecontroller: *mut SDL_GameController, type_: SDL_SensorType,
+    enabled: SDL_bool,
+  ) -> c_int;
+  /// See [`SDL_GameControllerIsSensorEnabled`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerIsSensorEnabled(
+    gamecontroller: *mut SDL_GameController, type_: SDL_SensorType,
+  ) -> SDL_bool;
+  /// See [`SDL_GameControllerGetSensorData`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerGetSensorData(
+    gamecontroller: *mut SDL_GameController, type_: SDL_SensorType,
+    data: *mut c_float, num_values: c_int,
+  ) -> c_int;
+  /// See [`SDL_GameControllerGetSensorDataWithTimestamp`]
+  #[cfg(all(feature = "gamecontroller", feature = "sdl2_2_26"))]
+  fn SDL_GameControllerGetSensorDataWithTimestamp(
+    gamecontroller: *mut SDL_GameController, type_: SDL_SensorType,
+    timestamp: *mut Uint64, data: *mut c_float, num_values: c_int,
+  ) -> c_int;
+  /// See [`SDL_GameControllerRumble`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerRumble(
+    gamecontroller: *mut SDL_GameController, low_frequency_rumble: Uint16,
+    high_frequency_rumble: Uint16, duration_ms: Uint32,
+  ) -> c_int;
+  /// See [`SDL_GameControllerRumbleTriggers`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerRumbleTriggers(
+    gamecontroller: *mut SDL_GameController, left_rumble: Uint16,
+    right_rumble: Uint16, duration_ms: Uint32,
+  ) -> c_int;
+  /// See [`SDL_GameControllerHasLED`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerHasLED(
+    gamecontroller: *mut SDL_GameController,
+  ) -> SDL_bool;
+  /// See [`SDL_GameControllerHasRumble`]
+  #[cfg(all(feature = "gamecontroller", feature = "sdl2_2_0_18"))]
+  fn SDL_GameControllerHasRumble(
+    gamecontroller: *mut SDL_GameController,
+  ) -> SDL_bool;
+  /// See [`SDL_GameControllerHasRumbleTriggers`]
+  #[cfg(all(feature = "gamecontroller", feature = "sdl2_2_0_18"))]
+  fn SDL_GameControllerHasRumbleTriggers(
+    gamecontroller: *mut SDL_GameController,
+  ) -> SDL_bool;
+  /// See [`SDL_GameControllerSetLED`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerSetLED(
+    gamecontroller: *mut SDL_GameController, red: Uint8, green: Uint8,
+    blue: Uint8,
+  ) -> c_int;
+  /// See [`SDL_GameControllerClose`]
+  #[cfg(feature = "gamecontroller")]
+  fn SDL_GameControllerClose(gamecontroller: *mut SDL_GameController);
+  /// See [`SDL_GameControllerSendEffect`]
+  #[cfg(all(feature = "gamecontroller", feature = "sdl2_2_0_16"))]
+  fn SDL_GameControllerSendEffect(
+    gamecontroller: *mut SDL_GameController, data: *const c_void, size: c_int,
+  ) -> c_int;
+  /// See [`SDL_GameControllerGetSensorDataRate`]
+  #[cfg(all(feature = "gamecontroller", feature = "sdl2_2_0_16"))]
+  fn SDL_GameControllerGetSensorDataRate(
+    gamecontroller: *mut SDL_GameController, type_: SDL_SensorType,
+  ) -> c_float;
+
+  // gesture
+  /// See [`SDL_RecordGesture`]
+  fn SDL_RecordGesture(touchId: SDL_TouchID) -> c_int;
+  /// See [`SDL_SaveAllDollarTemplates`]
+  fn SDL_SaveAllDollarTemplates(dst: *mut SDL_RWops) -> c_int;
+  /// See [`SDL_SaveDollarTemplate`]
+  fn SDL_SaveDollarTemplate(
+    gestureId: SDL_GestureID, dst: *mut SDL_RWops,
+  ) -> c_int;
+  /// See [`SDL_LoadDollarTemplates`]
+  fn SDL_LoadDollarTemplates(
+    touchId: SDL_TouchID, src: *mut SDL_RWops,
+  ) -> c_int;
+
+  // guid
+  /// See [`SDL_GUIDToString`]
+  #[cfg(feature = "sdl2_2_24")]
+  fn SDL_GUIDToString(guid: SDL_GUID, pszGUID: *mut c_char, cbGUID: c_int);
+  /// See [`SDL_GUIDFromString`]
+  #[cfg(feature = "sdl2_2_24")]
+  fn SDL_GUIDFromString(pchGUID: *const c_char) -> SDL_GUID;
+
+  // haptic
+  /// See [`SDL_NumHaptics`]
+  #[cfg(feature = "haptic")]
+  fn SDL_NumHaptics() -> c_int;
+  /// See [`SDL_HapticName`]
+  #[cfg(feature = "haptic")]
+  fn SDL_HapticName(device_index: c_int) -> *const c_char;
+  /// See [`SDL_HapticOpen`]
+  #[cfg(feature = "haptic")]
+  fn SDL_HapticOpen(device_index: c_int) -> *mut SDL_Haptic;
+  /// See [`SDL_HapticOpened`]
+  #[cfg(feature = "haptic")]
+  fn SDL_HapticOpened(device_index: c_int) -> c_int;
+  /// See [`SDL_HapticIndex`]
+  #[cfg(feature = "haptic")]
+  fn SDL_HapticIndex(haptic: *mut SDL_Haptic) -> c_int;
+  /// See [`SDL_MouseIsHaptic`]
+  #[cfg(feature = "haptic")]
+  fn SDL_MouseIsHaptic() -> c_int;
+  /// See [`SDL_HapticOpenFromMouse`]
+  #[cfg(feature = "haptic")]
+  fn SDL_HapticOpenFromMouse() -> *mut SDL_Haptic;
+  /// See [`SDL_JoystickIsHaptic`]
+  #[cfg(feature = "haptic")]
+  fn SDL_JoystickIsHaptic(joystick: *mut SDL_Joystick) -> c_int;
+  /// See [`SDL_HapticOpenFromJoystick`]
+  #[cfg(feature = "haptic")]
+  fn SDL_HapticOpenFromJoystick(joystick: *mut SDL_Joystick)
+    -> *mut SDL_Haptic;
+  /// See [`SDL_HapticClose`]
+  #[cfg(feature = "haptic")]
+  fn SDL_HapticClose(haptic: *mut SDL_Haptic);
+  /// See [`SDL_HapticNumEffects`]
+  #[cfg(feature = "haptic")]
+  fn SDL_HapticNumEffects(haptic: *mut SDL_Haptic) -> c_int;
+  /// See [`SDL_HapticNumEffectsPlaying`]
+  #[cfg(feature = "haptic")]
+  fn SDL_HapticNumEffectsPlaying(haptic: *mut SDL_Haptic) -> c_int;
+  /// See [`SDL_HapticQuery`]
+  #[cfg(feature = "haptic")]
+  fn SDL_HapticQuery(haptic: *mut SDL_Haptic) -> c_uint;
+  /// See [`SDL_HapticNumAxes`]
+  #[cfg(feature = "haptic")]
+  fn SDL_HapticNumAxes(haptic: *mut SDL_Haptic) -> c_int;
+  /// See [`SDL_HapticEffectSupported`]
+  #[cfg(feature = "haptic")]
+  fn SDL_HapticEffectSupported(
+    haptic: *mut SDL_Haptic, effect: *mut SDL_HapticEffect,
+  ) -> c_int;
+  /// See [`SDL_HapticNewEffect`]
+  #[cfg(feature = "haptic")]
+  fn SDL_HapticNewEffect(
+    haptic: *mut SDL_Haptic, effect: *mut SDL_HapticEffect,
+  ) -> c_int;
+  /// See [`SDL_HapticUpdateEffect`]
+  #[cfg(feature = "haptic")]
+  fn SDL_HapticUpdateEffect(
+    haptic: *mut SDL_Haptic, effect: c_int, data: *mut SDL_HapticEffect,
+  ) -> c_int;
+  /// See [`SDL_HapticRunEffect`]
+  #[cfg(feature = "haptic")]
+  fn SDL_HapticRunEffect(
+    haptic: *mut SDL_Haptic, effect: c_int, iterations: Uint32,
+  ) -> c_int;
+  /// See [`SDL_HapticStopEffect`]
+  #[cfg(feature = "haptic")]
+  fn SDL_HapticStopEffect(haptic: *mut SDL_Haptic, effect: c_int) -> c_int;
+  /// See [`SDL_HapticDestroyEffect`]
+  #[cfg(feature = "haptic")]
+  fn SDL_HapticDestroyEffect(haptic: *mut SDL_Haptic, effect: c_int);
+  /// See [`SDL_HapticGetEffectStatus`]
+  #[cfg(feature = "haptic")]
+  fn SDL_HapticGetEffectStatus(haptic: *mut SDL_Haptic, effect: c_int)
+    -> c_int;
+  /// See [`SDL_HapticSetGain`]
+  #[cfg(feature = "haptic")]
+  fn SDL_HapticSetGain(haptic: *mut SDL_Haptic, gain: c_int) -> c_int;
+  /// See [`SDL_HapticSetAutocenter`]
+  #[cfg(feature = "haptic")]
+  fn SDL_HapticSetAutocenter(
+    haptic: *mut SDL_Haptic, autocenter: c_int,
+  ) -> c_int;
+  /// See [`SDL_HapticPause`]
+  #[cfg(feature = "haptic")]
+  fn SDL_HapticPause(haptic: *mut SDL_Haptic) -> c_int;
+  /// See [`SDL_HapticUnpause`]
+  #[cfg(feature = "haptic")]
+  fn SDL_HapticUnpause(haptic: *mut SDL_Haptic) -> c_int;
+  /// See [`SDL_HapticStopAll`]
+  #[cfg(feature = "haptic")]
+  fn SDL_HapticStopAll(haptic: *mut SDL_Haptic) -> c_int;
+  /// See [`SDL_HapticRumbleSupported`]
+  #[cfg(feature = "haptic")]
+  fn SDL_HapticRumbleSupported(haptic: *mut SDL_Haptic) -> c_int;
+  /// See [`SDL_HapticRumbleInit`]
+  #[cfg(feature = "haptic")]
+  fn SDL_HapticRumbleInit(haptic: *mut SDL_Haptic) -> c_int;
+  /// See [`SDL_HapticRumblePlay`]
+  #[cfg(feature = "haptic")]
+  fn SDL_HapticRumblePlay(
+    haptic: *mut SDL_Haptic, strength: c_float, length: Uint32,
+  ) -> c_int;
+  /// See [`SDL_HapticRumbleStop`]
+  #[cfg(feature = "haptic")]
+  fn SDL_HapticRumbleStop(haptic: *mut SDL_Haptic) -> c_int;
+
+  // hidapi
+  /// See [`SDL_hid_init`]
+  #[cfg(feature = "sdl2_2_0_18")]
+  fn SDL_hid_init() -> c_int;
+  /// See [`SDL_hid_exit`]
+  #[cfg(feature = "sdl2_2_0_18")]
+  fn SDL_hid_exit() -> c_int;
+  /// See [`SDL_hid_device_change_count`]
+  #[cfg(feature = "sdl2_2_0_18")]
+  fn SDL_hid_device_change_count() -> Uint32;
+  /// See [`SDL_hid_enumerate`]
+  #[cfg(feature = "sdl2_2_0_18")]
+  fn SDL_hid_enumerate(
+    vendor_id: c_ushort, product_id: c_ushort,
+  ) -> *mut SDL_hid_device_info;
+  /// See [`SDL_hid_free_enumeration`]
+  #[cfg(feature = "sdl2_2_0_18")]
+  fn SDL_hid_free_enumeration(devs: *mut SDL_hid_device_info);
+  /// See [`SDL_hid_open`]
+  #[cfg(feature = "sdl2_2_0_18")]
+  fn SDL_hid_open(
+    vendor_id: c_ushort, product_id: c_ushort, serial_number: *const wchar_t,
+  ) -> *mut SDL_hid_device;
+  /// See [`SDL_hid_open_path`]
+  #[cfg(feature = "sdl2_2_0_18")]
+  fn SDL_hid_open_path(
+    path: *const c_char, bExclusive: c_int,
+  ) -> *mut SDL_hid_device;
+  /// See [`SDL_hid_write`]
+  #[cfg(feature = "sdl2_2_0_18")]
+  fn SDL_hid_write(
+    dev: *mut SDL_hid_device, data: *const c_uchar, length: usize,
+  ) -> c_int;
+  /// See [`SDL_hid_read_timeout`]
+  #[cfg(feature = "sdl2_2_0_18")]
+  fn SDL_hid_read_timeout(
+    dev: *mut SDL_hid_device, data: *mut c_uchar, length: usize,
+    milliseconds: c_int,
+  ) -> c_int;
+  /// See [`SDL_hid_read`]
+  #[cfg(feature = "sdl2_2_0_18")]
+  fn SDL_hid_read(
+    dev: *mut SDL_hid_device, data: *mut c_uchar, length: usize,
+  ) -> c_int;
+  /// See [`SDL_hid_set_nonblocking`]
+  #[cfg(feature = "sdl2_2_0_18")]
+  fn SDL_hid_set_nonblocking(
+    dev: *mut SDL_hid_device, nonblock: c_int,
+  ) -> c_int;
+  /// See [`SDL_hid_send_feature_report`]
+  #[cfg(feature = "sdl2_2_0_18")]
+  fn SDL_hid_send_feature_report(
+    dev: *mut SDL_hid_device, data: *const c_uchar, length: usize,
+  ) -> c_int;
+  /// See [`SDL_hid_get_feature_report`]
+  #[cfg(feature = "sdl2_2_0_18")]
+  fn SDL_hid_get_feature_report(
+    dev: *mut SDL_hid_device, data: *mut c_uchar, length: usize,
+  ) -> c_int;
+  /// See [`SDL_hid_close`]
+  #[cfg(feature = "sdl2_2_0_18")]
+  fn SDL_hid_close(dev: *mut SDL_hid_device);
+  /// See [`SDL_hid_get_manufacturer_string`]
+  #[cfg(feature = "sdl2_2_0_18")]
+  fn SDL_hid_get_manufacturer_string(
+    dev: *mut SDL_hid_device, string: *mut wchar_t, maxlen: usize,
+  ) -> c_int;
+  /// See [`SDL_hid_get_product_string`]
+  #[cfg(feature = "sdl2_2_0_18")]
+  fn SDL_hid_get_product_string(
+    dev: *mut SDL_hid_device, string: *mut wchar_t, maxlen: usize,
+  ) -> c_int;
+  /// See [`SDL_hid_get_serial_number_string`]
+  #[cfg(feature = "sdl2_2_0_18")]
+  fn SDL_hid_get_serial_number_string(
+    dev: *mut SDL_hid_device, string: *mut wchar_t, maxlen: usize,
+  ) -> c_int;
+  /// See [`SDL_hid_get_indexed_string`]
+  #[cfg(feature = "sdl2_2_0_18")]
+  fn SDL_hid_get_indexed_string(
+    dev: *mut SDL_hid_device, string_index: c_int, string: *mut wchar_t,
+    maxlen: usize,
+  ) -> c_int;
+  /// See [`SDL_hid_ble_scan`]
+  #[cfg(feature = "sdl2_2_0_18")]
+  fn SDL_hid_ble_scan(active: SDL_bool);
+
+  // hints
+  /// See [`SDL_SetHintWithPriority`]
+  fn SDL_SetHintWithPriority(
+    name: *const c_char, value: *const c_char, priority: SDL_HintPriority,
+  ) -> SDL_bool;
+  /// See [`SDL_SetHint`]
+  fn SDL_SetHint(name: *const c_char, value: *const c_char) -> SDL_bool;
+  /// See [`SDL_ResetHint`]
+  #[cfg(feature = "sdl2_2_24")]
+  fn SDL_ResetHint(name: *const c_char) -> SDL_bool;
+  /// See [`SDL_ResetHints`]
+  #[cfg(feature = "sdl2_2_26")]
+  fn SDL_ResetHints();
+  /// See [`SDL_GetHint`]
+  fn SDL_GetHint(name: *const c_char) -> *const c_char;
+  /// See [`SDL_GetHintBoolean`]
+  fn SDL_GetHintBoolean(
+    name: *const c_char, default_value: SDL_bool,
+  ) -> SDL_bool;
+  /// See [`SDL_AddHintCallback`]
+  fn SDL_AddHintCallback(
+    name: *const c_char, callback: SDL_HintCallback, userdata: *mut c_void,
+  );
+  /// See [`SDL_DelHintCallback`]
+  fn SDL_DelHintCallback(
+    name: *const c_char, callback: SDL_HintCallback, userdata: *mut c_void,
+  );
+  /// See [`SDL_ClearHints`]
+  fn SDL_ClearHints();
+
+  // joystick
+  /// See [`SDL_LockJoysticks`]
+  #[cfg(feature = "joystick")]
+  fn SDL_LockJoysticks();
+  /// See [`SDL_UnlockJoysticks`]
+  #[cfg(feature = "joystick")]
+  fn SDL_UnlockJoysticks();
+  /// See [`SDL_NumJoysticks`]
+  #[cfg(feature = "joystick")]
+  fn SDL_NumJoysticks() -> c_int;
+  /// See [`SDL_JoystickNameForIndex`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickNameForIndex(device_index: c_int) -> *const c_char;
+  /// See [`SDL_JoystickGetDevicePlayerIndex`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickGetDevicePlayerIndex(device_index: c_int) -> c_int;
+  /// See [`SDL_JoystickGetDeviceGUID`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickGetDeviceGUID(device_index: c_int) -> SDL_JoystickGUID;
+  /// See [`SDL_JoystickGetDeviceVendor`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickGetDeviceVendor(device_index: c_int) -> Uint16;
+  /// See [`SDL_JoystickGetDeviceProduct`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickGetDeviceProduct(device_index: c_int) -> Uint16;
+  /// See [`SDL_JoystickGetDeviceProductVersion`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickGetDeviceProductVersion(device_index: c_int) -> Uint16;
+  /// See [`SDL_JoystickGetDeviceType`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickGetDeviceType(device_index: c_int) -> SDL_JoystickType;
+  /// See [`SDL_JoystickGetDeviceInstanceID`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickGetDeviceInstanceID(device_index: c_int) -> SDL_JoystickID;
+  /// See [`SDL_JoystickOpen`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickOpen(device_index: c_int) -> *mut SDL_Joystick;
+  /// See [`SDL_JoystickFromInstanceID`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickFromInstanceID(
+    instance_id: SDL_JoystickID,
+  ) -> *mut SDL_Joystick;
+  /// See [`SDL_JoystickFromPlayerIndex`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickFromPlayerIndex(player_index: c_int) -> *mut SDL_Joystick;
+  /// See [`SDL_JoystickAttachVirtual`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickAttachVirtual(
+    type_: SDL_JoystickType, naxes: c_int, nbuttons: c_int, nhats: c_int,
+  ) -> c_int;
+  /// See [`SDL_JoystickAttachVirtualEx`]
+  #[cfg(all(feature = "joystick", feature = "sdl2_2_24"))]
+  fn SDL_JoystickAttachVirtualEx(desc: *const SDL_VirtualJoystickDesc)
+    -> c_int;
+  /// See [`SDL_JoystickDetachVirtual`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickDetachVirtual(device_index: c_int) -> c_int;
+  /// See [`SDL_JoystickIsVirtual`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickIsVirtual(device_index: c_int) -> SDL_bool;
+  /// See [`SDL_JoystickSetVirtualAxis`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickSetVirtualAxis(
+    joystick: *mut SDL_Joystick, axis: c_int, value: Sint16,
+  ) -> c_int;
+  /// See [`SDL_JoystickSetVirtualButton`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickSetVirtualButton(
+    joystick: *mut SDL_Joystick, button: c_int, value: Uint8,
+  ) -> c_int;
+  /// See [`SDL_JoystickSetVirtualHat`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickSetVirtualHat(
+    joystick: *mut SDL_Joystick, hat: c_int, value: Uint8,
+  ) -> c_int;
+  /// See [`SDL_JoystickName`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickName(joystick: *mut SDL_Joystick) -> *const c_char;
+  /// See [`SDL_JoystickGetPlayerIndex`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickGetPlayerIndex(joystick: *mut SDL_Joystick) -> c_int;
+  /// See [`SDL_JoystickSetPlayerIndex`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickSetPlayerIndex(
+    joystick: *mut SDL_Joystick, player_index: c_int,
+  );
+  /// See [`SDL_JoystickGetGUID`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickGetGUID(joystick: *mut SDL_Joystick) -> SDL_JoystickGUID;
+  /// See [`SDL_JoystickGetVendor`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickGetVendor(joystick: *mut SDL_Joystick) -> Uint16;
+  /// See [`SDL_JoystickGetProduct`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickGetProduct(joystick: *mut SDL_Joystick) -> Uint16;
+  /// See [`SDL_JoystickGetProductVersion`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickGetProductVersion(joystick: *mut SDL_Joystick) -> Uint16;
+  /// See [`SDL_JoystickGetSerial`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickGetSerial(joystick: *mut SDL_Joystick) -> *const c_char;
+  /// See [`SDL_JoystickGetType`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickGetType(joystick: *mut SDL_Joystick) -> SDL_JoystickType;
+  /// See [`SDL_JoystickGetGUIDString`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickGetGUIDString(
+    guid: SDL_JoystickGUID, pszGUID: *mut c_char, cbGUID: c_int,
+  );
+  /// See [`SDL_JoystickGetGUIDFromString`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickGetGUIDFromString(pchGUID: *const c_char) -> SDL_JoystickGUID;
+  /// See [`SDL_JoystickGetAttached`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickGetAttached(joystick: *mut SDL_Joystick) -> SDL_bool;
+  /// See [`SDL_JoystickInstanceID`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickInstanceID(joystick: *mut SDL_Joystick) -> SDL_JoystickID;
+  /// See [`SDL_JoystickNumAxes`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickNumAxes(joystick: *mut SDL_Joystick) -> c_int;
+  /// See [`SDL_JoystickNumBalls`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickNumBalls(joystick: *mut SDL_Joystick) -> c_int;
+  /// See [`SDL_JoystickNumHats`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickNumHats(joystick: *mut SDL_Joystick) -> c_int;
+  /// See [`SDL_JoystickNumButtons`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickNumButtons(joystick: *mut SDL_Joystick) -> c_int;
+  /// See [`SDL_JoystickUpdate`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickUpdate();
+  /// See [`SDL_JoystickEventState`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickEventState(state: c_int) -> c_int;
+  /// See [`SDL_JoystickGetAxis`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickGetAxis(joystick: *mut SDL_Joystick, axis: c_int) -> Sint16;
+  /// See [`SDL_JoystickGetAxisInitialState`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickGetAxisInitialState(
+    joystick: *mut SDL_Joystick, axis: c_int, state: *mut Sint16,
+  ) -> SDL_bool;
+  /// See [`SDL_JoystickGetHat`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickGetHat(joystick: *mut SDL_Joystick, hat: c_int) -> u8;
+  /// See [`SDL_JoystickGetBall`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickGetBall(
+    joystick: *mut SDL_Joystick, ball: c_int, dx: *mut c_int, dy: *mut c_int,
+  ) -> c_int;
+  /// See [`SDL_JoystickGetButton`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickGetButton(joystick: *mut SDL_Joystick, button: c_int)
+    -> Uint8;
+  /// See [`SDL_JoystickRumble`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickRumble(
+    joystick: *mut SDL_Joystick, low_frequency_rumble: Uint16,
+    high_frequency_rumble: Uint16, duration_ms: Uint32,
+  ) -> c_int;
+  /// See [`SDL_JoystickRumbleTriggers`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickRumbleTriggers(
+    joystick: *mut SDL_Joystick, left_rumble: Uint16, right_rumble: Uint16,
+    duration_ms: Uint32,
+  ) -> c_int;
+  /// See [`SDL_JoystickHasLED`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickHasLED(joystick: *mut SDL_Joystick) -> SDL_bool;
+  /// See [`SDL_JoystickSetLED`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickSetLED(
+    joystick: *mut SDL_Joystick, red: Uint8, green: Uint8, blue: Uint8,
+  ) -> c_int;
+  /// See [`SDL_JoystickSendEffect`]
+  #[cfg(all(feature = "joystick", feature = "sdl2_2_0_16"))]
+  fn SDL_JoystickSendEffect(
+    joystick: *mut SDL_Joystick, data: *const c_void, size: c_int,
+  ) -> c_int;
+  /// See [`SDL_JoystickClose`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickClose(joystick: *mut SDL_Joystick);
+  /// See [`SDL_JoystickCurrentPowerLevel`]
+  #[cfg(feature = "joystick")]
+  fn SDL_JoystickCurrentPowerLevel(
+    joystick: *mut SDL_Joystick,
+  ) -> SDL_JoystickPowerLevel;
+
+  // keyboard
+  /// See [`SDL_GetKeyboardFocus`]
+  fn SDL_GetKeyboardFocus() -> *mut SDL_Window;
+  /// See [`SDL_GetKeyboardState`]
+  fn SDL_GetKeyboardState(numkeys: *mut c_int) -> *const u8;
+  /// See [`SDL_GetModState`]
+  fn SDL_GetModState() -> SDL_Keymod;
+  /// See [`SDL_SetModState`]
+  fn SDL_SetModState(modstate: SDL_Keymod);
+  /// See [`SDL_GetKeyFromScancode`]
+  fn SDL_GetKeyFromScancode(scancode: SDL_Scancode) -> SDL_Keycode;
+  /// See [`SDL_GetScancodeFromKey`]
+  fn SDL_GetScancodeFromKey(key: SDL_Keycode) -> SDL_Scancode;
+  /// See [`SDL_GetScancodeName`]
+  fn SDL_GetScancodeName(scancode: SDL_Scancode) -> *const c_char;
+  /// See [`SDL_GetScancodeFromName`]
+  fn SDL_GetScancodeFromName(name: *const c_char) -> SDL_Scancode;
+  /// See [`SDL_GetKeyName`]
+  fn SDL_GetKeyName(key: SDL_Keycode) -> *const c_char;
+  /// See [`SDL_GetKeyFromName`]
+  fn SDL_GetKeyFromName(name: *const c_char) -> SDL_Keycode;
+  /// See [`SDL_StartTextInput`]
+  fn SDL_StartTextInput();
+  /// See [`SDL_IsTextInputActive`]
+  fn SDL_IsTextInputActive() -> SDL_bool;
+  /// See [`SDL_StopTextInput`]
+  fn SDL_StopTextInput();
+  /// See [`SDL_ClearComposition`]
+  #[cfg(feature = "sdl2_2_0_22")]
+  fn SDL_ClearComposition();
+  /// See [`SDL_IsTextInputShown`]
+  #[cfg(feature = "sdl2_2_0_22")]
+  fn SDL_IsTextInputShown() -> SDL_bool;
+  /// See [`SDL_SetTextInputRect`]
+  fn SDL_SetTextInputRect(rect: *mut SDL_Rect);
+  /// See [`SDL_HasScreenKeyboardSupport`]
+  fn SDL_HasScreenKeyboardSupport() -> SDL_bool;
+  /// See [`SDL_IsScreenKeyboardShown`]
+  fn SDL_IsScreenKeyboardShown(window: *mut SDL_Window) -> SDL_bool;
+
+  // loadso
+  /// See [`SDL_LoadObject`]
+  fn SDL_LoadObject(sofile: *const c_char) -> *mut c_void;
+  /// See [`SDL_LoadFunction`]
+  fn SDL_LoadFunction(handle: *mut c_void, name: *const c_char) -> *mut c_void;
+  /// See [`SDL_UnloadObject`]
+  fn SDL_UnloadObject(handle: *mut c_void);
+
+  // locale
+  /// See [`SDL_GetPreferredLocales`]
+  fn SDL_GetPreferredLocales() -> *mut SDL_Locale;
+
+  // messagebox
+  /// See [`SDL_ShowMessageBox`]
+  fn SDL_ShowMessageBox(
+    messageboxdata: *const SDL_MessageBoxData, buttonid: *mut c_int,
+  ) -> c_int;
+  /// See [`SDL_ShowSimpleMessageBox`]
+  fn SDL_ShowSimpleMessageBox(
+    flags: SDL_MessageBoxFlags, title: *const c_char, message: *const c_char,
+    window: *mut SDL_Window,
+  ) -> c_int;
+
+  // metal
+  /// See [`SDL_Metal_CreateView`]
+  fn SDL_Metal_CreateView(window: *mut SDL_Window) -> SDL_MetalView;
+  /// See [`SDL_Metal_DestroyView`]
+  fn SDL_Metal_DestroyView(view: SDL_MetalView);
+  /// See [`SDL_Metal_GetLayer`]
+  fn SDL_Metal_GetLayer(view: SDL_MetalView) -> SDL_MetalLayer;
+  /// See [`SDL_Metal_GetDrawableSize`]
+  fn SDL_Metal_GetDrawableSize(
+    window: *mut SDL_Window, w: *mut c_int, h: *mut c_int,
+  );
+
+  // misc
+  /// See [`SDL_OpenURL`]
+  fn SDL_OpenURL(url: *const c_char) -> c_int;
+
+  // mouse
+  /// See [`SDL_GetMouseFocus`]
+  fn SDL_GetMouseFocus() -> *mut SDL_Window;
+  /// See [`SDL_GetMouseState`]
+  fn SDL_GetMouseState(x: *mut c_int, y: *mut c_int) -> u32;
+  /// See [`SDL_GetGlobalMouseState`]
+  fn SDL_GetGlobalMouseState(x: *mut c_int, y: *mut c_int) -> u32;
+  /// See [`SDL_GetRelativeMouseState`]
+  fn SDL_GetRelativeMouseState(x: *mut c_int, y: *mut c_int) -> u32;
+  /// See [`SDL_WarpMouseInWindow`]
+  fn SDL_WarpMouseInWindow(window: *mut SDL_Window, x: c_int, y: c_int);
+  /// See [`SDL_WarpMouseGlobal`]
+  fn SDL_WarpMouseGlobal(x: c_int, y: c_int) -> c_int;
+  /// See [`SDL_SetRelativeMouseMode`]
+  fn SDL_SetRelativeMouseMode(enabled: SDL_bool) -> c_int;
+  /// See [`SDL_CaptureMouse`]
+  fn SDL_CaptureMouse(enabled: SDL_bool) -> c_int;
+  /// See [`SDL_GetRelativeMouseMode`]
+  fn SDL_GetRelativeMouseMode() -> SDL_bool;
+  /// See [`SDL_CreateCursor`]
+  fn SDL_CreateCursor(
+    data: *const Uint8, mask: *const Uint8, w: c_int, h: c_int, hot_x: c_int,
+    hot_y: c_int,
+  ) -> *mut SDL_Cursor;
+  /// See [`SDL_CreateColorCursor`]
+  fn SDL_CreateColorCursor(
+    surface: *mut SDL_Surface, hot_x: c_int, hot_y: c_int,
+  ) -> *mut SDL_Cursor;
+  /// See [`SDL_CreateSystemCursor`]
+  fn SDL_CreateSystemCursor(id: SDL_SystemCursor) -> *mut SDL_Cursor;
+  /// See [`SDL_SetCursor`]
+  fn SDL_SetCursor(cursor: *mut SDL_Cursor);
+  /// See [`SDL_GetCursor`]
+  fn SDL_GetCursor() -> *mut SDL_Cursor;
+  /// See [`SDL_GetDefaultCursor`]
+  fn SDL_GetDefaultCursor() -> *mut SDL_Cursor;
+  /// See [`SDL_FreeCursor`]
+  fn SDL_FreeCursor(cursor: *mut SDL_Cursor);
+  /// See [`SDL_ShowCursor`]
+  fn SDL_ShowCursor(toggle: c_int) -> c_int;
+
+  // mutex
+  /// See [`SDL_CreateMutex`]
+  fn SDL_CreateMutex() -> *mut SDL_mutex;
+  /// See [`SDL_LockMutex`]
+  fn SDL_LockMutex(mutex: *mut SDL_mutex) -> c_int;
+  /// See [`SDL_TryLockMutex`]
+  fn SDL_TryLockMutex(mutex: *mut SDL_mutex) -> c_int;
+  /// See [`SDL_UnlockMutex`]
+  fn SDL_UnlockMutex(mutex: *mut SDL_mutex) -> c_int;
+  /// See [`SDL_DestroyMutex`]
+  fn SDL_DestroyMutex(mutex: *mut SDL_mutex);
+  /// See [`SDL_CreateSemaphore`]
+  fn SDL_CreateSemaphore(initial_value: Uint32) -> *mut SDL_sem;
+  /// See [`SDL_DestroySemaphore`]
+  fn SDL_DestroySemaphore(sem: *mut SDL_sem);
+  /// See [`SDL_SemWait`]
+  fn SDL_SemWait(sem: *mut SDL_sem) -> c_int;
+  /// See [`SDL_SemTryWait`]
+  fn SDL_SemTryWait(sem: *mut SDL_sem) -> c_int;
+  /// See [`SDL_SemWaitTimeout`]
+  fn SDL_SemWaitTimeout(sem: *mut SDL_sem, ms: Uint32) -> c_int;
+  /// See [`SDL_SemPost`]
+  fn SDL_SemPost(sem: *mut SDL_sem) -> c_int;
+  /// See [`SDL_SemValue`]
+  fn SDL_SemValue(sem: *mut SDL_sem) -> Uint32;
+  /// See [`SDL_CreateCond`]
+  fn SDL_CreateCond() -> *mut SDL_cond;
+  /// See [`SDL_DestroyCond`]
+  fn SDL_DestroyCond(cond: *mut SDL_cond);
+  /// See [`SDL_CondSignal`]
+  fn SDL_CondSignal(cond: *mut SDL_cond) -> c_int;
+  /// See [`SDL_CondBroadcast`]
+  fn SDL_CondBroadcast(cond: *mut SDL_cond) -> c_int;
+  /// See [`SDL_CondWait`]
+  fn SDL_CondWait(cond: *mut SDL_cond, mutex: *mut SDL_mutex) -> c_int;
+  /// See [`SDL_CondWaitTimeout`]
+  fn SDL_CondWaitTimeout(
+    cond: *mut SDL_cond, mutex: *mut SDL_mutex, ms: Uint32,
+  ) -> c_int;
+
+  // pixels
+  /// See [`SDL_GetPixelFormatName`]
+  fn SDL_GetPixelFormatName(format: Uint32) -> *const c_char;
+  /// See [`SDL_PixelFormatEnumToMasks`]
+  fn SDL_PixelFormatEnumToMasks(
+    format: Uint32, bpp: *mut c_int, Rmask: *mut Uint32, Gmask: *mut Uint32,
+    Bmask: *mut Uint32, Amask: *mut Uint32,
+  ) -> SDL_bool;
+  /// See [`SDL_MasksToPixelFormatEnum`]
+  fn SDL_MasksToPixelFormatEnum(
+    bpp: c_int, Rmask: Uint32, Gmask: Uint32, Bmask: Uint32, Amask: Uint32,
+  ) -> Uint32;
+  /// See [`SDL_AllocFormat`]
+  fn SDL_AllocFormat(pixel_format: Uint32) -> *mut SDL_PixelFormat;
+  /// See [`SDL_FreeFormat`]
+  fn SDL_FreeFormat(format: *mut SDL_PixelFormat);
+  /// See [`SDL_AllocPalette`]
+  fn SDL_AllocPalette(ncolors: c_int) -> *mut SDL_Palette;
+  /// See [`SDL_SetPixelFormatPalette`]
+  fn SDL_SetPixelFormatPalette(
+    format: *mut SDL_PixelFormat, palette: *mut SDL_Palette,
+  ) -> c_int;
+  /// See [`SDL_SetPaletteColors`]
+  fn SDL_SetPaletteColors(
+    palette: *mut SDL_Palette, colors: *const SDL_Color, firstcolor: c_int,
+    ncolors: c_int,
+  ) -> c_int;
+  /// See [`SDL_FreePalette`]
+  fn SDL_FreePalette(palette: *mut SDL_Palette);
+  /// See [`SDL_MapRGB`]
+  fn SDL_MapRGB(
+    format: *const SDL_PixelFormat, r: Uint8, g: Uint8, b: Uint8,
+  ) -> Uint32;
+  /// See [`SDL_MapRGBA`]
+  fn SDL_MapRGBA(
+    format: *const SDL_PixelFormat, r: Uint8, g: Uint8, b: Uint8, a: Uint8,
+  ) -> Uint32;
+  /// See [`SDL_GetRGB`]
+  fn SDL_GetRGB(
+    pixel: Uint32, format: *const SDL_PixelFormat, r: *mut Uint8,
+    g: *mut Uint8, b: *mut Uint8,
+  );
+  /// See [`SDL_GetRGBA`]
+  fn SDL_GetRGBA(
+    pixel: Uint32, format: *const SDL_PixelFormat, r: *mut Uint8,
+    g: *mut Uint8, b: *mut Uint8, a: *mut Uint8,
+  );
+  /// See [`SDL_CalculateGammaRamp`]
+  fn SDL_CalculateGammaRamp(gamma: f32, ramp: *mut Uint16);
+
+  // platform
+  /// See [`SDL_GetPlatform`]
+  fn SDL_GetPlatform() -> *const c_char;
+
+  // power
+  /// See [`SDL_GetPowerInfo`]
+  fn SDL_GetPowerInfo(secs: *mut c_int, pct: *mut c_int) -> SDL_PowerState;
+
+  // rect
+  /// See [`SDL_HasIntersection`]
+  fn SDL_HasIntersection(A: *const SDL_Rect, B: *const SDL_Rect) -> SDL_bool;
+  /// See [`SDL_IntersectRect`]
+  fn SDL_IntersectRect(
+    A: *const SDL_Rect, B: *const SDL_Rect, result: *mut SDL_Rect,
+  ) -> SDL_bool;
+  /// See [`SDL_UnionRect`]
+  fn SDL_UnionRect(
+    A: *const SDL_Rect, B: *const SDL_Rect, result: *mut SDL_Rect,
+  );
+  /// See [`SDL_EnclosePoints`]
+  fn SDL_EnclosePoints(
+    points: *const SDL_Point, count: c_int, clip: *const SDL_Rect,
+    result: *mut SDL_Rect,
+  ) -> SDL_bool;
+  /// See [`SDL_IntersectRectAndLine`]
+  fn SDL_IntersectRectAndLine(
+    rect: *const SDL_Rect, X1: *mut c_int, Y1: *mut c_int, X2: *mut c_int,
+    Y2: *mut c_int,
+  ) -> SDL_bool;
+
+  // renderer
+  /// See [`SDL_GetNumRenderDrivers`]
+  #[cfg(feature = "renderer")]
+  fn SDL_GetNumRenderDrivers() -> c_int;
+  /// See [`SDL_GetRenderDriverInfo`]
+  #[cfg(feature = "renderer")]
+  fn SDL_GetRenderDriverInfo(
+    index: c_int, info: *mut SDL_RendererInfo,
+  ) -> c_int;
+  /// See [`SDL_CreateWindowAndRenderer`]
+  #[cfg(feature = "renderer")]
+  fn SDL_CreateWindowAndRenderer(
+    width: c_int, height: c_int, window_flags: Uint32,
+    window: *mut *mut SDL_Window, renderer: *mut *mut SDL_Renderer,
+  ) -> c_int;
+  /// See [`SDL_CreateRenderer`]
+  #[cfg(feature = "renderer")]
+  fn SDL_CreateRenderer(
+    window: *mut SDL_Window, index: c_int, flags: Uint32,
+  ) -> *mut SDL_Renderer;
+  /// See [`SDL_CreateSoftwareRenderer`]
+  #[cfg(feature = "renderer")]
+  fn SDL_CreateSoftwareRenderer(surface: *mut SDL_Surface)
+    -> *mut SDL_Renderer;
+  /// See [`SDL_GetRenderer`]
+  #[cfg(feature = "renderer")]
+  fn SDL_GetRenderer(window: *mut SDL_Window) -> *mut SDL_Renderer;
+  /// See [`SDL_GetRendererInfo`]
+  #[cfg(feature = "renderer")]
+  fn SDL_GetRendererInfo(
+    renderer: *mut SDL_Renderer, info: *mut SDL_RendererInfo,
+  ) -> c_int;
+  /// See [`SDL_GetRendererOutputSize`]
+  #[cfg(feature = "renderer")]
+  fn SDL_GetRendererOutputSize(
+    renderer: *mut SDL_Renderer, w: *mut c_int, h: *mut c_int,
+  ) -> c_int;
+  /// See [`SDL_CreateTexture`]
+  #[cfg(feature = "renderer")]
+  fn SDL_CreateTexture(
+    renderer: *mut SDL_Renderer, format: Uint32, access: c_int, w: c_int,
+    h: c_int,
+  ) -> *mut SDL_Texture;
+  /// See [`SDL_CreateTextureFromSurface`]
+  #[cfg(feature = "renderer")]
+  fn SDL_CreateTextureFromSurface(
+    renderer: *mut SDL_Renderer, surface: *mut SDL_Surface,
+  ) -> *mut SDL_Texture;
+  /// See [`SDL_QueryTexture`]
+  #[cfg(feature = "renderer")]
+  fn SDL_QueryTexture(
+    texture: *mut SDL_Texture, format: *mut Uint32, access: *mut c_int,
+    w: *mut c_int, h: *mut c_int,
+  ) -> c_int;
+  /// See [`SDL_SetTextureColorMod`]
+  #[cfg(feature = "renderer")]
+  fn SDL_SetTextureColorMod(
+    texture: *mut SDL_Texture, r: Uint8, g: Uint8, b: Uint8,
+  ) -> c_int;
+  /// See [`SDL_GetTextureColorMod`]
+  #[cfg(feature = "renderer")]
+  fn SDL_GetTextureColorMod(
+    texture: *mut SDL_Texture, r: *mut Uint8, g: *mut Uint8, b: *mut Uint8,
+  ) -> c_int;
+  /// See [`SDL_SetTextureAlphaMod`]
+  #[cfg(feature = "renderer")]
+  fn SDL_SetTextureAlphaMod(texture: *mut SDL_Texture, alpha: Uint8) -> c_int;
+  /// See [`SDL_GetTextureAlphaMod`]
+  #[cfg(feature = "renderer")]
+  fn SDL_GetTextureAlphaMod(
+    texture: *mut SDL_Texture, alpha: *mut Uint8,
+  ) -> c_int;
+  /// See [`SDL_SetTextureBlendMode`]
+  #[cfg(feature = "renderer")]
+  fn SDL_SetTextureBlendMode(
+    texture: *mut SDL_Texture, blendMode: SDL_BlendMode,
+  ) -> c_int;
+  /// See [`SDL_GetTextureBlendMode`]
+  #[cfg(feature = "renderer")]
+  fn SDL_GetTextureBlendMode(
+    texture: *mut SDL_Texture, blendMode: *mut SDL_BlendMode,
+  ) -> c_int;
+  /// See [`SDL_SetTextureScaleMode`]
+  #[cfg(feature = "renderer")]
+  fn SDL_SetTextureScaleMode(
+    texture: *mut SDL_Texture, scaleMode: SDL_ScaleMode,
+  ) -> c_int;
+  /// See [`SDL_GetTextureScaleMode`]
+  #[cfg(feature = "renderer")]
+  fn SDL_GetTextureScaleMode(
+    texture: *mut SDL_Texture, scaleMode: *mut SDL_ScaleMode,
+  ) -> c_int;
+  /// See [`SDL_UpdateTexture`]
+  #[cfg(feature = "renderer")]
+  fn SDL_UpdateTexture(
+    texture: *mut SDL_Texture, rect: *const SDL_Rect, pixels: *const c_void,
+    pitch: c_int,
+  ) -> c_int;
+  /// See [`SDL_UpdateYUVTexture`]
+  #[cfg(feature = "renderer")]
+  fn SDL_UpdateYUVTexture(
+    texture: *mut SDL_Texture, rect: *const SDL_Rect, Yplane: *const Uint8,
+    Ypitch: c_int, Uplane: *const Uint8, Upitch: c_int, Vplane: *const Uint8,
+    Vpitch: c_int,
+  ) -> c_int;
+  /// See [`SDL_LockTexture`]
+  #[cfg(feature = "renderer")]
+  fn SDL_LockTexture(
+    texture: *mut SDL_Texture, rect: *const SDL_Rect, pixels: *mut *mut c_void,
+    pitch: *mut c_int,
+  ) -> c_int;
+  /// See [`SDL_LockTextureToSurface`]
+  #[cfg(feature = "renderer")]
+  fn SDL_LockTextureToSurface(
+    texture: *mut SDL_Texture, rect: *const SDL_Rect,
+    surface: *mut *mut SDL_Surface,
+  ) -> c_int;
+  /// See [`SDL_UnlockTexture`]
+  #[cfg(feature = "renderer")]
+  fn SDL_UnlockTexture(texture: *mut SDL_Texture);
+  /// See [`SDL_RenderTargetSupported`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderTargetSupported(renderer: *mut SDL_Renderer) -> SDL_bool;
+  /// See [`SDL_SetRenderTarget`]
+  #[cfg(feature = "renderer")]
+  fn SDL_SetRenderTarget(
+    renderer: *mut SDL_Renderer, texture: *mut SDL_Texture,
+  ) -> c_int;
+  /// See [`SDL_GetRenderTarget`]
+  #[cfg(feature = "renderer")]
+  fn SDL_GetRenderTarget(renderer: *mut SDL_Renderer) -> *mut SDL_Texture;
+  /// See [`SDL_RenderSetLogicalSize`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderSetLogicalSize(
+    renderer: *mut SDL_Renderer, w: c_int, h: c_int,
+  ) -> c_int;
+  /// See [`SDL_RenderGetLogicalSize`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderGetLogicalSize(
+    renderer: *mut SDL_Renderer, w: *mut c_int, h: *mut c_int,
+  );
+  /// See [`SDL_RenderSetIntegerScale`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderSetIntegerScale(
+    renderer: *mut SDL_Renderer, enable: SDL_bool,
+  ) -> c_int;
+  /// See [`SDL_RenderGetIntegerScale`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderGetIntegerScale(renderer: *mut SDL_Renderer) -> SDL_bool;
+  /// See [`SDL_RenderSetViewport`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderSetViewport(
+    renderer: *mut SDL_Renderer, rect: *const SDL_Rect,
+  ) -> c_int;
+  /// See [`SDL_RenderGetViewport`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderGetViewport(
+    renderer: *mut SDL_Renderer, rect: *mut SDL_Rect,
+  ) -> c_void;
+  /// See [`SDL_RenderSetClipRect`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderSetClipRect(
+    renderer: *mut SDL_Renderer, rect: *const SDL_Rect,
+  ) -> c_int;
+  /// See [`SDL_RenderGetClipRect`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderGetClipRect(
+    renderer: *mut SDL_Renderer, rect: *mut SDL_Rect,
+  ) -> c_void;
+  /// See [`SDL_RenderIsClipEnabled`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderIsClipEnabled(renderer: *mut SDL_Renderer) -> SDL_bool;
+  /// See [`SDL_RenderSetScale`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderSetScale(
+    renderer: *mut SDL_Renderer, scaleX: f32, scaleY: f32,
+  ) -> c_int;
+  /// See [`SDL_RenderGetScale`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderGetScale(
+    renderer: *mut SDL_Renderer, scaleX: *mut f32, scaleY: *mut f32,
+  );
+  /// See [`SDL_RenderWindowToLogical`]
+  #[cfg(all(feature = "renderer", feature = "sdl2_2_0_18"))]
+  fn SDL_RenderWindowToLogical(
+    renderer: *mut SDL_Renderer, windowX: c_int, windowY: c_int,
+    logicalX: *mut f32, logicalY: *mut f32,
+  );
+  /// See [`SDL_RenderLogicalToWindow`]
+  #[cfg(all(feature = "renderer", feature = "sdl2_2_0_18"))]
+  fn SDL_RenderLogicalToWindow(
+    renderer: *mut SDL_Renderer, logicalX: f32, logicalY: f32,
+    windowX: *mut c_int, windowY: *mut c_int,
+  );
+  /// See [`SDL_SetRenderDrawColor`]
+  #[cfg(feature = "renderer")]
+  fn SDL_SetRenderDrawColor(
+    renderer: *mut SDL_Renderer, r: Uint8, g: Uint8, b: Uint8, a: Uint8,
+  ) -> c_int;
+  /// See [`SDL_GetRenderDrawColor`]
+  #[cfg(feature = "renderer")]
+  fn SDL_GetRenderDrawColor(
+    renderer: *mut SDL_Renderer, r: *mut Uint8, g: *mut Uint8, b: *mut Uint8,
+    a: *mut Uint8,
+  ) -> c_int;
+  /// See [`SDL_SetRenderDrawBlendMode`]
+  #[cfg(feature = "renderer")]
+  fn SDL_SetRenderDrawBlendMode(
+    renderer: *mut SDL_Renderer, blendMode: SDL_BlendMode,
+  ) -> c_int;
+  /// See [`SDL_GetRenderDrawBlendMode`]
+  #[cfg(feature = "renderer")]
+  fn SDL_GetRenderDrawBlendMode(
+    renderer: *mut SDL_Renderer, blendMode: *mut SDL_BlendMode,
+  ) -> c_int;
+  /// See [`SDL_RenderClear`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderClear(renderer: *mut SDL_Renderer) -> c_int;
+  /// See [`SDL_RenderDrawPoint`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderDrawPoint(
+    renderer: *mut SDL_Renderer, x: c_int, y: c_int,
+  ) -> c_int;
+  /// See [`SDL_RenderDrawPoints`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderDrawPoints(
+    renderer: *mut SDL_Renderer, points: *const SDL_Point, count: c_int,
+  ) -> c_int;
+  /// See [`SDL_RenderDrawLine`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderDrawLine(
+    renderer: *mut SDL_Renderer, x1: c_int, y1: c_int, x2: c_int, y2: c_int,
+  ) -> c_int;
+  /// See [`SDL_RenderDrawLines`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderDrawLines(
+    renderer: *mut SDL_Renderer, points: *const SDL_Point, count: c_int,
+  ) -> c_int;
+  /// See [`SDL_RenderDrawRect`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderDrawRect(
+    renderer: *mut SDL_Renderer, rect: *const SDL_Rect,
+  ) -> c_int;
+  /// See [`SDL_RenderDrawRects`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderDrawRects(
+    renderer: *mut SDL_Renderer, rects: *const SDL_Rect, count: c_int,
+  ) -> c_int;
+  /// See [`SDL_RenderFillRect`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderFillRect(
+    renderer: *mut SDL_Renderer, rect: *const SDL_Rect,
+  ) -> c_int;
+  /// See [`SDL_RenderFillRects`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderFillRects(
+    renderer: *mut SDL_Renderer, rects: *const SDL_Rect, count: c_int,
+  ) -> c_int;
+  /// See [`SDL_RenderCopy`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderCopy(
+    renderer: *mut SDL_Renderer, texture: *mut SDL_Texture,
+    srcrect: *const SDL_Rect, dstrect: *const SDL_Rect,
+  ) -> c_int;
+  /// See [`SDL_RenderCopyEx`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderCopyEx(
+    renderer: *mut SDL_Renderer, texture: *mut SDL_Texture,
+    srcrect: *const SDL_Rect, dstrect: *const SDL_Rect, angle: f64,
+    center: *const SDL_Point, flip: SDL_RendererFlip,
+  ) -> c_int;
+  /// See [`SDL_RenderDrawPointF`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderDrawPointF(renderer: *mut SDL_Renderer, x: f32, y: f32)
+    -> c_int;
+  /// See [`SDL_RenderDrawPointsF`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderDrawPointsF(
+    renderer: *mut SDL_Renderer, points: *const SDL_FPoint, count: c_int,
+  ) -> c_int;
+  /// See [`SDL_RenderDrawLineF`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderDrawLineF(
+    renderer: *mut SDL_Renderer, x1: f32, y1: f32, x2: f32, y2: f32,
+  ) -> c_int;
+  /// See [`SDL_RenderDrawLinesF`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderDrawLinesF(
+    renderer: *mut SDL_Renderer, points: *const SDL_FPoint, count: c_int,
+  ) -> c_int;
+  /// See [`SDL_RenderDrawRectF`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderDrawRectF(
+    renderer: *mut SDL_Renderer, rect: *const SDL_FRect,
+  ) -> c_int;
+  /// See [`SDL_RenderDrawRectsF`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderDrawRectsF(
+    renderer: *mut SDL_Renderer, rects: *const SDL_FRect, count: c_int,
+  ) -> c_int;
+  /// See [`SDL_RenderFillRectF`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderFillRectF(
+    renderer: *mut SDL_Renderer, rect: *const SDL_FRect,
+  ) -> c_int;
+  /// See [`SDL_RenderFillRectsF`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderFillRectsF(
+    renderer: *mut SDL_Renderer, rects: *const SDL_FRect, count: c_int,
+  ) -> c_int;
+  /// See [`SDL_RenderCopyF`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderCopyF(
+    renderer: *mut SDL_Renderer, texture: *mut SDL_Texture,
+    srcrect: *const SDL_Rect, dstrect: *const SDL_FRect,
+  ) -> c_int;
+  /// See [`SDL_RenderCopyExF`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderCopyExF(
+    renderer: *mut SDL_Renderer, texture: *mut SDL_Texture,
+    srcrect: *const SDL_Rect, dstrect: *const SDL_FRect, angle: f64,
+    center: *const SDL_FPoint, flip: SDL_RendererFlip,
+  ) -> c_int;
+  /// See [`SDL_RenderGeometry`]
+  #[cfg(all(feature = "renderer", feature = "sdl2_2_0_18"))]
+  fn SDL_RenderGeometry(
+    renderer: *mut SDL_Renderer, texture: *mut SDL_Texture,
+    vertices: *const SDL_Vertex, num_vertices: c_int, indices: *const c_int,
+    num_indices: c_int,
+  ) -> c_int;
+  /// See [`SDL_RenderGeometryRaw`]
+  #[cfg(all(feature = "renderer", feature = "sdl2_2_0_18"))]
+  fn SDL_RenderGeometryRaw(
+    renderer: *mut SDL_Renderer, texture: *mut SDL_Texture, xy: *const f32,
+    xy_stride: c_int, color: *const SDL_Color, color_stride: c_int,
+    uv: *const f32, uv_stride: c_int, num_vertices: c_int,
+    indices: *const c_void, num_indices: c_int, size_indices: c_int,
+  ) -> c_int;
+  /// See [`SDL_RenderReadPixels`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderReadPixels(
+    renderer: *mut SDL_Renderer, rect: *const SDL_Rect, format: Uint32,
+    pixels: *mut c_void, pitch: c_int,
+  ) -> c_int;
+  /// See [`SDL_RenderPresent`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderPresent(renderer: *mut SDL_Renderer);
+  /// See [`SDL_DestroyTexture`]
+  #[cfg(feature = "renderer")]
+  fn SDL_DestroyTexture(texture: *mut SDL_Texture);
+  /// See [`SDL_DestroyRenderer`]
+  #[cfg(feature = "renderer")]
+  fn SDL_DestroyRenderer(renderer: *mut SDL_Renderer);
+  /// See [`SDL_RenderFlush`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderFlush(renderer: *mut SDL_Renderer) -> c_int;
+  /// See [`SDL_GL_BindTexture`]
+  #[cfg(feature = "renderer")]
+  fn SDL_GL_BindTexture(
+    texture: *mut SDL_Texture, texw: *mut f32, texh: *mut f32,
+  ) -> c_int;
+  /// See [`SDL_GL_UnbindTexture`]
+  #[cfg(feature = "renderer")]
+  fn SDL_GL_UnbindTexture(texture: *mut SDL_Texture) -> c_int;
+  /// See [`SDL_RenderGetMetalLayer`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderGetMetalLayer(renderer: *mut SDL_Renderer) -> *mut c_void;
+  /// See [`SDL_RenderGetMetalCommandEncoder`]
+  #[cfg(feature = "renderer")]
+  fn SDL_RenderGetMetalCommandEncoder(
+    renderer: *mut SDL_Renderer,
+  ) -> *mut c_void;
+  /// See [`SDL_RenderSetVSync`]
+  #[cfg(all(feature = "renderer", feature = "sdl2_2_0_18"))]
+  fn SDL_RenderSetVSync(renderer: *mut SDL_Renderer, vsync: c_int) -> c_int;
+  /// See [`SDL_UpdateNVTexture`]
+  #[cfg(all(feature = "renderer", feature = "sdl2_2_0_16"))]
+  fn SDL_UpdateNVTexture(
+    texture: *mut SDL_Texture, rect: *const SDL_Rect, Yplane: *const Uint8,
+    Ypitch: c_int, UVplane: *const Uint8, UVpitch: c_int,
+  ) -> c_int;
+
+  // rwops
+  /// See [`SDL_RWFromFile`]
+  fn SDL_RWFromFile(file: *const c_char, mode: *const c_char)
+    -> *mut SDL_RWops;
+  /// See [`SDL_RWFromMem`]
+  fn SDL_RWFromMem(mem: *mut c_void, size: c_int) -> *mut SDL_RWops;
+  /// See [`SDL_RWFromConstMem`]
+  fn SDL_RWFromConstMem(mem: *const c_void, size: c_int) -> *mut SDL_RWops;
+  /// See [`SDL_FreeRW`]
+  fn SDL_FreeRW(area: *mut SDL_RWops);
+
+  // sensor
+  /// See [`SDL_LockSensors`]
+  #[cfg(feature = "sensor")]
+  fn SDL_LockSensors() -> c_int;
+  /// See [`SDL_UnlockSensors`]
+  #[cfg(feature = "sensor")]
+  fn SDL_UnlockSensors() -> c_int;
+  /// See [`SDL_NumSensors`]
+  #[cfg(feature = "sensor")]
+  fn SDL_NumSensors() -> c_int;
+  /// See [`SDL_SensorGetDeviceName`]
+  #[cfg(feature = "sensor")]
+  fn SDL_SensorGetDeviceName(device_index: c_int) -> *const c_char;
+  /// See [`SDL_SensorGetDeviceType`]
+  #[cfg(feature = "sensor")]
+  fn SDL_SensorGetDeviceType(device_index: c_int) -> SDL_SensorType;
+  /// See [`SDL_SensorGetDeviceNonPortableType`]
+  #[cfg(feature = "sensor")]
+  fn SDL_SensorGetDeviceNonPortableType(device_index: c_int) -> c_int;
+  /// See [`SDL_SensorGetDeviceInstanceID`]
+  #[cfg(feature = "sensor")]
+  fn SDL_SensorGetDeviceInstanceID(device_index: c_int) -> SDL_SensorID;
+  /// See [`SDL_SensorOpen`]
+  #[cfg(feature = "sensor")]
+  fn SDL_SensorOpen(device_index: c_int) -> *mut SDL_Sensor;
+  /// See [`SDL_SensorFromInstanceID`]
+  #[cfg(feature = "sensor")]
+  fn SDL_SensorFromInstanceID(instance_id: SDL_SensorID) -> *mut SDL_Sensor;
+  /// See [`SDL_SensorGetName`]
+  #[cfg(feature = "sensor")]
+  fn SDL_SensorGetName(sensor: *mut SDL_Sensor) -> *const c_char;
+  /// See [`SDL_SensorGetType`]
+  #[cfg(feature = "sensor")]
+  fn SDL_SensorGetType(sensor: *mut SDL_Sensor) -> SDL_SensorType;
+  /// See [`SDL_SensorGetNonPortableType`]
+  #[cfg(feature = "sensor")]
+  fn SDL_SensorGetNonPortableType(sensor: *mut SDL_Sensor) -> c_int;
+  /// See [`SDL_SensorGetInstanceID`]
+  #[cfg(feature = "sensor")]
+  fn SDL_SensorGetInstanceID(sensor: *mut SDL_Sensor) -> SDL_SensorID;
+  /// See [`SDL_SensorGetData`]
+  #[cfg(feature = "sensor")]
+  fn SDL_SensorGetData(
+    sensor: *mut SDL_Sensor, data: *mut c_float, num_values: c_int,
+  ) -> c_int;
+  /// See [`SDL_SensorGetDataWithTimestamp`]
+  #[cfg(all(feature = "sensor", feature = "sdl2_2_26"))]
+  fn SDL_SensorGetDataWithTimestamp(
+    sensor: *mut SDL_Sensor, timestamp: *mut Uint64, data: *mut c_float,
+    num_values: c_int,
+  ) -> c_int;
+  /// See [`SDL_SensorClose`]
+  #[cfg(feature = "sensor")]
+  fn SDL_SensorClose(sensor: *mut SDL_Sensor);
+  /// See [`SDL_SensorUpdate`]
+  #[cfg(feature = "sensor")]
+  fn SDL_SensorUpdate();
+
+  // shape
+  /// See [`SDL_CreateShapedWindow`]
+  fn SDL_CreateShapedWindow(
+    title: *const c_char, x: c_uint, y: c_uint, w: c_uint, h: c_uint,
+    flags: Uint32,
+  ) -> *mut SDL_Window;
+  /// See [`SDL_IsShapedWindow`]
+  fn SDL_IsShapedWindow(window: *const SDL_Window) -> SDL_bool;
+  /// See [`SDL_SetWindowShape`]
+  fn SDL_SetWindowShape(
+    window: *mut SDL_Window, shape: *mut SDL_Surface,
+    shape_mode: *mut SDL_WindowShapeMode,
+  ) -> c_int;
+  /// See [`SDL_GetShapedWindowMode`]
+  fn SDL_GetShapedWindowMode(
+    window: *mut SDL_Window, shape_mode: *mut SDL_WindowShapeMode,
+  ) -> c_int;
+
+  // stdinc
+  /// See [`SDL_malloc`]
+  fn SDL_malloc(size: usize) -> *mut c_void;
+  /// See [`SDL_calloc`]
+  fn SDL_calloc(nmemb: usize, size: usize) -> *mut c_void;
+  /// See [`SDL_realloc`]
+  fn SDL_realloc(mem: *mut c_void, size: usize) -> *mut c_void;
+  /// See [`SDL_free`]
+  fn SDL_free(mem: *mut c_void);
+  /// See [`SDL_GetMemoryFunctions`]
+  fn SDL_GetMemoryFunctions(
+    malloc_func: *mut SDL_malloc_func, calloc_func: *mut SDL_calloc_func,
+    realloc_func: *mut SDL_realloc_func, free_func: *mut SDL_free_func,
+  );
+  /// See [`SDL_SetMemoryFunctions`]
+  fn SDL_SetMemoryFunctions(
+    malloc_func: SDL_malloc_func, calloc_func: SDL_calloc_func,
+    realloc_func: SDL_realloc_func, free_func: SDL_free_func,
+  ) -> c_int;
+  /// See [`SDL_GetNumAllocations`]
+  fn SDL_GetNumAllocations() -> c_int;
+
+  // surface
+  /// See [`SDL_CreateRGBSurface`]
+  fn SDL_CreateRGBSurface(
+    flags: Uint32, width: c_int, height: c_int, depth: c_int, Rmask: Uint32,
+    Gmask: Uint32, Bmask: Uint32, Amask: Uint32,
+  ) -> *mut SDL_Surface;
+  /// See [`SDL_CreateRGBSurfaceWithFormat`]
+  fn SDL_CreateRGBSurfaceWithFormat(
+    flags: Uint32, width: c_int, height: c_int, depth: c_int, format: Uint32,
+  ) -> *mut SDL_Surface;
+  /// See [`SDL_CreateRGBSurfaceFrom`]
+  fn SDL_CreateRGBSurfaceFrom(
+    pixels: *mut c_void, width: c_int, height: c_int, depth: c_int,
+    pitch: c_int, Rmask: Uint32, Gmask: Uint32, Bmask: Uint32, Amask: Uint32,
+  ) -> *mut SDL_Surface;
+  /// See [`SDL_CreateRGBSurfaceWithFormatFrom`]
+  fn SDL_CreateRGBSurfaceWithFormatFrom(
+    pixels: *mut c_void, width: c_int, height: c_int, depth: c_int,
+    pitch: c_int, format: Uint32,
+  ) -> *mut SDL_Surface;
+  /// See [`SDL_FreeSurface`]
+  fn SDL_FreeSurface(surface: *mut SDL_Surface);
+  /// See [`SDL_SetSurfacePalette`]
+  fn SDL_SetSurfacePalette(
+    surface: *mut SDL_Surface, palette: *mut SDL_Palette,
+  ) -> c_int;
+  /// See [`SDL_LockSurface`]
+  fn SDL_LockSurface(surface: *mut SDL_Surface) -> c_int;
+  /// See [`SDL_UnlockSurface`]
+  fn SDL_UnlockSurface(surface: *mut SDL_Surface);
+  /// See [`SDL_LoadBMP_RW`]
+  fn SDL_LoadBMP_RW(src: *mut SDL_RWops, freesrc: c_int) -> *mut SDL_Surface;
+  /// See [`SDL_SaveBMP_RW`]
+  fn SDL_SaveBMP_RW(
+    surface: *mut SDL_Surface, dst: *mut SDL_RWops, freedst: c_int,
+  ) -> c_int;
+  /// See [`SDL_SetSurfaceRLE`]
+  fn SDL_SetSurfaceRLE(surface: *mut SDL_Surface, flag: c_int) -> c_int;
+  /// See [`SDL_HasSurfaceRLE`]
+  fn SDL_HasSurfaceRLE(surface: *mut SDL_Surface) -> SDL_bool;
+  /// See [`SDL_SetColorKey`]
+  fn SDL_SetColorKey(
+    surface: *mut SDL_Surface, flag: c_int, key: Uint32,
+  ) -> c_int;
+  /// See [`SDL_HasColorKey`]
+  fn SDL_HasColorKey(surface: *mut SDL_Surface) -> SDL_bool;
+  /// See [`SDL_GetColorKey`]
+  fn SDL_GetColorKey(surface: *mut SDL_Surface, key: *mut Uint32) -> c_int;
+  /// See [`SDL_SetSurfaceColorMod`]
+  fn SDL_SetSurfaceColorMod(
+    surface: *mut SDL_Surface, r: Uint8, g: Uint8, b: Uint8,
+  ) -> c_int;
+  /// See [`SDL_GetSurfaceColorMod`]
+  fn SDL_GetSurfaceColorMod(
+    surface: *mut SDL_Surface, r: *mut Uint8, g: *mut Uint8, b: *mut Uint8,
+  ) -> c_int;
+  /// See [`SDL_SetSurfaceAlphaMod`]
+  fn SDL_SetSurfaceAlphaMod(surface: *mut SDL_Surface, alpha: Uint8) -> c_int;
+  /// See [`SDL_SetSurfaceBlendMode`]
+  fn SDL_SetSurfaceBlendMode(
+    surface: *mut SDL_Surface, blendMode: SDL_BlendMode,
+  ) -> c_int;
+  /// See [`SDL_GetSurfaceBlendMode`]
+  fn SDL_GetSurfaceBlendMode(
+    surface: *mut SDL_Surface, blendMode: *mut SDL_BlendMode,
+  ) -> c_int;
+  /// See [`SDL_SetClipRect`]
+  fn SDL_SetClipRect(
+    surface: *mut SDL_Surface, rect: *const SDL_Rect,
+  ) -> SDL_bool;
+  /// See [`SDL_GetClipRect`]
+  fn SDL_GetClipRect(surface: *mut SDL_Surface, rect: *mut SDL_Rect);
+  /// See [`SDL_DuplicateSurface`]
+  fn SDL_DuplicateSurface(surface: *mut SDL_Surface) -> *mut SDL_Surface;
+  /// See [`SDL_ConvertSurface`]
+  fn SDL_ConvertSurface(
+    src: *mut SDL_Surface, fmt: *const SDL_PixelFormat, flags: Uint32,
+  ) -> *mut SDL_Surface;
+  /// See [`SDL_ConvertSurfaceFormat`]
+  fn SDL_ConvertSurfaceFormat(
+    src: *mut SDL_Surface, pixel_format: Uint32, flags: Uint32,
+  ) -> *mut SDL_Surface;
+  /// See [`SDL_ConvertPixels`]
+  fn SDL_ConvertPixels(
+    width: c_int, height: c_int, src_format: Uint32, src: *const c_void,
+    src_pitch: c_int, dst_format: Uint32, dst: *mut c_void, dst_pitch: c_int,
+  ) -> c_int;
+  /// See [`SDL_PremultiplyAlpha`]
+  #[cfg(feature = "sdl2_2_0_18")]
+  fn SDL_PremultiplyAlpha(
+    width: c_int, height: c_int, src_format: Uint32, src: *const c_void,
+    src_pitch: c_int, dst_format: Uint32, dst: *mut c_void, dst_pitch: c_int,
+  ) -> c_int;
+  /// See [`SDL_FillRect`]
+  fn SDL_FillRect(
+    dst: *mut SDL_Surface, rect: *const SDL_Rect, color: Uint32,
+  ) -> c_int;
+  /// See [`SDL_FillRects`]
+  fn SDL_FillRects(
+    dst: *mut SDL_Surface, rects: *const SDL_Rect, count: c_int, color: Uint32,
+  ) -> c_int;
+  /// See [`SDL_BlitSurface`]
+  fn SDL_UpperBlit(
+    src: *mut SDL_Surface, srcrect: *const SDL_Rect, dst: *mut SDL_Surface,
+    dstrect: *mut SDL_Rect,
+  ) -> c_int;
+  /// See [`SDL_BlitScaled`]
+  fn SDL_UpperBlitScaled(
+    src: *mut SDL_Surface, srcrect: *const SDL_Rect, dst: *mut SDL_Surface,
+    dstrect: *mut SDL_Rect,
+  ) -> c_int;
+  /// See [`SDL_SetYUVConversionMode`]
+  fn SDL_SetYUVConversionMode(mode: SDL_YUV_CONVERSION_MODE);
+  /// See [`SDL_GetYUVConversionMode`]
+  fn SDL_GetYUVConversionMode() -> SDL_YUV_CONVERSION_MODE;
+  /// See [`SDL_GetYUVConversionModeForResolution`]
+  fn SDL_GetYUVConversionModeForResolution(
+    width: c_int, height: c_int,
+  ) -> SDL_YUV_CONVERSION_MODE;
+  /// See [`SDL_SoftStretch`]
+  fn SDL_SoftStretch(
+    src: *mut SDL_Surface, srcrect: *const SDL_Rect, dst: *mut SDL_Surface,
+    dstrect: *const SDL_Rect,
+  ) -> c_int;
+  /// See [`SDL_SoftStretchLinear`]
+  #[cfg(feature = "sdl2_2_0_16")]
+  fn SDL_SoftStretchLinear(
+    src: *mut SDL_Surface, srcrect: *const SDL_Rect, dst: *mut SDL_Surface,
+    dstrect: *const SDL_Rect,
+  ) -> c_int;
+
+  // system
+  /// See [`SDL_AndroidGetJNIEnv`]
+  #[cfg(any(target_os = "android", doc))]
+  fn SDL_AndroidGetJNIEnv() -> SDL_AndroidJNIEnv;
+  /// See [`SDL_AndroidGetActivity`]
+  #[cfg(any(target_os = "android", doc))]
+  fn SDL_AndroidGetActivity() -> SDL_AndroidJObject;
+  /// See [`SDL_GetAndroidSDKVersion`]
+  #[cfg(any(target_os = "android", doc))]
+  fn SDL_GetAndroidSDKVersion() -> c_int;
+  /// See [`SDL_IsAndroidTV`]
+  #[cfg(any(target_os = "android", doc))]
+  fn SDL_IsAndroidTV() -> SDL_bool;
+  /// See [`SDL_IsChromebook`]
+  #[cfg(any(target_os = "android", doc))]
+  fn SDL_IsChromebook() -> SDL_bool;
+  /// See [`SDL_IsDeXMode`]
+  #[cfg(any(target_os = "android", doc))]
+  fn SDL_IsDeXMode() -> SDL_bool;
+  /// See [`SDL_AndroidBackButton`]
+  #[cfg(any(target_os = "android", doc))]
+  fn SDL_AndroidBackButton();
+  /// See [`SDL_AndroidGetInternalStoragePath`]
+  #[cfg(any(target_os = "android", doc))]
+  fn SDL_AndroidGetInternalStoragePath() -> *const c_char;
+  /// See [`SDL_AndroidGetExternalStorageState`]
+  #[cfg(any(target_os = "android", doc))]
+  fn SDL_AndroidGetExternalStorageState() -> c_int;
+  /// See [`SDL_AndroidGetExternalStoragePath`]
+  #[cfg(any(target_os = "android", doc))]
+  fn SDL_AndroidGetExternalStoragePath() -> *const c_char;
+  /// See [`SDL_AndroidRequestPermission`]
+  #[cfg(any(target_os = "android", doc))]
+  fn SDL_AndroidRequestPermission(permission: *const c_char) -> SDL_bool;
+  /// See [`SDL_AndroidShowToast`]
+  #[cfg(all(any(target_os = "android", doc), feature = "sdl2_2_0_16"))]
+  fn SDL_AndroidShowToast(
+    message: *const c_char, duration: c_int, gravity: c_int, xoffset: c_int,
+    yoffset: c_int,
+  ) -> c_int;
+  /// See [`SDL_AndroidSendMessage`]
+  #[cfg(all(any(target_os = "android", doc), feature = "sdl2_2_0_22"))]
+  fn SDL_AndroidSendMessage(command: Uint32, param: c_int) -> c_int;
+  /// See [`SDL_iPhoneSetAnimationCallback`]
+  #[cfg(any(target_os = "ios", target_os = "tvos", doc))]
+  fn SDL_iPhoneSetAnimationCallback(
+    window: *mut SDL_Window, interval: c_int,
+    callback: Option<unsafe extern "C" fn(*mut c_void)>,
+    callbackParam: *mut c_void,
+  ) -> c_int;
+  /// See [`SDL_iPhoneSetEventPump`]
+  #[cfg(any(target_os = "ios", target_os = "tvos", doc))]
+  fn SDL_iPhoneSetEventPump(enabled: SDL_bool);
+  /// See [`SDL_OnApplicationDidChangeStatusBarOrientation`]
+  #[cfg(any(target_os = "ios", target_os = "tvos", doc))]
+  fn SDL_OnApplicationDidChangeStatusBarOrientation();
+  /// See [`SDL_SetWindowsMessageHook`]
+  #[cfg(any(target_os = "windows", doc))]
+  fn SDL_SetWindowsMessageHook(
+    callback: SDL_WindowsMessageHook, userdata: *mut c_void,
+  );
+  /// See [`SDL_Direct3D9GetAdapterIndex`]
+  #[cfg(any(target_os = "windows", doc))]
+  fn SDL_Direct3D9GetAdapterIndex(displayIndex: c_int) -> c_int;
+  /// See [`SDL_DXGIGetOutputInfo`]
+  #[cfg(any(target_os = "windows", doc))]
+  fn SDL_DXGIGetOutputInfo(
+    displayIndex: c_int, adapterIndex: *mut c_int, outputIndex: *mut c_int,
+  ) -> SDL_bool;
+  /// See [`SDL_RenderGetD3D9Device`]
+  #[cfg(all(any(target_os = "windows", doc), feature = "renderer"))]
+  fn SDL_RenderGetD3D9Device(
+    renderer: *mut SDL_Renderer,
+  ) -> *mut IDirect3DDevice9;
+  /// See [`SDL_RenderGetD3D11Device`]
+  #[cfg(all(
+    all(any(target_os = "windows", doc), feature = "renderer"),
+    feature = "sdl2_2_0_16"
+  ))]
+  fn SDL_RenderGetD3D11Device(renderer: *mut SDL_Renderer)
+    -> *mut ID3D11Device;
+  /// See [`SDL_RenderGetD3D12Device`]
+  #[cfg(all(
+    all(any(target_os = "windows", doc), feature = "renderer"),
+    feature = "sdl2_2_24"
+  ))]
+  fn SDL_RenderGetD3D12Device(renderer: *mut SDL_Renderer)
+    -> *mut ID3D12Device;
+  /// See [`SDL_LinuxSetThreadPriority`]
+  #[cfg(any(target_os = "linux", doc))]
+  fn SDL_LinuxSetThreadPriority(threadID: Sint64, priority: c_int) -> c_int;
+  /// See [`SDL_LinuxSetThreadPriorityAndPolicy`]
+  #[cfg(all(any(target_os = "linux", doc), feature = "sdl2_2_0_18"))]
+  fn SDL_LinuxSetThreadPriorityAndPolicy(
+    threadID: Sint64, sdlPriority: c_int, schedPolicy: c_int,
+  ) -> c_int;
+  /// See [`SDL_WinRTGetFSPathUNICODE`]
+  #[cfg(any(all(target_os = "windows", target_vendor = "uwp"), doc))]
+  fn SDL_WinRTGetFSPathUNICODE(pathType: SDL_WinRT_Path) -> *const u16;
+  /// See [`SDL_WinRTGetFSPathUTF8`]
+  #[cfg(any(all(target_os = "windows", target_vendor = "uwp"), doc))]
+  fn SDL_WinRTGetFSPathUTF8(pathType: SDL_WinRT_Path) -> *const c_char;
+  /// See [`SDL_WinRTGetDeviceFamily`]
+  #[cfg(any(all(target_os = "windows", target_vendor = "uwp"), doc))]
+  fn SDL_WinRTGetDeviceFamily() -> SDL_WinRT_DeviceFamily;
+  /// See [`SDL_WinRTRunApp`]
+  #[cfg(any(all(target_os = "windows", target_vendor = "uwp"), doc))]
+  fn SDL_WinRTRunApp(
+    mainFunction: SDL_main_func, reserved: *mut c_void,
+  ) -> c_int;
+  /// See [`SDL_OnApplicationWillTerminate`]
+  fn SDL_OnApplicationWillTerminate();
+  /// See [`SDL_OnApplicationDidReceiveMemoryWarning`]
+  fn SDL_OnApplicationDidReceiveMemoryWarning();
+  /// See [`SDL_OnApplicationWillResignActive`]
+  fn SDL_OnApplicationWillResignActive();
+  /// See [`SDL_OnApplicationDidEnterBackground`]
+  fn SDL_OnApplicationDidEnterBackground();
+  /// See [`SDL_OnApplicationWillEnterForeground`]
+  fn SDL_OnApplicationWillEnterForeground();
+  /// See [`SDL_OnApplicationDidBecomeActive`]
+  fn SDL_OnApplicationDidBecomeActive();
+
+  // syswm
+  /// See [`SDL_GetWindowWMInfo`]
+  fn SDL_GetWindowWMInfo(
+    window: *mut SDL_Window, info: *mut SDL_SysWMinfo,
+  ) -> SDL_bool;
+
+  // timer
+  /// See [`SDL_GetTicks`]
+  fn SDL_GetTicks() -> Uint32;
+  /// See [`SDL_GetTicks64`]
+  #[cfg(feature = "sdl2_2_0_18")]
+  fn SDL_GetTicks64() -> Uint64;
+  /// See [`SDL_GetPerformanceCounter`]
+  fn SDL_GetPerformanceCounter() -> Uint64;
+  /// See [`SDL_GetPerformanceFrequency`]
+  fn SDL_GetPerformanceFrequency() -> Uint64;
+  /// See [`SDL_Delay`]
+  fn SDL_Delay(ms: Uint32);
+  /// See [`SDL_AddTimer`]
+  fn SDL_AddTimer(
+    interval: Uint32, callback: SDL_TimerCallback, param: *mut c_void,
+  ) -> SDL_TimerID;
+  /// See [`SDL_RemoveTimer`]
+  fn SDL_RemoveTimer(id: SDL_TimerID) -> SDL_bool;
+
+  // touch
+  /// See [`SDL_GetNumTouchDevices`]
+  fn SDL_GetNumTouchDevices() -> c_int;
+  /// See [`SDL_GetTouchDevice`]
+  fn SDL_GetTouchDevice(index: c_int) -> SDL_TouchID;
+  /// See [`SDL_GetTouchName`]
+  #[cfg(feature = "sdl2_2_0_22")]
+  fn SDL_GetTouchName(index: c_int) -> *const c_char;
+  /// See [`SDL_GetTouchDeviceType`]
+  fn SDL_GetTouchDeviceType(touchID: SDL_TouchID) -> SDL_TouchDeviceType;
+  /// See [`SDL_GetNumTouchFingers`]
+  fn SDL_GetNumTouchFingers(touchID: SDL_TouchID) -> c_int;
+  /// See [`SDL_GetTouchFinger`]
+  fn SDL_GetTouchFinger(touchID: SDL_TouchID, index: c_int) -> *mut SDL_Finger;
+
+  // version
+  /// See [`SDL_GetVersion`]
+  fn SDL_GetVersion(ver: *mut SDL_version);
+  /// See [`SDL_GetRevision`]
+  fn SDL_GetRevision() -> *const c_char;
+
+  // video
+  /// See [`SDL_GetNumVideoDrivers`]
+  fn SDL_GetNumVideoDrivers() -> c_int;
+  /// See [`SDL_GetVideoDriver`]
+  fn SDL_GetVideoDriver(index: c_int) -> *const c_char;
+  /// See [`SDL_VideoInit`]
+  fn SDL_VideoInit(driver_name: *const c_char) -> c_int;
+  /// See [`SDL_VideoQuit`]
+  fn SDL_VideoQuit();
+  /// See [`SDL_GetCurrentVideoDriver`]
+  fn SDL_GetCurrentVideoDriver() -> *const c_char;
+  /// See [`SDL_GetNumVideoDisplays`]
+  fn SDL_GetNumVideoDisplays() -> c_int;
+  /// See [`SDL_GetDisplayName`]
+  fn SDL_GetDisplayName(displayIndex: c_int) -> *const c_char;
+  /// See [`SDL_GetDisplayBounds`]
+  fn SDL_GetDisplayBounds(displayIndex: c_int, rect: *mut SDL_Rect) -> c_int;
+  /// See [`SDL_GetDisplayUsableBounds`]
+  fn SDL_GetDisplayUsableBounds(
+    displayIndex: c_int, rect: *mut SDL_Rect,
+  ) -> c_int;
+  /// See [`SDL_GetDisplayDPI`]
+  fn SDL_GetDisplayDPI(
+    displayIndex: c_int, ddpi: *mut f32, hdpi: *mut f32, vdpi: *mut f32,
+  ) -> c_int;
+  /// See [`SDL_GetDisplayOrientation`]
+  fn SDL_GetDisplayOrientation(displayIndex: c_int) -> SDL_DisplayOrientation;
+  /// See [`SDL_GetNumDisplayModes`]
+  fn SDL_GetNumDisplayModes(displayIndex: c_int) -> c_int;
+  /// See [`SDL_GetDisplayMode`]
+  fn SDL_GetDisplayMode(
+    displayIndex: c_int, modeIndex: c_int, mode: *mut SDL_DisplayMode,
+  ) -> c_int;
+  /// See [`SDL_GetDesktopDisplayMode`]
+  fn SDL_GetDesktopDisplayMode(
+    displayIndex: c_int, mode: *mut SDL_DisplayMode,
+  ) -> c_int;
+  /// See [`SDL_GetCurrentDisplayMode`]
+  fn SDL_GetCurrentDisplayMode(
+    displayIndex: c_int, mode: *mut SDL_DisplayMode,
+  ) -> c_int;
+  /// See [`SDL_GetClosestDisplayMode`]
+  fn SDL_GetClosestDisplayMode(
+    displayIndex: c_int, mode: *const SDL_DisplayMode,
+    closest: *mut SDL_DisplayMode,
+  ) -> *mut SDL_DisplayMode;
+  /// See [`SDL_GetWindowDisplayIndex`]
+  fn SDL_GetWindowDisplayIndex(window: *mut SDL_Window) -> c_int;
+  /// See [`SDL_GetWindowFromID`]
+  fn SDL_GetWindowFromID(id: SDL_WindowID) -> *mut SDL_Window;
+  /// See [`SDL_SetWindowDisplayMode`]
+  fn SDL_SetWindowDisplayMode(
+    window: *mut SDL_Window, mode: *const SDL_DisplayMode,
+  ) -> c_int;
+  /// See [`SDL_GetWindowDisplayMode`]
+  fn SDL_GetWindowDisplayMode(
+    window: *mut SDL_Window, mode: *mut SDL_DisplayMode,
+  ) -> c_int;
+  /// See [`SDL_GetWindowICCProfile`]
+  #[cfg(feature = "sdl2_2_0_18")]
+  fn SDL_GetWindowICCProfile(
+    window: *mut SDL_Window, size: *mut usize,
+  ) -> *mut c_void;
+  /// See [`SDL_GetWindowPixelFormat`]
+  fn SDL_GetWindowPixelFormat(window: *mut SDL_Window) -> Uint32;
+  /// See [`SDL_CreateWindow`]
+  fn SDL_CreateWindow(
+    title: *const c_char, x: c_int, y: c_int, w: c_int, h: c_int, flags: Uint32,
+  ) -> *mut SDL_Window;
+  /// See [`SDL_CreateWindowFrom`]
+  fn SDL_CreateWindowFrom(data: *const c_void) -> *mut SDL_Window;
+  /// See [`SDL_GetWindowID`]
+  fn SDL_GetWindowID(window: *mut SDL_Window) -> SDL_WindowID;
+  /// See [`SDL_GetWindowFlags`]
+  fn SDL_GetWindowFlags(window: *mut SDL_Window) -> Uint32;
+  /// See [`SDL_SetWindowTitle`]
+  fn SDL_SetWindowTitle(window: *mut SDL_Window, title: *const c_char);
+  /// See [`SDL_GetWindowTitle`]
+  fn SDL_GetWindowTitle(window: *mut SDL_Window) -> *const c_char;
+  /// See [`SDL_SetWindowIcon`]
+  fn SDL_SetWindowIcon(window: *mut SDL_Window, icon: *mut SDL_Surface);
+  /// See [`SDL_SetWindowData`]
+  fn SDL_SetWindowData(
+    window: *mut SDL_Window, name: *const c_char, userdata: *mut c_void,
+  ) -> *mut c_void;
+  /// See [`SDL_GetWindowData`]
+  fn SDL_GetWindowData(
+    window: *mut SDL_Window, name: *const c_char,
+  ) -> *mut c_void;
+  /// See [`SDL_SetWindowPosition`]
+  fn SDL_SetWindowPosition(window: *mut SDL_Window, x: c_int, y: c_int);
+  /// See [`SDL_GetWindowPosition`]
+  fn SDL_GetWindowPosition(
+    window: *mut SDL_Window, x: *mut c_int, y: *mut c_int,
+  );
+  /// See [`SDL_SetWindowSize`]
+  fn SDL_SetWindowSize(window: *mut SDL_Window, w: c_int, h: c_int);
+  /// See [`SDL_GetWindowSize`]
+  fn SDL_GetWindowSize(window: *mut SDL_Window, w: *mut c_int, h: *mut c_int);
+  /// See [`SDL_GetWindowSizeInPixels`]
+  #[cfg(feature = "sdl2_2_26")]
+  fn SDL_GetWindowSizeInPixels(
+    window: *mut SDL_Window, w: *mut c_int, h: *mut c_int,
+  );
+  /// See [`SDL_GetWindowBordersSize`]
+  fn SDL_GetWindowBordersSize(
+    window: *mut SDL_Window, top: *mut c_int, left: *mut c_int,
+    bottom: *mut c_int, right: *mut c_int,
+  ) -> c_int;
+  /// See [`SDL_SetWindowMinimumSize`]
+  fn SDL_SetWindowMinimumSize(
+    window: *mut SDL_Window, min_w: c_int, min_h: c_int,
+  );
+  /// See [`SDL_GetWindowMinimumSize`]
+  fn SDL_GetWindowMinimumSize(
+    window: *mut SDL_Window, w: *mut c_int, h: *mut c_int,
+  );
+  /// See [`SDL_SetWindowMaximumSize`]
+  fn SDL_SetWindowMaximumSize(
+    window: *mut SDL_Window, max_w: c_int, max_h: c_int,
+  );
+  /// See [`SDL_GetWindowMaximumSize`]
+  fn SDL_GetWindowMaximumSize(
+    window: *mut SDL_Window, w: *mut c_int, h: *mut c_int,
+  );
+  /// See [`SDL_SetWindowBordered`]
+  fn SDL_SetWindowBordered(window: *mut SDL_Window, bordered: SDL_bool);
+  /// See [`SDL_SetWindowResizable`]
+  fn SDL_SetWindowResizable(window: *mut SDL_Window, resizable: SDL_bool);
+  /// See [`SDL_ShowWindow`]
+  fn SDL_ShowWindow(window: *mut SDL_Window);
+  /// See [`SDL_HideWindow`]
+  fn SDL_HideWindow(window: *mut SDL_Window);
+  /// See [`SDL_RaiseWindow`]
+  fn SDL_RaiseWindow(window: *mut SDL_Window);
+  /// See [`SDL_MaximizeWindow`]
+  fn SDL_MaximizeWindow(window: *mut SDL_Window);
+  /// See [`SDL_MinimizeWindow`]
+  fn SDL_MinimizeWindow(window: *mut SDL_Window);
+  /// See [`SDL_RestoreWindow`]
+  fn SDL_RestoreWindow(window: *mut SDL_Window);
+  /// See [`SDL_SetWindowFullscreen`]
+  fn SDL_SetWindowFullscreen(window: *mut SDL_Window, flags: Uint32) -> c_int;
+  /// See [`SDL_GetWindowSurface`]
+  fn SDL_GetWindowSurface(window: *mut SDL_Window) -> *mut SDL_Surface;
+  /// See [`SDL_UpdateWindowSurface`]
+  fn SDL_UpdateWindowSurface(window: *mut SDL_Window) -> c_int;
+  /// See [`SDL_UpdateWindowSurfaceRects`]
+  fn SDL_UpdateWindowSurfaceRects(
+    window: *mut SDL_Window, rects: *const SDL_Rect, numrects: c_int,
+  ) -> c_int;
+  /// See [`SDL_SetWindowGrab`]
+  fn SDL_SetWindowGrab(window: *mut SDL_Window, grabbed: SDL_bool);
+  /// See [`SDL_GetWindowGrab`]
+  fn SDL_GetWindowGrab(window: *mut SDL_Window) -> SDL_bool;
+  /// See [`SDL_GetGrabbedWindow`]
+  fn SDL_GetGrabbedWindow() -> *mut SDL_Window;
+  /// See [`SDL_SetWindowBrightness`]
+  fn SDL_SetWindowBrightness(window: *mut SDL_Window, brightness: f32)
+    -> c_int;
+  /// See [`SDL_GetWindowBrightness`]
+  fn SDL_GetWindowBrightness(window: *mut SDL_Window) -> f32;
+  /// See [`SDL_SetWindowOpacity`]
+  fn SDL_SetWindowOpacity(window: *mut SDL_Window, opacity: f32) -> c_int;
+  /// See [`SDL_GetWindowOpacity`]
+  fn SDL_GetWindowOpacity(
+    window: *mut SDL_Window, out_opacity: *mut f32,
+  ) -> c_int;
+  /// See [`SDL_SetWindowModalFor`]
+  fn SDL_SetWindowModalFor(
+    modal_window: *mut SDL_Window, parent_window: *mut SDL_Window,
+  ) -> c_int;
+  /// See [`SDL_SetWindowInputFocus`]
+  fn SDL_SetWindowInputFocus(window: *mut SDL_Window) -> c_int;
+  /// See [`SDL_SetWindowGammaRamp`]
+  fn SDL_SetWindowGammaRamp(
+    window: *mut SDL_Window, red: *const Uint16, green: *const Uint16,
+    blue: *const Uint16,
+  ) -> c_int;
+  /// See [`SDL_GetWindowGammaRamp`]
+  fn SDL_GetWindowGammaRamp(
+    window: *mut SDL_Window, red: *mut Uint16, green: *mut Uint16,
+    blue: *mut Uint16,
+  ) -> c_int;
+  /// See [`SDL_SetWindowHitTest`]
+  fn SDL_SetWindowHitTest(
+    window: *mut SDL_Window, callback: SDL_HitTest, callback_data: *mut c_void,
+  ) -> c_int;
+  /// See [`SDL_DestroyWindow`]
+  fn SDL_DestroyWindow(window: *mut SDL_Window);
+  /// See [`SDL_IsScreenSaverEnabled`]
+  fn SDL_IsScreenSaverEnabled() -> SDL_bool;
+  /// See [`SDL_EnableScreenSaver`]
+  fn SDL_EnableScreenSaver();
+  /// See [`SDL_DisableScreenSaver`]
+  fn SDL_DisableScreenSaver();
+  /// See [`SDL_GL_LoadLibrary`]
+  fn SDL_GL_LoadLibrary(path: *const c_char) -> c_int;
+  /// See [`SDL_GL_GetProcAddress`]
+  fn SDL_GL_GetProcAddress(proc_: *const c_char) -> *mut c_void;
+  /// See [`SDL_GL_UnloadLibrary`]
+  fn SDL_GL_UnloadLibrary();
+  /// See [`SDL_GL_ExtensionSupported`]
+  fn SDL_GL_ExtensionSupported(extension: *const c_char) -> SDL_bool;
+  /// See [`SDL_GL_ResetAttributes`]
+  fn SDL_GL_ResetAttributes();
+  /// See [`SDL_GL_SetAttribute`]
+  fn SDL_GL_SetAttribute(attr: SDL_GLattr, value: c_int) -> c_int;
+  /// See [`SDL_GL_GetAttribute`]
+  fn SDL_GL_GetAttribute(attr: SDL_GLattr, value: *mut c_int) -> c_int;
+  /// See [`SDL_GL_CreateContext`]
+  fn SDL_GL_CreateContext(window: *mut SDL_Window) -> SDL_GLContext;
+  /// See [`SDL_GL_MakeCurrent`]
+  fn SDL_GL_MakeCurrent(
+    window: *mut SDL_Window, context: SDL_GLContext,
+  ) -> c_int;
+  /// See [`SDL_GL_GetCurrentWindow`]
+  fn SDL_GL_GetCurrentWindow() -> *mut SDL_Window;
+  /// See [`SDL_GL_GetCurrentContext`]
+  fn SDL_GL_GetCurrentContext() -> SDL_GLContext;
+  /// See [`SDL_GL_GetDrawableSize`]
+  fn SDL_GL_GetDrawableSize(
+    window: *mut SDL_Window, w: *mut c_int, h: *mut c_int,
+  );
+  /// See [`SDL_GL_SetSwapInterval`]
+  fn SDL_GL_SetSwapInterval(interval: c_int) -> c_int;
+  /// See [`SDL_GL_GetSwapInterval`]
+  fn SDL_GL_GetSwapInterval() -> c_int;
+  /// See [`SDL_GL_SwapWindow`]
+  fn SDL_GL_SwapWindow(window: *mut SDL_Window);
+  /// See [`SDL_GL_DeleteContext`]
+  fn SDL_GL_DeleteContext(context: SDL_GLContext);
+  /// See [`SDL_FlashWindow`]
+  #[cfg(feature = "sdl2_2_0_16")]
+  fn SDL_FlashWindow(
+    window: *mut SDL_Window, operation: SDL_FlashOperation,
+  ) -> c_int;
+  /// See [`SDL_SetWindowAlwaysOnTop`]
+  #[cfg(feature = "sdl2_2_0_16")]
+  fn SDL_SetWindowAlwaysOnTop(window: *mut SDL_Window, on_top: SDL_bool);
+  /// See [`SDL_SetWindowKeyboardGrab`]
+  #[cfg(feature = "sdl2_2_0_16")]
+  fn SDL_SetWindowKeyboardGrab(window: *mut SDL_Window, grabbed: SDL_bool);
+  /// See [`SDL_SetWindowMouseGrab`]
+  #[cfg(feature = "sdl2_2_0_16")]
+  fn SDL_SetWindowMouseGrab(window: *mut SDL_Window, grabbed: SDL_bool);
+  /// See [`SDL_GetWindowKeyboardGrab`]
+  #[cfg(feature = "sdl2_2_0_16")]
+  fn SDL_GetWindowKeyboardGrab(window: *mut SDL_Window) -> SDL_bool;
+  /// See [`SDL_GetWindowMouseGrab`]
+  #[cfg(feature = "sdl2_2_0_16")]
+  fn SDL_GetWindowMouseGrab(window: *mut SDL_Window) -> SDL_bool;
+  /// See [`SDL_SetWindowMouseRect`]
+  #[cfg(feature = "sdl2_2_0_18")]
+  fn SDL_SetWindowMouseRect(
+    window: *mut SDL_Window, rect: *const SDL_Rect,
+  ) -> c_int;
+  /// See [`SDL_GetWindowMouseRect`]
+  #[cfg(feature = "sdl2_2_0_18")]
+  fn SDL_GetWindowMouseRect(window: *mut SDL_Window) -> *const SDL_Rect;
+
+  // vulkan
+  /// See [`SDL_Vulkan_LoadLibrary`]
+  #[cfg(feature = "vulkan")]
+  fn SDL_Vulkan_LoadLibrary(path: *const c_char) -> c_int;
+  /// See [`SDL_Vulkan_GetVkGetInstanceProcAddr`]
+  #[cfg(feature = "vulkan")]
+  fn SDL_Vulkan_GetVkGetInstanceProcAddr() -> SDL_vkGetInstanceProcAddr;
+  /// See [`SDL_Vulkan_UnloadLibrary`]
+  #[cfg(feature = "vulkan")]
+  fn SDL_Vulkan_UnloadLibrary();
+  /// See [`SDL_Vulkan_GetInstanceExtensions`]
+  #[cfg(feature = "vulkan")]
+  fn SDL_Vulkan_GetInstanceExtensions(
+    window: *mut SDL_Window, pCount: *mut c_uint, pNames: *mut *const c_char,
+  ) -> SDL_bool;
+  /// See [`SDL_Vulkan_CreateSurface`]
+  #[cfg(feature = "vulkan")]
+  fn SDL_Vulkan_CreateSurface(
+    window: *mut SDL_Window, instance: SDL_vulkanInstance,
+    surface: *mut SDL_vulkanSurface,
+  ) -> SDL_bool;
+  /// See [`SDL_Vulkan_GetDrawableSize`]
+  #[cfg(feature = "vulkan")]
+  fn SDL_Vulkan_GetDrawableSize(
+    window: *mut SDL_Window, w: *mut c_int, h: *mut c_int,
+  );
+}
+
+impl SdlApi {
+  /// Opens SDL2 using the usual library names for this platform (see
+  /// [`SDL2_LIBRARY_NAMES`]).
+  ///
+  /// **Returns:** The first library that loaded. If none of them did, the
+  /// error from the last one tried.
+  ///
+  /// ## Safety
+  /// * As [`SdlApi::load`].
+  pub unsafe fn load_default() -> Result<Self, DynLoadError> {
+    let mut err = DynLoadError::LibraryNotFound;
+    for name in SDL2_LIBRARY_NAMES {
+      match Self::load(CStr::from_bytes_with_nul_unchecked(name)) {
+        Ok(api) => return Ok(api),
+        Err(e) => err = e,
+      }
+    }
+    Err(err)
+  }
+
+  /// Looks up any other function of the library by name.
+  ///
+  /// `F` should be the function pointer type of the function (eg:
+  /// `unsafe extern "C" fn(c_int) -> c_int`). The pointer is only valid while
+  /// this `SdlApi` exists.
+  ///
+  /// ## Safety
+  /// * `F` must match the function's real signature and calling convention.
+  ///
+  /// ## Panics
+  /// * If `F` isn't the size of a pointer.
+  pub unsafe fn get<F: Copy>(&self, name: &CStr) -> Option<F> {
+    assert_eq!(
+      core::mem::size_of::<F>(),
+      core::mem::size_of::<*mut c_void>(),
+      "SdlApi::get must be used with a function pointer type"
+    );
+    let p = os::sym(self.lib.as_ptr(), name.as_ptr());
+    if p.is_null() {
+      None
+    } else {
+      Some(core::mem::transmute_copy(&p))
+    }
+  }
+
+  /// The raw OS handle of the library.
+  #[inline]
+  #[must_use]
+  pub fn as_ptr(&self) -> *mut c_void {
+    self.lib.as_ptr()
+  }
+}
+impl fmt::Debug for SdlApi {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_struct("SdlApi").field("lib", &self.lib).finish_non_exhaustive()
+  }
+}
+impl Drop for SdlApi {
+  /// Unloads the library. Call `SDL_Quit` first.
+  fn drop(&mut self) {
+    unsafe { os::close(self.lib.as_ptr()) }
+  }
+}
@@ -1,8 +1,18 @@
 //! SDL uses a thread-local buffer to store the current error string.
 
+use core::fmt;
+
 use crate::{c_char, c_int};
 
 extern "C" {
+  /// Set the SDL error message for the current thread.
+  ///
+  /// Calling this function will replace any previous error message that was
+  /// set. The `fmt` string is `printf`-style, followed by any extra args.
+  ///
+  /// **Returns:** always -1, so that you can return it as an error code.
+  pub fn SDL_SetError(fmt: *const c_char, ...) -> c_int;
+
   /// Get the last error message that was set for the current thread.
   ///
   /// SDL API functions may set error messages and then succeed, so you should
@@ -18,3 +28,70 @@ extern "C" {
   /// Clear the error message for the current thread.
   pub fn SDL_ClearError();
 }
+
+/// An SDL error message, copied out of SDL's error buffer.
+///
+/// The next failing SDL call on the same thread overwrites SDL's own copy of
+/// the message, so this keeps the text (up to 119 bytes of it) in a buffer of
+/// its own. That makes it usable as the error type of a `Result`. The buffer
+/// is kept small so that returning one stays cheap, and SDL's messages are
+/// almost always short enough to fit.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SdlError {
+  msg: [u8; 120],
+}
+impl SdlError {
+  /// Copies the current thread's SDL error message.
+  #[inline]
+  #[must_use]
+  pub unsafe fn get() -> Self {
+    let mut msg = [0_u8; 120];
+    SDL_GetErrorMsg(msg.as_mut_ptr().cast(), msg.len() as c_int);
+    Self { msg }
+  }
+
+  /// The bytes of the message (without the null terminator).
+  #[inline]
+  #[must_use]
+  pub fn as_bytes(&self) -> &[u8] {
+    let len = self.msg.iter().position(|&b| b == 0).unwrap_or(self.msg.len());
+    &self.msg[..len]
+  }
+
+  /// The message as a `str`.
+  ///
+  /// SDL's messages are UTF-8, but a long message can be cut off partway
+  /// through a character. In that case the partial character is left off.
+  #[inline]
+  #[must_use]
+  pub fn as_str(&self) -> &str {
+    let bytes = self.as_bytes();
+    match core::str::from_utf8(bytes) {
+      Ok(s) => s,
+      Err(e) => {
+        core::str::from_utf8(&bytes[..e.valid_up_to()]).unwrap_or_default()
+      }
+    }
+  }
+}
+impl fmt::Debug for SdlError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_tuple("SdlError").field(&self.as_str()).finish()
+  }
+}
+impl fmt::Display for SdlError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(self.as_str())
+  }
+}
+
+/// The error from parsing a name that SDL doesn't recognize.
+///
+/// This is what the `FromStr` impls for key and controller values give.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ParseNameError;
+impl fmt::Display for ParseNameError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str("SDL doesn't recognize that name")
+  }
+}
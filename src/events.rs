@@ -1,17 +1,24 @@
 //! Module for event handling.
 
+use core::time::Duration;
+
 use crate::{
-  c_char, c_float, c_int, c_long, c_uint, c_void, gesture::*, joystick::*,
-  keyboard::*, mouse::*, stdinc::*, syswm::*, touch::*, version::*, video::*,
+  c_char, c_float, c_int, c_long, c_uint, c_void, gesture::*, keyboard::*,
+  mouse::*, stdinc::*, syswm::*, timer::*, touch::*, version::*, video::*,
 };
 
+#[cfg(feature = "joystick")]
+use crate::joystick::*;
+
+#[cfg(feature = "sensor")]
+use crate::sensor::*;
+
 // makes rustdoc link properly!
 #[allow(unused)]
+#[cfg(feature = "audio")]
 use crate::audio::*;
 #[allow(unused)]
-use crate::sensor::*;
-#[allow(unused)]
-use crate::timer::*;
+use crate::hints::*;
 
 /// Button is released.
 ///
@@ -24,7 +31,11 @@ pub const SDL_RELEASED: u8 = 0;
 pub const SDL_PRESSED: u8 = 1;
 
 /// The types of events that can be delivered.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+///
+/// The `Debug` and `Display` impls show the constant's name when there is one
+/// (see [`name`](Self::name)).
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_EventType(pub i32);
 
@@ -98,6 +109,13 @@ pub const SDL_TEXTINPUT: SDL_EventType = SDL_EventType(0x300 + 3);
 /// layout change.
 pub const SDL_KEYMAPCHANGED: SDL_EventType = SDL_EventType(0x300 + 4);
 
+/// Extended keyboard text editing (composition).
+///
+/// Only sent when [`SDL_HINT_IME_SUPPORT_EXTENDED_TEXT`] is enabled.
+#[cfg(feature = "sdl2_2_0_22")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_22")))]
+pub const SDL_TEXTEDITING_EXT: SDL_EventType = SDL_EventType(0x300 + 5);
+
 /// Mouse moved.
 pub const SDL_MOUSEMOTION: SDL_EventType = SDL_EventType(0x400);
 
@@ -131,6 +149,13 @@ pub const SDL_JOYDEVICEADDED: SDL_EventType = SDL_EventType(0x600 + 5);
 /// An opened joystick has been removed.
 pub const SDL_JOYDEVICEREMOVED: SDL_EventType = SDL_EventType(0x600 + 6);
 
+/// An opened joystick's battery level changed.
+///
+/// (Added in SDL 2.24)
+#[cfg(feature = "sdl2_2_24")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_24")))]
+pub const SDL_JOYBATTERYUPDATED: SDL_EventType = SDL_EventType(0x600 + 7);
+
 /// Game controller axis motion.
 pub const SDL_CONTROLLERAXISMOTION: SDL_EventType = SDL_EventType(0x650);
 
@@ -211,6 +236,13 @@ pub const SDL_RENDER_TARGETS_RESET: SDL_EventType = SDL_EventType(0x2000);
 /// The device has been reset and all textures need to be recreated.
 pub const SDL_RENDER_DEVICE_RESET: SDL_EventType = SDL_EventType(0x2000 + 1);
 
+/// Signals the end of an event poll cycle.
+///
+/// See [`SDL_HINT_POLL_SENTINEL`].
+#[cfg(feature = "sdl2_2_0_18")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_18")))]
+pub const SDL_POLLSENTINEL: SDL_EventType = SDL_EventType(0x7F00);
+
 /// Events [`SDL_USEREVENT`] through [`SDL_LASTEVENT`] are for your use, and
 /// should be allocated with [`SDL_RegisterEvents`].
 pub const SDL_USEREVENT: SDL_EventType = SDL_EventType(0x8000);
@@ -218,6 +250,106 @@ pub const SDL_USEREVENT: SDL_EventType = SDL_EventType(0x8000);
 /// This last event is only for bounding internal arrays.
 pub const SDL_LASTEVENT: SDL_EventType = SDL_EventType(0xFFFF);
 
+impl SDL_EventType {
+  /// The name of the constant for this event type, if any.
+  ///
+  /// Event types from [`SDL_RegisterEvents`] don't have their own names, use
+  /// [`is_user`](Self::is_user) to check for those.
+  #[must_use]
+  pub const fn name(self) -> Option<&'static str> {
+    Some(match self {
+      SDL_QUIT => "SDL_QUIT",
+      SDL_APP_TERMINATING => "SDL_APP_TERMINATING",
+      SDL_APP_LOWMEMORY => "SDL_APP_LOWMEMORY",
+      SDL_APP_WILLENTERBACKGROUND => "SDL_APP_WILLENTERBACKGROUND",
+      SDL_APP_DIDENTERBACKGROUND => "SDL_APP_DIDENTERBACKGROUND",
+      SDL_APP_WILLENTERFOREGROUND => "SDL_APP_WILLENTERFOREGROUND",
+      SDL_APP_DIDENTERFOREGROUND => "SDL_APP_DIDENTERFOREGROUND",
+      SDL_LOCALECHANGED => "SDL_LOCALECHANGED",
+      SDL_DISPLAYEVENT => "SDL_DISPLAYEVENT",
+      SDL_WINDOWEVENT => "SDL_WINDOWEVENT",
+      SDL_SYSWMEVENT => "SDL_SYSWMEVENT",
+      SDL_KEYDOWN => "SDL_KEYDOWN",
+      SDL_KEYUP => "SDL_KEYUP",
+      SDL_TEXTEDITING => "SDL_TEXTEDITING",
+      SDL_TEXTINPUT => "SDL_TEXTINPUT",
+      SDL_KEYMAPCHANGED => "SDL_KEYMAPCHANGED",
+      #[cfg(feature = "sdl2_2_0_22")]
+      SDL_TEXTEDITING_EXT => "SDL_TEXTEDITING_EXT",
+      SDL_MOUSEMOTION => "SDL_MOUSEMOTION",
+      SDL_MOUSEBUTTONDOWN => "SDL_MOUSEBUTTONDOWN",
+      SDL_MOUSEBUTTONUP => "SDL_MOUSEBUTTONUP",
+      SDL_MOUSEWHEEL => "SDL_MOUSEWHEEL",
+      SDL_JOYAXISMOTION => "SDL_JOYAXISMOTION",
+      SDL_JOYBALLMOTION => "SDL_JOYBALLMOTION",
+      SDL_JOYHATMOTION => "SDL_JOYHATMOTION",
+      SDL_JOYBUTTONDOWN => "SDL_JOYBUTTONDOWN",
+      SDL_JOYBUTTONUP => "SDL_JOYBUTTONUP",
+      SDL_JOYDEVICEADDED => "SDL_JOYDEVICEADDED",
+      SDL_JOYDEVICEREMOVED => "SDL_JOYDEVICEREMOVED",
+      #[cfg(feature = "sdl2_2_24")]
+      SDL_JOYBATTERYUPDATED => "SDL_JOYBATTERYUPDATED",
+      SDL_CONTROLLERAXISMOTION => "SDL_CONTROLLERAXISMOTION",
+      SDL_CONTROLLERBUTTONDOWN => "SDL_CONTROLLERBUTTONDOWN",
+      SDL_CONTROLLERBUTTONUP => "SDL_CONTROLLERBUTTONUP",
+      SDL_CONTROLLERDEVICEADDED => "SDL_CONTROLLERDEVICEADDED",
+      SDL_CONTROLLERDEVICEREMOVED => "SDL_CONTROLLERDEVICEREMOVED",
+      SDL_CONTROLLERDEVICEREMAPPED => "SDL_CONTROLLERDEVICEREMAPPED",
+      SDL_CONTROLLERTOUCHPADDOWN => "SDL_CONTROLLERTOUCHPADDOWN",
+      SDL_CONTROLLERTOUCHPADMOTION => "SDL_CONTROLLERTOUCHPADMOTION",
+      SDL_CONTROLLERTOUCHPADUP => "SDL_CONTROLLERTOUCHPADUP",
+      SDL_CONTROLLERSENSORUPDATE => "SDL_CONTROLLERSENSORUPDATE",
+      SDL_FINGERDOWN => "SDL_FINGERDOWN",
+      SDL_FINGERUP => "SDL_FINGERUP",
+      SDL_FINGERMOTION => "SDL_FINGERMOTION",
+      SDL_DOLLARGESTURE => "SDL_DOLLARGESTURE",
+      SDL_DOLLARRECORD => "SDL_DOLLARRECORD",
+      SDL_MULTIGESTURE => "SDL_MULTIGESTURE",
+      SDL_CLIPBOARDUPDATE => "SDL_CLIPBOARDUPDATE",
+      SDL_DROPFILE => "SDL_DROPFILE",
+      SDL_DROPTEXT => "SDL_DROPTEXT",
+      SDL_DROPBEGIN => "SDL_DROPBEGIN",
+      SDL_DROPCOMPLETE => "SDL_DROPCOMPLETE",
+      SDL_AUDIODEVICEADDED => "SDL_AUDIODEVICEADDED",
+      SDL_AUDIODEVICEREMOVED => "SDL_AUDIODEVICEREMOVED",
+      SDL_SENSORUPDATE => "SDL_SENSORUPDATE",
+      SDL_RENDER_TARGETS_RESET => "SDL_RENDER_TARGETS_RESET",
+      SDL_RENDER_DEVICE_RESET => "SDL_RENDER_DEVICE_RESET",
+      #[cfg(feature = "sdl2_2_0_18")]
+      SDL_POLLSENTINEL => "SDL_POLLSENTINEL",
+      SDL_USEREVENT => "SDL_USEREVENT",
+      _ => return None,
+    })
+  }
+
+  /// If this is [`SDL_USEREVENT`] or a type from [`SDL_RegisterEvents`].
+  #[inline]
+  #[must_use]
+  pub const fn is_user(self) -> bool {
+    self.0 >= SDL_USEREVENT.0 && self.0 < SDL_LASTEVENT.0
+  }
+}
+/// Shows the name, like `SDL_KEYDOWN`.
+///
+/// Registered user events show as an offset, like `SDL_USEREVENT+2`, and any
+/// other unknown value shows in hex.
+impl core::fmt::Display for SDL_EventType {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    if let Some(name) = self.name() {
+      f.write_str(name)
+    } else if self.is_user() {
+      write!(f, "SDL_USEREVENT+{}", self.0 - SDL_USEREVENT.0)
+    } else {
+      write!(f, "SDL_EventType({:#X})", self.0)
+    }
+  }
+}
+impl core::fmt::Debug for SDL_EventType {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    core::fmt::Display::fmt(self, f)
+  }
+}
+
 /// Fields shared by every event.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
@@ -257,7 +389,7 @@ pub struct SDL_WindowEvent {
   /// In milliseconds, populated using [`SDL_GetTicks`]
   pub timestamp: Uint32,
   /// The associated window
-  pub windowID: Uint32,
+  pub windowID: SDL_WindowID,
   pub event: SDL_WindowEventID,
   pub padding1: Uint8,
   pub padding2: Uint8,
@@ -277,7 +409,7 @@ pub struct SDL_KeyboardEvent {
   pub type_: SDL_EventType,
   pub timestamp: Uint32,
   /// The window with keyboard focus, if any.
-  pub windowID: Uint32,
+  pub windowID: SDL_WindowID,
   /// [`SDL_PRESSED`] or [`SDL_RELEASED`].
   pub state: Uint8,
   /// Non-zero if this is a key repeat.
@@ -301,7 +433,7 @@ pub struct SDL_TextEditingEvent {
   /// In milliseconds, populated using [`SDL_GetTicks`].
   pub timestamp: Uint32,
   /// The window with keyboard focus, if any.
-  pub windowID: Uint32,
+  pub windowID: SDL_WindowID,
   /// The editing text.
   pub text: [c_char; SDL_TEXTEDITINGEVENT_TEXT_SIZE],
   /// The start cursor of selected editing text.
@@ -310,6 +442,38 @@ pub struct SDL_TextEditingEvent {
   pub length: Sint32,
 }
 
+/// Extended keyboard text editing event structure (event.editExt.*)
+///
+/// This is sent instead of an [`SDL_TextEditingEvent`] when the text would
+/// be truncated to fit in that event's buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+#[allow(missing_docs)]
+#[cfg(feature = "sdl2_2_0_22")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_22")))]
+pub struct SDL_TextEditingExtEvent {
+  /// Should always be [`SDL_TEXTEDITING_EXT`].
+  pub type_: SDL_EventType,
+  /// In milliseconds, populated using [`SDL_GetTicks`].
+  pub timestamp: Uint32,
+  /// The window with keyboard focus, if any.
+  pub windowID: SDL_WindowID,
+  /// The editing text, which should be freed with [`SDL_free`], and will not
+  /// be NULL.
+  pub text: *mut c_char,
+  /// The start cursor of selected editing text.
+  pub start: Sint32,
+  /// The length of selected editing text.
+  pub length: Sint32,
+}
+#[cfg(feature = "sdl2_2_0_22")]
+impl Default for SDL_TextEditingExtEvent {
+  #[inline]
+  fn default() -> Self {
+    unsafe { core::mem::zeroed() }
+  }
+}
+
 /// Size of the [`SDL_TextInputEvent`] array.
 pub const SDL_TEXTINPUTEVENT_TEXT_SIZE: usize = 32;
 
@@ -323,7 +487,7 @@ pub struct SDL_TextInputEvent {
   /// In milliseconds, populated using [`SDL_GetTicks`].
   pub timestamp: Uint32,
   /// The window with keyboard focus, if any.
-  pub windowID: Uint32,
+  pub windowID: SDL_WindowID,
   /// The input text.
   pub text: [c_char; SDL_TEXTINPUTEVENT_TEXT_SIZE],
 }
@@ -338,7 +502,7 @@ pub struct SDL_MouseMotionEvent {
   /// In milliseconds, populated using [`SDL_GetTicks`].
   pub timestamp: Uint32,
   /// The window with mouse focus, if any
-  pub windowID: Uint32,
+  pub windowID: SDL_WindowID,
   /// The mouse instance id, or [`SDL_TOUCH_MOUSEID`]
   pub which: Uint32,
   /// The current button state
@@ -363,7 +527,7 @@ pub struct SDL_MouseButtonEvent {
   /// In milliseconds, populated using [`SDL_GetTicks`].
   pub timestamp: Uint32,
   /// The window with mouse focus, if any.
-  pub windowID: Uint32,
+  pub windowID: SDL_WindowID,
   /// The mouse instance id, or [`SDL_TOUCH_MOUSEID`].
   pub which: Uint32,
   /// The mouse button index
@@ -380,7 +544,7 @@ pub struct SDL_MouseButtonEvent {
 }
 
 /// Mouse wheel event structure (event.wheel.*)
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 #[repr(C)]
 #[allow(missing_docs)]
 pub struct SDL_MouseWheelEvent {
@@ -389,7 +553,7 @@ pub struct SDL_MouseWheelEvent {
   /// In milliseconds, populated using [`SDL_GetTicks`].
   pub timestamp: Uint32,
   /// The window with mouse focus, if any.
-  pub windowID: Uint32,
+  pub windowID: SDL_WindowID,
   /// The mouse instance id, or [`SDL_TOUCH_MOUSEID`].
   pub which: Uint32,
   /// The amount scrolled horizontally, positive to the right and negative to
@@ -403,9 +567,31 @@ pub struct SDL_MouseWheelEvent {
   /// When `SDL_MOUSEWHEEL_FLIPPED`, the values in X and Y will be opposite.
   /// Multiply by -1 to change them back.
   pub direction: SDL_MouseWheelDirection,
+  /// The amount scrolled horizontally, positive to the right and negative to
+  /// the left, with float precision
+  #[cfg(feature = "sdl2_2_0_18")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_18")))]
+  pub preciseX: c_float,
+  /// The amount scrolled vertically, positive away from the user and negative
+  /// toward the user, with float precision
+  #[cfg(feature = "sdl2_2_0_18")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_18")))]
+  pub preciseY: c_float,
+  /// X coordinate, relative to window
+  #[cfg(feature = "sdl2_2_26")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_26")))]
+  pub mouseX: Sint32,
+  /// Y coordinate, relative to window
+  #[cfg(feature = "sdl2_2_26")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_26")))]
+  pub mouseY: Sint32,
 }
+#[cfg(feature = "sdl2_2_26")]
+const _: () = assert!(core::mem::size_of::<SDL_MouseWheelEvent>() == 44);
 
 /// Joystick axis motion event structure (event.jaxis.*)
+#[cfg(feature = "joystick")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "joystick")))]
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 #[allow(missing_docs)]
@@ -427,6 +613,8 @@ pub struct SDL_JoyAxisEvent {
 }
 
 /// Joystick trackball motion event structure (event.jball.*)
+#[cfg(feature = "joystick")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "joystick")))]
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 #[allow(missing_docs)]
@@ -449,6 +637,8 @@ pub struct SDL_JoyBallEvent {
 }
 
 /// Joystick hat position change event structure (event.jhat.*)
+#[cfg(feature = "joystick")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "joystick")))]
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 #[allow(missing_docs)]
@@ -468,6 +658,8 @@ pub struct SDL_JoyHatEvent {
 }
 
 /// Joystick button event structure (event.jbutton.*)
+#[cfg(feature = "joystick")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "joystick")))]
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 #[allow(missing_docs)]
@@ -487,6 +679,8 @@ pub struct SDL_JoyButtonEvent {
 }
 
 /// Joystick device event structure (event.jdevice.*)
+#[cfg(feature = "joystick")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "joystick")))]
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 #[allow(missing_docs)]
@@ -500,7 +694,25 @@ pub struct SDL_JoyDeviceEvent {
   pub which: Sint32,
 }
 
+/// Joystick battery level change event structure (event.jbattery.*)
+#[cfg(all(feature = "joystick", feature = "sdl2_2_24"))]
+#[cfg_attr(docs_rs, doc(cfg(all(feature = "joystick", feature = "sdl2_2_24"))))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct SDL_JoyBatteryEvent {
+  /// Should always be [`SDL_JOYBATTERYUPDATED`]
+  pub type_: SDL_EventType,
+  /// In milliseconds, populated using [`SDL_GetTicks`].
+  pub timestamp: Uint32,
+  /// The joystick instance id
+  pub which: SDL_JoystickID,
+  /// The joystick battery level
+  pub level: SDL_JoystickPowerLevel,
+}
+
 /// Game controller axis motion event structure (event.caxis.*)
+#[cfg(feature = "gamecontroller")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "gamecontroller")))]
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 #[allow(missing_docs)]
@@ -522,6 +734,8 @@ pub struct SDL_ControllerAxisEvent {
 }
 
 /// Game controller button event structure (event.cbutton.*)
+#[cfg(feature = "gamecontroller")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "gamecontroller")))]
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 #[allow(missing_docs)]
@@ -542,6 +756,8 @@ pub struct SDL_ControllerButtonEvent {
 }
 
 /// Controller device event structure (event.cdevice.*)
+#[cfg(feature = "gamecontroller")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "gamecontroller")))]
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 #[allow(missing_docs)]
@@ -557,6 +773,8 @@ pub struct SDL_ControllerDeviceEvent {
 }
 
 /// Game controller touchpad event structure (event.ctouchpad.*)
+#[cfg(feature = "gamecontroller")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "gamecontroller")))]
 #[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 #[repr(C)]
 #[allow(missing_docs)]
@@ -581,6 +799,8 @@ pub struct SDL_ControllerTouchpadEvent {
 }
 
 /// Game controller sensor event structure (event.csensor.*)
+#[cfg(feature = "gamecontroller")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "gamecontroller")))]
 #[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 #[repr(C)]
 #[allow(missing_docs)]
@@ -595,6 +815,11 @@ pub struct SDL_ControllerSensorEvent {
   pub sensor: Sint32,
   /// Up to 3 values from the sensor, as defined in [`sensor`](crate::sensor)
   pub data: [c_float; 3],
+  /// The timestamp of the sensor reading in microseconds, if the hardware
+  /// provides this information.
+  #[cfg(feature = "sdl2_2_26")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_26")))]
+  pub timestamp_us: Uint64,
 }
 
 /// Audio device event structure (event.adevice.*)
@@ -642,7 +867,7 @@ pub struct SDL_TouchFingerEvent {
   /// Normalized in the range 0...1
   pub pressure: c_float,
   /// The window underneath the finger, if any
-  pub windowID: Uint32,
+  pub windowID: SDL_WindowID,
 }
 
 /// Multiple Finger Gesture Event (event.mgesture.*)
@@ -705,7 +930,7 @@ pub struct SDL_DropEvent {
   /// This is NULL on begin/complete.
   pub file: *const c_char,
   /// The window that was dropped on, if any
-  pub windowID: Uint32,
+  pub windowID: SDL_WindowID,
 }
 impl Default for SDL_DropEvent {
   #[inline]
@@ -716,6 +941,8 @@ impl Default for SDL_DropEvent {
 }
 
 /// Sensor event structure (event.sensor.*)
+#[cfg(feature = "sensor")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "sensor")))]
 #[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 #[repr(C)]
 #[allow(missing_docs)]
@@ -725,10 +952,15 @@ pub struct SDL_SensorEvent {
   /// In milliseconds, populated using [`SDL_GetTicks`].
   pub timestamp: Uint32,
   /// The instance ID of the sensor
-  pub which: Sint32,
+  pub which: SDL_SensorID,
   /// Up to 6 values from the sensor - additional values can be queried using
   /// [`SDL_SensorGetData`].
   pub data: [c_float; 6],
+  /// The timestamp of the sensor reading in microseconds, if the hardware
+  /// provides this information.
+  #[cfg(feature = "sdl2_2_26")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_26")))]
+  pub timestamp_us: Uint64,
 }
 
 /// The "quit requested" event
@@ -762,7 +994,7 @@ pub struct SDL_UserEvent {
   /// In milliseconds, populated using [`SDL_GetTicks`].
   pub timestamp: Uint32,
   /// The associated window if any
-  pub windowID: Uint32,
+  pub windowID: SDL_WindowID,
   /// User defined event code
   pub code: Sint32,
   /// User defined data pointer
@@ -850,21 +1082,52 @@ pub union SDL_Event {
   pub window: SDL_WindowEvent,
   pub key: SDL_KeyboardEvent,
   pub edit: SDL_TextEditingEvent,
+  #[cfg(feature = "sdl2_2_0_22")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_22")))]
+  pub editExt: SDL_TextEditingExtEvent,
   pub text: SDL_TextInputEvent,
   pub motion: SDL_MouseMotionEvent,
   pub button: SDL_MouseButtonEvent,
   pub wheel: SDL_MouseWheelEvent,
+  #[cfg(feature = "joystick")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "joystick")))]
   pub jaxis: SDL_JoyAxisEvent,
+  #[cfg(feature = "joystick")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "joystick")))]
   pub jball: SDL_JoyBallEvent,
+  #[cfg(feature = "joystick")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "joystick")))]
   pub jhat: SDL_JoyHatEvent,
+  #[cfg(feature = "joystick")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "joystick")))]
   pub jbutton: SDL_JoyButtonEvent,
+  #[cfg(feature = "joystick")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "joystick")))]
   pub jdevice: SDL_JoyDeviceEvent,
+  #[cfg(all(feature = "joystick", feature = "sdl2_2_24"))]
+  #[cfg_attr(
+    docs_rs,
+    doc(cfg(all(feature = "joystick", feature = "sdl2_2_24")))
+  )]
+  pub jbattery: SDL_JoyBatteryEvent,
+  #[cfg(feature = "gamecontroller")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "gamecontroller")))]
   pub caxis: SDL_ControllerAxisEvent,
+  #[cfg(feature = "gamecontroller")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "gamecontroller")))]
   pub cbutton: SDL_ControllerButtonEvent,
+  #[cfg(feature = "gamecontroller")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "gamecontroller")))]
   pub cdevice: SDL_ControllerDeviceEvent,
+  #[cfg(feature = "gamecontroller")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "gamecontroller")))]
   pub ctouchpad: SDL_ControllerTouchpadEvent,
+  #[cfg(feature = "gamecontroller")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "gamecontroller")))]
   pub csensor: SDL_ControllerSensorEvent,
   pub adevice: SDL_AudioDeviceEvent,
+  #[cfg(feature = "sensor")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sensor")))]
   pub sensor: SDL_SensorEvent,
   pub quit: SDL_QuitEvent,
   pub user: SDL_UserEvent,
@@ -884,6 +1147,7 @@ impl Default for SDL_Event {
 
 /// Used with [`SDL_PeepEvents`]
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_eventaction(pub i32);
 
@@ -1056,3 +1320,96 @@ pub const SDL_ENABLE: i32 = 1;
 pub unsafe fn SDL_GetEventState(type_: SDL_EventType) -> Uint8 {
   SDL_EventState(type_, SDL_QUERY)
 }
+
+/// Pumps the event loop once and then moves as many events as will fit out of
+/// the queue and into `events`.
+///
+/// This is one [`SDL_PumpEvents`] call and one [`SDL_PeepEvents`] call (using
+/// [`SDL_GETEVENT`] over all event types), which is a lot less overhead than
+/// calling [`SDL_PollEvent`] once per event when many events arrive in a single
+/// frame (eg: a flood of touch or mouse motion). Any events that don't fit are
+/// left in the queue for next time.
+///
+/// **Returns:** The number of events written to the start of `events`, or -1
+/// if there was an error.
+#[inline]
+pub unsafe fn poll_events(events: &mut [SDL_Event]) -> c_int {
+  SDL_PumpEvents();
+  SDL_PeepEvents(
+    events.as_mut_ptr(),
+    events.len().try_into().unwrap_or(c_int::MAX),
+    SDL_GETEVENT,
+    SDL_FIRSTEVENT,
+    SDL_LASTEVENT,
+  )
+}
+
+/// Polls for the next pending event, if there is one.
+///
+/// This calls [`SDL_PollEvent`] with a zeroed event for it to fill in, so it
+/// also pumps the event loop. SDL just reports that there are no events if it
+/// hasn't been initialized, so this is safe to call at any time, but as usual
+/// it should only be called on the thread that set up the video subsystem.
+#[inline]
+#[must_use]
+pub fn poll_event() -> Option<SDL_Event> {
+  let mut event = SDL_Event::default();
+  if unsafe { SDL_PollEvent(&mut event) } != 0 {
+    Some(event)
+  } else {
+    None
+  }
+}
+
+/// Waits up to `timeout` for the next event.
+///
+/// This is [`SDL_WaitEventTimeout`], with the timeout converted by
+/// [`duration_to_ms`] (capped to `c_int::MAX` milliseconds). As with
+/// [`poll_event`], call it on the thread that set up the video subsystem.
+///
+/// **Returns:** The event, or `None` if the time ran out (or there was an
+/// error while waiting).
+#[inline]
+#[must_use]
+pub fn wait_event_timeout(timeout: Duration) -> Option<SDL_Event> {
+  let ms = duration_to_ms(timeout).min(c_int::MAX as u32) as c_int;
+  let mut event = SDL_Event::default();
+  if unsafe { SDL_WaitEventTimeout(&mut event, ms) } != 0 {
+    Some(event)
+  } else {
+    None
+  }
+}
+
+/// Runs a closure on each event in the queue, and removes the events that it
+/// returns `false` for.
+///
+/// This is [`SDL_FilterEvents`] with the closure passed along through the
+/// `userdata` pointer. Unlike with [`SDL_SetEventFilter`], the closure is only
+/// used during this call, so it's a good way to purge stale events all at
+/// once. For example, dropping all mouse motion after a long loading screen:
+///
+/// ```no_run
+/// # use fermium::prelude::*;
+/// filter_events(|event| unsafe { event.type_ } != SDL_MOUSEMOTION);
+/// ```
+///
+/// If the closure panics the program will abort, since the panic can't unwind
+/// back through SDL.
+#[inline]
+pub fn filter_events<F: FnMut(&SDL_Event) -> bool>(mut filter: F) {
+  unsafe extern "C" fn trampoline<F: FnMut(&SDL_Event) -> bool>(
+    userdata: *mut c_void, event: *mut SDL_Event,
+  ) -> c_int {
+    let filter = &mut *userdata.cast::<F>();
+    let keep =
+      crate::abort_on_unwind("the `filter_events` closure", || filter(&*event));
+    keep as c_int
+  }
+  unsafe {
+    SDL_FilterEvents(
+      Some(trampoline::<F>),
+      (&mut filter as *mut F).cast::<c_void>(),
+    )
+  };
+}
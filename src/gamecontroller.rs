@@ -15,8 +15,11 @@
 //!
 //! See Also: [`joystick`](crate::joystick)
 
+use core::str::FromStr;
+
 use crate::{
-  c_char, c_float, c_int, c_void, joystick::*, rwops::*, sensor::*, stdinc::*,
+  c_char, c_float, c_int, c_void, error::*, joystick::*, rwops::*, sensor::*,
+  stdinc::*,
 };
 
 // makes rustdoc link properly!
@@ -37,6 +40,7 @@ pub struct SDL_GameController(c_void);
 ///
 /// See `SDL_CONTROLLER_TYPE_*`
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_GameControllerType(pub i32);
 #[allow(missing_docs)]
@@ -75,6 +79,7 @@ pub const SDL_CONTROLLER_TYPE_GOOGLE_STADIA: SDL_GameControllerType =
 ///
 /// See `SDL_CONTROLLER_BINDTYPE_*`
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_GameControllerBindType(pub i32);
 #[allow(missing_docs)]
@@ -125,6 +130,7 @@ pub struct SDL_GameControllerButtonBind {
 ///
 /// Trigger axis values range from 0 to [`SDL_JOYSTICK_AXIS_MAX`].
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_GameControllerAxis(pub i32);
 
@@ -156,6 +162,7 @@ pub const SDL_CONTROLLER_AXIS_MAX: usize = 6;
 
 /// The list of buttons available from a controller.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_GameControllerButton(pub i32);
 
@@ -245,6 +252,63 @@ pub unsafe fn SDL_GameControllerAddMappingsFromFile(
   )
 }
 
+/// The community sourced controller mappings from
+/// [SDL_GameControllerDB](https://github.com/mdqinc/SDL_GameControllerDB).
+///
+/// This is a copy of `gamecontrollerdb.txt` that's built into the crate, so it
+/// only updates when the crate does.
+#[cfg(feature = "controllerdb")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "controllerdb")))]
+pub static GAMECONTROLLERDB: &[u8] =
+  include_bytes!("../SDL_GameControllerDB/gamecontrollerdb.txt");
+
+/// Load all of the mappings in [`GAMECONTROLLERDB`].
+///
+/// Results are filtered by the current [`SDL_GetPlatform`]. Call this after
+/// initializing [`SDL_INIT_GAMECONTROLLER`] (and before opening controllers).
+/// Mappings that are added later with [`SDL_GameControllerAddMapping`] (or
+/// from the `SDL_GAMECONTROLLERCONFIG` env var) will replace these.
+///
+/// **Returns:** number of mappings added, -1 on error
+#[cfg(feature = "controllerdb")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "controllerdb")))]
+pub unsafe fn add_builtin_controller_mappings() -> c_int {
+  SDL_GameControllerAddMappingsFromRW(
+    SDL_RWFromConstMem(
+      GAMECONTROLLERDB.as_ptr().cast(),
+      GAMECONTROLLERDB.len() as c_int,
+    ),
+    1,
+  )
+}
+
+/// Load a set of mappings from bytes in memory.
+///
+/// This is for mapping files that are built into your program (eg: with
+/// `include_bytes!`). Results are filtered by the current [`SDL_GetPlatform`].
+///
+/// Convenience function that calls [`SDL_GameControllerAddMappingsFromRW`].
+///
+/// **Returns:** number of mappings added
+pub unsafe fn add_controller_mappings(bytes: &[u8]) -> Result<i32, SdlError> {
+  let size = match c_int::try_from(bytes.len()) {
+    Ok(size) => size,
+    Err(_) => {
+      SDL_SetError(b"Mapping data is too large\0".as_ptr().cast());
+      return Err(SdlError::get());
+    }
+  };
+  let added = SDL_GameControllerAddMappingsFromRW(
+    SDL_RWFromConstMem(bytes.as_ptr().cast(), size),
+    1,
+  );
+  if added < 0 {
+    Err(SdlError::get())
+  } else {
+    Ok(added)
+  }
+}
+
 extern "C" {
   /// Load a set of mappings from a seekable SDL data stream (memory or file).
   ///
@@ -555,6 +619,27 @@ extern "C" {
     data: *mut c_float, num_values: c_int,
   ) -> c_int;
 
+  /// Get the current state of a game controller sensor with the timestamp of
+  /// the last update.
+  ///
+  /// The number of values and interpretation of the data is sensor dependent.
+  /// See [`sensor`](crate::sensor) for the details for each type of sensor.
+  ///
+  /// * `gamecontroller` The controller to query
+  /// * `type` The type of sensor to query
+  /// * `timestamp` A pointer filled with the timestamp in microseconds of the
+  ///   current sensor reading if available, or 0 if not
+  /// * `data` A pointer filled with the current sensor state
+  /// * `num_values` The number of values to write to data
+  ///
+  /// **Returns:** 0, or -1 if an error occurred.
+  #[cfg(feature = "sdl2_2_26")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_26")))]
+  pub fn SDL_GameControllerGetSensorDataWithTimestamp(
+    gamecontroller: *mut SDL_GameController, type_: SDL_SensorType,
+    timestamp: *mut Uint64, data: *mut c_float, num_values: c_int,
+  ) -> c_int;
+
   /// Trigger a rumble effect.
   ///
   /// Each call to this function cancels any previous rumble effect, and calling
@@ -601,6 +686,34 @@ extern "C" {
     gamecontroller: *mut SDL_GameController,
   ) -> SDL_bool;
 
+  /// Query whether a game controller has rumble support.
+  ///
+  /// * `gamecontroller` The controller to query
+  ///
+  /// **Return:** `SDL_TRUE`, or `SDL_FALSE` if this controller does not have
+  /// rumble support.
+  ///
+  /// See Also: [`SDL_GameControllerRumble`]
+  #[cfg(feature = "sdl2_2_0_18")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_18")))]
+  pub fn SDL_GameControllerHasRumble(
+    gamecontroller: *mut SDL_GameController,
+  ) -> SDL_bool;
+
+  /// Query whether a game controller has rumble support on triggers.
+  ///
+  /// * `gamecontroller` The controller to query
+  ///
+  /// **Return:** `SDL_TRUE`, or `SDL_FALSE` if this controller does not have
+  /// trigger rumble support.
+  ///
+  /// See Also: [`SDL_GameControllerRumbleTriggers`]
+  #[cfg(feature = "sdl2_2_0_18")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_18")))]
+  pub fn SDL_GameControllerHasRumbleTriggers(
+    gamecontroller: *mut SDL_GameController,
+  ) -> SDL_bool;
+
   /// Update a controller's LED color.
   ///
   /// * `gamecontroller` The controller to update
@@ -624,6 +737,8 @@ extern "C" {
   /// * `size` The size of the data to send to the controller
   /// * **Returns:** 0, or -1 if this controller or driver doesn't support
   ///   effect packets
+  #[cfg(feature = "sdl2_2_0_16")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_16")))]
   pub fn SDL_GameControllerSendEffect(
     gamecontroller: *mut SDL_GameController, data: *const c_void, size: c_int,
   ) -> c_int;
@@ -634,7 +749,59 @@ extern "C" {
   /// * `gamecontroller` The controller to query
   /// * `type` The type of sensor to query
   /// * **Returns:** the data rate, or 0.0f if the data rate is not available.
+  #[cfg(feature = "sdl2_2_0_16")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_16")))]
   pub fn SDL_GameControllerGetSensorDataRate(
     gamecontroller: *mut SDL_GameController, type_: SDL_SensorType,
   ) -> c_float;
 }
+
+/// Parses an axis name with [`SDL_GameControllerGetAxisFromString`].
+///
+/// These are the names used in controller mapping strings (eg: `"leftx"`,
+/// `"righttrigger"`), which [`SDL_GameControllerGetStringForAxis`] gives
+/// back. Unknown names are an error rather than
+/// [`SDL_CONTROLLER_AXIS_INVALID`].
+impl FromStr for SDL_GameControllerAxis {
+  type Err = ParseNameError;
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match with_short_c_str(s, |p| unsafe {
+      SDL_GameControllerGetAxisFromString(p)
+    }) {
+      None | Some(SDL_CONTROLLER_AXIS_INVALID) => Err(ParseNameError),
+      Some(axis) => Ok(axis),
+    }
+  }
+}
+impl TryFrom<&str> for SDL_GameControllerAxis {
+  type Error = ParseNameError;
+  #[inline]
+  fn try_from(s: &str) -> Result<Self, Self::Error> {
+    s.parse()
+  }
+}
+
+/// Parses a button name with [`SDL_GameControllerGetButtonFromString`].
+///
+/// These are the names used in controller mapping strings (eg: `"a"`,
+/// `"leftshoulder"`), which [`SDL_GameControllerGetStringForButton`] gives
+/// back. Unknown names are an error rather than
+/// [`SDL_CONTROLLER_BUTTON_INVALID`].
+impl FromStr for SDL_GameControllerButton {
+  type Err = ParseNameError;
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match with_short_c_str(s, |p| unsafe {
+      SDL_GameControllerGetButtonFromString(p)
+    }) {
+      None | Some(SDL_CONTROLLER_BUTTON_INVALID) => Err(ParseNameError),
+      Some(button) => Ok(button),
+    }
+  }
+}
+impl TryFrom<&str> for SDL_GameControllerButton {
+  type Error = ParseNameError;
+  #[inline]
+  fn try_from(s: &str) -> Result<Self, Self::Error> {
+    s.parse()
+  }
+}
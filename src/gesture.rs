@@ -2,24 +2,48 @@
 
 use crate::{c_int, rwops::*, stdinc::*, touch::*};
 
+// makes rustdoc link properly!
+#[allow(unused)]
+use crate::{error::*, events::*};
+
 /// Used with gesture events.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_GestureID(pub Sint64);
 
 extern "C" {
-  /// Begin Recording a gesture on the specified touch, or all touches (-1).
+  /// Begin recording a gesture on a specified touch device or all touch
+  /// devices.
+  ///
+  /// If the parameter `touchId` is -1 (i.e., all devices), this function will
+  /// always return 1, regardless of whether there actually are any devices.
+  ///
+  /// The recorded gesture arrives as an [`SDL_DOLLARRECORD`] event.
+  ///
+  /// **Returns:** 1 on success or 0 if the specified device could not be
+  /// found.
   pub fn SDL_RecordGesture(touchId: SDL_TouchID) -> c_int;
 
   /// Save all currently loaded Dollar Gesture templates.
+  ///
+  /// **Returns:** the number of saved templates on success or 0 on failure
+  /// (call [`SDL_GetErrorMsg`] for more information).
   pub fn SDL_SaveAllDollarTemplates(dst: *mut SDL_RWops) -> c_int;
 
   /// Save a currently loaded Dollar Gesture template.
+  ///
+  /// **Returns:** 1 on success or 0 on failure (call [`SDL_GetErrorMsg`] for
+  /// more information).
   pub fn SDL_SaveDollarTemplate(
     gestureId: SDL_GestureID, dst: *mut SDL_RWops,
   ) -> c_int;
 
   /// Load Dollar Gesture templates from a file.
+  ///
+  /// **Returns:** the number of loaded templates on success or a negative
+  /// error code (or 0) on failure (call [`SDL_GetErrorMsg`] for more
+  /// information).
   pub fn SDL_LoadDollarTemplates(
     touchId: SDL_TouchID, src: *mut SDL_RWops,
   ) -> c_int;
@@ -0,0 +1,604 @@
+//! Bindings to the SDL2_gfx library: drawing primitives, surface rotation and
+//! zooming, and a framerate manager.
+//!
+//! This is a separate library from SDL2 itself, so it must also be installed
+//! for your program to link and run. These bindings match SDL2_gfx 1.0.4.
+//!
+//! The drawing functions each come in two forms: `*Color` takes the color as
+//! one `Uint32`, and `*RGBA` takes separate components. The `Uint32` is read
+//! as R, G, B, A bytes *in memory order*, so on little-endian targets it's
+//! `0xAABBGGRR` (despite the C docs saying `0xRRGGBBAA`). Use
+//! `u32::from_ne_bytes([r, g, b, a])` to build one. Any alpha less than 255
+//! switches the renderer to blended drawing.
+
+use crate::{
+  c_char, c_double, c_float, c_int, c_void, renderer::*, stdinc::*, surface::*,
+};
+
+/// Turns off smoothing for [`rotozoomSurface`] and friends.
+pub const SMOOTHING_OFF: c_int = 0;
+/// Turns on smoothing (antialiasing) for [`rotozoomSurface`] and friends.
+pub const SMOOTHING_ON: c_int = 1;
+
+/// The highest rate that [`SDL_setFramerate`] accepts.
+pub const FPS_UPPER_LIMIT: Uint32 = 200;
+/// The lowest rate that [`SDL_setFramerate`] accepts.
+pub const FPS_LOWER_LIMIT: Uint32 = 1;
+/// The rate that [`SDL_initFramerate`] sets.
+pub const FPS_DEFAULT: Uint32 = 30;
+
+/// Keeps track of time so that [`SDL_framerateDelay`] can hold a steady
+/// framerate.
+///
+/// Set this up with [`SDL_initFramerate`], the fields are private to the
+/// library.
+#[derive(Debug, Clone, Copy, Default)]
+#[repr(C)]
+#[allow(missing_docs)]
+pub struct FPSmanager {
+  pub framecount: Uint32,
+  pub rateticks: c_float,
+  pub baseticks: Uint32,
+  pub lastticks: Uint32,
+  pub rate: Uint32,
+}
+
+extern "C" {
+  /// Draws a single pixel.
+  ///
+  /// * `color` is R, G, B, A bytes in memory order, see the module docs.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn pixelColor(
+    renderer: *mut SDL_Renderer, x: Sint16, y: Sint16, color: Uint32,
+  ) -> c_int;
+
+  /// As [`pixelColor`], with the color as separate components.
+  pub fn pixelRGBA(
+    renderer: *mut SDL_Renderer, x: Sint16, y: Sint16, r: Uint8, g: Uint8,
+    b: Uint8, a: Uint8,
+  ) -> c_int;
+
+  /// Draws a horizontal line.
+  ///
+  /// * `color` is R, G, B, A bytes in memory order, see the module docs.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn hlineColor(
+    renderer: *mut SDL_Renderer, x1: Sint16, x2: Sint16, y: Sint16,
+    color: Uint32,
+  ) -> c_int;
+
+  /// As [`hlineColor`], with the color as separate components.
+  pub fn hlineRGBA(
+    renderer: *mut SDL_Renderer, x1: Sint16, x2: Sint16, y: Sint16, r: Uint8,
+    g: Uint8, b: Uint8, a: Uint8,
+  ) -> c_int;
+
+  /// Draws a vertical line.
+  ///
+  /// * `color` is R, G, B, A bytes in memory order, see the module docs.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn vlineColor(
+    renderer: *mut SDL_Renderer, x: Sint16, y1: Sint16, y2: Sint16,
+    color: Uint32,
+  ) -> c_int;
+
+  /// As [`vlineColor`], with the color as separate components.
+  pub fn vlineRGBA(
+    renderer: *mut SDL_Renderer, x: Sint16, y1: Sint16, y2: Sint16, r: Uint8,
+    g: Uint8, b: Uint8, a: Uint8,
+  ) -> c_int;
+
+  /// Draws a rectangle outline.
+  ///
+  /// * `color` is R, G, B, A bytes in memory order, see the module docs.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn rectangleColor(
+    renderer: *mut SDL_Renderer, x1: Sint16, y1: Sint16, x2: Sint16,
+    y2: Sint16, color: Uint32,
+  ) -> c_int;
+
+  /// As [`rectangleColor`], with the color as separate components.
+  pub fn rectangleRGBA(
+    renderer: *mut SDL_Renderer, x1: Sint16, y1: Sint16, x2: Sint16,
+    y2: Sint16, r: Uint8, g: Uint8, b: Uint8, a: Uint8,
+  ) -> c_int;
+
+  /// Draws a rectangle outline with rounded corners.
+  ///
+  /// * `color` is R, G, B, A bytes in memory order, see the module docs.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn roundedRectangleColor(
+    renderer: *mut SDL_Renderer, x1: Sint16, y1: Sint16, x2: Sint16,
+    y2: Sint16, rad: Sint16, color: Uint32,
+  ) -> c_int;
+
+  /// As [`roundedRectangleColor`], with the color as separate components.
+  pub fn roundedRectangleRGBA(
+    renderer: *mut SDL_Renderer, x1: Sint16, y1: Sint16, x2: Sint16,
+    y2: Sint16, rad: Sint16, r: Uint8, g: Uint8, b: Uint8, a: Uint8,
+  ) -> c_int;
+
+  /// Draws a filled rectangle.
+  ///
+  /// * `color` is R, G, B, A bytes in memory order, see the module docs.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn boxColor(
+    renderer: *mut SDL_Renderer, x1: Sint16, y1: Sint16, x2: Sint16,
+    y2: Sint16, color: Uint32,
+  ) -> c_int;
+
+  /// As [`boxColor`], with the color as separate components.
+  pub fn boxRGBA(
+    renderer: *mut SDL_Renderer, x1: Sint16, y1: Sint16, x2: Sint16,
+    y2: Sint16, r: Uint8, g: Uint8, b: Uint8, a: Uint8,
+  ) -> c_int;
+
+  /// Draws a filled rectangle with rounded corners.
+  ///
+  /// * `color` is R, G, B, A bytes in memory order, see the module docs.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn roundedBoxColor(
+    renderer: *mut SDL_Renderer, x1: Sint16, y1: Sint16, x2: Sint16,
+    y2: Sint16, rad: Sint16, color: Uint32,
+  ) -> c_int;
+
+  /// As [`roundedBoxColor`], with the color as separate components.
+  pub fn roundedBoxRGBA(
+    renderer: *mut SDL_Renderer, x1: Sint16, y1: Sint16, x2: Sint16,
+    y2: Sint16, rad: Sint16, r: Uint8, g: Uint8, b: Uint8, a: Uint8,
+  ) -> c_int;
+
+  /// Draws a line.
+  ///
+  /// * `color` is R, G, B, A bytes in memory order, see the module docs.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn lineColor(
+    renderer: *mut SDL_Renderer, x1: Sint16, y1: Sint16, x2: Sint16,
+    y2: Sint16, color: Uint32,
+  ) -> c_int;
+
+  /// As [`lineColor`], with the color as separate components.
+  pub fn lineRGBA(
+    renderer: *mut SDL_Renderer, x1: Sint16, y1: Sint16, x2: Sint16,
+    y2: Sint16, r: Uint8, g: Uint8, b: Uint8, a: Uint8,
+  ) -> c_int;
+
+  /// Draws an antialiased line.
+  ///
+  /// * `color` is R, G, B, A bytes in memory order, see the module docs.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn aalineColor(
+    renderer: *mut SDL_Renderer, x1: Sint16, y1: Sint16, x2: Sint16,
+    y2: Sint16, color: Uint32,
+  ) -> c_int;
+
+  /// As [`aalineColor`], with the color as separate components.
+  pub fn aalineRGBA(
+    renderer: *mut SDL_Renderer, x1: Sint16, y1: Sint16, x2: Sint16,
+    y2: Sint16, r: Uint8, g: Uint8, b: Uint8, a: Uint8,
+  ) -> c_int;
+
+  /// Draws a line that's `width` pixels thick.
+  ///
+  /// * `color` is R, G, B, A bytes in memory order, see the module docs.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn thickLineColor(
+    renderer: *mut SDL_Renderer, x1: Sint16, y1: Sint16, x2: Sint16,
+    y2: Sint16, width: Uint8, color: Uint32,
+  ) -> c_int;
+
+  /// As [`thickLineColor`], with the color as separate components.
+  pub fn thickLineRGBA(
+    renderer: *mut SDL_Renderer, x1: Sint16, y1: Sint16, x2: Sint16,
+    y2: Sint16, width: Uint8, r: Uint8, g: Uint8, b: Uint8, a: Uint8,
+  ) -> c_int;
+
+  /// Draws a circle outline.
+  ///
+  /// * `color` is R, G, B, A bytes in memory order, see the module docs.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn circleColor(
+    renderer: *mut SDL_Renderer, x: Sint16, y: Sint16, rad: Sint16,
+    color: Uint32,
+  ) -> c_int;
+
+  /// As [`circleColor`], with the color as separate components.
+  pub fn circleRGBA(
+    renderer: *mut SDL_Renderer, x: Sint16, y: Sint16, rad: Sint16, r: Uint8,
+    g: Uint8, b: Uint8, a: Uint8,
+  ) -> c_int;
+
+  /// Draws an arc, from `start` to `end` degrees (0 is right, going clockwise).
+  ///
+  /// * `color` is R, G, B, A bytes in memory order, see the module docs.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn arcColor(
+    renderer: *mut SDL_Renderer, x: Sint16, y: Sint16, rad: Sint16,
+    start: Sint16, end: Sint16, color: Uint32,
+  ) -> c_int;
+
+  /// As [`arcColor`], with the color as separate components.
+  pub fn arcRGBA(
+    renderer: *mut SDL_Renderer, x: Sint16, y: Sint16, rad: Sint16,
+    start: Sint16, end: Sint16, r: Uint8, g: Uint8, b: Uint8, a: Uint8,
+  ) -> c_int;
+
+  /// Draws an antialiased circle outline.
+  ///
+  /// * `color` is R, G, B, A bytes in memory order, see the module docs.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn aacircleColor(
+    renderer: *mut SDL_Renderer, x: Sint16, y: Sint16, rad: Sint16,
+    color: Uint32,
+  ) -> c_int;
+
+  /// As [`aacircleColor`], with the color as separate components.
+  pub fn aacircleRGBA(
+    renderer: *mut SDL_Renderer, x: Sint16, y: Sint16, rad: Sint16, r: Uint8,
+    g: Uint8, b: Uint8, a: Uint8,
+  ) -> c_int;
+
+  /// Draws a filled circle.
+  ///
+  /// * `color` is R, G, B, A bytes in memory order, see the module docs.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn filledCircleColor(
+    renderer: *mut SDL_Renderer, x: Sint16, y: Sint16, rad: Sint16,
+    color: Uint32,
+  ) -> c_int;
+
+  /// As [`filledCircleColor`], with the color as separate components.
+  pub fn filledCircleRGBA(
+    renderer: *mut SDL_Renderer, x: Sint16, y: Sint16, rad: Sint16, r: Uint8,
+    g: Uint8, b: Uint8, a: Uint8,
+  ) -> c_int;
+
+  /// Draws an ellipse outline.
+  ///
+  /// * `color` is R, G, B, A bytes in memory order, see the module docs.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn ellipseColor(
+    renderer: *mut SDL_Renderer, x: Sint16, y: Sint16, rx: Sint16, ry: Sint16,
+    color: Uint32,
+  ) -> c_int;
+
+  /// As [`ellipseColor`], with the color as separate components.
+  pub fn ellipseRGBA(
+    renderer: *mut SDL_Renderer, x: Sint16, y: Sint16, rx: Sint16, ry: Sint16,
+    r: Uint8, g: Uint8, b: Uint8, a: Uint8,
+  ) -> c_int;
+
+  /// Draws an antialiased ellipse outline.
+  ///
+  /// * `color` is R, G, B, A bytes in memory order, see the module docs.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn aaellipseColor(
+    renderer: *mut SDL_Renderer, x: Sint16, y: Sint16, rx: Sint16, ry: Sint16,
+    color: Uint32,
+  ) -> c_int;
+
+  /// As [`aaellipseColor`], with the color as separate components.
+  pub fn aaellipseRGBA(
+    renderer: *mut SDL_Renderer, x: Sint16, y: Sint16, rx: Sint16, ry: Sint16,
+    r: Uint8, g: Uint8, b: Uint8, a: Uint8,
+  ) -> c_int;
+
+  /// Draws a filled ellipse.
+  ///
+  /// * `color` is R, G, B, A bytes in memory order, see the module docs.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn filledEllipseColor(
+    renderer: *mut SDL_Renderer, x: Sint16, y: Sint16, rx: Sint16, ry: Sint16,
+    color: Uint32,
+  ) -> c_int;
+
+  /// As [`filledEllipseColor`], with the color as separate components.
+  pub fn filledEllipseRGBA(
+    renderer: *mut SDL_Renderer, x: Sint16, y: Sint16, rx: Sint16, ry: Sint16,
+    r: Uint8, g: Uint8, b: Uint8, a: Uint8,
+  ) -> c_int;
+
+  /// Draws a pie slice outline, from `start` to `end` degrees.
+  ///
+  /// * `color` is R, G, B, A bytes in memory order, see the module docs.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn pieColor(
+    renderer: *mut SDL_Renderer, x: Sint16, y: Sint16, rad: Sint16,
+    start: Sint16, end: Sint16, color: Uint32,
+  ) -> c_int;
+
+  /// As [`pieColor`], with the color as separate components.
+  pub fn pieRGBA(
+    renderer: *mut SDL_Renderer, x: Sint16, y: Sint16, rad: Sint16,
+    start: Sint16, end: Sint16, r: Uint8, g: Uint8, b: Uint8, a: Uint8,
+  ) -> c_int;
+
+  /// Draws a filled pie slice, from `start` to `end` degrees.
+  ///
+  /// * `color` is R, G, B, A bytes in memory order, see the module docs.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn filledPieColor(
+    renderer: *mut SDL_Renderer, x: Sint16, y: Sint16, rad: Sint16,
+    start: Sint16, end: Sint16, color: Uint32,
+  ) -> c_int;
+
+  /// As [`filledPieColor`], with the color as separate components.
+  pub fn filledPieRGBA(
+    renderer: *mut SDL_Renderer, x: Sint16, y: Sint16, rad: Sint16,
+    start: Sint16, end: Sint16, r: Uint8, g: Uint8, b: Uint8, a: Uint8,
+  ) -> c_int;
+
+  /// Draws a triangle outline.
+  ///
+  /// * `color` is R, G, B, A bytes in memory order, see the module docs.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn trigonColor(
+    renderer: *mut SDL_Renderer, x1: Sint16, y1: Sint16, x2: Sint16,
+    y2: Sint16, x3: Sint16, y3: Sint16, color: Uint32,
+  ) -> c_int;
+
+  /// As [`trigonColor`], with the color as separate components.
+  pub fn trigonRGBA(
+    renderer: *mut SDL_Renderer, x1: Sint16, y1: Sint16, x2: Sint16,
+    y2: Sint16, x3: Sint16, y3: Sint16, r: Uint8, g: Uint8, b: Uint8, a: Uint8,
+  ) -> c_int;
+
+  /// Draws an antialiased triangle outline.
+  ///
+  /// * `color` is R, G, B, A bytes in memory order, see the module docs.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn aatrigonColor(
+    renderer: *mut SDL_Renderer, x1: Sint16, y1: Sint16, x2: Sint16,
+    y2: Sint16, x3: Sint16, y3: Sint16, color: Uint32,
+  ) -> c_int;
+
+  /// As [`aatrigonColor`], with the color as separate components.
+  pub fn aatrigonRGBA(
+    renderer: *mut SDL_Renderer, x1: Sint16, y1: Sint16, x2: Sint16,
+    y2: Sint16, x3: Sint16, y3: Sint16, r: Uint8, g: Uint8, b: Uint8, a: Uint8,
+  ) -> c_int;
+
+  /// Draws a filled triangle.
+  ///
+  /// * `color` is R, G, B, A bytes in memory order, see the module docs.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn filledTrigonColor(
+    renderer: *mut SDL_Renderer, x1: Sint16, y1: Sint16, x2: Sint16,
+    y2: Sint16, x3: Sint16, y3: Sint16, color: Uint32,
+  ) -> c_int;
+
+  /// As [`filledTrigonColor`], with the color as separate components.
+  pub fn filledTrigonRGBA(
+    renderer: *mut SDL_Renderer, x1: Sint16, y1: Sint16, x2: Sint16,
+    y2: Sint16, x3: Sint16, y3: Sint16, r: Uint8, g: Uint8, b: Uint8, a: Uint8,
+  ) -> c_int;
+
+  /// Draws a polygon outline, with `n` points from the `vx` and `vy` arrays.
+  ///
+  /// * `color` is R, G, B, A bytes in memory order, see the module docs.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn polygonColor(
+    renderer: *mut SDL_Renderer, vx: *const Sint16, vy: *const Sint16,
+    n: c_int, color: Uint32,
+  ) -> c_int;
+
+  /// As [`polygonColor`], with the color as separate components.
+  pub fn polygonRGBA(
+    renderer: *mut SDL_Renderer, vx: *const Sint16, vy: *const Sint16,
+    n: c_int, r: Uint8, g: Uint8, b: Uint8, a: Uint8,
+  ) -> c_int;
+
+  /// Draws an antialiased polygon outline, with `n` points from the `vx` and `vy`
+  /// arrays.
+  ///
+  /// * `color` is R, G, B, A bytes in memory order, see the module docs.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn aapolygonColor(
+    renderer: *mut SDL_Renderer, vx: *const Sint16, vy: *const Sint16,
+    n: c_int, color: Uint32,
+  ) -> c_int;
+
+  /// As [`aapolygonColor`], with the color as separate components.
+  pub fn aapolygonRGBA(
+    renderer: *mut SDL_Renderer, vx: *const Sint16, vy: *const Sint16,
+    n: c_int, r: Uint8, g: Uint8, b: Uint8, a: Uint8,
+  ) -> c_int;
+
+  /// Draws a filled polygon, with `n` points from the `vx` and `vy` arrays.
+  ///
+  /// * `color` is R, G, B, A bytes in memory order, see the module docs.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn filledPolygonColor(
+    renderer: *mut SDL_Renderer, vx: *const Sint16, vy: *const Sint16,
+    n: c_int, color: Uint32,
+  ) -> c_int;
+
+  /// As [`filledPolygonColor`], with the color as separate components.
+  pub fn filledPolygonRGBA(
+    renderer: *mut SDL_Renderer, vx: *const Sint16, vy: *const Sint16,
+    n: c_int, r: Uint8, g: Uint8, b: Uint8, a: Uint8,
+  ) -> c_int;
+
+  /// Draws a polygon filled with a tiled texture, with `n` points from the
+  /// `vx` and `vy` arrays.
+  ///
+  /// * `texture_dx` and `texture_dy` offset the texture tiling.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn texturedPolygon(
+    renderer: *mut SDL_Renderer, vx: *const Sint16, vy: *const Sint16,
+    n: c_int, texture: *mut SDL_Surface, texture_dx: c_int, texture_dy: c_int,
+  ) -> c_int;
+
+  /// Draws a bezier curve through `n` control points (at least 3), with `s`
+  /// steps of interpolation (at least 2).
+  ///
+  /// * `color` is R, G, B, A bytes in memory order, see the module docs.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn bezierColor(
+    renderer: *mut SDL_Renderer, vx: *const Sint16, vy: *const Sint16,
+    n: c_int, s: c_int, color: Uint32,
+  ) -> c_int;
+
+  /// As [`bezierColor`], with the color as separate components.
+  pub fn bezierRGBA(
+    renderer: *mut SDL_Renderer, vx: *const Sint16, vy: *const Sint16,
+    n: c_int, s: c_int, r: Uint8, g: Uint8, b: Uint8, a: Uint8,
+  ) -> c_int;
+
+  /// Draws a character of the current font, see [`gfxPrimitivesSetFont`].
+  ///
+  /// * `color` is R, G, B, A bytes in memory order, see the module docs.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn characterColor(
+    renderer: *mut SDL_Renderer, x: Sint16, y: Sint16, c: c_char, color: Uint32,
+  ) -> c_int;
+
+  /// As [`characterColor`], with the color as separate components.
+  pub fn characterRGBA(
+    renderer: *mut SDL_Renderer, x: Sint16, y: Sint16, c: c_char, r: Uint8,
+    g: Uint8, b: Uint8, a: Uint8,
+  ) -> c_int;
+
+  /// Draws a string of the current font, see [`gfxPrimitivesSetFont`].
+  ///
+  /// * `color` is R, G, B, A bytes in memory order, see the module docs.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn stringColor(
+    renderer: *mut SDL_Renderer, x: Sint16, y: Sint16, s: *const c_char,
+    color: Uint32,
+  ) -> c_int;
+
+  /// As [`stringColor`], with the color as separate components.
+  pub fn stringRGBA(
+    renderer: *mut SDL_Renderer, x: Sint16, y: Sint16, s: *const c_char,
+    r: Uint8, g: Uint8, b: Uint8, a: Uint8,
+  ) -> c_int;
+
+  /// Sets the font used by [`characterColor`] and [`stringColor`].
+  ///
+  /// * `fontdata` the glyph bitmaps (256 glyphs, one bit per pixel), or null
+  ///   for the built-in 8x8 font.
+  /// * `cw` and `ch` the size of each glyph in pixels.
+  pub fn gfxPrimitivesSetFont(fontdata: *const c_void, cw: Uint32, ch: Uint32);
+
+  /// Sets how the font is rotated, in 90 degree clockwise steps (0 to 3).
+  pub fn gfxPrimitivesSetFontRotation(rotation: Uint32);
+
+  /// Rotates and zooms a surface into a new 32-bit or 8-bit surface.
+  ///
+  /// * `angle` in degrees, counter-clockwise.
+  /// * `zoom` the scale factor.
+  /// * `smooth` [`SMOOTHING_ON`] or [`SMOOTHING_OFF`]. Smoothing only applies
+  ///   to 32-bit surfaces.
+  ///
+  /// **Returns:** The new surface, or null on error.
+  pub fn rotozoomSurface(
+    src: *mut SDL_Surface, angle: c_double, zoom: c_double, smooth: c_int,
+  ) -> *mut SDL_Surface;
+
+  /// As [`rotozoomSurface`], with separate horizontal and vertical zoom.
+  ///
+  /// A negative zoom flips the image on that axis.
+  pub fn rotozoomSurfaceXY(
+    src: *mut SDL_Surface, angle: c_double, zoomx: c_double, zoomy: c_double,
+    smooth: c_int,
+  ) -> *mut SDL_Surface;
+
+  /// Gets the size of the surface that [`rotozoomSurface`] would make.
+  pub fn rotozoomSurfaceSize(
+    width: c_int, height: c_int, angle: c_double, zoom: c_double,
+    dstwidth: *mut c_int, dstheight: *mut c_int,
+  );
+
+  /// Gets the size of the surface that [`rotozoomSurfaceXY`] would make.
+  pub fn rotozoomSurfaceSizeXY(
+    width: c_int, height: c_int, angle: c_double, zoomx: c_double,
+    zoomy: c_double, dstwidth: *mut c_int, dstheight: *mut c_int,
+  );
+
+  /// Zooms a surface into a new surface, without rotating.
+  ///
+  /// A negative zoom flips the image on that axis.
+  ///
+  /// **Returns:** The new surface, or null on error.
+  pub fn zoomSurface(
+    src: *mut SDL_Surface, zoomx: c_double, zoomy: c_double, smooth: c_int,
+  ) -> *mut SDL_Surface;
+
+  /// Gets the size of the surface that [`zoomSurface`] would make.
+  pub fn zoomSurfaceSize(
+    width: c_int, height: c_int, zoomx: c_double, zoomy: c_double,
+    dstwidth: *mut c_int, dstheight: *mut c_int,
+  );
+
+  /// Shrinks a surface by whole number factors, averaging the pixels.
+  ///
+  /// **Returns:** The new surface, or null on error.
+  pub fn shrinkSurface(
+    src: *mut SDL_Surface, factorx: c_int, factory: c_int,
+  ) -> *mut SDL_Surface;
+
+  /// Rotates a 32-bit surface by a multiple of 90 degrees, into a new
+  /// surface.
+  ///
+  /// **Returns:** The new surface, or null on error.
+  pub fn rotateSurface90Degrees(
+    src: *mut SDL_Surface, numClockwiseTurns: c_int,
+  ) -> *mut SDL_Surface;
+
+  /// Sets up a framerate manager at [`FPS_DEFAULT`].
+  pub fn SDL_initFramerate(manager: *mut FPSmanager);
+
+  /// Sets the target framerate, from [`FPS_LOWER_LIMIT`] to
+  /// [`FPS_UPPER_LIMIT`].
+  ///
+  /// **Returns:** 0 on success, or -1 if the rate is out of range.
+  pub fn SDL_setFramerate(manager: *mut FPSmanager, rate: Uint32) -> c_int;
+
+  /// Gets the target framerate.
+  ///
+  /// **Returns:** The rate, or -1 on error.
+  pub fn SDL_getFramerate(manager: *mut FPSmanager) -> c_int;
+
+  /// Gets the number of frames since the framerate was last set.
+  ///
+  /// **Returns:** The count, or -1 on error.
+  pub fn SDL_getFramecount(manager: *mut FPSmanager) -> c_int;
+
+  /// Delays for the rest of the current frame's time.
+  ///
+  /// Call this once per frame. If the frame ran long, this returns right away
+  /// and the timing is reset.
+  ///
+  /// **Returns:** The time that passed since the last call, in milliseconds.
+  pub fn SDL_framerateDelay(manager: *mut FPSmanager) -> Uint32;
+}
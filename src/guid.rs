@@ -0,0 +1,98 @@
+//! A GUID is a 128-bit identifier for an input device that identifies that
+//! device across runs of SDL programs on the same platform.
+//!
+//! If the device is detached and then re-attached to a different port, or if
+//! the base system is rebooted, the device should still report the same GUID.
+//!
+//! GUIDs are as precise as possible but are not guaranteed to distinguish
+//! physically distinct but equivalent devices. For example, two game
+//! controllers from the same vendor with the same product ID and revision may
+//! have the same GUID.
+//!
+//! GUIDs may be platform-dependent (i.e., the same device may report different
+//! GUIDs on different operating systems).
+
+use core::{fmt, str::FromStr};
+
+#[cfg(feature = "sdl2_2_24")]
+use crate::{c_char, c_int};
+
+/// An `SDL_GUID` is a 128-bit identifier.
+///
+/// The joystick GUID type (`SDL_JoystickGUID`) is an alias for this, same as
+/// in the C headers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+#[allow(missing_docs)]
+pub struct SDL_GUID {
+  pub data: [u8; 16],
+}
+impl_pod!(SDL_GUID);
+/// Formats the GUID as 32 lower case hex digits.
+///
+/// This is the same text that `SDL_GUIDToString` writes, but without needing
+/// a buffer (use `to_string` if you want an owned `String`).
+impl fmt::Display for SDL_GUID {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    for byte in self.data.iter() {
+      write!(f, "{byte:02x}")?;
+    }
+    Ok(())
+  }
+}
+/// Parses a GUID from 32 hex digits (in either case).
+///
+/// This is the inverse of the `Display` impl. Unlike `SDL_GUIDFromString`,
+/// which quietly turns bad input into zero bits, the string must be exactly 32
+/// hex digits or an error is returned.
+impl FromStr for SDL_GUID {
+  type Err = ParseGuidError;
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let s = s.as_bytes();
+    if s.len() != 32 || !s.iter().all(u8::is_ascii_hexdigit) {
+      return Err(ParseGuidError);
+    }
+    let nibble = |c: u8| (c as char).to_digit(16).unwrap_or(0) as u8;
+    let mut data = [0_u8; 16];
+    for (byte, pair) in data.iter_mut().zip(s.chunks_exact(2)) {
+      *byte = nibble(pair[0]) << 4 | nibble(pair[1]);
+    }
+    Ok(Self { data })
+  }
+}
+
+/// The error from parsing an invalid GUID string.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ParseGuidError;
+impl fmt::Display for ParseGuidError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str("a GUID must be exactly 32 hex digits")
+  }
+}
+
+extern "C" {
+  /// Get an ASCII string representation for a given [`SDL_GUID`].
+  ///
+  /// You should supply at least 33 bytes for `pszGUID`.
+  ///
+  /// * `guid` the [`SDL_GUID`] you wish to convert to string
+  /// * `pszGUID` buffer in which to write the ASCII string
+  /// * `cbGUID` the size of `pszGUID`
+  #[cfg(feature = "sdl2_2_24")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_24")))]
+  pub fn SDL_GUIDToString(guid: SDL_GUID, pszGUID: *mut c_char, cbGUID: c_int);
+
+  /// Convert a GUID string into a [`SDL_GUID`] structure.
+  ///
+  /// Performs no error checking. If this function is given a string
+  /// containing an invalid GUID, the function will silently succeed, but the
+  /// GUID generated will not be useful.
+  ///
+  /// * `pchGUID` string containing an ASCII representation of a GUID
+  ///
+  /// **Returns:** a [`SDL_GUID`] structure.
+  #[cfg(feature = "sdl2_2_24")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_24")))]
+  pub fn SDL_GUIDFromString(pchGUID: *const c_char) -> SDL_GUID;
+}
@@ -0,0 +1,719 @@
+//! Lets you control haptic (force feedback) devices.
+//!
+//! The basic usage is as follows:
+//! * Initialize the subsystem ([`SDL_INIT_HAPTIC`]).
+//! * Open a haptic device, with [`SDL_HapticOpen`] to open by index or with
+//!   [`SDL_HapticOpenFromJoystick`] to open from an existing joystick.
+//! * Fill in an [`SDL_HapticEffect`] (zero is a safe default for every
+//!   field).
+//! * Upload the effect with [`SDL_HapticNewEffect`].
+//! * Run the effect with [`SDL_HapticRunEffect`].
+//! * (optional) Free the effect with [`SDL_HapticDestroyEffect`].
+//! * Close the haptic device with [`SDL_HapticClose`].
+//!
+//! If you just want a controller to rumble, the `SDL_HapticRumble*` functions
+//! do that without needing to build an effect.
+//!
+//! All effect values max out at 32767 (`0x7FFF`), and signed values can also
+//! be negative. Time values are in milliseconds unless noted otherwise.
+
+use crate::{c_char, c_float, c_int, c_uint, c_void, joystick::*, stdinc::*};
+
+// makes rustdoc link properly!
+#[allow(unused)]
+use crate::{error::*, *};
+
+/// The haptic structure used to identify an SDL haptic device.
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct SDL_Haptic(c_void);
+
+/// Constant effect supported.
+///
+/// See [`SDL_HapticConstant`].
+pub const SDL_HAPTIC_CONSTANT: Uint16 = 1 << 0;
+/// Sine wave effect supported.
+///
+/// See [`SDL_HapticPeriodic`].
+pub const SDL_HAPTIC_SINE: Uint16 = 1 << 1;
+/// Left/Right effect supported, for direct control over the high and low
+/// frequency motors.
+///
+/// See [`SDL_HapticLeftRight`].
+pub const SDL_HAPTIC_LEFTRIGHT: Uint16 = 1 << 2;
+/// Triangle wave effect supported.
+///
+/// See [`SDL_HapticPeriodic`].
+pub const SDL_HAPTIC_TRIANGLE: Uint16 = 1 << 3;
+/// Sawtooth up wave effect supported.
+///
+/// See [`SDL_HapticPeriodic`].
+pub const SDL_HAPTIC_SAWTOOTHUP: Uint16 = 1 << 4;
+/// Sawtooth down wave effect supported.
+///
+/// See [`SDL_HapticPeriodic`].
+pub const SDL_HAPTIC_SAWTOOTHDOWN: Uint16 = 1 << 5;
+/// Ramp effect supported.
+///
+/// See [`SDL_HapticRamp`].
+pub const SDL_HAPTIC_RAMP: Uint16 = 1 << 6;
+/// Spring effect supported, based on the axes position.
+///
+/// See [`SDL_HapticCondition`].
+pub const SDL_HAPTIC_SPRING: Uint16 = 1 << 7;
+/// Damper effect supported, based on the axes velocity.
+///
+/// See [`SDL_HapticCondition`].
+pub const SDL_HAPTIC_DAMPER: Uint16 = 1 << 8;
+/// Inertia effect supported, based on the axes acceleration.
+///
+/// See [`SDL_HapticCondition`].
+pub const SDL_HAPTIC_INERTIA: Uint16 = 1 << 9;
+/// Friction effect supported, based on the axes movement.
+///
+/// See [`SDL_HapticCondition`].
+pub const SDL_HAPTIC_FRICTION: Uint16 = 1 << 10;
+/// Custom effect supported.
+///
+/// See [`SDL_HapticCustom`].
+pub const SDL_HAPTIC_CUSTOM: Uint16 = 1 << 11;
+
+/// Device can set the global gain (see [`SDL_HapticSetGain`]).
+///
+/// This and the following bits are features of the device rather than
+/// effects, they're only found in the [`SDL_HapticQuery`] output.
+pub const SDL_HAPTIC_GAIN: Uint16 = 1 << 12;
+/// Device can set autocenter (see [`SDL_HapticSetAutocenter`]).
+pub const SDL_HAPTIC_AUTOCENTER: Uint16 = 1 << 13;
+/// Device can be queried for effect status (see
+/// [`SDL_HapticGetEffectStatus`]).
+pub const SDL_HAPTIC_STATUS: Uint16 = 1 << 14;
+/// Device can be paused (see [`SDL_HapticPause`] and [`SDL_HapticUnpause`]).
+pub const SDL_HAPTIC_PAUSE: Uint16 = 1 << 15;
+
+/// Uses polar coordinates for the direction.
+///
+/// See [`SDL_HapticDirection`].
+pub const SDL_HAPTIC_POLAR: Uint8 = 0;
+/// Uses cartesian coordinates for the direction.
+///
+/// See [`SDL_HapticDirection`].
+pub const SDL_HAPTIC_CARTESIAN: Uint8 = 1;
+/// Uses spherical coordinates for the direction.
+///
+/// See [`SDL_HapticDirection`].
+pub const SDL_HAPTIC_SPHERICAL: Uint8 = 2;
+/// Plays the effect on the steering wheel axis.
+///
+/// This works better across platforms and devices than picking an axis
+/// yourself, since SDL will guess the correct axis.
+pub const SDL_HAPTIC_STEERING_AXIS: Uint8 = 3;
+
+/// Used to play an effect an infinite number of times (see
+/// [`SDL_HapticRunEffect`]), or as an effect `length` that never ends.
+pub const SDL_HAPTIC_INFINITY: Uint32 = 4294967295;
+
+/// The direction that a haptic force comes from (not the direction in which
+/// the force is exerted).
+///
+/// Cardinal directions are relative to the positioning of the device. North
+/// is considered to be away from the user, and East is to the user's right.
+///
+/// * [`SDL_HAPTIC_POLAR`]: `dir[0]` is in hundredths of a degree, starting
+///   north and turning clockwise. North is 0, East is 9000, South is 18000,
+///   and West is 27000.
+/// * [`SDL_HAPTIC_CARTESIAN`]: `dir` is an X, Y, Z position. North is `(0,
+///   -1, 0)`, East is `(1, 0, 0)`, South is `(0, 1, 0)`, and West is `(-1, 0,
+///   0)`. Z is the height of the effect if supported (otherwise it's unused).
+///   Only the direction matters, so `(1, 2)` is the same as `(2, 4)`.
+/// * [`SDL_HAPTIC_SPHERICAL`]: `dir[0]` is degrees from `(1, 0)` rotated
+///   towards `(0, 1)`, and `dir[1]` is degrees towards `(0, 0, 1)` (the
+///   device needs at least 3 axes). Both are in hundredths of a degree.
+/// * [`SDL_HAPTIC_STEERING_AXIS`]: `dir` is unused.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct SDL_HapticDirection {
+  /// The type of encoding.
+  pub type_: Uint8,
+  /// The encoded direction.
+  pub dir: [Sint32; 3],
+}
+
+/// A template for a Constant effect ([`SDL_HAPTIC_CONSTANT`]).
+///
+/// A constant effect applies a constant force in the specified direction to
+/// the joystick.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct SDL_HapticConstant {
+  /// [`SDL_HAPTIC_CONSTANT`]
+  pub type_: Uint16,
+  /// Direction of the effect.
+  pub direction: SDL_HapticDirection,
+  /// Duration of the effect.
+  pub length: Uint32,
+  /// Delay before starting the effect.
+  pub delay: Uint16,
+  /// Button that triggers the effect.
+  pub button: Uint16,
+  /// How soon it can be triggered again after button.
+  pub interval: Uint16,
+  /// Strength of the constant effect.
+  pub level: Sint16,
+  /// Duration of the attack.
+  pub attack_length: Uint16,
+  /// Level at the start of the attack.
+  pub attack_level: Uint16,
+  /// Duration of the fade.
+  pub fade_length: Uint16,
+  /// Level at the end of the fade.
+  pub fade_level: Uint16,
+}
+
+/// A template for a Periodic effect.
+///
+/// The `type_` is one of [`SDL_HAPTIC_SINE`], [`SDL_HAPTIC_TRIANGLE`],
+/// [`SDL_HAPTIC_SAWTOOTHUP`], or [`SDL_HAPTIC_SAWTOOTHDOWN`].
+///
+/// A periodic effect consists in a wave-shaped effect that repeats itself
+/// over time. The type determines the shape of the wave and the fields
+/// determine the dimensions of the wave.
+///
+/// The `phase` is in hundredths of a degree, so a phase of 9000 displaces the
+/// wave by 25% of its period (and 36000 is the same as 0).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct SDL_HapticPeriodic {
+  /// The kind of wave.
+  pub type_: Uint16,
+  /// Direction of the effect.
+  pub direction: SDL_HapticDirection,
+  /// Duration of the effect.
+  pub length: Uint32,
+  /// Delay before starting the effect.
+  pub delay: Uint16,
+  /// Button that triggers the effect.
+  pub button: Uint16,
+  /// How soon it can be triggered again after button.
+  pub interval: Uint16,
+  /// Period of the wave.
+  pub period: Uint16,
+  /// Peak value. If negative, this is equivalent to 180 degrees of extra
+  /// phase shift.
+  pub magnitude: Sint16,
+  /// Mean value of the wave.
+  pub offset: Sint16,
+  /// Positive phase shift, in hundredths of a degree.
+  pub phase: Uint16,
+  /// Duration of the attack.
+  pub attack_length: Uint16,
+  /// Level at the start of the attack.
+  pub attack_level: Uint16,
+  /// Duration of the fade.
+  pub fade_length: Uint16,
+  /// Level at the end of the fade.
+  pub fade_level: Uint16,
+}
+
+/// A template for a Condition effect.
+///
+/// The `type_` is one of [`SDL_HAPTIC_SPRING`], [`SDL_HAPTIC_DAMPER`],
+/// [`SDL_HAPTIC_INERTIA`], or [`SDL_HAPTIC_FRICTION`].
+///
+/// Direction is handled by the condition fields instead of `direction`. Each
+/// of those has three values, for the X, Y, and Z axis. The "right" values
+/// are for the positive side of the axis and the "left" values are for the
+/// negative side (see [`SDL_HapticDirection`] for which side is which).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct SDL_HapticCondition {
+  /// The kind of condition.
+  pub type_: Uint16,
+  /// Direction of the effect (not used at the moment).
+  pub direction: SDL_HapticDirection,
+  /// Duration of the effect.
+  pub length: Uint32,
+  /// Delay before starting the effect.
+  pub delay: Uint16,
+  /// Button that triggers the effect.
+  pub button: Uint16,
+  /// How soon it can be triggered again after button.
+  pub interval: Uint16,
+  /// Level when joystick is to the positive side, max `0xFFFF`.
+  pub right_sat: [Uint16; 3],
+  /// Level when joystick is to the negative side, max `0xFFFF`.
+  pub left_sat: [Uint16; 3],
+  /// How fast to increase the force towards the positive side.
+  pub right_coeff: [Sint16; 3],
+  /// How fast to increase the force towards the negative side.
+  pub left_coeff: [Sint16; 3],
+  /// Size of the dead zone, max `0xFFFF` (the whole axis range when
+  /// 0-centered).
+  pub deadband: [Uint16; 3],
+  /// Position of the dead zone.
+  pub center: [Sint16; 3],
+}
+
+/// A template for a Ramp effect ([`SDL_HAPTIC_RAMP`]).
+///
+/// The ramp effect starts at `start` strength and goes linearly to `end`
+/// strength. If you use attack and fade with a ramp they're added to the
+/// ramp, making the effect quadratic instead of linear.
+///
+/// A ramp can't have a `length` of [`SDL_HAPTIC_INFINITY`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct SDL_HapticRamp {
+  /// [`SDL_HAPTIC_RAMP`]
+  pub type_: Uint16,
+  /// Direction of the effect.
+  pub direction: SDL_HapticDirection,
+  /// Duration of the effect.
+  pub length: Uint32,
+  /// Delay before starting the effect.
+  pub delay: Uint16,
+  /// Button that triggers the effect.
+  pub button: Uint16,
+  /// How soon it can be triggered again after button.
+  pub interval: Uint16,
+  /// Beginning strength level.
+  pub start: Sint16,
+  /// Ending strength level.
+  pub end: Sint16,
+  /// Duration of the attack.
+  pub attack_length: Uint16,
+  /// Level at the start of the attack.
+  pub attack_level: Uint16,
+  /// Duration of the fade.
+  pub fade_length: Uint16,
+  /// Level at the end of the fade.
+  pub fade_level: Uint16,
+}
+
+/// A template for a Left/Right effect ([`SDL_HAPTIC_LEFTRIGHT`]).
+///
+/// This directly controls the large and small motors commonly found in game
+/// controllers. The small (right) motor is high frequency, and the large
+/// (left) motor is low frequency.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct SDL_HapticLeftRight {
+  /// [`SDL_HAPTIC_LEFTRIGHT`]
+  pub type_: Uint16,
+  /// Duration of the effect in milliseconds.
+  pub length: Uint32,
+  /// Control of the large controller motor.
+  pub large_magnitude: Uint16,
+  /// Control of the small controller motor.
+  pub small_magnitude: Uint16,
+}
+
+/// A template for a Custom effect ([`SDL_HAPTIC_CUSTOM`]).
+///
+/// A custom effect is much like a periodic effect, but the application
+/// defines its exact shape. You have to allocate the `data` yourself, and it
+/// must hold `channels * samples` values.
+///
+/// If `channels` is one, the effect is rotated using the defined direction.
+/// Otherwise it uses the samples in `data` for the different axes.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct SDL_HapticCustom {
+  /// [`SDL_HAPTIC_CUSTOM`]
+  pub type_: Uint16,
+  /// Direction of the effect.
+  pub direction: SDL_HapticDirection,
+  /// Duration of the effect.
+  pub length: Uint32,
+  /// Delay before starting the effect.
+  pub delay: Uint16,
+  /// Button that triggers the effect.
+  pub button: Uint16,
+  /// How soon it can be triggered again after button.
+  pub interval: Uint16,
+  /// Axes to use, minimum of one.
+  pub channels: Uint8,
+  /// Sample periods.
+  pub period: Uint16,
+  /// Amount of samples.
+  pub samples: Uint16,
+  /// Should contain `channels * samples` items.
+  pub data: *mut Uint16,
+  /// Duration of the attack.
+  pub attack_length: Uint16,
+  /// Level at the start of the attack.
+  pub attack_level: Uint16,
+  /// Duration of the fade.
+  pub fade_length: Uint16,
+  /// Level at the end of the fade.
+  pub fade_level: Uint16,
+}
+impl Default for SDL_HapticCustom {
+  #[inline]
+  fn default() -> Self {
+    unsafe { core::mem::zeroed() }
+  }
+}
+
+/// The generic template for any haptic effect.
+///
+/// Every variant starts with the same `type_` field, which says which of the
+/// other fields is the active one.
+///
+/// The `length` can be [`SDL_HAPTIC_INFINITY`] instead of a 0 to 32767
+/// value. The `delay`, `interval`, `attack_length`, and `fade_length` don't
+/// support that, and the fade isn't used since the effect never ends.
+///
+/// Button triggers may not be supported on all devices, so it's better to
+/// not use them. Buttons start at index 1 instead of index 0 like the
+/// joystick.
+///
+/// If both `attack_length` and `fade_level` are 0 the envelope is not used,
+/// otherwise both values are used. Either level may be above the actual
+/// effect level.
+#[derive(Clone, Copy)]
+#[repr(C)]
+#[allow(missing_docs)]
+pub union SDL_HapticEffect {
+  /// Effect type, shared by all the other fields.
+  pub type_: Uint16,
+  pub constant: SDL_HapticConstant,
+  pub periodic: SDL_HapticPeriodic,
+  pub condition: SDL_HapticCondition,
+  pub ramp: SDL_HapticRamp,
+  pub leftright: SDL_HapticLeftRight,
+  pub custom: SDL_HapticCustom,
+}
+impl Default for SDL_HapticEffect {
+  #[inline]
+  fn default() -> Self {
+    unsafe { core::mem::zeroed() }
+  }
+}
+impl core::fmt::Debug for SDL_HapticEffect {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    // The type is always initialized, and says which field to show.
+    let type_ = unsafe { self.type_ };
+    let mut x = f.debug_struct("SDL_HapticEffect");
+    unsafe {
+      match type_ {
+        SDL_HAPTIC_CONSTANT => x.field("constant", &self.constant),
+        SDL_HAPTIC_SINE
+        | SDL_HAPTIC_TRIANGLE
+        | SDL_HAPTIC_SAWTOOTHUP
+        | SDL_HAPTIC_SAWTOOTHDOWN => x.field("periodic", &self.periodic),
+        SDL_HAPTIC_SPRING | SDL_HAPTIC_DAMPER | SDL_HAPTIC_INERTIA
+        | SDL_HAPTIC_FRICTION => x.field("condition", &self.condition),
+        SDL_HAPTIC_RAMP => x.field("ramp", &self.ramp),
+        SDL_HAPTIC_LEFTRIGHT => x.field("leftright", &self.leftright),
+        SDL_HAPTIC_CUSTOM => x.field("custom", &self.custom),
+        _ => x.field("type_", &type_),
+      };
+    }
+    x.finish()
+  }
+}
+
+// The sizes that a C compiler gives these on 64-bit targets.
+#[cfg(target_pointer_width = "64")]
+const _: () = {
+  use core::mem::size_of;
+  assert!(size_of::<SDL_HapticDirection>() == 16);
+  assert!(size_of::<SDL_HapticConstant>() == 40);
+  assert!(size_of::<SDL_HapticPeriodic>() == 48);
+  assert!(size_of::<SDL_HapticCondition>() == 68);
+  assert!(size_of::<SDL_HapticRamp>() == 44);
+  assert!(size_of::<SDL_HapticLeftRight>() == 12);
+  assert!(size_of::<SDL_HapticCustom>() == 56);
+  assert!(size_of::<SDL_HapticEffect>() == 72);
+};
+
+extern "C" {
+  /// Count the number of haptic devices attached to the system.
+  ///
+  /// **Returns:** the number of haptic devices detected on the system or a
+  /// negative error code on failure (call [`SDL_GetErrorMsg`] for more
+  /// information).
+  pub fn SDL_NumHaptics() -> c_int;
+
+  /// Get the implementation dependent name of a haptic device.
+  ///
+  /// This can be called before any joysticks are opened.
+  ///
+  /// * `device_index` index of the device to query.
+  ///
+  /// **Returns:** the name of the device or NULL on failure (call
+  /// [`SDL_GetErrorMsg`] for more information).
+  pub fn SDL_HapticName(device_index: c_int) -> *const c_char;
+
+  /// Open a haptic device for use.
+  ///
+  /// The index passed as an argument refers to the N'th haptic device on this
+  /// system.
+  ///
+  /// When opening a haptic device, its gain will be set to maximum and
+  /// autocenter will be disabled. To modify these values use
+  /// [`SDL_HapticSetGain`] and [`SDL_HapticSetAutocenter`].
+  ///
+  /// * `device_index` index of the device to open
+  ///
+  /// **Returns:** the device identifier or NULL on failure (call
+  /// [`SDL_GetErrorMsg`] for more information).
+  ///
+  /// See Also: [`SDL_HapticClose`], [`SDL_HapticOpenFromJoystick`]
+  pub fn SDL_HapticOpen(device_index: c_int) -> *mut SDL_Haptic;
+
+  /// Check if the haptic device at the designated index has been opened.
+  ///
+  /// **Returns:** 1 if it has been opened, 0 if it hasn't or on failure.
+  pub fn SDL_HapticOpened(device_index: c_int) -> c_int;
+
+  /// Get the index of a haptic device.
+  ///
+  /// **Returns:** the index of the specified haptic device or a negative
+  /// error code on failure (call [`SDL_GetErrorMsg`] for more information).
+  pub fn SDL_HapticIndex(haptic: *mut SDL_Haptic) -> c_int;
+
+  /// Query whether or not the current mouse has haptic capabilities.
+  ///
+  /// **Returns:** [`SDL_TRUE`] if the mouse is haptic or [`SDL_FALSE`] if it
+  /// isn't.
+  pub fn SDL_MouseIsHaptic() -> c_int;
+
+  /// Try to open a haptic device from the current mouse.
+  ///
+  /// **Returns:** the haptic device identifier or NULL on failure (call
+  /// [`SDL_GetErrorMsg`] for more information).
+  pub fn SDL_HapticOpenFromMouse() -> *mut SDL_Haptic;
+
+  /// Query if a joystick has haptic features.
+  ///
+  /// **Returns:** [`SDL_TRUE`] if the joystick is haptic, [`SDL_FALSE`] if it
+  /// isn't, or a negative error code on failure (call [`SDL_GetErrorMsg`] for
+  /// more information).
+  pub fn SDL_JoystickIsHaptic(joystick: *mut SDL_Joystick) -> c_int;
+
+  /// Open a haptic device for use from a joystick device.
+  ///
+  /// You must still close the haptic device separately. It will not be closed
+  /// with the joystick.
+  ///
+  /// When opened from a joystick you should first close the haptic device
+  /// before closing the joystick device. If not, on some implementations the
+  /// haptic device will also get unallocated and you'll be unable to use
+  /// force feedback on that device.
+  ///
+  /// **Returns:** a valid haptic device identifier on success or NULL on
+  /// failure (call [`SDL_GetErrorMsg`] for more information).
+  pub fn SDL_HapticOpenFromJoystick(
+    joystick: *mut SDL_Joystick,
+  ) -> *mut SDL_Haptic;
+
+  /// Close a haptic device previously opened with [`SDL_HapticOpen`].
+  pub fn SDL_HapticClose(haptic: *mut SDL_Haptic);
+
+  /// Get the number of effects a haptic device can store.
+  ///
+  /// On some platforms this isn't fully supported, and therefore is an
+  /// approximation. Always check to see if your created effect was actually
+  /// created and do not rely solely on this.
+  ///
+  /// **Returns:** the number of effects the haptic device can store or a
+  /// negative error code on failure (call [`SDL_GetErrorMsg`] for more
+  /// information).
+  pub fn SDL_HapticNumEffects(haptic: *mut SDL_Haptic) -> c_int;
+
+  /// Get the number of effects a haptic device can play at the same time.
+  ///
+  /// This is not supported on all platforms, but will always return a value.
+  ///
+  /// **Returns:** the number of effects the haptic device can play at the
+  /// same time or a negative error code on failure (call [`SDL_GetErrorMsg`]
+  /// for more information).
+  pub fn SDL_HapticNumEffectsPlaying(haptic: *mut SDL_Haptic) -> c_int;
+
+  /// Get the haptic device's supported features in bitwise manner.
+  ///
+  /// **Returns:** the supported `SDL_HAPTIC_*` effect and feature bits OR'd
+  /// together, or 0 on failure (call [`SDL_GetErrorMsg`] for more
+  /// information).
+  pub fn SDL_HapticQuery(haptic: *mut SDL_Haptic) -> c_uint;
+
+  /// Get the number of haptic axes the device has.
+  ///
+  /// The number of haptic axes might be useful if working with the
+  /// [`SDL_HapticDirection`] of an effect.
+  ///
+  /// **Returns:** the number of axes on success or a negative error code on
+  /// failure (call [`SDL_GetErrorMsg`] for more information).
+  pub fn SDL_HapticNumAxes(haptic: *mut SDL_Haptic) -> c_int;
+
+  /// Check to see if an effect is supported by a haptic device.
+  ///
+  /// **Returns:** [`SDL_TRUE`] if effect is supported, [`SDL_FALSE`] if it
+  /// isn't, or a negative error code on failure (call [`SDL_GetErrorMsg`] for
+  /// more information).
+  pub fn SDL_HapticEffectSupported(
+    haptic: *mut SDL_Haptic, effect: *mut SDL_HapticEffect,
+  ) -> c_int;
+
+  /// Create a new haptic effect on a specified device.
+  ///
+  /// * `haptic` the device to create the effect on
+  /// * `effect` the properties of the effect to create
+  ///
+  /// **Returns:** the ID of the effect on success or a negative error code on
+  /// failure (call [`SDL_GetErrorMsg`] for more information).
+  ///
+  /// See Also: [`SDL_HapticRunEffect`], [`SDL_HapticUpdateEffect`],
+  /// [`SDL_HapticDestroyEffect`]
+  pub fn SDL_HapticNewEffect(
+    haptic: *mut SDL_Haptic, effect: *mut SDL_HapticEffect,
+  ) -> c_int;
+
+  /// Update the properties of an effect.
+  ///
+  /// Can be used dynamically, although behavior when dynamically changing
+  /// direction may be strange. Specifically the effect may re-upload itself
+  /// and start playing from the start. You also cannot change the type.
+  ///
+  /// * `haptic` the device that has the effect
+  /// * `effect` the identifier of the effect to update
+  /// * `data` the new effect properties to use
+  ///
+  /// **Returns:** 0 on success or a negative error code on failure (call
+  /// [`SDL_GetErrorMsg`] for more information).
+  pub fn SDL_HapticUpdateEffect(
+    haptic: *mut SDL_Haptic, effect: c_int, data: *mut SDL_HapticEffect,
+  ) -> c_int;
+
+  /// Run the haptic effect on its associated haptic device.
+  ///
+  /// To repeat the effect over and over indefinitely, set `iterations` to
+  /// [`SDL_HAPTIC_INFINITY`] (this repeats the envelope, the attack and
+  /// fade). To make one instance of the effect last indefinitely (so the
+  /// effect does not fade), set the effect's `length` to
+  /// [`SDL_HAPTIC_INFINITY`] instead.
+  ///
+  /// * `haptic` the device to run the effect on
+  /// * `effect` the ID of the haptic effect to run
+  /// * `iterations` the number of iterations to run the effect
+  ///
+  /// **Returns:** 0 on success or a negative error code on failure (call
+  /// [`SDL_GetErrorMsg`] for more information).
+  pub fn SDL_HapticRunEffect(
+    haptic: *mut SDL_Haptic, effect: c_int, iterations: Uint32,
+  ) -> c_int;
+
+  /// Stop the haptic effect on its associated haptic device.
+  ///
+  /// **Returns:** 0 on success or a negative error code on failure (call
+  /// [`SDL_GetErrorMsg`] for more information).
+  pub fn SDL_HapticStopEffect(haptic: *mut SDL_Haptic, effect: c_int) -> c_int;
+
+  /// Destroy a haptic effect on the device.
+  ///
+  /// This will stop the effect if it's running. Effects are automatically
+  /// destroyed when the device is closed.
+  pub fn SDL_HapticDestroyEffect(haptic: *mut SDL_Haptic, effect: c_int);
+
+  /// Get the status of the current effect on the specified haptic device.
+  ///
+  /// Device must support the [`SDL_HAPTIC_STATUS`] feature.
+  ///
+  /// **Returns:** 0 if it isn't playing, 1 if it is playing, or a negative
+  /// error code on failure (call [`SDL_GetErrorMsg`] for more information).
+  pub fn SDL_HapticGetEffectStatus(
+    haptic: *mut SDL_Haptic, effect: c_int,
+  ) -> c_int;
+
+  /// Set the global gain of the specified haptic device.
+  ///
+  /// Device must support the [`SDL_HAPTIC_GAIN`] feature.
+  ///
+  /// The user may specify the maximum gain by setting the environment
+  /// variable `SDL_HAPTIC_GAIN_MAX`, which should be between 0 and 100. All
+  /// calls to this will scale linearly using `SDL_HAPTIC_GAIN_MAX` as the
+  /// maximum.
+  ///
+  /// * `gain` value to set the gain to, should be between 0 and 100
+  ///
+  /// **Returns:** 0 on success or a negative error code on failure (call
+  /// [`SDL_GetErrorMsg`] for more information).
+  pub fn SDL_HapticSetGain(haptic: *mut SDL_Haptic, gain: c_int) -> c_int;
+
+  /// Set the global autocenter of the device.
+  ///
+  /// Device must support the [`SDL_HAPTIC_AUTOCENTER`] feature.
+  ///
+  /// * `autocenter` value to set autocenter to (0 to 100). Setting it to 0
+  ///   will disable autocentering.
+  ///
+  /// **Returns:** 0 on success or a negative error code on failure (call
+  /// [`SDL_GetErrorMsg`] for more information).
+  pub fn SDL_HapticSetAutocenter(
+    haptic: *mut SDL_Haptic, autocenter: c_int,
+  ) -> c_int;
+
+  /// Pause a haptic device.
+  ///
+  /// Device must support the [`SDL_HAPTIC_PAUSE`] feature. Call
+  /// [`SDL_HapticUnpause`] to resume playback.
+  ///
+  /// Do not modify the effects nor add new ones while the device is paused.
+  /// That can cause all sorts of weird errors.
+  ///
+  /// **Returns:** 0 on success or a negative error code on failure (call
+  /// [`SDL_GetErrorMsg`] for more information).
+  pub fn SDL_HapticPause(haptic: *mut SDL_Haptic) -> c_int;
+
+  /// Unpause a haptic device.
+  ///
+  /// **Returns:** 0 on success or a negative error code on failure (call
+  /// [`SDL_GetErrorMsg`] for more information).
+  pub fn SDL_HapticUnpause(haptic: *mut SDL_Haptic) -> c_int;
+
+  /// Stop all the currently playing effects on a haptic device.
+  ///
+  /// **Returns:** 0 on success or a negative error code on failure (call
+  /// [`SDL_GetErrorMsg`] for more information).
+  pub fn SDL_HapticStopAll(haptic: *mut SDL_Haptic) -> c_int;
+
+  /// Check whether rumble is supported on a haptic device.
+  ///
+  /// **Returns:** [`SDL_TRUE`] if effect is supported, [`SDL_FALSE`] if it
+  /// isn't, or a negative error code on failure (call [`SDL_GetErrorMsg`] for
+  /// more information).
+  pub fn SDL_HapticRumbleSupported(haptic: *mut SDL_Haptic) -> c_int;
+
+  /// Initialize a haptic device for simple rumble playback.
+  ///
+  /// **Returns:** 0 on success or a negative error code on failure (call
+  /// [`SDL_GetErrorMsg`] for more information).
+  ///
+  /// See Also: [`SDL_HapticRumblePlay`], [`SDL_HapticRumbleStop`]
+  pub fn SDL_HapticRumbleInit(haptic: *mut SDL_Haptic) -> c_int;
+
+  /// Run a simple rumble effect on a haptic device.
+  ///
+  /// * `strength` strength of the rumble to play, from 0.0 to 1.0
+  /// * `length` length of the rumble to play in milliseconds
+  ///
+  /// **Returns:** 0 on success or a negative error code on failure (call
+  /// [`SDL_GetErrorMsg`] for more information).
+  pub fn SDL_HapticRumblePlay(
+    haptic: *mut SDL_Haptic, strength: c_float, length: Uint32,
+  ) -> c_int;
+
+  /// Stop the simple rumble on a haptic device.
+  ///
+  /// **Returns:** 0 on success or a negative error code on failure (call
+  /// [`SDL_GetErrorMsg`] for more information).
+  pub fn SDL_HapticRumbleStop(haptic: *mut SDL_Haptic) -> c_int;
+}
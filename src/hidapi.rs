@@ -0,0 +1,294 @@
+//! Talk to HID devices with SDL's copy of the HIDAPI library.
+//!
+//! This is the same HID backend that SDL's own joystick drivers use, so you
+//! can talk to devices that aren't joysticks (eg: custom controllers and I/O
+//! boards) without also loading a separate HIDAPI library.
+//!
+//! The functions here use the same names and conventions as the
+//! [HIDAPI](https://github.com/libusb/hidapi) library, with an `SDL_` prefix.
+
+use crate::{c_char, c_int, c_uchar, c_ushort, c_void, stdinc::*};
+
+/// The C `wchar_t` type, used for the HID device strings.
+///
+/// This is 16-bit UTF-16 on Windows, and 32-bit UTF-32 elsewhere.
+#[cfg(target_os = "windows")]
+pub type wchar_t = u16;
+/// The C `wchar_t` type, used for the HID device strings.
+///
+/// This is 16-bit UTF-16 on Windows, and 32-bit UTF-32 elsewhere.
+#[cfg(not(target_os = "windows"))]
+pub type wchar_t = i32;
+
+/// An opened HID device (opaque hidapi structure).
+#[derive(Debug)]
+#[repr(transparent)]
+pub struct SDL_hid_device(c_void);
+
+/// Information about a connected HID device.
+///
+/// These form a linked list, see [`SDL_hid_enumerate`].
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct SDL_hid_device_info {
+  /// Platform-specific device path
+  pub path: *mut c_char,
+  /// Device Vendor ID
+  pub vendor_id: c_ushort,
+  /// Device Product ID
+  pub product_id: c_ushort,
+  /// Serial Number
+  pub serial_number: *mut wchar_t,
+  /// Device Release Number in binary-coded decimal, also known as Device
+  /// Version Number
+  pub release_number: c_ushort,
+  /// Manufacturer String
+  pub manufacturer_string: *mut wchar_t,
+  /// Product string
+  pub product_string: *mut wchar_t,
+  /// Usage Page for this Device/Interface (Windows/Mac only).
+  pub usage_page: c_ushort,
+  /// Usage for this Device/Interface (Windows/Mac only).
+  pub usage: c_ushort,
+  /// The USB interface which this logical device represents.
+  ///
+  /// * Valid on both Linux implementations in all cases.
+  /// * Valid on the Windows implementation only if the device contains more
+  ///   than one interface.
+  pub interface_number: c_int,
+  /// Additional information about the USB interface.
+  ///
+  /// Valid on libusb and Android implementations.
+  pub interface_class: c_int,
+  /// See `interface_class`
+  pub interface_subclass: c_int,
+  /// See `interface_class`
+  pub interface_protocol: c_int,
+  /// Pointer to the next device
+  pub next: *mut SDL_hid_device_info,
+}
+
+extern "C" {
+  /// Initialize the HIDAPI library.
+  ///
+  /// This function initializes the HIDAPI library. Calling it is not strictly
+  /// necessary, as it will be called automatically by [`SDL_hid_enumerate`]
+  /// and any of the `SDL_hid_open_*` functions if it is needed. This function
+  /// should be called at the beginning of execution however, if there is a
+  /// chance of HIDAPI handles being opened by different threads
+  /// simultaneously.
+  ///
+  /// Each call to this function should have a matching call to
+  /// [`SDL_hid_exit`]
+  ///
+  /// **Returns:** 0 on success and -1 on error.
+  pub fn SDL_hid_init() -> c_int;
+
+  /// Finalize the HIDAPI library.
+  ///
+  /// This function frees all of the static data associated with HIDAPI. It
+  /// should be called at the end of execution to avoid memory leaks.
+  ///
+  /// **Returns:** 0 on success and -1 on error.
+  pub fn SDL_hid_exit() -> c_int;
+
+  /// Check to see if devices may have been added or removed.
+  ///
+  /// Enumerating the HID devices is an expensive operation, so you can call
+  /// this to see if there have been any system device changes since the last
+  /// call to this function. A change in the counter returned doesn't
+  /// necessarily mean that anything has changed, but you can call
+  /// [`SDL_hid_enumerate`] to get an updated device list.
+  ///
+  /// Calling this function for the first time may cause a thread or other
+  /// system resource to be allocated to track device change notifications.
+  ///
+  /// **Returns:** a change counter that is incremented with each potential
+  /// device change, or 0 if device change detection isn't available.
+  pub fn SDL_hid_device_change_count() -> Uint32;
+
+  /// Enumerate the HID Devices.
+  ///
+  /// This function returns a linked list of all the HID devices attached to
+  /// the system which match `vendor_id` and `product_id`. If `vendor_id` is
+  /// set to 0 then any vendor matches. If `product_id` is set to 0 then any
+  /// product matches. If both are 0, then all HID devices will be returned.
+  ///
+  /// * `vendor_id` The Vendor ID (VID) of the types of device to open.
+  /// * `product_id` The Product ID (PID) of the types of device to open.
+  ///
+  /// **Returns:** a pointer to a linked list of [`SDL_hid_device_info`], or
+  /// NULL in the case of failure. Free this linked list by calling
+  /// [`SDL_hid_free_enumeration`].
+  pub fn SDL_hid_enumerate(
+    vendor_id: c_ushort, product_id: c_ushort,
+  ) -> *mut SDL_hid_device_info;
+
+  /// Free an enumeration linked list created by [`SDL_hid_enumerate`].
+  pub fn SDL_hid_free_enumeration(devs: *mut SDL_hid_device_info);
+
+  /// Open a HID device using a Vendor ID (VID), Product ID (PID) and
+  /// optionally a serial number.
+  ///
+  /// If `serial_number` is NULL, the first device with the specified VID and
+  /// PID is opened.
+  ///
+  /// **Returns:** the device on success or NULL on failure.
+  pub fn SDL_hid_open(
+    vendor_id: c_ushort, product_id: c_ushort, serial_number: *const wchar_t,
+  ) -> *mut SDL_hid_device;
+
+  /// Open a HID device by its path name.
+  ///
+  /// The path name be determined by calling [`SDL_hid_enumerate`], or a
+  /// platform-specific path name can be used (eg: `/dev/hidraw0` on Linux).
+  ///
+  /// * `bExclusive` should be 0 (it's not used at the moment).
+  ///
+  /// **Returns:** the device on success or NULL on failure.
+  pub fn SDL_hid_open_path(
+    path: *const c_char, bExclusive: c_int,
+  ) -> *mut SDL_hid_device;
+
+  /// Write an Output report to a HID device.
+  ///
+  /// The first byte of `data` must contain the Report ID. For devices which
+  /// only support a single report, this must be set to 0x0. The remaining
+  /// bytes contain the report data. Since the Report ID is mandatory, calls
+  /// to this will always contain one more byte than the report contains (eg:
+  /// a 16 byte report is written with a `length` of 17).
+  ///
+  /// This will send the data on the first OUT endpoint, if one exists. If it
+  /// does not, it will send the data through the Control Endpoint (Endpoint
+  /// 0).
+  ///
+  /// **Returns:** the actual number of bytes written and -1 on error.
+  pub fn SDL_hid_write(
+    dev: *mut SDL_hid_device, data: *const c_uchar, length: usize,
+  ) -> c_int;
+
+  /// Read an Input report from a HID device with timeout.
+  ///
+  /// Input reports are returned to the host through the INTERRUPT IN
+  /// endpoint. The first byte will contain the Report number if the device
+  /// uses numbered reports.
+  ///
+  /// * `length` The number of bytes to read. For devices with multiple
+  ///   reports, make sure to read an extra byte for the report number.
+  /// * `milliseconds` timeout in milliseconds or -1 for blocking wait.
+  ///
+  /// **Returns:** the actual number of bytes read and -1 on error. If no
+  /// packet was available to be read within the timeout period, this function
+  /// returns 0.
+  pub fn SDL_hid_read_timeout(
+    dev: *mut SDL_hid_device, data: *mut c_uchar, length: usize,
+    milliseconds: c_int,
+  ) -> c_int;
+
+  /// Read an Input report from a HID device.
+  ///
+  /// Input reports are returned to the host through the INTERRUPT IN
+  /// endpoint. The first byte will contain the Report number if the device
+  /// uses numbered reports.
+  ///
+  /// * `length` The number of bytes to read. For devices with multiple
+  ///   reports, make sure to read an extra byte for the report number.
+  ///
+  /// **Returns:** the actual number of bytes read and -1 on error. If no
+  /// packet was available to be read and the handle is in non-blocking mode,
+  /// this function returns 0.
+  pub fn SDL_hid_read(
+    dev: *mut SDL_hid_device, data: *mut c_uchar, length: usize,
+  ) -> c_int;
+
+  /// Set the device handle to be non-blocking.
+  ///
+  /// In non-blocking mode calls to [`SDL_hid_read`] will return immediately
+  /// with a value of 0 if there is no data to be read. In blocking mode,
+  /// [`SDL_hid_read`] will wait (block) until there is data to read before
+  /// returning.
+  ///
+  /// Nonblocking can be turned on and off at any time.
+  ///
+  /// * `nonblock` 1 to enable nonblocking, 0 to disable nonblocking.
+  ///
+  /// **Returns:** 0 on success and -1 on error.
+  pub fn SDL_hid_set_nonblocking(
+    dev: *mut SDL_hid_device, nonblock: c_int,
+  ) -> c_int;
+
+  /// Send a Feature report to the device.
+  ///
+  /// Feature reports are sent over the Control endpoint as a Set_Report
+  /// transfer. As with [`SDL_hid_write`], the first byte of `data` must
+  /// contain the Report ID (or 0x0, for devices which do not use numbered
+  /// reports), and `length` includes that byte.
+  ///
+  /// **Returns:** the actual number of bytes written and -1 on error.
+  pub fn SDL_hid_send_feature_report(
+    dev: *mut SDL_hid_device, data: *const c_uchar, length: usize,
+  ) -> c_int;
+
+  /// Get a feature report from a HID device.
+  ///
+  /// Set the first byte of `data` to the Report ID of the report to be read
+  /// (or zero if your device does not use numbered reports). Make sure to
+  /// allow space for this extra byte in `data`. Upon return, the first byte
+  /// will still contain the Report ID, and the report data will start in
+  /// `data[1]`.
+  ///
+  /// * `length` The number of bytes to read, including an extra byte for the
+  ///   report ID. The buffer can be longer than the actual report.
+  ///
+  /// **Returns:** the number of bytes read plus one for the report ID (which
+  /// is still in the first byte), or -1 on error.
+  pub fn SDL_hid_get_feature_report(
+    dev: *mut SDL_hid_device, data: *mut c_uchar, length: usize,
+  ) -> c_int;
+
+  /// Close a HID device.
+  pub fn SDL_hid_close(dev: *mut SDL_hid_device);
+
+  /// Get The Manufacturer String from a HID device.
+  ///
+  /// * `maxlen` The length of the buffer in multiples of [`wchar_t`].
+  ///
+  /// **Returns:** 0 on success and -1 on error.
+  pub fn SDL_hid_get_manufacturer_string(
+    dev: *mut SDL_hid_device, string: *mut wchar_t, maxlen: usize,
+  ) -> c_int;
+
+  /// Get The Product String from a HID device.
+  ///
+  /// * `maxlen` The length of the buffer in multiples of [`wchar_t`].
+  ///
+  /// **Returns:** 0 on success and -1 on error.
+  pub fn SDL_hid_get_product_string(
+    dev: *mut SDL_hid_device, string: *mut wchar_t, maxlen: usize,
+  ) -> c_int;
+
+  /// Get The Serial Number String from a HID device.
+  ///
+  /// * `maxlen` The length of the buffer in multiples of [`wchar_t`].
+  ///
+  /// **Returns:** 0 on success and -1 on error.
+  pub fn SDL_hid_get_serial_number_string(
+    dev: *mut SDL_hid_device, string: *mut wchar_t, maxlen: usize,
+  ) -> c_int;
+
+  /// Get a string from a HID device, based on its string index.
+  ///
+  /// * `maxlen` The length of the buffer in multiples of [`wchar_t`].
+  ///
+  /// **Returns:** 0 on success and -1 on error.
+  pub fn SDL_hid_get_indexed_string(
+    dev: *mut SDL_hid_device, string_index: c_int, string: *mut wchar_t,
+    maxlen: usize,
+  ) -> c_int;
+
+  /// Start or stop a BLE scan on iOS and tvOS to pair Steam Controllers.
+  ///
+  /// * `active` [`SDL_TRUE`] to start the scan, [`SDL_FALSE`] to stop the
+  ///   scan
+  pub fn SDL_hid_ble_scan(active: SDL_bool);
+}
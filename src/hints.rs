@@ -10,17 +10,28 @@
 //! supported or applicable on any given platform, but they provide
 //! a way for an application or user to give the library a hint as
 //! to how they would like the library to work.
+//!
+//! The [`Hint`] enum has every hint, with methods that set and get them
+//! without any null terminator or pointer handling.
 
 use crate::{c_char, c_void, stdinc::*};
 
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, string::String, vec::Vec};
+
 // makes rustdoc link properly!
 #[allow(unused)]
 use crate::events::*;
 #[allow(unused)]
+#[cfg(feature = "gamecontroller")]
 use crate::gamecontroller::*;
 #[allow(unused)]
+#[cfg(feature = "sdl2_2_0_18")]
+use crate::hidapi::*;
+#[allow(unused)]
 use crate::platform::*;
 #[allow(unused)]
+#[cfg(feature = "renderer")]
 use crate::renderer::*;
 #[allow(unused)]
 use crate::rwops::*;
@@ -80,7 +91,7 @@ pub const SDL_HINT_RENDER_DRIVER: &[u8] = c_str!("SDL_RENDER_DRIVER");
 ///
 /// By default shaders are used if OpenGL supports them.
 pub const SDL_HINT_RENDER_OPENGL_SHADERS: &[u8] =
-  c_str!("SDL_HINT_RENDER_OPENGL_SHADERS");
+  c_str!("SDL_RENDER_OPENGL_SHADERS");
 
 /// A variable controlling whether the Direct3D device is initialized for
 /// thread-safe operations.
@@ -640,6 +651,10 @@ pub const SDL_HINT_JOYSTICK_HIDAPI_XBOX: &[u8] =
 ///
 /// The default is "1".  This hint applies to any joysticks opened after setting
 /// the hint.
+///
+/// This is a legacy name, it's not in the SDL 2.26 headers (see
+/// [`SDL_HINT_JOYSTICK_RAWINPUT_CORRELATE_XINPUT`] instead), so it's not one
+/// of the [`Hint`] variants.
 pub const SDL_HINT_JOYSTICK_HIDAPI_CORRELATE_XINPUT: &[u8] =
   c_str!("SDL_JOYSTICK_HIDAPI_CORRELATE_XINPUT");
 
@@ -691,6 +706,44 @@ pub const SDL_HINT_JOYSTICK_THREAD: &[u8] = c_str!("SDL_JOYSTICK_THREAD");
 pub const SDL_HINT_LINUX_JOYSTICK_DEADZONES: &[u8] =
   c_str!("SDL_LINUX_JOYSTICK_DEADZONES");
 
+/// A variable controlling whether joysticks on Linux will always treat 'hat'
+/// axis inputs (`ABS_HAT0X` - `ABS_HAT3Y`) as 8-way digital hats without
+/// checking whether they may be analog.
+///
+/// This variable can be set to the following values:
+/// * "0": Only map hat axis inputs to digital hat outputs if the input axes
+///   appear to actually be digital (the default)
+/// * "1": Always handle the input axes numbered `ABS_HAT0X` to `ABS_HAT3Y` as
+///   digital hats
+///
+/// This hint is available since SDL 2.24.0.
+pub const SDL_HINT_LINUX_DIGITAL_HATS: &[u8] = c_str!("SDL_LINUX_DIGITAL_HATS");
+
+/// A variable controlling whether digital hats on Linux will apply deadzones to
+/// their underlying input axes or use unfiltered values.
+///
+/// This variable can be set to the following values:
+/// * "0": Return digital hat values based on unfiltered input axis values
+/// * "1": Return digital hat values with deadzones on the input axes taken into
+///   account (the default)
+///
+/// This hint is available since SDL 2.24.0.
+pub const SDL_HINT_LINUX_HAT_DEADZONES: &[u8] =
+  c_str!("SDL_LINUX_HAT_DEADZONES");
+
+/// A variable controlling whether to use the classic `/dev/input/js*` joystick
+/// interface or the newer `/dev/input/event*` joystick interface on Linux.
+///
+/// This variable can be set to the following values:
+/// * "0": Use `/dev/input/event*`
+/// * "1": Use `/dev/input/js*`
+///
+/// By default the `/dev/input/event*` interfaces are used.
+///
+/// This hint is available since SDL 2.0.18.
+pub const SDL_HINT_LINUX_JOYSTICK_CLASSIC: &[u8] =
+  c_str!("SDL_LINUX_JOYSTICK_CLASSIC");
+
 /// If set to "0" then never set the top most bit on a SDL Window, even if the
 /// video mode expects it.   This is a debugging aid for developers and not
 /// expected to be used by end users. The default is "1"
@@ -1465,10 +1518,930 @@ pub const SDL_HINT_AUDIO_INCLUDE_MONITORS: &[u8] =
 pub const SDL_HINT_AUDIO_DEVICE_STREAM_ROLE: &[u8] =
   c_str!("SDL_AUDIO_DEVICE_STREAM_ROLE");
 
+/// A variable that decides what video backend to use.
+///
+/// By default, SDL will try all available video backends in a reasonable
+/// order until it finds one that can work, but this hint allows the app or
+/// user to force a specific target, such as "x11" if, say, you are on Wayland
+/// but want to try talking to the X server instead.
+///
+/// This can be a comma-separated list, and the first one that works is used.
+///
+/// This hint is available since SDL 2.0.22. Before that, the same value could
+/// only be given with the `SDL_VIDEODRIVER` environment variable.
+pub const SDL_HINT_VIDEODRIVER: &[u8] = c_str!("SDL_VIDEODRIVER");
+
+/// A variable that decides what audio backend to use.
+///
+/// By default, SDL will try all available audio backends in a reasonable
+/// order until it finds one that can work, but this hint allows the app or
+/// user to force a specific target, such as "alsa" if, say, you are on
+/// PulseAudio but want to try talking to the lower level instead.
+///
+/// This can be a comma-separated list, and the first one that works is used.
+///
+/// This hint is available since SDL 2.0.22. Before that, the same value could
+/// only be given with the `SDL_AUDIODRIVER` environment variable.
+pub const SDL_HINT_AUDIODRIVER: &[u8] = c_str!("SDL_AUDIODRIVER");
+
+/// A variable controlling whether the libdecor Wayland backend is allowed to be
+/// used.
+///
+/// This variable can be set to the following values:
+/// * "0": libdecor use is disabled.
+/// * "1": libdecor use is enabled (default).
+///
+/// libdecor is used over xdg-shell when xdg-decoration protocol is unavailable.
+///
+/// This hint is available since SDL 2.0.16.
+pub const SDL_HINT_VIDEO_WAYLAND_ALLOW_LIBDECOR: &[u8] =
+  c_str!("SDL_VIDEO_WAYLAND_ALLOW_LIBDECOR");
+
+/// A variable controlling whether the libdecor Wayland backend is preferred
+/// over native decorations.
+///
+/// When this hint is set, libdecor will be used to provide window decorations,
+/// even if xdg-decoration is available. (Note that, by default, libdecor will
+/// use xdg-decoration itself if available).
+///
+/// This variable can be set to the following values:
+/// * "0": libdecor is enabled only if server-side decorations are unavailable.
+/// * "1": libdecor is always enabled if available.
+///
+/// libdecor is used over xdg-shell when xdg-decoration protocol is unavailable.
+///
+/// This hint is available since SDL 2.0.22.
+pub const SDL_HINT_VIDEO_WAYLAND_PREFER_LIBDECOR: &[u8] =
+  c_str!("SDL_VIDEO_WAYLAND_PREFER_LIBDECOR");
+
+/// A variable controlling whether video mode emulation is enabled under
+/// Wayland.
+///
+/// When this hint is set, a standard set of emulated CVT video modes will be
+/// exposed for use by the application. If it is disabled, the only modes
+/// exposed will be the logical desktop size and, in the case of a scaled
+/// desktop, the native display resolution.
+///
+/// This variable can be set to the following values:
+/// * "0": Video mode emulation is disabled.
+/// * "1": Video mode emulation is enabled.
+///
+/// By default video mode emulation is enabled.
+///
+/// This hint is available since SDL 2.24.0.
+pub const SDL_HINT_VIDEO_WAYLAND_MODE_EMULATION: &[u8] =
+  c_str!("SDL_VIDEO_WAYLAND_MODE_EMULATION");
+
+/// Enable or disable mouse pointer warp emulation, needed by some older games.
+///
+/// When this hint is set, SDL will emulate mouse warps using relative mouse
+/// mode. This is required for some older games (such as Source engine games),
+/// which warp the mouse to the centre of the screen rather than using relative
+/// mouse motion. Note that relative mouse mode may have different mouse
+/// acceleration behaviour than pointer warps.
+///
+/// This variable can be set to the following values:
+/// * "0": All mouse warps fail, as mouse warping is not available under
+///   wayland.
+/// * "1": Some mouse warps will be emulated by forcing relative mouse mode.
+///
+/// If not set, this is automatically enabled unless an application uses
+/// relative mouse mode directly.
+///
+/// This hint is available since SDL 2.26.0.
+pub const SDL_HINT_VIDEO_WAYLAND_EMULATE_MOUSE_WARP: &[u8] =
+  c_str!("SDL_VIDEO_WAYLAND_EMULATE_MOUSE_WARP");
+
+/// Controls whether SDL will declare the process to be DPI aware.
+///
+/// This hint must be set before initializing the video subsystem.
+///
+/// The main purpose of declaring DPI awareness is to disable OS bitmap scaling
+/// of SDL windows on monitors with a DPI scale factor.
+///
+/// This hint is equivalent to requesting DPI awareness via external means (eg:
+/// calling `SetProcessDpiAwarenessContext`) and does not cause SDL to use a
+/// virtualized coordinate system, so it will generally give you 1 SDL
+/// coordinate = 1 pixel even on high-DPI displays.
+///
+/// For more information, see
+/// <https://docs.microsoft.com/en-us/windows/win32/hidpi/high-dpi-desktop-application-development-on-windows>
+///
+/// This variable can be set to the following values:
+/// * "": Do not change the DPI awareness (default).
+/// * "unaware": Declare the process as DPI unaware. (Windows 8.1 and later).
+/// * "system": Request system DPI awareness. (Vista and later).
+/// * "permonitor": Request per-monitor DPI awareness. (Windows 8.1 and later).
+/// * "permonitorv2": Request per-monitor V2 DPI awareness. (Windows 10,
+///   version 1607 and later). The most visible difference from "permonitor"
+///   is that window title bar will be scaled to the visually correct size
+///   when dragging between monitors with different scale factors. This is the
+///   preferred DPI awareness level.
+///
+/// If the requested DPI awareness is not available on the currently running
+/// OS, SDL will try to request the best available match.
+///
+/// This hint is available since SDL 2.24.0.
+pub const SDL_HINT_WINDOWS_DPI_AWARENESS: &[u8] =
+  c_str!("SDL_WINDOWS_DPI_AWARENESS");
+
+/// Uses DPI-scaled points as the SDL coordinate system on Windows.
+///
+/// This changes the SDL coordinate system units to be DPI-scaled points,
+/// rather than pixels everywhere. This means windows will be appropriately
+/// sized, even when created on high-DPI displays with scaling.
+///
+/// eg: requesting a 640x480 window from SDL, on a display with 125% scaling in
+/// Windows display settings, will create a window with an 800x600 client area
+/// (in pixels).
+///
+/// Setting this to "1" implicitly requests process DPI awareness (setting
+/// [`SDL_HINT_WINDOWS_DPI_AWARENESS`] is unnecessary), and forces
+/// [`SDL_WINDOW_ALLOW_HIGHDPI`] on all windows. Use
+/// [`SDL_GetWindowSizeInPixels`] to get the size of a window's client area in
+/// pixels.
+///
+/// This variable can be set to the following values:
+/// * "0": SDL coordinates equal Windows coordinates. No automatic window
+///   resizing when dragging between monitors with different scale factors
+///   (unless this is performed by Windows itself, which is the case when the
+///   process is DPI unaware).
+/// * "1": SDL coordinates are in DPI-scaled points. Automatically resize
+///   windows as needed on displays with non-100% scale factors.
+///
+/// This hint is available since SDL 2.24.0.
+pub const SDL_HINT_WINDOWS_DPI_SCALING: &[u8] =
+  c_str!("SDL_WINDOWS_DPI_SCALING");
+
+/// A variable controlling whether the HIDAPI driver for Nintendo Switch
+/// Joy-Cons should be used.
+///
+/// This variable can be set to the following values:
+/// * "0": HIDAPI driver is not used
+/// * "1": HIDAPI driver is used
+///
+/// The default is the value of SDL_HINT_JOYSTICK_HIDAPI
+///
+/// This hint is available since SDL 2.0.16.
+pub const SDL_HINT_JOYSTICK_HIDAPI_JOY_CONS: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_JOY_CONS");
+
+/// A variable controlling whether Nintendo Switch Joy-Con controllers will be
+/// combined into a single Pro-like controller when using the HIDAPI driver
+///
+/// This variable can be set to the following values:
+/// * "0": Left and right Joy-Con controllers will not be combined and each will
+///   be a mini-gamepad
+/// * "1": Left and right Joy-Con controllers will be combined into a single
+///   controller (the default)
+///
+/// This hint is available since SDL 2.24.0.
+pub const SDL_HINT_JOYSTICK_HIDAPI_COMBINE_JOY_CONS: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_COMBINE_JOY_CONS");
+
+/// A variable controlling whether Nintendo Switch Joy-Con controllers will be
+/// in vertical mode when using the HIDAPI driver
+///
+/// This variable can be set to the following values:
+/// * "0": Left and right Joy-Con controllers will not be in vertical mode (the
+///   default)
+/// * "1": Left and right Joy-Con controllers will be in vertical mode
+///
+/// This hint must be set before calling [`SDL_Init`] with
+/// `SDL_INIT_GAMECONTROLLER`
+///
+/// This hint is available since SDL 2.26.0.
+pub const SDL_HINT_JOYSTICK_HIDAPI_VERTICAL_JOY_CONS: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_VERTICAL_JOY_CONS");
+
+/// A variable controlling whether the Home button LED should be turned on when
+/// a Nintendo Switch Joy-Con controller is opened
+///
+/// This variable can be set to the following values:
+/// * "0": home button LED is turned off
+/// * "1": home button LED is turned on
+///
+/// By default the Home button LED state is not changed. This hint can also be
+/// set to a floating point value between 0.0 and 1.0 which controls the
+/// brightness of the Home button LED.
+///
+/// This hint is available since SDL 2.24.0.
+pub const SDL_HINT_JOYSTICK_HIDAPI_JOYCON_HOME_LED: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_JOYCON_HOME_LED");
+
+/// A variable controlling whether the HIDAPI driver for Amazon Luna controllers
+/// connected via Bluetooth should be used.
+///
+/// This variable can be set to the following values:
+/// * "0": HIDAPI driver is not used
+/// * "1": HIDAPI driver is used
+///
+/// The default is the value of SDL_HINT_JOYSTICK_HIDAPI
+///
+/// This hint is available since SDL 2.0.16.
+pub const SDL_HINT_JOYSTICK_HIDAPI_LUNA: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_LUNA");
+
+/// A variable controlling whether the HIDAPI driver for Nintendo Online classic
+/// controllers should be used.
+///
+/// This variable can be set to the following values:
+/// * "0": HIDAPI driver is not used
+/// * "1": HIDAPI driver is used
+///
+/// The default is the value of SDL_HINT_JOYSTICK_HIDAPI
+///
+/// This hint is available since SDL 2.24.0.
+pub const SDL_HINT_JOYSTICK_HIDAPI_NINTENDO_CLASSIC: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_NINTENDO_CLASSIC");
+
+/// A variable controlling whether the HIDAPI driver for NVIDIA SHIELD
+/// controllers should be used.
+///
+/// This variable can be set to the following values:
+/// * "0": HIDAPI driver is not used
+/// * "1": HIDAPI driver is used
+///
+/// The default is the value of SDL_HINT_JOYSTICK_HIDAPI
+///
+/// This hint is available since SDL 2.24.0.
+pub const SDL_HINT_JOYSTICK_HIDAPI_SHIELD: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_SHIELD");
+
+/// A variable controlling whether the HIDAPI driver for PS3 controllers should
+/// be used.
+///
+/// This variable can be set to the following values:
+/// * "0": HIDAPI driver is not used
+/// * "1": HIDAPI driver is used
+///
+/// The default is the value of SDL_HINT_JOYSTICK_HIDAPI on macOS, and "0" on
+/// other platforms.
+///
+/// It is not possible to use this driver on Windows, due to limitations in the
+/// default drivers installed. See <https://github.com/ViGEm/DsHidMini> for an
+/// alternative driver on Windows.
+///
+/// This hint is available since SDL 2.26.0.
+pub const SDL_HINT_JOYSTICK_HIDAPI_PS3: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_PS3");
+
+/// A variable controlling whether the HIDAPI driver for Google Stadia
+/// controllers should be used.
+///
+/// This variable can be set to the following values:
+/// * "0": HIDAPI driver is not used
+/// * "1": HIDAPI driver is used
+///
+/// The default is the value of SDL_HINT_JOYSTICK_HIDAPI
+///
+/// This hint is available since SDL 2.0.16.
+pub const SDL_HINT_JOYSTICK_HIDAPI_STADIA: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_STADIA");
+
+/// A variable controlling whether the Home button LED should be turned on when
+/// a Nintendo Switch Pro controller is opened
+///
+/// This variable can be set to the following values:
+/// * "0": home button LED is turned off
+/// * "1": home button LED is turned on
+///
+/// By default the Home button LED state is not changed. This hint can also be
+/// set to a floating point value between 0.0 and 1.0 which controls the
+/// brightness of the Home button LED.
+///
+/// This hint is available since SDL 2.0.16.
+pub const SDL_HINT_JOYSTICK_HIDAPI_SWITCH_HOME_LED: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_SWITCH_HOME_LED");
+
+/// A variable controlling whether the player LEDs should be lit to indicate
+/// which player is associated with a Nintendo Switch controller.
+///
+/// This variable can be set to the following values:
+/// * "0": player LEDs are not enabled
+/// * "1": player LEDs are enabled (the default)
+///
+/// This hint is available since SDL 2.24.0.
+pub const SDL_HINT_JOYSTICK_HIDAPI_SWITCH_PLAYER_LED: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_SWITCH_PLAYER_LED");
+
+/// A variable controlling whether the HIDAPI driver for Nintendo Wii and Wii U
+/// controllers should be used.
+///
+/// This variable can be set to the following values:
+/// * "0": HIDAPI driver is not used
+/// * "1": HIDAPI driver is used
+///
+/// This driver doesn't work with the dolphinbar, so the default is "0" for now.
+///
+/// This hint is available since SDL 2.26.0.
+pub const SDL_HINT_JOYSTICK_HIDAPI_WII: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_WII");
+
+/// A variable controlling whether the player LEDs should be lit to indicate
+/// which player is associated with a Wii controller.
+///
+/// This variable can be set to the following values:
+/// * "0": player LEDs are not enabled
+/// * "1": player LEDs are enabled (the default)
+///
+/// This hint is available since SDL 2.26.0.
+pub const SDL_HINT_JOYSTICK_HIDAPI_WII_PLAYER_LED: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_WII_PLAYER_LED");
+
+/// A variable controlling whether the HIDAPI driver for XBox 360 controllers
+/// should be used.
+///
+/// This variable can be set to the following values:
+/// * "0": HIDAPI driver is not used
+/// * "1": HIDAPI driver is used
+///
+/// The default is the value of SDL_HINT_JOYSTICK_HIDAPI_XBOX
+///
+/// This hint is available since SDL 2.26.0.
+pub const SDL_HINT_JOYSTICK_HIDAPI_XBOX_360: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_XBOX_360");
+
+/// A variable controlling whether the player LEDs should be lit to indicate
+/// which player is associated with an Xbox 360 controller.
+///
+/// This variable can be set to the following values:
+/// * "0": player LEDs are not enabled
+/// * "1": player LEDs are enabled (the default)
+///
+/// This hint is available since SDL 2.26.0.
+pub const SDL_HINT_JOYSTICK_HIDAPI_XBOX_360_PLAYER_LED: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_XBOX_360_PLAYER_LED");
+
+/// A variable controlling whether the HIDAPI driver for XBox 360 wireless
+/// controllers should be used.
+///
+/// This variable can be set to the following values:
+/// * "0": HIDAPI driver is not used
+/// * "1": HIDAPI driver is used
+///
+/// The default is the value of SDL_HINT_JOYSTICK_HIDAPI_XBOX_360
+///
+/// This hint is available since SDL 2.26.0.
+pub const SDL_HINT_JOYSTICK_HIDAPI_XBOX_360_WIRELESS: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_XBOX_360_WIRELESS");
+
+/// A variable controlling whether the HIDAPI driver for XBox One controllers
+/// should be used.
+///
+/// This variable can be set to the following values:
+/// * "0": HIDAPI driver is not used
+/// * "1": HIDAPI driver is used
+///
+/// The default is the value of SDL_HINT_JOYSTICK_HIDAPI_XBOX
+///
+/// This hint is available since SDL 2.26.0.
+pub const SDL_HINT_JOYSTICK_HIDAPI_XBOX_ONE: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_XBOX_ONE");
+
+/// A variable controlling whether the Home button LED should be turned on when
+/// an Xbox One controller is opened
+///
+/// This variable can be set to the following values:
+/// * "0": home button LED is turned off
+/// * "1": home button LED is turned on
+///
+/// By default the Home button LED state is not changed. This hint can also be
+/// set to a floating point value between 0.0 and 1.0 which controls the
+/// brightness of the Home button LED. The default brightness is 0.4.
+///
+/// This hint is available since SDL 2.26.0.
+pub const SDL_HINT_JOYSTICK_HIDAPI_XBOX_ONE_HOME_LED: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_XBOX_ONE_HOME_LED");
+
+/// A variable to control whether certain IMEs should show native UI components
+/// (such as the Candidate List) instead of suppressing them.
+///
+/// The variable can be set to the following values:
+/// * "0": Native UI components are not displayed. (default)
+/// * "1": Native UI components are displayed.
+///
+/// This hint is available since SDL 2.0.18.
+pub const SDL_HINT_IME_SHOW_UI: &[u8] = c_str!("SDL_IME_SHOW_UI");
+
+/// A variable to control if extended IME text support is enabled. If enabled
+/// then [`SDL_TextEditingExtEvent`] will be issued if the text would be
+/// truncated otherwise. Additionally [`SDL_TextInputEvent`] will be dispatched
+/// multiple times so that it is not truncated.
+///
+/// The variable can be set to the following values:
+/// * "0": Legacy behavior. Text can be truncated, no heap allocations.
+///   (default)
+/// * "1": Modern behavior.
+///
+/// This hint is available since SDL 2.0.22.
+pub const SDL_HINT_IME_SUPPORT_EXTENDED_TEXT: &[u8] =
+  c_str!("SDL_IME_SUPPORT_EXTENDED_TEXT");
+
+/// Specify an application name.
+///
+/// This hint lets you specify the application name sent to the OS when
+/// required. For example, this will often appear in volume control applets for
+/// audio streams, and in lists of applications which are inhibiting the
+/// screensaver. You should use a string that describes your program ("My Game
+/// 2: The Revenge")
+///
+/// Setting this to "" or leaving it unset will have SDL use a reasonable
+/// default: probably the application's name or "SDL Application" if SDL doesn't
+/// have any better information.
+///
+/// Note that, for audio streams, this can be overridden with
+/// SDL_HINT_AUDIO_DEVICE_APP_NAME.
+///
+/// On targets where this is not supported, this hint does nothing.
+///
+/// This hint is available since SDL 2.0.18.
+pub const SDL_HINT_APP_NAME: &[u8] = c_str!("SDL_APP_NAME");
+
+/// Specify an "activity name" for screensaver inhibition.
+///
+/// Some platforms, notably Linux desktops, list the applications which are
+/// inhibiting the screensaver or other power-saving features.
+///
+/// This hint lets you specify the "activity name" sent to the OS when
+/// [`SDL_DisableScreenSaver`] is used (or the screensaver is automatically
+/// disabled). The contents of this hint are used when the screensaver is
+/// disabled. You should use a string that describes what your program is doing
+/// (and, therefore, why the screensaver is disabled). For example, "Playing a
+/// game" or "Watching a video".
+///
+/// Setting this to "" or leaving it unset will have SDL use a reasonable
+/// default: "Playing a game" or something similar.
+///
+/// On targets where this is not supported, this hint does nothing.
+///
+/// This hint is available since SDL 2.0.18.
+pub const SDL_HINT_SCREENSAVER_INHIBIT_ACTIVITY_NAME: &[u8] =
+  c_str!("SDL_SCREENSAVER_INHIBIT_ACTIVITY_NAME");
+
+/// A variable controlling the use of a sentinel event when polling the event
+/// queue
+///
+/// This variable can be set to the following values:
+/// * "0": Disable poll sentinels
+/// * "1": Enable poll sentinels
+///
+/// When polling for events, [`SDL_PumpEvents`] is used to gather new events
+/// from devices. If a device keeps producing new events between calls to
+/// [`SDL_PumpEvents`], a poll loop will become stuck until the new events stop.
+/// This is most noticeable when moving a high frequency mouse.
+///
+/// By default, poll sentinels are enabled.
+///
+/// This hint is available since SDL 2.0.18.
+pub const SDL_HINT_POLL_SENTINEL: &[u8] = c_str!("SDL_POLL_SENTINEL");
+
+/// A variable that decides whether to send SDL_QUIT when closing the final
+/// window.
+///
+/// By default, SDL sends an SDL_QUIT event when there is only one window and it
+/// receives an SDL_WINDOWEVENT_CLOSE event, under the assumption most apps
+/// would also take the loss of this window as a signal to terminate the
+/// program.
+///
+/// However, it's not unreasonable in some cases to have the program continue to
+/// live on, perhaps to create new windows later.
+///
+/// Changing this hint to "0" will cause SDL to not send an SDL_QUIT event when
+/// the final window is requesting to close. Note that in this case, there are
+/// still other legitimate reasons one might get an SDL_QUIT event: choosing
+/// "Quit" from the macOS menu bar, sending a SIGINT (ctrl-c) on Unix, etc.
+///
+/// The default value is "1". This hint can be changed at any time.
+///
+/// This hint is available since SDL 2.0.22. Before then, you always get an
+/// SDL_QUIT event when closing the final window.
+pub const SDL_HINT_QUIT_ON_LAST_WINDOW_CLOSE: &[u8] =
+  c_str!("SDL_QUIT_ON_LAST_WINDOW_CLOSE");
+
+/// A variable controlling whether the mouse is captured while mouse buttons are
+/// pressed
+///
+/// This variable can be set to the following values:
+/// * "0": The mouse is not captured while mouse buttons are pressed
+/// * "1": The mouse is captured while mouse buttons are pressed
+///
+/// By default the mouse is captured while mouse buttons are pressed so if the
+/// mouse is dragged outside the window, the application continues to receive
+/// mouse events until the button is released.
+///
+/// This hint is available since SDL 2.0.22.
+pub const SDL_HINT_MOUSE_AUTO_CAPTURE: &[u8] = c_str!("SDL_MOUSE_AUTO_CAPTURE");
+
+/// A variable controlling whether a motion event should be generated for mouse
+/// warping in relative mode.
+///
+/// This variable can be set to the following values:
+/// * "0": Warping the mouse will not generate a motion event in relative mode
+/// * "1": Warping the mouse will generate a motion event in relative mode
+///
+/// By default warping the mouse will not generate motion events in relative
+/// mode. This avoids the application having to filter out large relative motion
+/// due to warping.
+///
+/// This hint is available since SDL 2.24.0.
+pub const SDL_HINT_MOUSE_RELATIVE_WARP_MOTION: &[u8] =
+  c_str!("SDL_MOUSE_RELATIVE_WARP_MOTION");
+
+/// A variable that treats trackpads as touch devices.
+///
+/// On macOS (and possibly other platforms in the future), SDL will report
+/// touches on a trackpad as mouse input, which is generally what users expect
+/// from this device; however, these are often actually full multitouch-capable
+/// touch devices, so it might be preferable to some apps to treat them as such.
+///
+/// Setting this hint to true will make the trackpad input report as a
+/// multitouch device instead of a mouse. The default is false.
+///
+/// Note that most platforms don't support this hint. As of 2.24.0, it only
+/// supports MacBooks' trackpads on macOS. Others may follow later.
+///
+/// This hint is checked during [`SDL_Init`] and can not be changed after.
+///
+/// This hint is available since SDL 2.24.0.
+pub const SDL_HINT_TRACKPAD_IS_TOUCH_ONLY: &[u8] =
+  c_str!("SDL_TRACKPAD_IS_TOUCH_ONLY");
+
+/// A variable containing a list of devices to ignore in [`SDL_hid_enumerate`].
+///
+/// For example, to ignore the Shanwan DS3 controller and any Valve controller,
+/// you might have the string "0x2563/0x0523,0x28de/0x0000"
+///
+/// This hint is available since SDL 2.26.0.
+pub const SDL_HINT_HIDAPI_IGNORE_DEVICES: &[u8] =
+  c_str!("SDL_HIDAPI_IGNORE_DEVICES");
+
+/// Determines whether SDL enforces that DRM master is required in order to
+/// initialize the KMSDRM video backend.
+///
+/// The DRM subsystem has a concept of a "DRM master" which is a DRM client that
+/// has the ability to set planes, set cursor, etc. When SDL is DRM master, it
+/// can draw to the screen using the SDL rendering APIs. Without DRM master, SDL
+/// is still able to process input and query attributes of attached displays,
+/// but it cannot change display state or draw to the screen directly.
+///
+/// In some cases, it can be useful to have the KMSDRM backend even if it cannot
+/// be used for rendering. An app may want to use SDL for input processing while
+/// using another rendering API (such as an MMAL overlay on Raspberry Pi) or
+/// using its own code to render to DRM overlays that SDL doesn't support.
+///
+/// This hint must be set before initializing the video subsystem.
+///
+/// This variable can be set to the following values:
+/// * "0": SDL will allow usage of the KMSDRM backend without DRM master
+/// * "1": SDL will require DRM master to use the KMSDRM backend (default)
+///
+/// This hint is available since SDL 2.0.16.
+pub const SDL_HINT_KMSDRM_REQUIRE_DRM_MASTER: &[u8] =
+  c_str!("SDL_KMSDRM_REQUIRE_DRM_MASTER");
+
+/// A variable that decides what KMSDRM device to use.
+///
+/// Internally, SDL might open something like "/dev/dri/cardNN" to access KMSDRM
+/// functionality, where "NN" is a device index number.
+///
+/// SDL makes a guess at the best index to use (usually zero), but the app or
+/// user can set this hint to a number between 0 and 99 to force selection.
+///
+/// This hint is available since SDL 2.24.0.
+pub const SDL_HINT_KMSDRM_DEVICE_INDEX: &[u8] =
+  c_str!("SDL_KMSDRM_DEVICE_INDEX");
+
+/// A variable controlling whether the EGL window is allowed to be composited as
+/// transparent, rather than opaque.
+///
+/// Most window systems will always render windows opaque, even if the surface
+/// format has an alpha channel. This is not always true, however, so by default
+/// SDL will try to enforce opaque composition. To override this behavior, you
+/// can set this hint to "1".
+///
+/// This hint is available since SDL 2.0.18.
+pub const SDL_HINT_VIDEO_EGL_ALLOW_TRANSPARENCY: &[u8] =
+  c_str!("SDL_VIDEO_EGL_ALLOW_TRANSPARENCY");
+
+/// A variable that forces X11 windows to create as a custom type.
+///
+/// This is currently only used for X11 and ignored elsewhere.
+///
+/// During [`SDL_CreateWindow`], SDL uses the `_NET_WM_WINDOW_TYPE` X11 property
+/// to report to the window manager the type of window it wants to create. This
+/// might be set to various things if [`SDL_WINDOW_TOOLTIP`] or
+/// [`SDL_WINDOW_POPUP_MENU`], etc, were specified. For "normal" windows that
+/// haven't set a specific type, this hint can be used to specify a custom type.
+/// For example, a dock window might set this to "_NET_WM_WINDOW_TYPE_DOCK".
+///
+/// If not set or set to "", this hint is ignored. This hint must be set before
+/// the [`SDL_CreateWindow`] call that it is intended to affect.
+///
+/// This hint is available since SDL 2.0.22.
+pub const SDL_HINT_X11_WINDOW_TYPE: &[u8] = c_str!("SDL_X11_WINDOW_TYPE");
+
+/// Mark X11 windows as override-redirect.
+///
+/// If set, this _might_ increase framerate at the expense of the desktop not
+/// working as expected. Override-redirect windows aren't noticed by the window
+/// manager at all.
+///
+/// You should probably only use this for fullscreen windows, and you probably
+/// shouldn't even use it for that. But it's here if you want to try!
+///
+/// This hint is available since SDL 2.0.22.
+pub const SDL_HINT_X11_FORCE_OVERRIDE_REDIRECT: &[u8] =
+  c_str!("SDL_X11_FORCE_OVERRIDE_REDIRECT");
+
+/// Specify the behavior of Alt+Tab while the keyboard is grabbed.
+///
+/// By default, SDL emulates Alt+Tab functionality while the keyboard is grabbed
+/// and your window is full-screen. This prevents the user from getting stuck in
+/// your application if you've enabled keyboard grab.
+///
+/// The variable can be set to the following values:
+/// * "0": SDL will not handle Alt+Tab. Your application is responsible for
+///   handling Alt+Tab while the keyboard is grabbed.
+/// * "1": SDL will minimize your window when Alt+Tab is pressed (default)
+pub const SDL_HINT_ALLOW_ALT_TAB_WHILE_GRABBED: &[u8] =
+  c_str!("SDL_ALLOW_ALT_TAB_WHILE_GRABBED");
+
+/// A variable that lets you disable the detection and use of DirectInput
+/// gamepad devices
+///
+/// The variable can be set to the following values:
+/// * "0": Disable DirectInput detection (only uses XInput)
+/// * "1": Enable DirectInput detection (the default)
+pub const SDL_HINT_DIRECTINPUT_ENABLED: &[u8] =
+  c_str!("SDL_DIRECTINPUT_ENABLED");
+
+/// A variable controlling whether raising the window should be done more
+/// forcefully
+///
+/// This variable can be set to the following values:
+/// * "0": No forcing (the default)
+/// * "1": Extra level of forcing
+///
+/// At present, this is only an issue under MS Windows, which makes it nearly
+/// impossible to programmatically move a window to the foreground, for
+/// "security" reasons. See <http://stackoverflow.com/a/34414846> for a
+/// discussion.
+pub const SDL_HINT_FORCE_RAISEWINDOW: &[u8] =
+  c_str!("SDL_HINT_FORCE_RAISEWINDOW");
+
+/// A comma separated list of devices to open as joysticks
+///
+/// This variable is currently only used by the Linux joystick driver.
+pub const SDL_HINT_JOYSTICK_DEVICE: &[u8] = c_str!("SDL_JOYSTICK_DEVICE");
+
+/// A variable controlling whether "low_frequency_rumble" and
+/// "high_frequency_rumble" is used to implement the GameCube controller's 3
+/// rumble modes, Stop(0), Rumble(1), and StopHard(2) this is useful for
+/// applications that need full compatibility for things like ADSR envelopes.
+/// Stop is implemented by setting "low_frequency_rumble" to "0" and
+/// "high_frequency_rumble" ">0" Rumble is both at any arbitrary value, StopHard
+/// is implemented by setting both "low_frequency_rumble" and
+/// "high_frequency_rumble" to "0"
+///
+/// This variable can be set to the following values:
+/// * "0": Normal rumble behavior is behavior is used (default)
+/// * "1": Proper GameCube controller rumble behavior is used
+pub const SDL_HINT_JOYSTICK_GAMECUBE_RUMBLE_BRAKE: &[u8] =
+  c_str!("SDL_JOYSTICK_GAMECUBE_RUMBLE_BRAKE");
+
+/// A variable controlling whether the player LEDs should be lit to indicate
+/// which player is associated with a PS5 controller.
+///
+/// This variable can be set to the following values:
+/// * "0": player LEDs are not enabled
+/// * "1": player LEDs are enabled (the default)
+pub const SDL_HINT_JOYSTICK_HIDAPI_PS5_PLAYER_LED: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_PS5_PLAYER_LED");
+
+/// A variable controlling whether extended input reports should be used for PS5
+/// controllers when using the HIDAPI driver.
+///
+/// This variable can be set to the following values:
+/// * "0": extended reports are not enabled (the default)
+/// * "1": extended reports
+///
+/// Extended input reports allow rumble on Bluetooth PS5 controllers, but break
+/// DirectInput handling for applications that don't use SDL.
+///
+/// Once extended reports are enabled, they can not be disabled without power
+/// cycling the controller.
+///
+/// For compatibility with applications written for versions of SDL prior to the
+/// introduction of PS5 controller support, this value defaults to the value of
+/// [`SDL_HINT_JOYSTICK_HIDAPI_PS4_RUMBLE`].
+pub const SDL_HINT_JOYSTICK_HIDAPI_PS5_RUMBLE: &[u8] =
+  c_str!("SDL_JOYSTICK_HIDAPI_PS5_RUMBLE");
+
+/// A variable controlling whether the RAWINPUT driver should pull correlated
+/// data from XInput.
+///
+/// This variable can be set to the following values:
+/// * "0": RAWINPUT driver will only use data from raw input APIs
+/// * "1": RAWINPUT driver will also pull data from XInput, providing better
+///   trigger axes, guide button presses, and rumble support for Xbox
+///   controllers
+///
+/// The default is "1".  This hint applies to any joysticks opened after setting
+/// the hint.
+pub const SDL_HINT_JOYSTICK_RAWINPUT_CORRELATE_XINPUT: &[u8] =
+  c_str!("SDL_JOYSTICK_RAWINPUT_CORRELATE_XINPUT");
+
+/// A variable controlling whether the ROG Chakram mice should show up as
+/// joysticks
+///
+/// This variable can be set to the following values:
+/// * "0": ROG Chakram mice do not show up as joysticks (the default)
+/// * "1": ROG Chakram mice show up as joysticks
+pub const SDL_HINT_JOYSTICK_ROG_CHAKRAM: &[u8] =
+  c_str!("SDL_JOYSTICK_ROG_CHAKRAM");
+
+/// A variable controlling whether dispatching OpenGL context updates should
+/// block the dispatching thread until the main thread finishes processing
+///
+/// This variable can be set to the following values:
+/// * "0": Dispatching OpenGL context updates will block the dispatching thread
+///   until the main thread finishes processing (default).
+/// * "1": Dispatching OpenGL context updates will allow the dispatching thread
+///   to continue execution.
+///
+/// Generally you want the default, but if you have OpenGL code in a background
+/// thread on a Mac, and the main thread hangs because it's waiting for that
+/// background thread, but that background thread is also hanging because it's
+/// waiting for the main thread to do an update, this might fix your issue.
+///
+/// This hint only applies to macOS.
+///
+/// This hint is available since SDL 2.24.0.
+pub const SDL_HINT_MAC_OPENGL_ASYNC_DISPATCH: &[u8] =
+  c_str!("SDL_MAC_OPENGL_ASYNC_DISPATCH");
+
+/// A variable controlling whether relative mouse mode constrains the mouse to
+/// the center of the window
+///
+/// This variable can be set to the following values:
+/// * "0": Relative mouse mode constrains the mouse to the window
+/// * "1": Relative mouse mode constrains the mouse to the center of the window
+///
+/// Constraining to the center of the window works better for FPS games and when
+/// the application is running over RDP. Constraining to the whole window works
+/// better for 2D games and increases the chance that the mouse will be in the
+/// correct position when using high DPI mice.
+///
+/// By default SDL will constrain the mouse to the center of the window
+pub const SDL_HINT_MOUSE_RELATIVE_MODE_CENTER: &[u8] =
+  c_str!("SDL_MOUSE_RELATIVE_MODE_CENTER");
+
+/// A variable controlling whether the system mouse acceleration curve is used
+/// for relative mouse motion.
+///
+/// This variable can be set to the following values:
+/// * "0": Relative mouse motion will be unscaled (the default)
+/// * "1": Relative mouse motion will be scaled using the system mouse
+///   acceleration curve.
+///
+/// If [`SDL_HINT_MOUSE_RELATIVE_SPEED_SCALE`] is set, that will override the
+/// system speed scale.
+pub const SDL_HINT_MOUSE_RELATIVE_SYSTEM_SCALE: &[u8] =
+  c_str!("SDL_MOUSE_RELATIVE_SYSTEM_SCALE");
+
+/// A variable controlling if VSYNC is automatically disable if doesn't reach
+/// the enough FPS
+///
+/// This variable can be set to the following values:
+/// * "0": It will be using VSYNC as defined in the main flag. Default
+/// * "1": If VSYNC was previously enabled, then it will disable VSYNC if
+///   doesn't reach enough speed
+///
+/// By default SDL does not enable the automatic VSYNC
+pub const SDL_HINT_PS2_DYNAMIC_VSYNC: &[u8] = c_str!("SDL_PS2_DYNAMIC_VSYNC");
+
+/// A variable controlling how the 2D render API renders lines
+///
+/// This variable can be set to the following values:
+/// * "0": Use the default line drawing method (Bresenham's line algorithm as of
+///   SDL 2.0.20)
+/// * "1": Use the driver point API using Bresenham's line algorithm (correct,
+///   draws many points)
+/// * "2": Use the driver line API (occasionally misses line endpoints based on
+///   hardware driver quirks, was the default before 2.0.20)
+/// * "3": Use the driver geometry API (correct, draws thicker diagonal lines)
+///
+/// This variable should be set when the renderer is created.
+pub const SDL_HINT_RENDER_LINE_METHOD: &[u8] = c_str!("SDL_RENDER_LINE_METHOD");
+
+/// A variable controlling the scaling policy for [`SDL_RenderSetLogicalSize`].
+///
+/// This variable can be set to the following values:
+/// * "0" or "letterbox": Uses letterbox/sidebars to fit the entire rendering on
+///   screen
+/// * "1" or "overscan": Will zoom the rendering so it fills the entire screen,
+///   allowing edges to be drawn offscreen
+///
+/// By default letterbox is used
+pub const SDL_HINT_RENDER_LOGICAL_SIZE_MODE: &[u8] =
+  c_str!("SDL_RENDER_LOGICAL_SIZE_MODE");
+
+/// When calling [`SDL_CreateWindowFrom`], make the window compatible with
+/// OpenGL.
+///
+/// This variable can be set to the following values:
+/// * "0": Don't add any graphics flags to the SDL_WindowFlags
+/// * "1": Add SDL_WINDOW_OPENGL to the SDL_WindowFlags
+///
+/// By default SDL will not make the foreign window compatible with OpenGL.
+pub const SDL_HINT_VIDEO_FOREIGN_WINDOW_OPENGL: &[u8] =
+  c_str!("SDL_VIDEO_FOREIGN_WINDOW_OPENGL");
+
+/// When calling [`SDL_CreateWindowFrom`], make the window compatible with
+/// Vulkan.
+///
+/// This variable can be set to the following values:
+/// * "0": Don't add any graphics flags to the SDL_WindowFlags
+/// * "1": Add SDL_WINDOW_VULKAN to the SDL_WindowFlags
+///
+/// By default SDL will not make the foreign window compatible with Vulkan.
+pub const SDL_HINT_VIDEO_FOREIGN_WINDOW_VULKAN: &[u8] =
+  c_str!("SDL_VIDEO_FOREIGN_WINDOW_VULKAN");
+
+/// A variable controlling which touchpad should generate synthetic mouse events
+///
+/// This variable can be set to the following values:
+/// * "0": Only front touchpad should generate mouse events. Default
+/// * "1": Only back touchpad should generate mouse events.
+/// * "2": Both touchpads should generate mouse events.
+///
+/// By default SDL will generate mouse events for all touch devices
+pub const SDL_HINT_VITA_TOUCH_MOUSE_DEVICE: &[u8] =
+  c_str!("SDL_HINT_VITA_TOUCH_MOUSE_DEVICE");
+
+/// Force SDL to use Critical Sections for mutexes on Windows. On Windows 7 and
+/// newer, Slim Reader/Writer Locks are available. They offer better
+/// performance, allocate no kernel ressources and use less memory. SDL will
+/// fall back to Critical Sections on older OS versions or if forced to by this
+/// hint.
+///
+/// This variable can be set to the following values:
+/// * "0": Use SRW Locks when available. If not, fall back to Critical Sections.
+///   (default)
+/// * "1": Force the use of Critical Sections in all cases.
+pub const SDL_HINT_WINDOWS_FORCE_MUTEX_CRITICAL_SECTIONS: &[u8] =
+  c_str!("SDL_WINDOWS_FORCE_MUTEX_CRITICAL_SECTIONS");
+
+/// Force SDL to use Kernel Semaphores on Windows. Kernel Semaphores are
+/// inter-process and require a context switch on every interaction. On Windows
+/// 8 and newer, the WaitOnAddress API is available. Using that and atomics to
+/// implement semaphores increases performance. SDL will fall back to Kernel
+/// Objects on older OS versions or if forced to by this hint.
+///
+/// This variable can be set to the following values:
+/// * "0": Use Atomics and WaitOnAddress API when available. If not, fall back
+///   to Kernel Objects. (default)
+/// * "1": Force the use of Kernel Objects in all cases.
+pub const SDL_HINT_WINDOWS_FORCE_SEMAPHORE_KERNEL: &[u8] =
+  c_str!("SDL_WINDOWS_FORCE_SEMAPHORE_KERNEL");
+
+/// Use the D3D9Ex API introduced in Windows Vista, instead of normal D3D9.
+/// Direct3D 9Ex contains changes to state management that can eliminate device
+/// loss errors during scenarios like Alt+Tab or UAC prompts. D3D9Ex may require
+/// some changes to your application to cope with the new behavior, so this is
+/// disabled by default.
+///
+/// This hint must be set before initializing the video subsystem.
+///
+/// For more information on Direct3D 9Ex, see:
+/// * <https://docs.microsoft.com/en-us/windows/win32/direct3darticles/graphics-apis-in-windows-vista#direct3d-9ex>
+/// * <https://docs.microsoft.com/en-us/windows/win32/direct3darticles/direct3d-9ex-improvements>
+///
+/// This variable can be set to the following values:
+/// * "0": Use the original Direct3D 9 API (default)
+/// * "1": Use the Direct3D 9Ex API on Vista and later (and fall back if D3D9Ex
+///   is unavailable)
+pub const SDL_HINT_WINDOWS_USE_D3D9EX: &[u8] = c_str!("SDL_WINDOWS_USE_D3D9EX");
+
+/// A variable controlling whether the window is activated when the
+/// SDL_ShowWindow function is called
+///
+/// This variable can be set to the following values:
+/// * "0": The window is activated when the SDL_ShowWindow function is called
+/// * "1": The window is not activated when the SDL_ShowWindow function is
+///   called
+///
+/// By default SDL will activate the window when the SDL_ShowWindow function is
+/// called
+pub const SDL_HINT_WINDOW_NO_ACTIVATION_WHEN_SHOWN: &[u8] =
+  c_str!("SDL_WINDOW_NO_ACTIVATION_WHEN_SHOWN");
+
 /// An enumeration of hint priorities.
 ///
 /// See `SDL_HINT_*`
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_HintPriority(pub i32);
 
@@ -1506,6 +2479,26 @@ extern "C" {
   /// **Returns:** `SDL_TRUE` if the hint was set, `SDL_FALSE` otherwise.
   pub fn SDL_SetHint(name: *const c_char, value: *const c_char) -> SDL_bool;
 
+  /// Reset a hint to the default value.
+  ///
+  /// This will reset a hint to the value of the environment variable, or NULL
+  /// if the environment isn't set. Callbacks will be called normally with this
+  /// change.
+  ///
+  /// **Returns:** `SDL_TRUE` if the hint was set, `SDL_FALSE` otherwise.
+  #[cfg(feature = "sdl2_2_24")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_24")))]
+  pub fn SDL_ResetHint(name: *const c_char) -> SDL_bool;
+
+  /// Reset all hints to the default values.
+  ///
+  /// This will reset all hints to the value of the associated environment
+  /// variable, or NULL if the environment isn't set. Callbacks will be called
+  /// normally with this change.
+  #[cfg(feature = "sdl2_2_26")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_26")))]
+  pub fn SDL_ResetHints();
+
   /// Get a hint
   ///
   /// **Returns:** The string value of a hint variable.
@@ -1541,3 +2534,423 @@ extern "C" {
   /// This function is called during [`SDL_Quit`] to free stored hints.
   pub fn SDL_ClearHints();
 }
+
+macro_rules! hint_enum {
+  ($($variant:ident = $name:ident,)*) => {
+    /// Every one of the `SDL_HINT_*` names, as an enum.
+    ///
+    /// The methods here take care of the null terminator and UTF-8 for you,
+    /// and [`Hint::ALL`] lists every hint (eg: for a settings menu or a config
+    /// file). Each variant is the matching constant's name in `CamelCase`.
+    ///
+    /// New SDL versions add more hints, so the enum is `#[non_exhaustive]`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[non_exhaustive]
+    pub enum Hint {
+      $(
+        #[doc = concat!("See [`", stringify!($name), "`].")]
+        $variant,
+      )*
+    }
+    impl Hint {
+      /// All of the hints, in the same order as the constants.
+      pub const ALL: &'static [Hint] = &[$(Hint::$variant,)*];
+
+      /// The hint's name, including the null terminator (this is the value of
+      /// the matching `SDL_HINT_*` constant).
+      #[inline]
+      #[must_use]
+      pub const fn as_bytes(self) -> &'static [u8] {
+        match self {
+          $(Hint::$variant => $name,)*
+        }
+      }
+    }
+  };
+}
+hint_enum! {
+  FramebufferAcceleration = SDL_HINT_FRAMEBUFFER_ACCELERATION,
+  RenderDriver = SDL_HINT_RENDER_DRIVER,
+  RenderOpenglShaders = SDL_HINT_RENDER_OPENGL_SHADERS,
+  RenderDirect3dThreadsafe = SDL_HINT_RENDER_DIRECT3D_THREADSAFE,
+  RenderDirect3d11Debug = SDL_HINT_RENDER_DIRECT3D11_DEBUG,
+  RenderScaleQuality = SDL_HINT_RENDER_SCALE_QUALITY,
+  RenderVsync = SDL_HINT_RENDER_VSYNC,
+  VideoAllowScreensaver = SDL_HINT_VIDEO_ALLOW_SCREENSAVER,
+  VideoExternalContext = SDL_HINT_VIDEO_EXTERNAL_CONTEXT,
+  VideoX11Xvidmode = SDL_HINT_VIDEO_X11_XVIDMODE,
+  VideoX11Xinerama = SDL_HINT_VIDEO_X11_XINERAMA,
+  VideoX11Xrandr = SDL_HINT_VIDEO_X11_XRANDR,
+  VideoX11WindowVisualid = SDL_HINT_VIDEO_X11_WINDOW_VISUALID,
+  VideoX11NetWmPing = SDL_HINT_VIDEO_X11_NET_WM_PING,
+  VideoX11NetWmBypassCompositor = SDL_HINT_VIDEO_X11_NET_WM_BYPASS_COMPOSITOR,
+  VideoX11ForceEgl = SDL_HINT_VIDEO_X11_FORCE_EGL,
+  WindowFrameUsableWhileCursorHidden =
+    SDL_HINT_WINDOW_FRAME_USABLE_WHILE_CURSOR_HIDDEN,
+  WindowsIntresourceIcon = SDL_HINT_WINDOWS_INTRESOURCE_ICON,
+  WindowsIntresourceIconSmall = SDL_HINT_WINDOWS_INTRESOURCE_ICON_SMALL,
+  WindowsEnableMessageloop = SDL_HINT_WINDOWS_ENABLE_MESSAGELOOP,
+  GrabKeyboard = SDL_HINT_GRAB_KEYBOARD,
+  MouseDoubleClickTime = SDL_HINT_MOUSE_DOUBLE_CLICK_TIME,
+  MouseDoubleClickRadius = SDL_HINT_MOUSE_DOUBLE_CLICK_RADIUS,
+  MouseNormalSpeedScale = SDL_HINT_MOUSE_NORMAL_SPEED_SCALE,
+  MouseRelativeSpeedScale = SDL_HINT_MOUSE_RELATIVE_SPEED_SCALE,
+  MouseRelativeScaling = SDL_HINT_MOUSE_RELATIVE_SCALING,
+  MouseRelativeModeWarp = SDL_HINT_MOUSE_RELATIVE_MODE_WARP,
+  MouseFocusClickthrough = SDL_HINT_MOUSE_FOCUS_CLICKTHROUGH,
+  TouchMouseEvents = SDL_HINT_TOUCH_MOUSE_EVENTS,
+  MouseTouchEvents = SDL_HINT_MOUSE_TOUCH_EVENTS,
+  VideoMinimizeOnFocusLoss = SDL_HINT_VIDEO_MINIMIZE_ON_FOCUS_LOSS,
+  IdleTimerDisabled = SDL_HINT_IDLE_TIMER_DISABLED,
+  Orientations = SDL_HINT_ORIENTATIONS,
+  AppleTvControllerUiEvents = SDL_HINT_APPLE_TV_CONTROLLER_UI_EVENTS,
+  AppleTvRemoteAllowRotation = SDL_HINT_APPLE_TV_REMOTE_ALLOW_ROTATION,
+  IosHideHomeIndicator = SDL_HINT_IOS_HIDE_HOME_INDICATOR,
+  AccelerometerAsJoystick = SDL_HINT_ACCELEROMETER_AS_JOYSTICK,
+  TvRemoteAsJoystick = SDL_HINT_TV_REMOTE_AS_JOYSTICK,
+  XinputEnabled = SDL_HINT_XINPUT_ENABLED,
+  XinputUseOldJoystickMapping = SDL_HINT_XINPUT_USE_OLD_JOYSTICK_MAPPING,
+  Gamecontrollertype = SDL_HINT_GAMECONTROLLERTYPE,
+  Gamecontrollerconfig = SDL_HINT_GAMECONTROLLERCONFIG,
+  GamecontrollerconfigFile = SDL_HINT_GAMECONTROLLERCONFIG_FILE,
+  GamecontrollerIgnoreDevices = SDL_HINT_GAMECONTROLLER_IGNORE_DEVICES,
+  GamecontrollerIgnoreDevicesExcept =
+    SDL_HINT_GAMECONTROLLER_IGNORE_DEVICES_EXCEPT,
+  GamecontrollerUseButtonLabels = SDL_HINT_GAMECONTROLLER_USE_BUTTON_LABELS,
+  JoystickAllowBackgroundEvents = SDL_HINT_JOYSTICK_ALLOW_BACKGROUND_EVENTS,
+  JoystickHidapi = SDL_HINT_JOYSTICK_HIDAPI,
+  JoystickHidapiPs4 = SDL_HINT_JOYSTICK_HIDAPI_PS4,
+  JoystickHidapiPs5 = SDL_HINT_JOYSTICK_HIDAPI_PS5,
+  JoystickHidapiPs4Rumble = SDL_HINT_JOYSTICK_HIDAPI_PS4_RUMBLE,
+  JoystickHidapiSteam = SDL_HINT_JOYSTICK_HIDAPI_STEAM,
+  JoystickHidapiSwitch = SDL_HINT_JOYSTICK_HIDAPI_SWITCH,
+  JoystickHidapiXbox = SDL_HINT_JOYSTICK_HIDAPI_XBOX,
+  JoystickHidapiGamecube = SDL_HINT_JOYSTICK_HIDAPI_GAMECUBE,
+  EnableSteamControllers = SDL_HINT_ENABLE_STEAM_CONTROLLERS,
+  JoystickRawinput = SDL_HINT_JOYSTICK_RAWINPUT,
+  JoystickThread = SDL_HINT_JOYSTICK_THREAD,
+  LinuxJoystickDeadzones = SDL_HINT_LINUX_JOYSTICK_DEADZONES,
+  LinuxDigitalHats = SDL_HINT_LINUX_DIGITAL_HATS,
+  LinuxHatDeadzones = SDL_HINT_LINUX_HAT_DEADZONES,
+  LinuxJoystickClassic = SDL_HINT_LINUX_JOYSTICK_CLASSIC,
+  AllowTopmost = SDL_HINT_ALLOW_TOPMOST,
+  TimerResolution = SDL_HINT_TIMER_RESOLUTION,
+  QtwaylandContentOrientation = SDL_HINT_QTWAYLAND_CONTENT_ORIENTATION,
+  QtwaylandWindowFlags = SDL_HINT_QTWAYLAND_WINDOW_FLAGS,
+  ThreadStackSize = SDL_HINT_THREAD_STACK_SIZE,
+  ThreadPriorityPolicy = SDL_HINT_THREAD_PRIORITY_POLICY,
+  ThreadForceRealtimeTimeCritical =
+    SDL_HINT_THREAD_FORCE_REALTIME_TIME_CRITICAL,
+  VideoHighdpiDisabled = SDL_HINT_VIDEO_HIGHDPI_DISABLED,
+  MacCtrlClickEmulateRightClick = SDL_HINT_MAC_CTRL_CLICK_EMULATE_RIGHT_CLICK,
+  VideoWinD3dcompiler = SDL_HINT_VIDEO_WIN_D3DCOMPILER,
+  VideoWindowSharePixelFormat = SDL_HINT_VIDEO_WINDOW_SHARE_PIXEL_FORMAT,
+  WinrtPrivacyPolicyUrl = SDL_HINT_WINRT_PRIVACY_POLICY_URL,
+  WinrtPrivacyPolicyLabel = SDL_HINT_WINRT_PRIVACY_POLICY_LABEL,
+  WinrtHandleBackButton = SDL_HINT_WINRT_HANDLE_BACK_BUTTON,
+  VideoMacFullscreenSpaces = SDL_HINT_VIDEO_MAC_FULLSCREEN_SPACES,
+  MacBackgroundApp = SDL_HINT_MAC_BACKGROUND_APP,
+  AndroidApkExpansionMainFileVersion =
+    SDL_HINT_ANDROID_APK_EXPANSION_MAIN_FILE_VERSION,
+  AndroidApkExpansionPatchFileVersion =
+    SDL_HINT_ANDROID_APK_EXPANSION_PATCH_FILE_VERSION,
+  ImeInternalEditing = SDL_HINT_IME_INTERNAL_EDITING,
+  AndroidTrapBackButton = SDL_HINT_ANDROID_TRAP_BACK_BUTTON,
+  AndroidBlockOnPause = SDL_HINT_ANDROID_BLOCK_ON_PAUSE,
+  AndroidBlockOnPausePauseaudio = SDL_HINT_ANDROID_BLOCK_ON_PAUSE_PAUSEAUDIO,
+  ReturnKeyHidesIme = SDL_HINT_RETURN_KEY_HIDES_IME,
+  EmscriptenKeyboardElement = SDL_HINT_EMSCRIPTEN_KEYBOARD_ELEMENT,
+  EmscriptenAsyncify = SDL_HINT_EMSCRIPTEN_ASYNCIFY,
+  NoSignalHandlers = SDL_HINT_NO_SIGNAL_HANDLERS,
+  WindowsNoCloseOnAltF4 = SDL_HINT_WINDOWS_NO_CLOSE_ON_ALT_F4,
+  BmpSaveLegacyFormat = SDL_HINT_BMP_SAVE_LEGACY_FORMAT,
+  WindowsDisableThreadNaming = SDL_HINT_WINDOWS_DISABLE_THREAD_NAMING,
+  RpiVideoLayer = SDL_HINT_RPI_VIDEO_LAYER,
+  VideoDoubleBuffer = SDL_HINT_VIDEO_DOUBLE_BUFFER,
+  OpenglEsDriver = SDL_HINT_OPENGL_ES_DRIVER,
+  AudioResamplingMode = SDL_HINT_AUDIO_RESAMPLING_MODE,
+  AudioCategory = SDL_HINT_AUDIO_CATEGORY,
+  RenderBatching = SDL_HINT_RENDER_BATCHING,
+  AutoUpdateJoysticks = SDL_HINT_AUTO_UPDATE_JOYSTICKS,
+  AutoUpdateSensors = SDL_HINT_AUTO_UPDATE_SENSORS,
+  EventLogging = SDL_HINT_EVENT_LOGGING,
+  WaveRiffChunkSize = SDL_HINT_WAVE_RIFF_CHUNK_SIZE,
+  WaveTruncation = SDL_HINT_WAVE_TRUNCATION,
+  WaveFactChunk = SDL_HINT_WAVE_FACT_CHUNK,
+  DisplayUsableBounds = SDL_HINT_DISPLAY_USABLE_BOUNDS,
+  AudioDeviceAppName = SDL_HINT_AUDIO_DEVICE_APP_NAME,
+  AudioDeviceStreamName = SDL_HINT_AUDIO_DEVICE_STREAM_NAME,
+  PreferredLocales = SDL_HINT_PREFERRED_LOCALES,
+  AudioIncludeMonitors = SDL_HINT_AUDIO_INCLUDE_MONITORS,
+  AudioDeviceStreamRole = SDL_HINT_AUDIO_DEVICE_STREAM_ROLE,
+  Videodriver = SDL_HINT_VIDEODRIVER,
+  Audiodriver = SDL_HINT_AUDIODRIVER,
+  VideoWaylandAllowLibdecor = SDL_HINT_VIDEO_WAYLAND_ALLOW_LIBDECOR,
+  VideoWaylandPreferLibdecor = SDL_HINT_VIDEO_WAYLAND_PREFER_LIBDECOR,
+  VideoWaylandModeEmulation = SDL_HINT_VIDEO_WAYLAND_MODE_EMULATION,
+  VideoWaylandEmulateMouseWarp = SDL_HINT_VIDEO_WAYLAND_EMULATE_MOUSE_WARP,
+  WindowsDpiAwareness = SDL_HINT_WINDOWS_DPI_AWARENESS,
+  WindowsDpiScaling = SDL_HINT_WINDOWS_DPI_SCALING,
+  JoystickHidapiJoyCons = SDL_HINT_JOYSTICK_HIDAPI_JOY_CONS,
+  JoystickHidapiCombineJoyCons = SDL_HINT_JOYSTICK_HIDAPI_COMBINE_JOY_CONS,
+  JoystickHidapiVerticalJoyCons = SDL_HINT_JOYSTICK_HIDAPI_VERTICAL_JOY_CONS,
+  JoystickHidapiJoyconHomeLed = SDL_HINT_JOYSTICK_HIDAPI_JOYCON_HOME_LED,
+  JoystickHidapiLuna = SDL_HINT_JOYSTICK_HIDAPI_LUNA,
+  JoystickHidapiNintendoClassic = SDL_HINT_JOYSTICK_HIDAPI_NINTENDO_CLASSIC,
+  JoystickHidapiShield = SDL_HINT_JOYSTICK_HIDAPI_SHIELD,
+  JoystickHidapiPs3 = SDL_HINT_JOYSTICK_HIDAPI_PS3,
+  JoystickHidapiStadia = SDL_HINT_JOYSTICK_HIDAPI_STADIA,
+  JoystickHidapiSwitchHomeLed = SDL_HINT_JOYSTICK_HIDAPI_SWITCH_HOME_LED,
+  JoystickHidapiSwitchPlayerLed = SDL_HINT_JOYSTICK_HIDAPI_SWITCH_PLAYER_LED,
+  JoystickHidapiWii = SDL_HINT_JOYSTICK_HIDAPI_WII,
+  JoystickHidapiWiiPlayerLed = SDL_HINT_JOYSTICK_HIDAPI_WII_PLAYER_LED,
+  JoystickHidapiXbox360 = SDL_HINT_JOYSTICK_HIDAPI_XBOX_360,
+  JoystickHidapiXbox360PlayerLed = SDL_HINT_JOYSTICK_HIDAPI_XBOX_360_PLAYER_LED,
+  JoystickHidapiXbox360Wireless = SDL_HINT_JOYSTICK_HIDAPI_XBOX_360_WIRELESS,
+  JoystickHidapiXboxOne = SDL_HINT_JOYSTICK_HIDAPI_XBOX_ONE,
+  JoystickHidapiXboxOneHomeLed = SDL_HINT_JOYSTICK_HIDAPI_XBOX_ONE_HOME_LED,
+  ImeShowUi = SDL_HINT_IME_SHOW_UI,
+  ImeSupportExtendedText = SDL_HINT_IME_SUPPORT_EXTENDED_TEXT,
+  AppName = SDL_HINT_APP_NAME,
+  ScreensaverInhibitActivityName = SDL_HINT_SCREENSAVER_INHIBIT_ACTIVITY_NAME,
+  PollSentinel = SDL_HINT_POLL_SENTINEL,
+  QuitOnLastWindowClose = SDL_HINT_QUIT_ON_LAST_WINDOW_CLOSE,
+  MouseAutoCapture = SDL_HINT_MOUSE_AUTO_CAPTURE,
+  MouseRelativeWarpMotion = SDL_HINT_MOUSE_RELATIVE_WARP_MOTION,
+  TrackpadIsTouchOnly = SDL_HINT_TRACKPAD_IS_TOUCH_ONLY,
+  HidapiIgnoreDevices = SDL_HINT_HIDAPI_IGNORE_DEVICES,
+  KmsdrmRequireDrmMaster = SDL_HINT_KMSDRM_REQUIRE_DRM_MASTER,
+  KmsdrmDeviceIndex = SDL_HINT_KMSDRM_DEVICE_INDEX,
+  VideoEglAllowTransparency = SDL_HINT_VIDEO_EGL_ALLOW_TRANSPARENCY,
+  X11WindowType = SDL_HINT_X11_WINDOW_TYPE,
+  X11ForceOverrideRedirect = SDL_HINT_X11_FORCE_OVERRIDE_REDIRECT,
+  AllowAltTabWhileGrabbed = SDL_HINT_ALLOW_ALT_TAB_WHILE_GRABBED,
+  DirectinputEnabled = SDL_HINT_DIRECTINPUT_ENABLED,
+  ForceRaisewindow = SDL_HINT_FORCE_RAISEWINDOW,
+  JoystickDevice = SDL_HINT_JOYSTICK_DEVICE,
+  JoystickGamecubeRumbleBrake = SDL_HINT_JOYSTICK_GAMECUBE_RUMBLE_BRAKE,
+  JoystickHidapiPs5PlayerLed = SDL_HINT_JOYSTICK_HIDAPI_PS5_PLAYER_LED,
+  JoystickHidapiPs5Rumble = SDL_HINT_JOYSTICK_HIDAPI_PS5_RUMBLE,
+  JoystickRawinputCorrelateXinput = SDL_HINT_JOYSTICK_RAWINPUT_CORRELATE_XINPUT,
+  JoystickRogChakram = SDL_HINT_JOYSTICK_ROG_CHAKRAM,
+  MacOpenglAsyncDispatch = SDL_HINT_MAC_OPENGL_ASYNC_DISPATCH,
+  MouseRelativeModeCenter = SDL_HINT_MOUSE_RELATIVE_MODE_CENTER,
+  MouseRelativeSystemScale = SDL_HINT_MOUSE_RELATIVE_SYSTEM_SCALE,
+  Ps2DynamicVsync = SDL_HINT_PS2_DYNAMIC_VSYNC,
+  RenderLineMethod = SDL_HINT_RENDER_LINE_METHOD,
+  RenderLogicalSizeMode = SDL_HINT_RENDER_LOGICAL_SIZE_MODE,
+  VideoForeignWindowOpengl = SDL_HINT_VIDEO_FOREIGN_WINDOW_OPENGL,
+  VideoForeignWindowVulkan = SDL_HINT_VIDEO_FOREIGN_WINDOW_VULKAN,
+  VitaTouchMouseDevice = SDL_HINT_VITA_TOUCH_MOUSE_DEVICE,
+  WindowsForceMutexCriticalSections =
+    SDL_HINT_WINDOWS_FORCE_MUTEX_CRITICAL_SECTIONS,
+  WindowsForceSemaphoreKernel = SDL_HINT_WINDOWS_FORCE_SEMAPHORE_KERNEL,
+  WindowsUseD3d9ex = SDL_HINT_WINDOWS_USE_D3D9EX,
+  WindowNoActivationWhenShown = SDL_HINT_WINDOW_NO_ACTIVATION_WHEN_SHOWN,
+}
+impl Hint {
+  /// The hint's name, which is also the name of the environment variable
+  /// that overrides it (eg: `"SDL_RENDER_DRIVER"`).
+  #[inline]
+  #[must_use]
+  pub const fn name(self) -> &'static str {
+    let bytes = self.as_bytes();
+    // Safety: the names are all ASCII, and they all end with a null.
+    unsafe {
+      core::str::from_utf8_unchecked(core::slice::from_raw_parts(
+        bytes.as_ptr(),
+        bytes.len() - 1,
+      ))
+    }
+  }
+
+  /// Looks up a hint by its name (eg: `"SDL_RENDER_DRIVER"`).
+  #[must_use]
+  pub fn from_name(name: &str) -> Option<Self> {
+    Self::ALL.iter().copied().find(|hint| hint.name() == name)
+  }
+
+  /// Sets the hint with normal priority, see [`SDL_SetHint`].
+  ///
+  /// **Returns:** if the hint was set. This is `false` if the hint already
+  /// has a value with a higher priority, or if `value` contains a null byte.
+  ///
+  /// ## Safety
+  /// * No other thread may use the hint API (the methods here, [`SDL_SetHint`],
+  ///   [`SDL_GetHint`], and so on) at the same time. SDL2 doesn't lock its
+  ///   list of hints.
+  #[cfg(feature = "alloc")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "alloc")))]
+  pub unsafe fn set(self, value: &str) -> bool {
+    self.set_with_priority(value, SDL_HINT_NORMAL)
+  }
+
+  /// Sets the hint with the given priority, see [`SDL_SetHintWithPriority`].
+  ///
+  /// **Returns:** if the hint was set. This is `false` if the hint already
+  /// has a value with a higher priority, or if `value` contains a null byte.
+  ///
+  /// ## Safety
+  /// * No other thread may use the hint API (the methods here, [`SDL_SetHint`],
+  ///   [`SDL_GetHint`], and so on) at the same time. SDL2 doesn't lock its
+  ///   list of hints.
+  #[cfg(feature = "alloc")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "alloc")))]
+  pub unsafe fn set_with_priority(
+    self, value: &str, priority: SDL_HintPriority,
+  ) -> bool {
+    if value.as_bytes().contains(&0) {
+      return false;
+    }
+    let mut buf = Vec::with_capacity(value.len() + 1);
+    buf.extend_from_slice(value.as_bytes());
+    buf.push(0_u8);
+    SDL_SetHintWithPriority(
+      self.as_bytes().as_ptr().cast(),
+      buf.as_ptr().cast(),
+      priority,
+    )
+    .into_bool()
+  }
+
+  /// Gets the hint's current value, see [`SDL_GetHint`].
+  ///
+  /// Any invalid UTF-8 is replaced with `U+FFFD`. This is `None` if the hint
+  /// isn't set (either as a hint or as an environment variable).
+  ///
+  /// ## Safety
+  /// * No other thread may use the hint API (the methods here, [`SDL_SetHint`],
+  ///   [`SDL_GetHint`], and so on) at the same time. SDL2 doesn't lock its
+  ///   list of hints.
+  #[cfg(feature = "alloc")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "alloc")))]
+  #[must_use]
+  pub unsafe fn get(self) -> Option<String> {
+    let p = SDL_GetHint(self.as_bytes().as_ptr().cast());
+    if p.is_null() {
+      return None;
+    }
+    let value = core::ffi::CStr::from_ptr(p);
+    Some(String::from_utf8_lossy(value.to_bytes()).into_owned())
+  }
+
+  /// Resets the hint to its environment variable (or unset), see
+  /// [`SDL_ResetHint`].
+  ///
+  /// **Returns:** if the hint was reset.
+  ///
+  /// ## Safety
+  /// * No other thread may use the hint API (the methods here, [`SDL_SetHint`],
+  ///   [`SDL_GetHint`], and so on) at the same time. SDL2 doesn't lock its
+  ///   list of hints.
+  #[cfg(feature = "sdl2_2_24")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_24")))]
+  pub unsafe fn reset(self) -> bool {
+    SDL_ResetHint(self.as_bytes().as_ptr().cast()).into_bool()
+  }
+
+  /// Calls `f` with the hint's value whenever it changes, until the returned
+  /// [`HintWatcher`] is dropped.
+  ///
+  /// `f` is called once right away with the current value, and then again
+  /// after each change. The value is `None` when the hint is unset, and any
+  /// invalid UTF-8 is replaced with `U+FFFD`. The closure can run again from
+  /// inside itself (if it sets the hint), so it must be `Fn`. Use a `Cell` or
+  /// similar for any state it updates.
+  ///
+  /// [`SDL_Quit`] (through [`SDL_ClearHints`]) removes every hint callback,
+  /// so the watcher goes quiet after that even if it's still alive.
+  ///
+  /// ## Safety
+  /// * No other thread may use the hint API (the methods here, [`SDL_SetHint`],
+  ///   [`SDL_GetHint`], and so on) at the same time, including while the
+  ///   [`HintWatcher`] is dropped. SDL2 doesn't lock its list of hints or
+  ///   their callbacks.
+  #[cfg(feature = "alloc")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "alloc")))]
+  pub unsafe fn watch<F>(self, f: F) -> HintWatcher
+  where
+    F: Fn(Option<&str>) + 'static,
+  {
+    unsafe extern "C" fn trampoline<F: Fn(Option<&str>)>(
+      userdata: *mut c_void, _name: *const c_char, _oldValue: *const c_char,
+      newValue: *const c_char,
+    ) {
+      let value = if newValue.is_null() {
+        None
+      } else {
+        let value = core::ffi::CStr::from_ptr(newValue);
+        Some(String::from_utf8_lossy(value.to_bytes()))
+      };
+      let f = &*userdata.cast::<F>();
+      crate::abort_on_unwind("the `Hint::watch` closure", || {
+        f(value.as_deref())
+      });
+    }
+    unsafe fn free<F>(userdata: *mut c_void) {
+      drop(Box::from_raw(userdata.cast::<F>()));
+    }
+    let userdata = Box::into_raw(Box::new(f)).cast::<c_void>();
+    let callback: SDL_HintCallback = Some(trampoline::<F>);
+    SDL_AddHintCallback(self.as_bytes().as_ptr().cast(), callback, userdata);
+    HintWatcher { hint: self, callback, userdata, free: free::<F> }
+  }
+
+  /// Gets the hint's current value as a boolean, see [`SDL_GetHintBoolean`].
+  ///
+  /// A value of `"0"` or `"false"` (in any case) is `false`, any other value
+  /// is `true`, and `default` is used if the hint isn't set.
+  ///
+  /// ## Safety
+  /// * No other thread may use the hint API (the methods here, [`SDL_SetHint`],
+  ///   [`SDL_GetHint`], and so on) at the same time. SDL2 doesn't lock its
+  ///   list of hints.
+  #[must_use]
+  pub unsafe fn get_boolean(self, default: bool) -> bool {
+    SDL_GetHintBoolean(self.as_bytes().as_ptr().cast(), default.into())
+      .into_bool()
+  }
+}
+impl core::fmt::Display for Hint {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    f.write_str(self.name())
+  }
+}
+
+/// Keeps a [`Hint::watch`] closure registered with SDL.
+///
+/// Dropping this unregisters the closure (with [`SDL_DelHintCallback`]) and
+/// then frees it.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "alloc")))]
+#[derive(Debug)]
+#[must_use = "the closure is unregistered when the watcher is dropped"]
+pub struct HintWatcher {
+  hint: Hint,
+  callback: SDL_HintCallback,
+  userdata: *mut c_void,
+  free: unsafe fn(*mut c_void),
+}
+#[cfg(feature = "alloc")]
+impl HintWatcher {
+  /// The hint being watched.
+  #[inline]
+  #[must_use]
+  pub const fn hint(&self) -> Hint {
+    self.hint
+  }
+}
+#[cfg(feature = "alloc")]
+impl Drop for HintWatcher {
+  fn drop(&mut self) {
+    unsafe {
+      SDL_DelHintCallback(
+        self.hint.as_bytes().as_ptr().cast(),
+        self.callback,
+        self.userdata,
+      );
+      (self.free)(self.userdata);
+    }
+  }
+}
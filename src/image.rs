@@ -0,0 +1,314 @@
+//! Bindings to the SDL2_image library, for loading and saving images.
+//!
+//! This is a separate library from SDL2 itself, so it must also be installed
+//! for your program to link and run. These bindings match SDL2_image 2.6.
+
+use crate::{c_char, c_int, rwops::*, surface::*, version::*};
+
+#[cfg(feature = "renderer")]
+use crate::renderer::*;
+
+// makes rustdoc link properly!
+#[allow(unused)]
+use crate::error::*;
+
+/// The major version of SDL2_image that these bindings are for.
+pub const SDL_IMAGE_MAJOR_VERSION: u8 = 2;
+/// The minor version of SDL2_image that these bindings are for.
+pub const SDL_IMAGE_MINOR_VERSION: u8 = 6;
+/// The patch version of SDL2_image that these bindings are for.
+pub const SDL_IMAGE_PATCHLEVEL: u8 = 3;
+
+/// Fills in the version of SDL2_image that these bindings are for.
+///
+/// To get the version of the library you're actually linked to, use
+/// [`IMG_Linked_Version`].
+#[inline]
+pub fn SDL_IMAGE_VERSION(x: &mut SDL_version) {
+  x.major = SDL_IMAGE_MAJOR_VERSION;
+  x.minor = SDL_IMAGE_MINOR_VERSION;
+  x.patch = SDL_IMAGE_PATCHLEVEL;
+}
+
+/// Flags for the image formats that need extra support libraries.
+///
+/// See [`IMG_Init`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct IMG_InitFlags(pub c_int);
+impl_bit_ops_for_tuple_newtype!(IMG_InitFlags);
+#[allow(missing_docs)]
+pub const IMG_INIT_JPG: IMG_InitFlags = IMG_InitFlags(0x00000001);
+#[allow(missing_docs)]
+pub const IMG_INIT_PNG: IMG_InitFlags = IMG_InitFlags(0x00000002);
+#[allow(missing_docs)]
+pub const IMG_INIT_TIF: IMG_InitFlags = IMG_InitFlags(0x00000004);
+#[allow(missing_docs)]
+pub const IMG_INIT_WEBP: IMG_InitFlags = IMG_InitFlags(0x00000008);
+#[allow(missing_docs)]
+pub const IMG_INIT_JXL: IMG_InitFlags = IMG_InitFlags(0x00000010);
+#[allow(missing_docs)]
+pub const IMG_INIT_AVIF: IMG_InitFlags = IMG_InitFlags(0x00000020);
+
+/// An animated image, as a series of frames.
+///
+/// Free this with [`IMG_FreeAnimation`].
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct IMG_Animation {
+  /// Width of each frame.
+  pub w: c_int,
+  /// Height of each frame.
+  pub h: c_int,
+  /// The number of frames.
+  pub count: c_int,
+  /// Array of `count` frames.
+  pub frames: *mut *mut SDL_Surface,
+  /// Array of `count` delays (in milliseconds), one per frame.
+  pub delays: *mut c_int,
+}
+
+extern "C" {
+  /// Gets the version of the SDL2_image library that's linked.
+  pub fn IMG_Linked_Version() -> *const SDL_version;
+
+  /// Loads the support libraries for the image formats given.
+  ///
+  /// You don't need to call this, the support libraries are loaded when
+  /// they're first needed. Calling it ahead of time avoids the delay during
+  /// the first load, and lets you check which formats are available.
+  ///
+  /// **Returns:** The flags of the formats that are now initialized. Check
+  /// this against what you asked for, and use [`SDL_GetErrorMsg`] to see why
+  /// something failed.
+  pub fn IMG_Init(flags: IMG_InitFlags) -> IMG_InitFlags;
+
+  /// Unloads the libraries that were loaded by [`IMG_Init`].
+  pub fn IMG_Quit();
+
+  /// Loads an image from an [`SDL_RWops`], using `type_` as a hint.
+  ///
+  /// * `freesrc` non-zero to close/free the `SDL_RWops` before returning.
+  /// * `type_` a string like `"PNG"` or `"JPG"`. Formats that can be detected
+  ///   from the data are detected anyway, but some formats (eg: TGA) can only
+  ///   be loaded with the right hint.
+  ///
+  /// **Returns:** The new surface, or null on error.
+  pub fn IMG_LoadTyped_RW(
+    src: *mut SDL_RWops, freesrc: c_int, type_: *const c_char,
+  ) -> *mut SDL_Surface;
+
+  /// Loads an image from a file.
+  ///
+  /// **Returns:** The new surface, or null on error.
+  pub fn IMG_Load(file: *const c_char) -> *mut SDL_Surface;
+
+  /// Loads an image from an [`SDL_RWops`].
+  ///
+  /// * `freesrc` non-zero to close/free the `SDL_RWops` before returning.
+  ///
+  /// **Returns:** The new surface, or null on error.
+  pub fn IMG_Load_RW(src: *mut SDL_RWops, freesrc: c_int) -> *mut SDL_Surface;
+
+  /// Loads an image from a file directly into a texture.
+  ///
+  /// **Returns:** The new texture, or null on error.
+  #[cfg(feature = "renderer")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "renderer")))]
+  pub fn IMG_LoadTexture(
+    renderer: *mut SDL_Renderer, file: *const c_char,
+  ) -> *mut SDL_Texture;
+
+  /// Loads an image from an [`SDL_RWops`] directly into a texture.
+  ///
+  /// * `freesrc` non-zero to close/free the `SDL_RWops` before returning.
+  ///
+  /// **Returns:** The new texture, or null on error.
+  #[cfg(feature = "renderer")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "renderer")))]
+  pub fn IMG_LoadTexture_RW(
+    renderer: *mut SDL_Renderer, src: *mut SDL_RWops, freesrc: c_int,
+  ) -> *mut SDL_Texture;
+
+  /// Loads an image from an [`SDL_RWops`] directly into a texture, using
+  /// `type_` as a hint.
+  ///
+  /// See [`IMG_LoadTyped_RW`].
+  #[cfg(feature = "renderer")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "renderer")))]
+  pub fn IMG_LoadTextureTyped_RW(
+    renderer: *mut SDL_Renderer, src: *mut SDL_RWops, freesrc: c_int,
+    type_: *const c_char,
+  ) -> *mut SDL_Texture;
+
+  /// Checks if the data is an AVIF image.
+  ///
+  /// All of the `IMG_is*` functions leave the stream position where it was.
+  ///
+  /// **Returns:** Non-zero if the data is in this format.
+  pub fn IMG_isAVIF(src: *mut SDL_RWops) -> c_int;
+  /// Checks if the data is an ICO image.
+  pub fn IMG_isICO(src: *mut SDL_RWops) -> c_int;
+  /// Checks if the data is a CUR image.
+  pub fn IMG_isCUR(src: *mut SDL_RWops) -> c_int;
+  /// Checks if the data is a BMP image.
+  pub fn IMG_isBMP(src: *mut SDL_RWops) -> c_int;
+  /// Checks if the data is a GIF image.
+  pub fn IMG_isGIF(src: *mut SDL_RWops) -> c_int;
+  /// Checks if the data is a JPG image.
+  pub fn IMG_isJPG(src: *mut SDL_RWops) -> c_int;
+  /// Checks if the data is a JXL image.
+  pub fn IMG_isJXL(src: *mut SDL_RWops) -> c_int;
+  /// Checks if the data is an LBM image.
+  pub fn IMG_isLBM(src: *mut SDL_RWops) -> c_int;
+  /// Checks if the data is a PCX image.
+  pub fn IMG_isPCX(src: *mut SDL_RWops) -> c_int;
+  /// Checks if the data is a PNG image.
+  pub fn IMG_isPNG(src: *mut SDL_RWops) -> c_int;
+  /// Checks if the data is a PNM image.
+  pub fn IMG_isPNM(src: *mut SDL_RWops) -> c_int;
+  /// Checks if the data is an SVG image.
+  pub fn IMG_isSVG(src: *mut SDL_RWops) -> c_int;
+  /// Checks if the data is a QOI image.
+  pub fn IMG_isQOI(src: *mut SDL_RWops) -> c_int;
+  /// Checks if the data is a TIF image.
+  pub fn IMG_isTIF(src: *mut SDL_RWops) -> c_int;
+  /// Checks if the data is an XCF image.
+  pub fn IMG_isXCF(src: *mut SDL_RWops) -> c_int;
+  /// Checks if the data is an XPM image.
+  pub fn IMG_isXPM(src: *mut SDL_RWops) -> c_int;
+  /// Checks if the data is an XV image.
+  pub fn IMG_isXV(src: *mut SDL_RWops) -> c_int;
+  /// Checks if the data is a WEBP image.
+  pub fn IMG_isWEBP(src: *mut SDL_RWops) -> c_int;
+
+  /// Loads an AVIF image.
+  ///
+  /// None of the format specific loaders close the [`SDL_RWops`].
+  ///
+  /// **Returns:** The new surface, or null on error.
+  pub fn IMG_LoadAVIF_RW(src: *mut SDL_RWops) -> *mut SDL_Surface;
+  /// Loads an ICO image.
+  pub fn IMG_LoadICO_RW(src: *mut SDL_RWops) -> *mut SDL_Surface;
+  /// Loads a CUR image.
+  pub fn IMG_LoadCUR_RW(src: *mut SDL_RWops) -> *mut SDL_Surface;
+  /// Loads a BMP image.
+  pub fn IMG_LoadBMP_RW(src: *mut SDL_RWops) -> *mut SDL_Surface;
+  /// Loads a GIF image (only the first frame).
+  pub fn IMG_LoadGIF_RW(src: *mut SDL_RWops) -> *mut SDL_Surface;
+  /// Loads a JPG image.
+  pub fn IMG_LoadJPG_RW(src: *mut SDL_RWops) -> *mut SDL_Surface;
+  /// Loads a JXL image.
+  pub fn IMG_LoadJXL_RW(src: *mut SDL_RWops) -> *mut SDL_Surface;
+  /// Loads an LBM image.
+  pub fn IMG_LoadLBM_RW(src: *mut SDL_RWops) -> *mut SDL_Surface;
+  /// Loads a PCX image.
+  pub fn IMG_LoadPCX_RW(src: *mut SDL_RWops) -> *mut SDL_Surface;
+  /// Loads a PNG image.
+  pub fn IMG_LoadPNG_RW(src: *mut SDL_RWops) -> *mut SDL_Surface;
+  /// Loads a PNM image.
+  pub fn IMG_LoadPNM_RW(src: *mut SDL_RWops) -> *mut SDL_Surface;
+  /// Loads an SVG image, at its own size.
+  pub fn IMG_LoadSVG_RW(src: *mut SDL_RWops) -> *mut SDL_Surface;
+  /// Loads a QOI image.
+  pub fn IMG_LoadQOI_RW(src: *mut SDL_RWops) -> *mut SDL_Surface;
+  /// Loads a TGA image.
+  pub fn IMG_LoadTGA_RW(src: *mut SDL_RWops) -> *mut SDL_Surface;
+  /// Loads a TIF image.
+  pub fn IMG_LoadTIF_RW(src: *mut SDL_RWops) -> *mut SDL_Surface;
+  /// Loads an XCF image.
+  pub fn IMG_LoadXCF_RW(src: *mut SDL_RWops) -> *mut SDL_Surface;
+  /// Loads an XPM image.
+  pub fn IMG_LoadXPM_RW(src: *mut SDL_RWops) -> *mut SDL_Surface;
+  /// Loads an XV image.
+  pub fn IMG_LoadXV_RW(src: *mut SDL_RWops) -> *mut SDL_Surface;
+  /// Loads a WEBP image.
+  pub fn IMG_LoadWEBP_RW(src: *mut SDL_RWops) -> *mut SDL_Surface;
+
+  /// Loads an SVG image, scaled to the size given.
+  ///
+  /// If only one of `width` or `height` is non-zero, the other is picked to
+  /// keep the aspect ratio. If both are zero the image's own size is used.
+  ///
+  /// **Returns:** The new surface, or null on error.
+  pub fn IMG_LoadSizedSVG_RW(
+    src: *mut SDL_RWops, width: c_int, height: c_int,
+  ) -> *mut SDL_Surface;
+
+  /// Loads an XPM image from an array of lines (as with an XPM C include).
+  ///
+  /// **Returns:** The new surface, or null on error.
+  pub fn IMG_ReadXPMFromArray(xpm: *mut *mut c_char) -> *mut SDL_Surface;
+
+  /// As [`IMG_ReadXPMFromArray`], but the surface is always in the
+  /// `SDL_PIXELFORMAT_RGB888` format.
+  pub fn IMG_ReadXPMFromArrayToRGB888(
+    xpm: *mut *mut c_char,
+  ) -> *mut SDL_Surface;
+
+  /// Saves a surface as a PNG file.
+  ///
+  /// **Returns:** 0 on success, or a negative error code on failure.
+  pub fn IMG_SavePNG(surface: *mut SDL_Surface, file: *const c_char) -> c_int;
+
+  /// Saves a surface as PNG data into an [`SDL_RWops`].
+  ///
+  /// * `freedst` non-zero to close/free the `SDL_RWops` before returning.
+  ///
+  /// **Returns:** 0 on success, or a negative error code on failure.
+  pub fn IMG_SavePNG_RW(
+    surface: *mut SDL_Surface, dst: *mut SDL_RWops, freedst: c_int,
+  ) -> c_int;
+
+  /// Saves a surface as a JPG file.
+  ///
+  /// * `quality` from 0 to 100.
+  ///
+  /// **Returns:** 0 on success, or a negative error code on failure.
+  pub fn IMG_SaveJPG(
+    surface: *mut SDL_Surface, file: *const c_char, quality: c_int,
+  ) -> c_int;
+
+  /// Saves a surface as JPG data into an [`SDL_RWops`].
+  ///
+  /// * `freedst` non-zero to close/free the `SDL_RWops` before returning.
+  /// * `quality` from 0 to 100.
+  ///
+  /// **Returns:** 0 on success, or a negative error code on failure.
+  pub fn IMG_SaveJPG_RW(
+    surface: *mut SDL_Surface, dst: *mut SDL_RWops, freedst: c_int,
+    quality: c_int,
+  ) -> c_int;
+
+  /// Loads an animation from a file.
+  ///
+  /// **Returns:** The new animation, or null on error.
+  pub fn IMG_LoadAnimation(file: *const c_char) -> *mut IMG_Animation;
+
+  /// Loads an animation from an [`SDL_RWops`].
+  ///
+  /// * `freesrc` non-zero to close/free the `SDL_RWops` before returning.
+  ///
+  /// **Returns:** The new animation, or null on error.
+  pub fn IMG_LoadAnimation_RW(
+    src: *mut SDL_RWops, freesrc: c_int,
+  ) -> *mut IMG_Animation;
+
+  /// Loads an animation from an [`SDL_RWops`], using `type_` as a hint.
+  ///
+  /// See [`IMG_LoadTyped_RW`].
+  pub fn IMG_LoadAnimationTyped_RW(
+    src: *mut SDL_RWops, freesrc: c_int, type_: *const c_char,
+  ) -> *mut IMG_Animation;
+
+  /// Frees an animation, along with all of its frames.
+  pub fn IMG_FreeAnimation(anim: *mut IMG_Animation);
+
+  /// Loads a GIF animation.
+  ///
+  /// This doesn't close the [`SDL_RWops`].
+  ///
+  /// **Returns:** The new animation, or null on error.
+  pub fn IMG_LoadGIFAnimation_RW(src: *mut SDL_RWops) -> *mut IMG_Animation;
+}
@@ -26,6 +26,8 @@
 
 use crate::{c_char, c_int, c_void, stdinc::*};
 
+pub use crate::guid::{ParseGuidError, SDL_GUID};
+
 // makes rustdoc link properly!
 #[allow(unused)]
 use crate::events::*;
@@ -40,12 +42,10 @@ use crate::*;
 pub struct SDL_Joystick(c_void);
 
 /// A structure that encodes the stable unique id for a joystick device.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[repr(C)]
-#[allow(missing_docs)]
-pub struct SDL_JoystickGUID {
-  pub data: [u8; 16],
-}
+///
+/// This is the same type as [`SDL_GUID`], so it can also be used with the
+/// functions of the [`guid`](crate::guid) module.
+pub type SDL_JoystickGUID = SDL_GUID;
 
 /// This is a unique ID for a joystick for the time it is connected to the
 /// system.
@@ -64,6 +64,7 @@ pub struct SDL_JoystickID(pub i32);
 ///
 /// See `SDL_JOYSTICK_TYPE_*`
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_JoystickType(pub i32);
 
@@ -91,10 +92,107 @@ pub const SDL_JOYSTICK_TYPE_ARCADE_PAD: SDL_JoystickType = SDL_JoystickType(8);
 #[allow(missing_docs)]
 pub const SDL_JOYSTICK_TYPE_THROTTLE: SDL_JoystickType = SDL_JoystickType(9);
 
+/// The current version of the [`SDL_VirtualJoystickDesc`] structure.
+#[cfg(feature = "sdl2_2_24")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_24")))]
+pub const SDL_VIRTUAL_JOYSTICK_DESC_VERSION: Uint16 = 1;
+
+/// The structure that defines an extended virtual joystick description.
+///
+/// The `version` must be [`SDL_VIRTUAL_JOYSTICK_DESC_VERSION`] before this is
+/// passed to [`SDL_JoystickAttachVirtualEx`]. All other fields are optional
+/// and can be left 0. The `Default` value is all zeroes with the version
+/// already filled in.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+#[cfg(feature = "sdl2_2_24")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_24")))]
+pub struct SDL_VirtualJoystickDesc {
+  /// [`SDL_VIRTUAL_JOYSTICK_DESC_VERSION`]
+  pub version: Uint16,
+  /// An [`SDL_JoystickType`] value.
+  pub type_: Uint16,
+  /// The number of axes on this joystick.
+  pub naxes: Uint16,
+  /// The number of buttons on this joystick.
+  pub nbuttons: Uint16,
+  /// The number of hats on this joystick.
+  pub nhats: Uint16,
+  /// The USB vendor ID of this joystick.
+  pub vendor_id: Uint16,
+  /// The USB product ID of this joystick.
+  pub product_id: Uint16,
+  /// unused
+  pub padding: Uint16,
+  /// A mask of which buttons are valid for this controller, e.g.
+  /// `1 << SDL_CONTROLLER_BUTTON_A.0`.
+  pub button_mask: Uint32,
+  /// A mask of which axes are valid for this controller, e.g.
+  /// `1 << SDL_CONTROLLER_AXIS_LEFTX.0`.
+  pub axis_mask: Uint32,
+  /// The name of the joystick.
+  pub name: *const c_char,
+  /// User data pointer passed to callbacks.
+  pub userdata: *mut c_void,
+  /// Called when the joystick state should be updated.
+  pub Update: Option<unsafe extern "C" fn(userdata: *mut c_void)>,
+  /// Called when the player index is set.
+  pub SetPlayerIndex:
+    Option<unsafe extern "C" fn(userdata: *mut c_void, player_index: c_int)>,
+  /// Implements [`SDL_JoystickRumble`].
+  pub Rumble: Option<
+    unsafe extern "C" fn(
+      userdata: *mut c_void,
+      low_frequency_rumble: Uint16,
+      high_frequency_rumble: Uint16,
+    ) -> c_int,
+  >,
+  /// Implements [`SDL_JoystickRumbleTriggers`].
+  pub RumbleTriggers: Option<
+    unsafe extern "C" fn(
+      userdata: *mut c_void,
+      left_rumble: Uint16,
+      right_rumble: Uint16,
+    ) -> c_int,
+  >,
+  /// Implements [`SDL_JoystickSetLED`].
+  pub SetLED: Option<
+    unsafe extern "C" fn(
+      userdata: *mut c_void,
+      red: Uint8,
+      green: Uint8,
+      blue: Uint8,
+    ) -> c_int,
+  >,
+  /// Implements [`SDL_JoystickSendEffect`].
+  pub SendEffect: Option<
+    unsafe extern "C" fn(
+      userdata: *mut c_void,
+      data: *const c_void,
+      size: c_int,
+    ) -> c_int,
+  >,
+}
+#[cfg(feature = "sdl2_2_24")]
+impl Default for SDL_VirtualJoystickDesc {
+  #[inline]
+  fn default() -> Self {
+    let mut desc: Self = unsafe { core::mem::zeroed() };
+    desc.version = SDL_VIRTUAL_JOYSTICK_DESC_VERSION;
+    desc
+  }
+}
+#[cfg(all(target_pointer_width = "64", feature = "sdl2_2_24"))]
+const _: () = {
+  use core::mem::size_of;
+  assert!(size_of::<SDL_VirtualJoystickDesc>() == 88);
+};
+
 /// The power level of a joystick.
 ///
 /// See `SDL_JOYSTICK_POWER_*`
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_JoystickPowerLevel(pub i32);
 
@@ -242,6 +340,23 @@ extern "C" {
     type_: SDL_JoystickType, naxes: c_int, nbuttons: c_int, nhats: c_int,
   ) -> c_int;
 
+  /// Attaches a new virtual joystick with extended properties.
+  ///
+  /// Returns the joystick's device index, or -1 if an error occurred.
+  #[cfg(feature = "sdl2_2_24")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_24")))]
+  pub fn SDL_JoystickAttachVirtualEx(
+    desc: *const SDL_VirtualJoystickDesc,
+  ) -> c_int;
+
+  /// Detaches a virtual joystick.
+  ///
+  /// * `device_index` a value previously returned from
+  ///   [`SDL_JoystickAttachVirtual`] or [`SDL_JoystickAttachVirtualEx`].
+  ///
+  /// Returns 0 on success, or -1 if an error occurred.
+  pub fn SDL_JoystickDetachVirtual(device_index: c_int) -> c_int;
+
   /// Indicates whether or not a virtual-joystick is at a given device index.
   pub fn SDL_JoystickIsVirtual(device_index: c_int) -> SDL_bool;
 
@@ -464,11 +579,68 @@ extern "C" {
     joystick: *mut SDL_Joystick, red: Uint8, green: Uint8, blue: Uint8,
   ) -> c_int;
 
+  /// Send a joystick specific effect packet.
+  ///
+  /// * `joystick` The joystick to affect
+  /// * `data` The data to send to the joystick
+  /// * `size` The size of the data to send to the joystick
+  ///
+  /// **Returns:** 0, or -1 if this joystick or driver doesn't support effect
+  /// packets.
+  #[cfg(feature = "sdl2_2_0_16")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_16")))]
+  pub fn SDL_JoystickSendEffect(
+    joystick: *mut SDL_Joystick, data: *const c_void, size: c_int,
+  ) -> c_int;
+
   /// Close a joystick previously opened with [`SDL_JoystickOpen`].
   pub fn SDL_JoystickClose(joystick: *mut SDL_Joystick);
 
   /// Return the battery level of this joystick.
+  ///
+  /// Wireless controllers often only report this coarsely, and wired ones give
+  /// [`SDL_JOYSTICK_POWER_WIRED`]. With SDL 2.24 or later you'll also get an
+  /// [`SDL_JOYBATTERYUPDATED`] event when the level changes.
+  ///
+  /// **Returns:** The power level, or [`SDL_JOYSTICK_POWER_UNKNOWN`] if it
+  /// can't be determined.
   pub fn SDL_JoystickCurrentPowerLevel(
     joystick: *mut SDL_Joystick,
   ) -> SDL_JoystickPowerLevel;
 }
+
+/// Formats a joystick GUID with [`SDL_JoystickGetGUIDString`].
+///
+/// This gives the same 32 hex digits as the `Display` impl of [`SDL_GUID`],
+/// but the text comes from SDL itself.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "alloc")))]
+#[must_use]
+pub fn joystick_guid_string(guid: SDL_JoystickGUID) -> alloc::string::String {
+  let mut buf = [0_u8; 33];
+  unsafe {
+    SDL_JoystickGetGUIDString(guid, buf.as_mut_ptr().cast(), buf.len() as c_int)
+  };
+  let len = buf.iter().position(|&b| b == 0).unwrap_or(buf.len());
+  alloc::string::String::from_utf8_lossy(&buf[..len]).into_owned()
+}
+
+/// Parses a joystick GUID with [`SDL_JoystickGetGUIDFromString`].
+///
+/// This follows SDL's rules, so bad hex digits quietly become zero bits and
+/// only the first 32 characters are looked at. The `FromStr` impl of
+/// [`SDL_GUID`] is the strict version, which rejects anything that isn't
+/// exactly 32 hex digits.
+///
+/// **Returns:** `None` if `s` contains a null byte.
+#[must_use]
+pub fn joystick_guid_from_string(s: &str) -> Option<SDL_JoystickGUID> {
+  if s.as_bytes().contains(&0) {
+    return None;
+  }
+  // SDL never reads more than 32 characters, so that's all that's copied.
+  let mut buf = [0_u8; 33];
+  let len = s.len().min(32);
+  buf[..len].copy_from_slice(&s.as_bytes()[..len]);
+  Some(unsafe { SDL_JoystickGetGUIDFromString(buf.as_ptr().cast()) })
+}
@@ -4,6 +4,10 @@ use crate::{
   c_char, c_int, keycode::*, rect::*, scancode::*, stdinc::*, video::*,
 };
 
+// makes rustdoc link properly!
+#[allow(unused)]
+use crate::events::*;
+
 /// SDL uses this for key events.
 ///
 /// Key events are for raw key events. For translated textual input you want the
@@ -104,6 +108,18 @@ extern "C" {
   /// See Also: [`SDL_StartTextInput`], [`SDL_HasScreenKeyboardSupport`]
   pub fn SDL_StopTextInput();
 
+  /// Dismiss the composition window/IME without disabling the subsystem.
+  ///
+  /// See Also: [`SDL_StartTextInput`], [`SDL_StopTextInput`]
+  #[cfg(feature = "sdl2_2_0_22")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_22")))]
+  pub fn SDL_ClearComposition();
+
+  /// Returns if an IME Composite or Candidate window is currently shown.
+  #[cfg(feature = "sdl2_2_0_22")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_22")))]
+  pub fn SDL_IsTextInputShown() -> SDL_bool;
+
   /// Set the rectangle used to type Unicode text inputs.
   ///
   /// This is used as a hint for IME and on-screen keyboard placement.
@@ -131,3 +147,23 @@ extern "C" {
   /// See Also: [`SDL_HasScreenKeyboardSupport`]
   pub fn SDL_IsScreenKeyboardShown(window: *mut SDL_Window) -> SDL_bool;
 }
+
+/// As [`SDL_GetKeyboardState`], but gives the array as a slice.
+///
+/// Index the slice with an [`SDL_Scancode`] value, a key is down if its
+/// entry is 1. (Use `get` when indexing, some scancodes are past the end.)
+///
+/// The slice is SDL's own internal state array, so it's valid for the whole
+/// time that SDL is initialized, and it's updated in place whenever events are
+/// processed (eg: by [`SDL_PollEvent`]).
+#[inline]
+#[must_use]
+pub unsafe fn keyboard_state() -> &'static [u8] {
+  let mut numkeys: c_int = 0;
+  let p = SDL_GetKeyboardState(&mut numkeys);
+  if p.is_null() {
+    &[]
+  } else {
+    core::slice::from_raw_parts(p, numkeys.max(0) as usize)
+  }
+}
@@ -2,7 +2,11 @@
 
 //! Logical key mappings (changes by locale).
 
+#[cfg(feature = "keycode_names")]
+use crate::error::ParseNameError;
 use crate::scancode::*;
+#[cfg(feature = "keycode_names")]
+use core::str::FromStr;
 
 // makes rustdoc link properly!
 #[allow(unused)]
@@ -23,6 +27,7 @@ use crate::keyboard::*;
 ///
 /// See all the constants named `SDLK_*`
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_Keycode(pub i32);
 
@@ -132,7 +137,7 @@ pub const SDLK_INSERT: SDL_Keycode =
 pub const SDLK_HOME: SDL_Keycode = SDL_SCANCODE_TO_KEYCODE(SDL_SCANCODE_HOME);
 pub const SDLK_PAGEUP: SDL_Keycode =
   SDL_SCANCODE_TO_KEYCODE(SDL_SCANCODE_PAGEUP);
-pub const SDLK_DELETE: SDL_Keycode = SDL_Keycode(177);
+pub const SDLK_DELETE: SDL_Keycode = SDL_Keycode(b'\x7F' as i32);
 pub const SDLK_END: SDL_Keycode = SDL_SCANCODE_TO_KEYCODE(SDL_SCANCODE_END);
 pub const SDLK_PAGEDOWN: SDL_Keycode =
   SDL_SCANCODE_TO_KEYCODE(SDL_SCANCODE_PAGEDOWN);
@@ -375,6 +380,7 @@ pub const SDLK_AUDIOFASTFORWARD: SDL_Keycode =
 ///
 /// See all the constants named `KMOD_*`
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_Keymod(pub i32);
 impl_bit_ops_for_tuple_newtype!(SDL_Keymod);
@@ -408,3 +414,105 @@ pub const KMOD_CTRL: SDL_Keymod = SDL_Keymod(KMOD_LCTRL.0 | KMOD_RCTRL.0);
 pub const KMOD_SHIFT: SDL_Keymod = SDL_Keymod(KMOD_LSHIFT.0 | KMOD_RSHIFT.0);
 pub const KMOD_ALT: SDL_Keymod = SDL_Keymod(KMOD_LALT.0 | KMOD_RALT.0);
 pub const KMOD_GUI: SDL_Keymod = SDL_Keymod(KMOD_LGUI.0 | KMOD_RGUI.0);
+
+#[cfg(feature = "keycode_names")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "keycode_names")))]
+impl SDL_Keycode {
+  /// The canonical name of this keycode.
+  ///
+  /// This gives the same text as [`SDL_GetKeyName`], but it's looked up within
+  /// the crate, so SDL doesn't need to be initialized for it to work.
+  ///
+  /// * Keycodes made from a scancode use that scancode's name (see
+  ///   [`SDL_Scancode::name`]).
+  /// * Printable ASCII keycodes are named by their character, with letters
+  ///   given in upper case (`SDLK_a` is named `"A"`).
+  /// * Return, Escape, Backspace, Tab, Space, and Delete use their scancode's
+  ///   name.
+  ///
+  /// Anything else (eg: a non-ASCII character from a non-US layout, or a
+  /// non-printing control character) gives `None`. For a non-ASCII key you can
+  /// still use `char::from_u32` on the keycode value.
+  #[must_use]
+  pub fn name(self) -> Option<&'static str> {
+    if self.0 & SDLK_SCANCODE_MASK != 0 {
+      return SDL_Scancode(self.0 & !SDLK_SCANCODE_MASK).name();
+    }
+    match self {
+      SDLK_RETURN => SDL_SCANCODE_RETURN.name(),
+      SDLK_ESCAPE => SDL_SCANCODE_ESCAPE.name(),
+      SDLK_BACKSPACE => SDL_SCANCODE_BACKSPACE.name(),
+      SDLK_TAB => SDL_SCANCODE_TAB.name(),
+      SDLK_SPACE => SDL_SCANCODE_SPACE.name(),
+      SDLK_DELETE => SDL_SCANCODE_DELETE.name(),
+      SDL_Keycode(k @ 0x21..=0x7E) => core::str::from_utf8(
+        core::slice::from_ref(&ASCII_KEY_NAMES[k as usize]),
+      )
+      .ok(),
+      _ => None,
+    }
+  }
+
+  /// Looks up a keycode by its canonical name.
+  ///
+  /// This works like [`SDL_GetKeyFromName`] does with SDL's default (US)
+  /// keymap, but it doesn't call into SDL.
+  ///
+  /// * A name that's a single character is that character's keycode (with
+  ///   ASCII letters made lower case, so `"A"` gives `SDLK_a`).
+  /// * Otherwise the name is looked up as a scancode name (case-insensitive),
+  ///   and the keycode is the one the default keymap gives for that scancode.
+  #[must_use]
+  pub fn from_name(name: &str) -> Option<Self> {
+    let mut chars = name.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+      return Some(SDL_Keycode(c.to_ascii_lowercase() as i32));
+    }
+    let scancode = SDL_Scancode::from_name(name)?;
+    Some(match scancode {
+      SDL_SCANCODE_RETURN => SDLK_RETURN,
+      SDL_SCANCODE_ESCAPE => SDLK_ESCAPE,
+      SDL_SCANCODE_BACKSPACE => SDLK_BACKSPACE,
+      SDL_SCANCODE_TAB => SDLK_TAB,
+      SDL_SCANCODE_SPACE => SDLK_SPACE,
+      SDL_SCANCODE_DELETE => SDLK_DELETE,
+      other => SDL_SCANCODE_TO_KEYCODE(other),
+    })
+  }
+}
+
+/// Parses a key name with [`SDL_Keycode::from_name`].
+///
+/// This accepts the same names that [`SDL_Keycode::name`] gives back (eg:
+/// `"A"`, `"Left Shift"`), using SDL's default keymap. Unknown names (and the
+/// empty string) are an error rather than [`SDLK_UNKNOWN`].
+#[cfg(feature = "keycode_names")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "keycode_names")))]
+impl FromStr for SDL_Keycode {
+  type Err = ParseNameError;
+  #[inline]
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Self::from_name(s).ok_or(ParseNameError)
+  }
+}
+#[cfg(feature = "keycode_names")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "keycode_names")))]
+impl TryFrom<&str> for SDL_Keycode {
+  type Error = ParseNameError;
+  #[inline]
+  fn try_from(s: &str) -> Result<Self, Self::Error> {
+    s.parse()
+  }
+}
+
+/// Each ASCII byte, made upper case, for building single character key names.
+#[cfg(feature = "keycode_names")]
+static ASCII_KEY_NAMES: [u8; 128] = {
+  let mut names = [0_u8; 128];
+  let mut i = 0;
+  while i < names.len() {
+    names[i] = (i as u8).to_ascii_uppercase();
+    i += 1;
+  }
+  names
+};
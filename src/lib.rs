@@ -3,6 +3,9 @@
 #![warn(missing_docs)]
 #![allow(clippy::missing_safety_doc)]
 #![cfg_attr(docs_rs, feature(doc_cfg))]
+// SDL3 reuses many SDL2 function names with new signatures. Only one of the
+// two libraries is ever linked, so the "clashing" declarations are expected.
+#![cfg_attr(feature = "sdl3", allow(clashing_extern_declarations))]
 
 //! Bindings to the SDL2 C library.
 //!
@@ -13,18 +16,215 @@
 //! (note that in between SDL2 changed their version number policy, so new
 //! versions now update the middle value). On Windows MSVC the "system" version
 //! will be SDL2's official pre-built development files that come bundled in the
-//! crate.
+//! crate. Elsewhere the system SDL2 is found with `pkg-config` (which respects
+//! `PKG_CONFIG_PATH`, `PKG_CONFIG_SYSROOT_DIR`, etc, for cross compiling), or
+//! with `sdl2-config` if `pkg-config` can't find it.
 //!
-//! If you use the `static_bundled_build` cargo feature then this will build
-//! SDL2 using a bundled copy of the source and then statically link to that.
-//! Building SDL2 can take a bit during that first build (usually 1 minute or
-//! more).
+//! If you use the `static_bundled_build` cargo feature (or `bundled`, which is
+//! just another name for it) then this will build SDL2 using a bundled copy of
+//! the source and then statically link to that. No system SDL2 is needed, only
+//! a C compiler and CMake. Building SDL2 can take a bit during that first build
+//! (usually 1 minute or more).
+//!
+//! ## Environment Variables
+//! These let you point the build at your own SDL2 (a patched fork, a debug
+//! build, etc) without editing anything. They don't affect a
+//! `static_bundled_build`.
+//! * `FERMIUM_SDL2_LIB_DIR`: The folder with the SDL2 library to link. When
+//!   this is set, all other ways of finding SDL2 are skipped.
+//! * `FERMIUM_SDL2_INCLUDE_DIR`: The folder with that build's headers. This
+//!   is only passed along to other build scripts, as `DEP_SDL2_INCLUDE`.
+//! * `FERMIUM_SDL2_LINK_KIND`: Either `static` or `dylib`. This overrides the
+//!   `static_link` feature.
 //!
 //! # Crate Features
 //! * `cargo_check`: This causes the entire build.rs to exit early. This is
 //!   useful if you don't need to make an executable, such as `cargo check` or
 //!   `cargo doc`.
+//! * `full` (default): Turns on all of the following subsystem features.
+//!   * `audio`: The [`audio`] module.
+//!   * `renderer`: The [`renderer`] module.
+//!   * `joystick`: The [`joystick`] module, and the joystick events.
+//!   * `gamecontroller`: The [`gamecontroller`] module, and the controller
+//!     events. This also enables `joystick` and `sensor`.
+//!   * `sensor`: The [`sensor`] module, and the sensor event.
+//!   * `haptic`: The [`haptic`] module, for force feedback. This also enables
+//!     `joystick`.
+//!   * `keycode_names`: The scancode and keycode name tables
+//!     ([`SDL_Scancode::name`](scancode::SDL_Scancode::name) and friends).
+//!
+//!   There's no `video` feature: the [`video`] module (along with events,
+//!   keyboard, and mouse) is always included. SDL2's events carry window IDs,
+//!   and most of its input functions take a window, so those can't be split
+//!   apart. Vulkan support is the separate `vulkan` feature (below), which
+//!   isn't part of `full`.
+//! * `sdl2_2_26` (default): The newest of the SDL2 version features. These
+//!   are `sdl2_2_0_16`, `sdl2_2_0_18`, `sdl2_2_0_20`, `sdl2_2_0_22`,
+//!   `sdl2_2_24`, and `sdl2_2_26`, and each one also enables all the older
+//!   ones. Items that were added to SDL2 after 2.0.14 are only available with
+//!   the feature for that SDL2 version (or newer). If you turn off the default
+//!   features and pick an older version, then using anything that the
+//!   version's SDL2 doesn't have is a compile error, instead of a link error
+//!   or a crash when your program runs with that SDL2.
+//! * `alloc`: Enables helpers that need the `alloc` crate, such as
+//!   [`get_clipboard_text`](clipboard::get_clipboard_text).
+//! * `static_link`: Statically links the system SDL2 instead of dynamically
+//!   linking it. On Windows MSVC this needs an `SDL2-static.lib` in the
+//!   developer files folder, elsewhere it uses `sdl2-config --static-libs` to
+//!   find `libSDL2.a` and all the system libraries that SDL2 depends on. This
+//!   is how to make a fully static binary for a target such as
+//!   `x86_64-unknown-linux-musl`, using an SDL2 that was built for that
+//!   target. The extension libraries (`image`, `mixer`, etc) are still
+//!   dynamically linked.
+//! * `vcpkg`: Finds SDL2 with [vcpkg](https://vcpkg.io) (using the `vcpkg`
+//!   crate) instead of the developer files or `sdl2-config`. The libraries of
+//!   the extension features (`image`, `mixer`, etc) are found there too. On
+//!   Windows MSVC the triplet is `x64-windows` by default, or
+//!   `x64-windows-static(-md)` with `static_link`, and setting
+//!   `VCPKGRS_TRIPLET` overrides that. Any DLLs are copied into `OUT_DIR`, and
+//!   their paths are passed along to your `build.rs` as the `PATH`-style list
+//!   `DEP_SDL2_VCPKG_DLLS`, so it can copy them next to the final binary.
+//! * `prebuilt`: (Windows only) Downloads SDL2's official development archive
+//!   (`SDL2-devel-2.26.5-VC.zip`, or the `mingw` one for GNU targets) and links
+//!   to that. This uses the `curl` and `tar` programs that come with Windows.
+//!   The archive is cached in `target/fermium-prebuilt` (or
+//!   `FERMIUM_SDL2_PREBUILT_CACHE`), so later builds work offline. The
+//!   archive is always checked against the SHA-256 that the build script
+//!   pins for it, or against `FERMIUM_SDL2_PREBUILT_SHA256` if that's set.
+//!   `FERMIUM_SDL2_PREBUILT_URL` sets a mirror to download from instead of
+//!   SDL's GitHub releases.
+//! * `copy_dll`: (Windows and macOS) Copies the SDL2 runtime library next to
+//!   the binaries, tests, and examples in the target directory, so that they
+//!   run without SDL2 being installed or on the `PATH`. Either way, when the
+//!   build script can find that library its path is passed to your `build.rs`
+//!   as `DEP_SDL2_DLL`, which you can use when packaging your program.
+//! * `vulkan`: The [`vulkan`] module.
+//! * `ash_types`: Makes the [`vulkan`] module use the handle and function
+//!   pointer types of the [`ash`](https://docs.rs/ash) crate. This also enables
+//!   `vulkan`.
+//! * `metal_types`: (macOS and iOS only) Makes the [`metal`] module use the
+//!   layer type of the [`metal`](https://docs.rs/metal) crate, and adds
+//!   [`metal_layer`](metal::metal_layer).
+//! * `jni`: (Android only) Makes
+//!   [`SDL_AndroidGetJNIEnv`](system::SDL_AndroidGetJNIEnv) and
+//!   [`SDL_AndroidGetActivity`](system::SDL_AndroidGetActivity) return the
+//!   [`jni-sys`](https://docs.rs/jni-sys) types (the same ones as `jni::sys`),
+//!   so they can be used with the `jni` crate directly.
+//! * `windows_interop`: (Windows only) Adds
+//!   [`d3d9_device`](system::d3d9_device),
+//!   [`d3d11_device`](system::d3d11_device), and
+//!   [`d3d12_device`](system::d3d12_device), which return the renderer's
+//!   Direct3D device as the [`windows`](https://docs.rs/windows) crate's
+//!   interface type (releasing SDL's reference when dropped). This also
+//!   enables `renderer`.
+//! * `delay_load`: (Windows MSVC only) Links `SDL2.dll` as a delay-loaded DLL.
+//!   The DLL isn't loaded until the first SDL2 function is called, so your
+//!   program can first check that it's present (eg: with `LoadLibraryW`) and
+//!   show a friendly message if it isn't. Cargo doesn't forward linker
+//!   arguments from a dependency, so the binary's own `build.rs` also needs
+//!   to do this:
+//!   ```no_run
+//!   if let Ok(dll) = std::env::var("DEP_SDL2_DELAYLOAD") {
+//!     println!("cargo:rustc-link-arg=/DELAYLOAD:{dll}");
+//!   }
+//!   ```
+//!   (This only works when `fermium` is a direct dependency of that package.)
+//! * `sdl2main`: (Windows only) Links `SDL2main`, which provides the actual
+//!   `main` and `WinMain` entry points. It converts the command line to UTF-8,
+//!   calls [`SDL_SetMainReady`], and then calls *your* `SDL_main` function.
+//!   Because `main` comes from the C library, your binary must use
+//!   `#![no_main]` and export `SDL_main` itself:
+//!   ```ignore
+//!   #![no_main]
+//!   #![windows_subsystem = "windows"]
+//!   use fermium::{c_char, c_int};
+//!
+//!   #[no_mangle]
+//!   pub extern "C" fn SDL_main(argc: c_int, argv: *mut *mut c_char) -> c_int {
+//!     // your program goes here
+//!     0
+//!   }
+//!   ```
+//!   Using `windows_subsystem = "windows"` works either way: SDL2main provides
+//!   both entry points, so the link succeeds and SDL's setup still happens.
+//!   The [`main!`] macro can also export `SDL_main` for you (and it does the
+//!   right thing on Android and iOS as well).
+//!   Without this feature, keep a normal Rust `main` and call
+//!   [`SDL_SetMainReady`] before [`SDL_Init`]. The `static_bundled_build`
+//!   feature builds SDL2main along with SDL2, and always links it on Windows
+//!   (as it did before this feature existed). Otherwise, on MSVC you must
+//!   place `SDL2main.lib` (from SDL's official VC development files) into the
+//!   crate's `SDL2-2.26.5-devel` folder.
+//! * `image`: The [`image`] module, bindings to the SDL2_image library for
+//!   loading PNG, JPG, WebP, and other image formats. This links
+//!   `SDL2_image`, which must be installed separately. On Windows MSVC, put
+//!   `SDL2_image.lib` and `SDL2_image.dll` into the crate's
+//!   `SDL2-2.26.5-devel` folder. This can't be used with
+//!   `static_bundled_build`.
+//! * `mixer`: The [`mixer`] module, bindings to the SDL2_mixer library for
+//!   playing sound effects and music. This also enables `audio`. As with
+//!   `image`, `SDL2_mixer` must be installed separately (on Windows MSVC, put
+//!   its `.lib` and `.dll` in the `SDL2-2.26.5-devel` folder).
+//! * `ttf`: The [`ttf`] module, bindings to the SDL2_ttf library for
+//!   rendering text with TrueType fonts. This links `SDL2_ttf`, which must be
+//!   installed separately, the same as with `image`.
+//! * `net`: The [`net`] module, bindings to the SDL2_net library for TCP and
+//!   UDP sockets. This links `SDL2_net`, which must be installed separately,
+//!   the same as with `image`.
+//! * `gfx`: The [`gfx`] module, bindings to the SDL2_gfx library for drawing
+//!   primitives (circles, polygons, thick lines, etc), rotating and zooming
+//!   surfaces, and holding a steady framerate. This also enables `renderer`.
+//!   It links `SDL2_gfx`, which must be installed separately, the same as with
+//!   `image`.
+//! * `sdltest`: The [`sdltest`] module, bindings to parts of SDL's test
+//!   library (the common setup of SDL's test programs, the fuzzer, surface
+//!   comparison, and the debug font). This also enables `renderer`, `audio`,
+//!   and `sdl2_2_0_18` (the test library's structs have the newer fields no
+//!   matter which version you pick). It links the static `SDL2_test` library,
+//!   which comes with SDL's development files (and is part of
+//!   `static_bundled_build`). On Windows MSVC, put `SDL2test.lib` (from SDL's
+//!   official VC development files) into the crate's `SDL2-2.26.5-devel`
+//!   folder.
+//! * `controllerdb`: Builds in the community game controller database as
+//!   [`GAMECONTROLLERDB`](gamecontroller::GAMECONTROLLERDB), which
+//!   `gamecontroller::add_builtin_controller_mappings` loads.
+//! * `mock`: Doesn't link SDL2 at all. Instead the [`mock`] module provides
+//!   scriptable stand-ins for a core set of SDL2 functions, for unit testing.
+//! * `dynamic_load`: Doesn't link SDL2 at all. Instead the [`dynload`] module
+//!   opens SDL2 at runtime and gives you a table with a pointer to each of
+//!   the SDL2 functions that the crate binds.
+//! * `raw-window-handle`: Adds
+//!   [`get_raw_window_handle`](syswm::get_raw_window_handle) and
+//!   [`get_raw_display_handle`](syswm::get_raw_display_handle), for using SDL
+//!   windows with crates like `wgpu`, `ash`, and `softbuffer`.
+//! * `bytemuck`: Implements [`Zeroable`](https://docs.rs/bytemuck) and `Pod`
+//!   for the plain data structs (eg: [`SDL_Rect`](rect::SDL_Rect),
+//!   [`SDL_FPoint`](rect::SDL_FPoint), [`SDL_Color`](pixels::SDL_Color), and
+//!   `SDL_Vertex`), so that slices of them can be cast to bytes (eg: for GPU
+//!   uploads) without any `unsafe` code. Types with pointers in them (eg:
+//!   [`SDL_DisplayMode`](video::SDL_DisplayMode)) are only `Zeroable`.
+//! * `serde`: Implements `Serialize` and `Deserialize` for the value-like
+//!   types, such as the [`SDL_Keycode`](keycode::SDL_Keycode),
+//!   [`SDL_Scancode`](scancode::SDL_Scancode), and other newtype wrappers,
+//!   [`SDL_Rect`](rect::SDL_Rect), [`SDL_Color`](pixels::SDL_Color),
+//!   [`SDL_DisplayMode`](video::SDL_DisplayMode), and
+//!   [`SDL_GUID`](guid::SDL_GUID). The newtypes serialize as their inner
+//!   number. Types that are only handles for things that exist while the
+//!   program runs (eg: [`SDL_WindowID`](video::SDL_WindowID)) don't get these
+//!   impls.
+//! * `tracing`: Enables the [`traced`] module, which has versions of some key
+//!   calls (init, window and renderer creation, texture uploads, opening
+//!   audio, etc) that record [`tracing`](https://docs.rs/tracing) spans.
+//! * `sdl3`: Links to SDL3 instead of SDL2, and enables the [`sdl3`] module.
+//!   With this feature on, only the items within that module may be used.
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+// The C types are the ones from `core::ffi`, so they're the same types that
+// other FFI crates use, and each target gets the right definitions (eg:
+// `c_char` is unsigned on ARM Linux). They're re-exported here so that the
+// older `fermium::c_int` (etc) paths keep working.
 pub use core::ffi::{
   c_char, c_double, c_float, c_int, c_long, c_longlong, c_schar, c_short,
   c_uchar, c_uint, c_ulong, c_ulonglong, c_ushort, c_void,
@@ -85,6 +285,56 @@ macro_rules! impl_bit_ops_for_tuple_newtype {
   };
 }
 
+// With the `bytemuck` feature, marks types as `Zeroable`, which is sound for
+// any type where all zero bytes is a valid value (eg: integers, floats, and
+// raw pointers).
+macro_rules! impl_zeroable {
+  ($($t:ty),* $(,)?) => {
+    $(
+      #[cfg(feature = "bytemuck")]
+      unsafe impl bytemuck::Zeroable for $t {}
+    )*
+  };
+}
+
+// With the `bytemuck` feature, marks types as `Zeroable` and `Pod`. Only use
+// this on `Copy` types made of integers and floats that have no padding bytes.
+macro_rules! impl_pod {
+  ($($t:ty),* $(,)?) => {
+    $(
+      impl_zeroable!($t);
+      #[cfg(feature = "bytemuck")]
+      unsafe impl bytemuck::Pod for $t {}
+    )*
+  };
+}
+
+/// Writes the names of the set bits as `A | B`, with any unnamed bits left over
+/// at the end in hex, or `0` when no bits are set.
+pub(crate) fn fmt_flag_names(
+  f: &mut core::fmt::Formatter<'_>, bits: u32, names: &[(u32, &str)],
+) -> core::fmt::Result {
+  let mut rest = bits;
+  let mut first = true;
+  for &(bit, name) in names {
+    if bit != 0 && rest & bit == bit {
+      if !first {
+        f.write_str(" | ")?;
+      }
+      f.write_str(name)?;
+      rest &= !bit;
+      first = false;
+    }
+  }
+  if rest != 0 || first {
+    if !first {
+      f.write_str(" | ")?;
+    }
+    write!(f, "{rest:#X}")?;
+  }
+  Ok(())
+}
+
 // Note(Lokathor): Declarations are organized into modules according to SDL's
 // public header organization. A file like `include/SDL_foo.h` becomes a module
 // named `foo`. Also there is a `prelude` module which lets you grab all exports
@@ -92,11 +342,9 @@ macro_rules! impl_bit_ops_for_tuple_newtype {
 
 pub mod prelude;
 
-// TODO: haptic (joystick force feedback system).
-// TODO: shape (allows shaped windows).
-// TODO: mutex (portable, no_std mutex would be handy).
-// TODO: locale (locale info)
-// TODO: misc (lets you open a browser to a URL)
+pub mod assert;
+#[cfg(feature = "audio")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "audio")))]
 pub mod audio;
 pub mod blendmode;
 pub mod clipboard;
@@ -104,40 +352,96 @@ pub mod cpuinfo;
 pub mod error;
 pub mod events;
 pub mod filesystem;
+#[cfg(feature = "gamecontroller")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "gamecontroller")))]
 pub mod gamecontroller;
 pub mod gesture;
+#[cfg(feature = "gfx")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "gfx")))]
+pub mod gfx;
+pub mod guid;
+#[cfg(feature = "haptic")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "haptic")))]
+pub mod haptic;
+#[cfg(feature = "sdl2_2_0_18")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_18")))]
+pub mod hidapi;
 pub mod hints;
+#[cfg(feature = "image")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "image")))]
+pub mod image;
+#[cfg(feature = "joystick")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "joystick")))]
 pub mod joystick;
 pub mod keyboard;
 pub mod keycode;
 pub mod loadso;
+pub mod locale;
 pub mod messagebox;
+pub mod metal;
+pub mod misc;
+#[cfg(feature = "mixer")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "mixer")))]
+pub mod mixer;
 pub mod mouse;
+pub mod mutex;
+#[cfg(feature = "net")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "net")))]
+pub mod net;
 pub mod pixels;
 pub mod platform;
 pub mod power;
 pub mod quit;
 pub mod rect;
+#[cfg(feature = "renderer")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "renderer")))]
 pub mod renderer;
 pub mod rwops;
 pub mod scancode;
+#[cfg(feature = "sdltest")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "sdltest")))]
+pub mod sdltest;
+#[cfg(feature = "sensor")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "sensor")))]
 pub mod sensor;
+pub mod shape;
 pub mod stdinc;
 pub mod surface;
+pub mod system;
 pub mod syswm;
 pub mod timer;
 pub mod touch;
+#[cfg(feature = "ttf")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "ttf")))]
+pub mod ttf;
 pub mod version;
 pub mod video;
 
 #[cfg(feature = "vulkan")]
 pub mod vulkan;
 
+#[cfg(feature = "mock")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "mock")))]
+pub mod mock;
+
+#[cfg(feature = "dynamic_load")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "dynamic_load")))]
+pub mod dynload;
+
+#[cfg(feature = "tracing")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "tracing")))]
+pub mod traced;
+
+#[cfg(feature = "sdl3")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "sdl3")))]
+pub mod sdl3;
+
 /// SDL2's initialization flags.
 ///
 /// These are the flags which may be passed to [`SDL_Init`]. You should specify
 /// the subsystems which you will be using in your application.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_InitFlags(pub u32);
 impl_bit_ops_for_tuple_newtype!(SDL_InitFlags);
@@ -199,4 +503,210 @@ extern "C" {
   ///
   /// You should call it upon all exit conditions.
   pub fn SDL_Quit();
+
+  /// Tells SDL that the program's entry point has already done any setup that
+  /// `SDL2main` would have done.
+  ///
+  /// When you're not using the `sdl2main` feature (which calls this for you),
+  /// call this before [`SDL_Init`] so that SDL doesn't fail with an error
+  /// saying the application didn't use `SDL_main`. This matters on the
+  /// platforms where SDL requires its own `main` (WinRT, GDK, iOS, tvOS,
+  /// Android, and NaCl). Elsewhere, including regular Windows, SDL2main is
+  /// optional and this does nothing harmful.
+  pub fn SDL_SetMainReady();
+}
+
+/// The signature of a C-style `main` function, as passed to SDL's platform
+/// entry points (eg: [`SDL_WinRTRunApp`](system::SDL_WinRTRunApp)).
+pub type SDL_main_func =
+  Option<unsafe extern "C" fn(argc: c_int, argv: *mut *mut c_char) -> c_int>;
+
+#[cfg(any(target_os = "ios", target_os = "tvos", doc))]
+#[cfg_attr(docs_rs, doc(cfg(any(target_os = "ios", target_os = "tvos"))))]
+extern "C" {
+  /// Starts UIKit's app loop, which calls `mainFunction` once the app has
+  /// finished launching.
+  ///
+  /// This is what SDL2main's `main` does on iOS and tvOS. This function only
+  /// returns when the app exits, and the [`main!`] macro calls it for you.
+  pub fn SDL_UIKitRunApp(
+    argc: c_int, argv: *mut *mut c_char, mainFunction: SDL_main_func,
+  ) -> c_int;
+}
+
+/// Makes a Rust function into the program's entry point on every platform
+/// that SDL supports.
+///
+/// Most platforms are happy with a normal `main`, but on some of them SDL
+/// has to be in charge of starting the program:
+/// * **Android:** SDL's Java activity loads your library and then calls its
+///   `SDL_main` function on a new thread. Without that export nothing runs at
+///   all, and the app just shows a black screen. Build the crate as a
+///   `cdylib` named `main` (or change `getLibraries` in your `SDLActivity`
+///   subclass to match), and use the macro in that library.
+/// * **iOS and tvOS:** `main` has to start UIKit with [`SDL_UIKitRunApp`],
+///   and UIKit then calls your function after the app has launched.
+/// * **Windows:** with the `sdl2main` feature SDL2main provides `main` (and
+///   `WinMain`), and the macro exports the `SDL_main` that it calls. The
+///   crate root must then also have `#![cfg_attr(windows, no_main)]`. Without
+///   that feature the macro makes a normal `main`.
+///
+/// Everywhere else the macro makes a normal `main`. In all cases
+/// [`SDL_SetMainReady`] is called before your function runs, so it can call
+/// [`SDL_Init`] right away.
+///
+/// The function must be a `fn()`. If it panics when called from SDL (as
+/// opposed to from a Rust `main`) the program aborts, since the panic can't
+/// unwind into C.
+///
+/// ```ignore
+/// fn my_main() {
+///   unsafe { fermium::SDL_Init(fermium::SDL_INIT_VIDEO) };
+///   // ...
+/// }
+/// fermium::main!(my_main);
+/// ```
+#[macro_export]
+macro_rules! main {
+  ($main:path $(,)?) => {
+    #[cfg(target_os = "android")]
+    $crate::__sdl_main_export!($main);
+
+    #[cfg(any(target_os = "ios", target_os = "tvos"))]
+    fn main() {
+      extern "C" fn sdl_main(
+        _argc: $crate::c_int, _argv: *mut *mut $crate::c_char,
+      ) -> $crate::c_int {
+        $crate::__call_main($main)
+      }
+      unsafe { $crate::__run_uikit_app(sdl_main) };
+    }
+
+    #[cfg(target_os = "windows")]
+    $crate::__windows_main!($main);
+
+    #[cfg(not(any(
+      target_os = "android",
+      target_os = "ios",
+      target_os = "tvos",
+      target_os = "windows"
+    )))]
+    fn main() {
+      unsafe { $crate::SDL_SetMainReady() };
+      $main()
+    }
+  };
+}
+
+/// Exports `fn()` as the `SDL_main` that SDL calls, for [`main!`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __sdl_main_export {
+  ($main:path) => {
+    #[no_mangle]
+    pub extern "C" fn SDL_main(
+      _argc: $crate::c_int, _argv: *mut *mut $crate::c_char,
+    ) -> $crate::c_int {
+      $crate::__call_main($main)
+    }
+  };
+}
+
+// The feature has to be checked here, since a `cfg` in the output of `main!`
+// would check the features of the crate that uses it.
+/// The Windows part of [`main!`].
+#[cfg(feature = "sdl2main")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __windows_main {
+  ($main:path) => {
+    $crate::__sdl_main_export!($main);
+  };
+}
+/// The Windows part of [`main!`].
+#[cfg(not(feature = "sdl2main"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __windows_main {
+  ($main:path) => {
+    fn main() {
+      unsafe { $crate::SDL_SetMainReady() };
+      $main()
+    }
+  };
+}
+
+/// Runs `f`, aborting the program if it panics.
+///
+/// Unwinding back into SDL isn't allowed, so any Rust code that SDL calls
+/// goes through this. A panic in `f` becomes a double panic (and so an abort)
+/// when the guard is dropped, with `what` in the second panic's message.
+#[inline]
+pub(crate) fn abort_on_unwind<R>(
+  what: &'static str, f: impl FnOnce() -> R,
+) -> R {
+  struct AbortOnUnwind(&'static str);
+  impl Drop for AbortOnUnwind {
+    fn drop(&mut self) {
+      panic!("{} panicked", self.0);
+    }
+  }
+  let guard = AbortOnUnwind(what);
+  let r = f();
+  core::mem::forget(guard);
+  r
+}
+
+/// Calls the program's main function from SDL, for [`main!`].
+#[doc(hidden)]
+pub fn __call_main(main: fn()) -> c_int {
+  abort_on_unwind("the `main!` function", || {
+    unsafe { SDL_SetMainReady() };
+    main();
+  });
+  0
+}
+
+/// Starts UIKit with the process's own arguments, for [`main!`].
+#[cfg(any(target_os = "ios", target_os = "tvos"))]
+#[doc(hidden)]
+pub unsafe fn __run_uikit_app(
+  main: unsafe extern "C" fn(c_int, *mut *mut c_char) -> c_int,
+) -> c_int {
+  extern "C" {
+    fn _NSGetArgc() -> *mut c_int;
+    fn _NSGetArgv() -> *mut *mut *mut c_char;
+  }
+  SDL_UIKitRunApp(*_NSGetArgc(), *_NSGetArgv(), Some(main))
+}
+
+#[cfg(any(target_os = "windows", doc))]
+#[cfg_attr(docs_rs, doc(cfg(target_os = "windows")))]
+extern "C" {
+  /// Registers the win32 window class that SDL uses for its windows.
+  ///
+  /// SDL calls this itself when the video subsystem starts, so most programs
+  /// don't need it. It's for embedding SDL in a larger Win32 program that wants
+  /// to pick the window class name, style, and instance handle (and so the
+  /// icon resources that are used). Call it before initializing video.
+  ///
+  /// Every call should be paired with an [`SDL_UnregisterApp`]. While a
+  /// registration is active, more registrations only increase a counter.
+  ///
+  /// * `name` the window class name (UTF-8), or null for SDL's default. With a
+  ///   null name SDL also ignores `style`.
+  /// * `style` the `WNDCLASSEX::style` value.
+  /// * `hInst` the `HINSTANCE` to use, or null for `GetModuleHandle(NULL)`.
+  ///
+  /// **Returns:** 0 on success, -1 on error (call
+  /// [`SDL_GetErrorMsg`](error::SDL_GetErrorMsg)).
+  pub fn SDL_RegisterApp(
+    name: *const c_char, style: u32, hInst: *mut c_void,
+  ) -> c_int;
+
+  /// Undoes an [`SDL_RegisterApp`] call.
+  ///
+  /// The window class is only unregistered once every registration has been
+  /// undone.
+  pub fn SDL_UnregisterApp();
 }
@@ -1,6 +1,8 @@
 //! Module for loading "shared objects" (aka dynamic libraries / DLLs).
 
-use crate::{c_char, c_void};
+use core::{ffi::CStr, marker::PhantomData, ops::Deref, ptr::NonNull};
+
+use crate::{c_char, c_void, error::*};
 
 extern "C" {
   /// This function dynamically loads a shared object and returns a pointer to
@@ -20,3 +22,93 @@ extern "C" {
   /// Unload a shared object from memory.
   pub fn SDL_UnloadObject(handle: *mut c_void);
 }
+
+/// A shared object loaded with [`SDL_LoadObject`], unloaded when dropped.
+///
+/// Functions looked up with [`get`](Self::get) borrow the library, so they
+/// can't outlive it.
+///
+/// ```no_run
+/// # use fermium::prelude::*;
+/// # use core::ffi::CStr;
+/// type AddFn = unsafe extern "C" fn(c_int, c_int) -> c_int;
+/// unsafe {
+///   let lib =
+///     SdlLibrary::open(CStr::from_bytes_with_nul(b"libplugin.so\0").unwrap())
+///       .unwrap();
+///   let add =
+///     lib.get::<AddFn>(CStr::from_bytes_with_nul(b"add\0").unwrap()).unwrap();
+///   assert_eq!((*add)(2, 3), 5);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct SdlLibrary {
+  handle: NonNull<c_void>,
+}
+impl SdlLibrary {
+  /// Loads a shared object by its system dependent file name.
+  ///
+  /// ## Safety
+  /// Loading a library runs its initialization code, which can do anything.
+  #[inline]
+  pub unsafe fn open(sofile: &CStr) -> Result<Self, SdlError> {
+    match NonNull::new(SDL_LoadObject(sofile.as_ptr())) {
+      Some(handle) => Ok(Self { handle }),
+      None => Err(SdlError::get()),
+    }
+  }
+
+  /// Looks up a function by name with [`SDL_LoadFunction`].
+  ///
+  /// `F` should be the function pointer type of the function (eg:
+  /// `unsafe extern "C" fn(c_int) -> c_int`).
+  ///
+  /// ## Safety
+  /// * `F` must match the function's real signature and calling convention.
+  ///
+  /// ## Panics
+  /// * If `F` isn't the size of a pointer.
+  #[inline]
+  pub unsafe fn get<F: Copy>(
+    &self, name: &CStr,
+  ) -> Result<SdlSymbol<'_, F>, SdlError> {
+    assert_eq!(
+      core::mem::size_of::<F>(),
+      core::mem::size_of::<*mut c_void>(),
+      "SdlLibrary::get must be used with a function pointer type"
+    );
+    let p = SDL_LoadFunction(self.handle.as_ptr(), name.as_ptr());
+    if p.is_null() {
+      Err(SdlError::get())
+    } else {
+      Ok(SdlSymbol { f: core::mem::transmute_copy(&p), _lib: PhantomData })
+    }
+  }
+
+  /// The raw handle, for use with the C API.
+  #[inline]
+  #[must_use]
+  pub fn as_ptr(&self) -> *mut c_void {
+    self.handle.as_ptr()
+  }
+}
+impl Drop for SdlLibrary {
+  #[inline]
+  fn drop(&mut self) {
+    unsafe { SDL_UnloadObject(self.handle.as_ptr()) }
+  }
+}
+
+/// A function from an [`SdlLibrary`], which derefs to the function pointer.
+#[derive(Debug, Clone, Copy)]
+pub struct SdlSymbol<'lib, F> {
+  f: F,
+  _lib: PhantomData<&'lib SdlLibrary>,
+}
+impl<F> Deref for SdlSymbol<'_, F> {
+  type Target = F;
+  #[inline]
+  fn deref(&self) -> &F {
+    &self.f
+  }
+}
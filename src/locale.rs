@@ -0,0 +1,106 @@
+//! Lets you get the user's preferred locales (languages and countries).
+
+use crate::c_char;
+
+#[cfg(feature = "alloc")]
+use crate::stdinc::*;
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+// makes rustdoc link properly!
+#[allow(unused)]
+use crate::{events::*, stdinc::*};
+
+/// A locale, a language and (maybe) a country.
+///
+/// See [`SDL_GetPreferredLocales`].
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct SDL_Locale {
+  /// A language name, like "en" for English.
+  pub language: *const c_char,
+  /// A country, like "US" for America. Can be NULL.
+  pub country: *const c_char,
+}
+
+extern "C" {
+  /// Report the user's preferred locales.
+  ///
+  /// This returns an array of [`SDL_Locale`] structs, the final item zeroed
+  /// out. When you're done with this array, call [`SDL_free`] on the returned
+  /// value. All the memory involved is allocated in a single block, so a
+  /// single [`SDL_free`] will suffice.
+  ///
+  /// Returned language strings are in the format `xx`, where `xx` is an
+  /// ISO-639 language specifier (such as "en" for English, "de" for German,
+  /// etc). Country strings are in the format `YY`, where `YY` is an ISO-3166
+  /// country code (such as "US" for the United States, "CA" for Canada,
+  /// etc). Country might be NULL if there's no specific guidance on them (so
+  /// you might get `{ "en", "US" }` for American English, but `{ "en", NULL
+  /// }` means "English language, generically"). Language strings are never
+  /// NULL, except to terminate the array.
+  ///
+  /// Please note that not all of these strings are 2 characters, some are
+  /// three or more.
+  ///
+  /// The returned list of locales are in the order of the user's preference.
+  /// For example, a German citizen that is fluent in US English and knows
+  /// enough Japanese to navigate around Tokyo might have a list like `{ "de",
+  /// "en_US", "jp", NULL }`.
+  ///
+  /// This might be a "slow" call that has to query the operating system.
+  /// It's best to ask for this once and save the results. However, the list
+  /// can change, usually because the user has changed a system preference
+  /// outside of your program. SDL will send an [`SDL_LOCALECHANGED`] event
+  /// in this case, if possible, and you can call this function again to get
+  /// an updated copy of preferred locales.
+  ///
+  /// **Returns:** the array of locales, or NULL on error (including when the
+  /// platform doesn't supply this information at all).
+  pub fn SDL_GetPreferredLocales() -> *mut SDL_Locale;
+}
+
+/// Gets the user's preferred locales, most preferred first.
+///
+/// Each entry is the language and the (optional) country, copied out of
+/// SDL's array before that array is freed with [`SDL_free`]. Any invalid
+/// UTF-8 is replaced with `U+FFFD`.
+///
+/// ```no_run
+/// # use fermium::prelude::*;
+/// for (language, country) in preferred_locales() {
+///   match country {
+///     Some(country) => println!("{language} ({country})"),
+///     None => println!("{language}"),
+///   }
+/// }
+/// ```
+///
+/// **Returns:** the locales, or an empty list if SDL can't get them (which
+/// includes platforms that don't supply this information at all).
+#[cfg(feature = "alloc")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "alloc")))]
+#[must_use]
+pub fn preferred_locales() -> Vec<(String, Option<String>)> {
+  let to_string = |p: *const c_char| {
+    let s = unsafe { core::ffi::CStr::from_ptr(p) };
+    String::from_utf8_lossy(s.to_bytes()).into_owned()
+  };
+  let mut out = Vec::new();
+  unsafe {
+    let list = SDL_GetPreferredLocales();
+    if list.is_null() {
+      return out;
+    }
+    let mut p = list;
+    while !(*p).language.is_null() {
+      let SDL_Locale { language, country } = *p;
+      let country =
+        if country.is_null() { None } else { Some(to_string(country)) };
+      out.push((to_string(language), country));
+      p = p.add(1);
+    }
+    SDL_free(list.cast());
+  }
+  out
+}
@@ -2,6 +2,11 @@
 
 use crate::{c_char, c_int, stdinc::*, video::*};
 
+#[cfg(feature = "alloc")]
+use crate::error::*;
+#[cfg(feature = "alloc")]
+use alloc::{ffi::CString, vec::Vec};
+
 // makes rustdoc link properly!
 #[allow(unused)]
 use crate::*;
@@ -12,6 +17,7 @@ use crate::*;
 ///
 /// See the `SDL_MESSAGEBOX_*` constants.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_MessageBoxFlags(pub u32);
 impl_bit_ops_for_tuple_newtype!(SDL_MessageBoxFlags);
@@ -38,6 +44,7 @@ pub const SDL_MESSAGEBOX_BUTTONS_RIGHT_TO_LEFT: SDL_MessageBoxFlags =
 ///
 /// See the `SDL_MESSAGEBOX_BUTTON_*` constants.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_MessageBoxButtonFlags(pub u32);
 impl_bit_ops_for_tuple_newtype!(SDL_MessageBoxButtonFlags);
@@ -80,6 +87,7 @@ pub struct SDL_MessageBoxColor {
 
 /// See the `SDL_MESSAGEBOX_COLOR_*` constants.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_MessageBoxColorType(pub u32);
 
@@ -177,3 +185,173 @@ extern "C" {
     window: *mut SDL_Window,
   ) -> c_int;
 }
+
+/// Builds up a full [`SDL_ShowMessageBox`] call from Rust strings.
+///
+/// The builder owns null terminated copies of all the strings, and assembles
+/// the C structs pointing at them only for the duration of [`show`](Self::show).
+/// If any of the text has a null byte in it then [`show`](Self::show) gives
+/// an error instead of showing the box.
+///
+/// ```no_run
+/// # use fermium::prelude::*;
+/// let choice = unsafe {
+///   MessageBoxBuilder::new("Unsaved Changes", "Save before closing?")
+///     .flags(SDL_MESSAGEBOX_WARNING)
+///     .button(1, "Save")
+///     .button(2, "Discard")
+///     .button(0, "Cancel")
+///     .return_key_default(1)
+///     .escape_key_default(0)
+///     .show()
+/// };
+/// ```
+#[cfg(feature = "alloc")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone)]
+pub struct MessageBoxBuilder {
+  flags: SDL_MessageBoxFlags,
+  window: *mut SDL_Window,
+  title: CString,
+  message: CString,
+  buttons: Vec<(SDL_MessageBoxButtonFlags, c_int, CString)>,
+  color_scheme: Option<SDL_MessageBoxColorScheme>,
+  has_null_byte: bool,
+}
+#[cfg(feature = "alloc")]
+impl MessageBoxBuilder {
+  /// Starts a message box with no flags, no parent window, no buttons, and the
+  /// system's colors.
+  #[inline]
+  #[must_use]
+  pub fn new(title: &str, message: &str) -> Self {
+    let mut has_null_byte = false;
+    Self {
+      flags: SDL_MessageBoxFlags::default(),
+      window: core::ptr::null_mut(),
+      title: c_string(title, &mut has_null_byte),
+      message: c_string(message, &mut has_null_byte),
+      buttons: Vec::new(),
+      color_scheme: None,
+      has_null_byte,
+    }
+  }
+
+  /// Sets the box flags (the icon and the button order).
+  #[inline]
+  #[must_use]
+  pub fn flags(mut self, flags: SDL_MessageBoxFlags) -> Self {
+    self.flags = flags;
+    self
+  }
+
+  /// Sets the parent window (null for no parent).
+  #[inline]
+  #[must_use]
+  pub fn window(mut self, window: *mut SDL_Window) -> Self {
+    self.window = window;
+    self
+  }
+
+  /// Adds a button, which makes [`show`](Self::show) return `id` when pressed.
+  #[inline]
+  #[must_use]
+  pub fn button(mut self, id: c_int, text: &str) -> Self {
+    let text = c_string(text, &mut self.has_null_byte);
+    self.buttons.push((SDL_MessageBoxButtonFlags::default(), id, text));
+    self
+  }
+
+  /// Makes the button(s) with the given `id` be pressed by the return key.
+  ///
+  /// Only buttons that were already added are affected.
+  #[inline]
+  #[must_use]
+  pub fn return_key_default(self, id: c_int) -> Self {
+    self.with_button_flag(id, SDL_MESSAGEBOX_BUTTON_RETURNKEY_DEFAULT)
+  }
+
+  /// Makes the button(s) with the given `id` be pressed by the escape key.
+  ///
+  /// Only buttons that were already added are affected.
+  #[inline]
+  #[must_use]
+  pub fn escape_key_default(self, id: c_int) -> Self {
+    self.with_button_flag(id, SDL_MESSAGEBOX_BUTTON_ESCAPEKEY_DEFAULT)
+  }
+
+  /// Sets a color scheme, rather than using the system's colors.
+  ///
+  /// Not all platforms support this.
+  #[inline]
+  #[must_use]
+  pub fn color_scheme(mut self, scheme: SDL_MessageBoxColorScheme) -> Self {
+    self.color_scheme = Some(scheme);
+    self
+  }
+
+  fn with_button_flag(
+    mut self, id: c_int, flag: SDL_MessageBoxButtonFlags,
+  ) -> Self {
+    for (flags, _, _) in self.buttons.iter_mut().filter(|b| b.1 == id) {
+      *flags |= flag;
+    }
+    self
+  }
+
+  /// Shows the message box, blocking until the user closes it.
+  ///
+  /// **Returns:** The id of the button pressed, or -1 if the box was closed
+  /// without pressing a button.
+  ///
+  /// It's also an error if any of the text given to the builder had a null
+  /// byte in it.
+  ///
+  /// ## Safety
+  /// * The window (if any) must be valid.
+  /// * As with [`SDL_ShowMessageBox`], call this from the thread that created
+  ///   the parent window, or the main thread if there's no parent.
+  pub unsafe fn show(&self) -> Result<c_int, SdlError> {
+    if self.has_null_byte {
+      SDL_SetError(b"Message box text contains a null byte\0".as_ptr().cast());
+      return Err(SdlError::get());
+    }
+    let buttons: Vec<SDL_MessageBoxButtonData> = self
+      .buttons
+      .iter()
+      .map(|(flags, buttonid, text)| SDL_MessageBoxButtonData {
+        flags: *flags,
+        buttonid: *buttonid,
+        text: text.as_ptr(),
+      })
+      .collect();
+    let data = SDL_MessageBoxData {
+      flags: self.flags,
+      window: self.window,
+      title: self.title.as_ptr(),
+      message: self.message.as_ptr(),
+      numbuttons: buttons.len() as c_int,
+      buttons: buttons.as_ptr(),
+      colorScheme: match &self.color_scheme {
+        Some(scheme) => scheme,
+        None => core::ptr::null(),
+      },
+    };
+    let mut buttonid: c_int = -1;
+    if SDL_ShowMessageBox(&data, &mut buttonid) < 0 {
+      Err(SdlError::get())
+    } else {
+      Ok(buttonid)
+    }
+  }
+}
+
+/// Copies the text, or flags it and gives an empty string if it has a null
+/// byte.
+#[cfg(feature = "alloc")]
+fn c_string(s: &str, has_null_byte: &mut bool) -> CString {
+  CString::new(s).unwrap_or_else(|_| {
+    *has_null_byte = true;
+    CString::default()
+  })
+}
@@ -0,0 +1,92 @@
+//! Functions to make Metal and SDL work together.
+//!
+//! These let you attach a `CAMetalLayer` to a window, for use with your own
+//! Metal code (eg: with the [metal](https://docs.rs/metal) crate). They only
+//! do anything on macOS and iOS, elsewhere they just return null.
+//!
+//! With the `metal_types` feature (on Apple targets), the layer pointer is
+//! typed as metal-rs's `CAMetalLayer`, and [`metal_layer`] gives you the
+//! layer as a `&MetalLayerRef` directly.
+
+use crate::{c_int, c_void, video::*};
+
+// makes rustdoc link properly!
+#[allow(unused)]
+use crate::error::*;
+
+/// A handle to a `CAMetalLayer`-backed `NSView` (macOS) or `UIView`
+/// (iOS/tvOS).
+///
+/// This can be cast directly to an `NSView` or `UIView`.
+pub type SDL_MetalView = *mut c_void;
+
+/// A pointer to the `CAMetalLayer` of an [`SDL_MetalView`].
+#[cfg(not(all(feature = "metal_types", target_vendor = "apple")))]
+pub type SDL_MetalLayer = *mut c_void;
+/// A pointer to the `CAMetalLayer` of an [`SDL_MetalView`].
+#[cfg(all(feature = "metal_types", target_vendor = "apple"))]
+pub type SDL_MetalLayer = *mut ::metal::CAMetalLayer;
+
+extern "C" {
+  /// Create a `CAMetalLayer`-backed `NSView`/`UIView` and attach it to the
+  /// specified window.
+  ///
+  /// On macOS, this does *not* associate a `MTLDevice` with the
+  /// `CAMetalLayer` on its own. It is up to user code to do that.
+  ///
+  /// The returned handle can be casted directly to a `NSView` or `UIView`. To
+  /// access the backing `CAMetalLayer`, call [`SDL_Metal_GetLayer`].
+  ///
+  /// **Returns:** The view, or null on error (call [`SDL_GetErrorMsg`]).
+  ///
+  /// See Also: [`SDL_Metal_DestroyView`], [`SDL_Metal_GetLayer`]
+  pub fn SDL_Metal_CreateView(window: *mut SDL_Window) -> SDL_MetalView;
+
+  /// Destroy an existing [`SDL_MetalView`] object.
+  ///
+  /// This should be called before [`SDL_DestroyWindow`], if
+  /// [`SDL_Metal_CreateView`] was called after [`SDL_CreateWindow`].
+  ///
+  /// See Also: [`SDL_Metal_CreateView`]
+  pub fn SDL_Metal_DestroyView(view: SDL_MetalView);
+
+  /// Get a pointer to the backing `CAMetalLayer` for the given view.
+  ///
+  /// See Also: [`SDL_Metal_CreateView`]
+  pub fn SDL_Metal_GetLayer(view: SDL_MetalView) -> SDL_MetalLayer;
+
+  /// Get the size of a window's underlying drawable in pixels (for use with
+  /// setting viewport, scissor & etc).
+  ///
+  /// * `window` The window from which the drawable size should be queried.
+  /// * `w` Pointer to variable for storing the width in pixels, may be null.
+  /// * `h` Pointer to variable for storing the height in pixels, may be null.
+  ///
+  /// See Also: [`SDL_GetWindowSize`], [`SDL_CreateWindow`]
+  pub fn SDL_Metal_GetDrawableSize(
+    window: *mut SDL_Window, w: *mut c_int, h: *mut c_int,
+  );
+}
+
+/// Gets the `CAMetalLayer` of a view, as a metal-rs layer reference.
+///
+/// **Returns:** `None` if SDL gave a null layer.
+///
+/// ## Safety
+/// * `view` must be a view from [`SDL_Metal_CreateView`] that hasn't been
+///   destroyed, and the layer reference must not outlive the view.
+#[cfg(all(feature = "metal_types", target_vendor = "apple"))]
+#[cfg_attr(docs_rs, doc(cfg(feature = "metal_types")))]
+#[inline]
+#[must_use]
+pub unsafe fn metal_layer<'a>(
+  view: SDL_MetalView,
+) -> Option<&'a ::metal::MetalLayerRef> {
+  use ::metal::foreign_types::ForeignTypeRef;
+  let layer = SDL_Metal_GetLayer(view);
+  if layer.is_null() {
+    None
+  } else {
+    Some(::metal::MetalLayerRef::from_ptr(layer))
+  }
+}
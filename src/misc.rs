@@ -0,0 +1,69 @@
+//! Things that don't fit anywhere else.
+
+use crate::{c_char, c_int};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+// makes rustdoc link properly!
+#[allow(unused)]
+use crate::error::*;
+
+extern "C" {
+  /// Open a URL/URI in the browser or other appropriate external application.
+  ///
+  /// Open a URL in a separate, system-provided application. How this works
+  /// will vary wildly depending on the platform. This will likely launch what
+  /// makes sense to handle a specific URL's protocol (a web browser for
+  /// `http://`, etc), but it might also be able to launch file managers for
+  /// directories and other things.
+  ///
+  /// What happens when you open a URL varies wildly as well: your game window
+  /// may lose focus (and may or may not lose focus if your game was
+  /// fullscreen or grabbing input at the time). On mobile devices, your app
+  /// will likely move to the background or your process might be paused. Any
+  /// given platform may or may not handle a given URL.
+  ///
+  /// If this is unimplemented (or simply unavailable) for a platform, this
+  /// will fail with an error. A successful result does not mean the URL
+  /// loaded, just that we launched _something_ to handle it (or at least
+  /// believe we did).
+  ///
+  /// All this to say: this function can be useful, but you should definitely
+  /// test it on every platform you target.
+  ///
+  /// * `url` A valid URL/URI to open. Use `file:///full/path/to/file` for
+  ///   local files, if supported.
+  ///
+  /// **Returns:** 0 on success, or -1 on error (call [`SDL_GetErrorMsg`] for
+  /// more information).
+  pub fn SDL_OpenURL(url: *const c_char) -> c_int;
+}
+
+/// Opens a URL (or a `file:///` path) with the system's default handler.
+///
+/// The null terminator that SDL needs is added for you. See [`SDL_OpenURL`]
+/// for what "success" does and doesn't mean here.
+///
+/// ## Failure
+/// * The URL can't contain a null byte, since SDL would cut the URL off
+///   there.
+/// * The platform might not support opening URLs at all.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "alloc")))]
+pub fn open_url(url: &str) -> Result<(), SdlError> {
+  unsafe {
+    if url.as_bytes().contains(&0) {
+      SDL_SetError(b"URL contains a null byte\0".as_ptr().cast());
+      return Err(SdlError::get());
+    }
+    let mut buf = Vec::with_capacity(url.len() + 1);
+    buf.extend_from_slice(url.as_bytes());
+    buf.push(0_u8);
+    if SDL_OpenURL(buf.as_ptr().cast()) < 0 {
+      Err(SdlError::get())
+    } else {
+      Ok(())
+    }
+  }
+}
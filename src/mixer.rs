@@ -0,0 +1,695 @@
+//! Bindings to the SDL2_mixer library, for mixing sound effects and music.
+//!
+//! This is a separate library from SDL2 itself, so it must also be installed
+//! for your program to link and run. These bindings match SDL2_mixer 2.6.
+//!
+//! The mixer opens the audio device itself (see [`Mix_OpenAudioDevice`]), so
+//! don't also open the same device with the functions of the [`audio`]
+//! module.
+//!
+//! [`audio`]: crate::audio
+
+use crate::{
+  audio::*, c_char, c_double, c_int, c_void, rwops::*, stdinc::*, version::*,
+};
+
+// makes rustdoc link properly!
+#[allow(unused)]
+use crate::error::*;
+
+/// The major version of SDL2_mixer that these bindings are for.
+pub const SDL_MIXER_MAJOR_VERSION: u8 = 2;
+/// The minor version of SDL2_mixer that these bindings are for.
+pub const SDL_MIXER_MINOR_VERSION: u8 = 6;
+/// The patch version of SDL2_mixer that these bindings are for.
+pub const SDL_MIXER_PATCHLEVEL: u8 = 3;
+
+/// Fills in the version of SDL2_mixer that these bindings are for.
+///
+/// To get the version of the library you're actually linked to, use
+/// [`Mix_Linked_Version`].
+#[inline]
+pub fn SDL_MIXER_VERSION(x: &mut SDL_version) {
+  x.major = SDL_MIXER_MAJOR_VERSION;
+  x.minor = SDL_MIXER_MINOR_VERSION;
+  x.patch = SDL_MIXER_PATCHLEVEL;
+}
+
+/// Flags for the audio formats that need extra support libraries.
+///
+/// See [`Mix_Init`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct MIX_InitFlags(pub c_int);
+impl_bit_ops_for_tuple_newtype!(MIX_InitFlags);
+#[allow(missing_docs)]
+pub const MIX_INIT_FLAC: MIX_InitFlags = MIX_InitFlags(0x00000001);
+#[allow(missing_docs)]
+pub const MIX_INIT_MOD: MIX_InitFlags = MIX_InitFlags(0x00000002);
+#[allow(missing_docs)]
+pub const MIX_INIT_MP3: MIX_InitFlags = MIX_InitFlags(0x00000008);
+#[allow(missing_docs)]
+pub const MIX_INIT_OGG: MIX_InitFlags = MIX_InitFlags(0x00000010);
+#[allow(missing_docs)]
+pub const MIX_INIT_MID: MIX_InitFlags = MIX_InitFlags(0x00000020);
+#[allow(missing_docs)]
+pub const MIX_INIT_OPUS: MIX_InitFlags = MIX_InitFlags(0x00000040);
+
+/// The default number of mixing channels.
+pub const MIX_CHANNELS: c_int = 8;
+/// A good default output frequency.
+pub const MIX_DEFAULT_FREQUENCY: c_int = 44100;
+/// A good default output format.
+pub const MIX_DEFAULT_FORMAT: SDL_AudioFormat = AUDIO_S16SYS;
+/// A good default output channel count (stereo).
+pub const MIX_DEFAULT_CHANNELS: c_int = 2;
+/// The maximum value for any volume setting.
+pub const MIX_MAX_VOLUME: c_int = SDL_MIX_MAXVOLUME;
+
+/// The "channel" to use with the effect functions to apply an effect to the
+/// final mixed output, after all channels and the music.
+pub const MIX_CHANNEL_POST: c_int = -2;
+
+/// The environment variable that can be set to `"1"` to make the built-in
+/// effects favor speed over quality.
+pub const MIX_EFFECTSMAXSPEED: &[u8] = b"MIX_EFFECTSMAXSPEED\0";
+
+/// A loaded sound effect.
+///
+/// Free this with [`Mix_FreeChunk`].
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct Mix_Chunk {
+  /// Non-zero if the mixer owns the `abuf` memory.
+  pub allocated: c_int,
+  /// The sample data, already in the output format.
+  pub abuf: *mut Uint8,
+  /// The length of `abuf` in bytes.
+  pub alen: Uint32,
+  /// The volume of the chunk, from 0 to [`MIX_MAX_VOLUME`].
+  pub volume: Uint8,
+}
+
+/// The fading status of a channel or of the music.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct Mix_Fading(pub u32);
+#[allow(missing_docs)]
+pub const MIX_NO_FADING: Mix_Fading = Mix_Fading(0);
+#[allow(missing_docs)]
+pub const MIX_FADING_OUT: Mix_Fading = Mix_Fading(1);
+#[allow(missing_docs)]
+pub const MIX_FADING_IN: Mix_Fading = Mix_Fading(2);
+
+/// The format of a [`Mix_Music`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct Mix_MusicType(pub u32);
+#[allow(missing_docs)]
+pub const MUS_NONE: Mix_MusicType = Mix_MusicType(0);
+/// Music played by an external command, see [`Mix_SetMusicCMD`].
+pub const MUS_CMD: Mix_MusicType = Mix_MusicType(1);
+#[allow(missing_docs)]
+pub const MUS_WAV: Mix_MusicType = Mix_MusicType(2);
+#[allow(missing_docs)]
+pub const MUS_MOD: Mix_MusicType = Mix_MusicType(3);
+#[allow(missing_docs)]
+pub const MUS_MID: Mix_MusicType = Mix_MusicType(4);
+#[allow(missing_docs)]
+pub const MUS_OGG: Mix_MusicType = Mix_MusicType(5);
+#[allow(missing_docs)]
+pub const MUS_MP3: Mix_MusicType = Mix_MusicType(6);
+/// No longer used.
+pub const MUS_MP3_MAD_UNUSED: Mix_MusicType = Mix_MusicType(7);
+#[allow(missing_docs)]
+pub const MUS_FLAC: Mix_MusicType = Mix_MusicType(8);
+/// No longer used.
+pub const MUS_MODPLUG_UNUSED: Mix_MusicType = Mix_MusicType(9);
+#[allow(missing_docs)]
+pub const MUS_OPUS: Mix_MusicType = Mix_MusicType(10);
+
+/// An opaque type for loaded music.
+///
+/// Free this with [`Mix_FreeMusic`].
+#[repr(transparent)]
+pub struct Mix_Music(c_void);
+
+/// A function that mixes extra audio into a stream.
+///
+/// Used by [`Mix_SetPostMix`] and [`Mix_HookMusic`].
+///
+/// * `udata` the pointer that was given along with the function.
+/// * `stream` the audio data, in the format of the opened device.
+/// * `len` the length of `stream` in bytes.
+pub type Mix_MixFunc = Option<
+  unsafe extern "C" fn(udata: *mut c_void, stream: *mut Uint8, len: c_int),
+>;
+
+/// A function that's called when the music finishes.
+pub type Mix_MusicFinishedFunc = Option<unsafe extern "C" fn()>;
+
+/// A function that's called when a channel finishes.
+///
+/// * `channel` the channel that finished.
+pub type Mix_ChannelFinishedFunc = Option<unsafe extern "C" fn(channel: c_int)>;
+
+/// An effect that processes one channel's audio.
+///
+/// * `chan` the channel the effect is on (or [`MIX_CHANNEL_POST`]).
+/// * `stream` the audio data to edit in place, in the format of the opened
+///   device.
+/// * `len` the length of `stream` in bytes.
+/// * `udata` the pointer given to [`Mix_RegisterEffect`].
+pub type Mix_EffectFunc_t = Option<
+  unsafe extern "C" fn(
+    chan: c_int,
+    stream: *mut c_void,
+    len: c_int,
+    udata: *mut c_void,
+  ),
+>;
+
+/// Called when an effect is removed from a channel, or when the channel
+/// finishes playing.
+///
+/// * `chan` the channel the effect was on.
+/// * `udata` the pointer given to [`Mix_RegisterEffect`].
+pub type Mix_EffectDone_t =
+  Option<unsafe extern "C" fn(chan: c_int, udata: *mut c_void)>;
+
+/// A function that's called for each sound font path.
+///
+/// Return 0 to stop the iteration.
+pub type Mix_EachSoundFontFunc =
+  Option<unsafe extern "C" fn(path: *const c_char, data: *mut c_void) -> c_int>;
+
+extern "C" {
+  /// Gets the version of the SDL2_mixer library that's linked.
+  pub fn Mix_Linked_Version() -> *const SDL_version;
+
+  /// Loads the support libraries for the audio formats given.
+  ///
+  /// You don't need to call this, the support libraries are loaded when
+  /// they're first needed.
+  ///
+  /// **Returns:** The flags of the formats that are now initialized.
+  pub fn Mix_Init(flags: MIX_InitFlags) -> MIX_InitFlags;
+
+  /// Unloads the libraries that were loaded by [`Mix_Init`].
+  pub fn Mix_Quit();
+
+  /// Opens the default audio device for mixing.
+  ///
+  /// This is [`Mix_OpenAudioDevice`] with no device name and
+  /// `SDL_AUDIO_ALLOW_FREQUENCY_CHANGE | SDL_AUDIO_ALLOW_CHANNELS_CHANGE`.
+  ///
+  /// **Returns:** 0 on success, or a negative error code on failure.
+  pub fn Mix_OpenAudio(
+    frequency: c_int, format: SDL_AudioFormat, channels: c_int,
+    chunksize: c_int,
+  ) -> c_int;
+
+  /// Opens an audio device for mixing.
+  ///
+  /// * `frequency` the output sample rate, such as [`MIX_DEFAULT_FREQUENCY`].
+  /// * `format` the output sample format, such as [`MIX_DEFAULT_FORMAT`].
+  /// * `channels` the number of output channels (not mixing channels).
+  /// * `chunksize` the audio buffer size in sample frames. Smaller sizes are
+  ///   lower latency but need to be filled more often.
+  /// * `device` the device name from [`SDL_GetAudioDeviceName`], or null for
+  ///   the default device.
+  /// * `allowed_changes` the `SDL_AUDIO_ALLOW_*` flags, as with
+  ///   [`SDL_OpenAudioDevice`]. Check the actual format with
+  ///   [`Mix_QuerySpec`].
+  ///
+  /// **Returns:** 0 on success, or a negative error code on failure.
+  pub fn Mix_OpenAudioDevice(
+    frequency: c_int, format: SDL_AudioFormat, channels: c_int,
+    chunksize: c_int, device: *const c_char, allowed_changes: c_int,
+  ) -> c_int;
+
+  /// Gets the actual format of the opened audio device.
+  ///
+  /// **Returns:** 0 if the audio isn't open, otherwise the number of times
+  /// it's been opened.
+  pub fn Mix_QuerySpec(
+    frequency: *mut c_int, format: *mut SDL_AudioFormat, channels: *mut c_int,
+  ) -> c_int;
+
+  /// Sets the number of mixing channels.
+  ///
+  /// Channels above the new count that are playing are halted.
+  ///
+  /// * `numchans` the new count, or -1 to just get the current count.
+  ///
+  /// **Returns:** The number of channels now allocated.
+  pub fn Mix_AllocateChannels(numchans: c_int) -> c_int;
+
+  /// Loads a sound effect from an [`SDL_RWops`].
+  ///
+  /// This supports WAV, AIFF, RIFF, OGG, VOC, and more (depending on the
+  /// decoders available). The data is converted to the device's format.
+  ///
+  /// * `freesrc` non-zero to close/free the `SDL_RWops` before returning.
+  ///
+  /// **Returns:** The new chunk, or null on error.
+  pub fn Mix_LoadWAV_RW(src: *mut SDL_RWops, freesrc: c_int) -> *mut Mix_Chunk;
+
+  /// Loads a sound effect from a file.
+  ///
+  /// See [`Mix_LoadWAV_RW`].
+  pub fn Mix_LoadWAV(file: *const c_char) -> *mut Mix_Chunk;
+
+  /// Loads music from a file.
+  ///
+  /// Music is streamed as it plays, rather than decoded all at once.
+  ///
+  /// **Returns:** The new music, or null on error.
+  pub fn Mix_LoadMUS(file: *const c_char) -> *mut Mix_Music;
+
+  /// Loads music from an [`SDL_RWops`].
+  ///
+  /// * `freesrc` non-zero to have the `SDL_RWops` closed/freed when the music
+  ///   is freed. Otherwise you must keep it open until the music is freed.
+  ///
+  /// **Returns:** The new music, or null on error.
+  pub fn Mix_LoadMUS_RW(src: *mut SDL_RWops, freesrc: c_int) -> *mut Mix_Music;
+
+  /// Loads music from an [`SDL_RWops`], as a specific format.
+  ///
+  /// See [`Mix_LoadMUS_RW`].
+  pub fn Mix_LoadMUSType_RW(
+    src: *mut SDL_RWops, type_: Mix_MusicType, freesrc: c_int,
+  ) -> *mut Mix_Music;
+
+  /// Makes a chunk from WAV data that's already in memory in the output
+  /// format.
+  ///
+  /// The chunk uses `mem` directly, so it must stay valid for as long as the
+  /// chunk exists.
+  pub fn Mix_QuickLoad_WAV(mem: *mut Uint8) -> *mut Mix_Chunk;
+
+  /// Makes a chunk from raw samples that are already in the output format.
+  ///
+  /// The chunk uses `mem` directly, so it must stay valid for as long as the
+  /// chunk exists.
+  pub fn Mix_QuickLoad_RAW(mem: *mut Uint8, len: Uint32) -> *mut Mix_Chunk;
+
+  /// Frees a chunk. If it's playing, it's halted first.
+  pub fn Mix_FreeChunk(chunk: *mut Mix_Chunk);
+
+  /// Frees music. If it's playing, it's halted first.
+  pub fn Mix_FreeMusic(music: *mut Mix_Music);
+
+  /// Gets the number of chunk decoders available.
+  pub fn Mix_GetNumChunkDecoders() -> c_int;
+
+  /// Gets the name of a chunk decoder, such as `"WAVE"` or `"OGG"`.
+  pub fn Mix_GetChunkDecoder(index: c_int) -> *const c_char;
+
+  /// Checks if a chunk decoder is available.
+  pub fn Mix_HasChunkDecoder(name: *const c_char) -> SDL_bool;
+
+  /// Gets the number of music decoders available.
+  pub fn Mix_GetNumMusicDecoders() -> c_int;
+
+  /// Gets the name of a music decoder, such as `"MP3"` or `"FLAC"`.
+  pub fn Mix_GetMusicDecoder(index: c_int) -> *const c_char;
+
+  /// Checks if a music decoder is available.
+  pub fn Mix_HasMusicDecoder(name: *const c_char) -> SDL_bool;
+
+  /// Gets the format of some music, or of the playing music if null.
+  pub fn Mix_GetMusicType(music: *const Mix_Music) -> Mix_MusicType;
+
+  /// Gets the title of some music (or of the playing music if null).
+  ///
+  /// If there's no title tag, this is the file name.
+  pub fn Mix_GetMusicTitle(music: *const Mix_Music) -> *const c_char;
+
+  /// Gets the title tag of some music (or of the playing music if null).
+  ///
+  /// **Returns:** The tag, or an empty string if there isn't one.
+  pub fn Mix_GetMusicTitleTag(music: *const Mix_Music) -> *const c_char;
+
+  /// Gets the artist tag of some music (or of the playing music if null).
+  pub fn Mix_GetMusicArtistTag(music: *const Mix_Music) -> *const c_char;
+
+  /// Gets the album tag of some music (or of the playing music if null).
+  pub fn Mix_GetMusicAlbumTag(music: *const Mix_Music) -> *const c_char;
+
+  /// Gets the copyright tag of some music (or of the playing music if null).
+  pub fn Mix_GetMusicCopyrightTag(music: *const Mix_Music) -> *const c_char;
+
+  /// Sets a function to process the final mixed output, after all effects.
+  ///
+  /// Pass `None` to remove the function.
+  pub fn Mix_SetPostMix(mix_func: Mix_MixFunc, arg: *mut c_void);
+
+  /// Replaces the music player with your own function.
+  ///
+  /// The function fills the stream with music, which is then mixed with the
+  /// channels as normal. Pass `None` to go back to the normal music player.
+  pub fn Mix_HookMusic(mix_func: Mix_MixFunc, arg: *mut c_void);
+
+  /// Sets a function to call when the music finishes (or is halted).
+  ///
+  /// Don't call any mixer functions from this callback.
+  pub fn Mix_HookMusicFinished(music_finished: Mix_MusicFinishedFunc);
+
+  /// Gets the `arg` pointer given to [`Mix_HookMusic`].
+  pub fn Mix_GetMusicHookData() -> *mut c_void;
+
+  /// Sets a function to call when any channel finishes (or is halted).
+  ///
+  /// Don't call any mixer functions from this callback.
+  pub fn Mix_ChannelFinished(channel_finished: Mix_ChannelFinishedFunc);
+
+  /// Adds an effect to a channel.
+  ///
+  /// Effects are applied in the order they're registered. The effect stays on
+  /// the channel (across any number of chunks) until it's removed.
+  ///
+  /// * `chan` the channel, or [`MIX_CHANNEL_POST`] for the final output.
+  /// * `f` the effect function.
+  /// * `d` a function to call when the effect is removed, or `None`.
+  /// * `arg` a pointer passed to both functions.
+  ///
+  /// **Returns:** Non-zero on success, or zero on error.
+  pub fn Mix_RegisterEffect(
+    chan: c_int, f: Mix_EffectFunc_t, d: Mix_EffectDone_t, arg: *mut c_void,
+  ) -> c_int;
+
+  /// Removes an effect from a channel.
+  ///
+  /// If the effect was registered more than once, only the first is removed.
+  ///
+  /// **Returns:** Non-zero on success, or zero on error.
+  pub fn Mix_UnregisterEffect(channel: c_int, f: Mix_EffectFunc_t) -> c_int;
+
+  /// Removes all effects from a channel.
+  ///
+  /// **Returns:** Non-zero on success, or zero on error.
+  pub fn Mix_UnregisterAllEffects(channel: c_int) -> c_int;
+
+  /// Sets the left and right volume of a channel (a built-in effect).
+  ///
+  /// 255 is full volume. Setting both to 255 removes the effect.
+  ///
+  /// **Returns:** Non-zero on success, or zero on error.
+  pub fn Mix_SetPanning(channel: c_int, left: Uint8, right: Uint8) -> c_int;
+
+  /// Sets the position of a channel's sound (a built-in effect).
+  ///
+  /// * `angle` degrees, with 0 being in front and going clockwise.
+  /// * `distance` from 0 (nearest) to 255 (farthest).
+  ///
+  /// **Returns:** Non-zero on success, or zero on error.
+  pub fn Mix_SetPosition(
+    channel: c_int, angle: Sint16, distance: Uint8,
+  ) -> c_int;
+
+  /// Sets the distance of a channel's sound (a built-in effect).
+  ///
+  /// * `distance` from 0 (nearest) to 255 (farthest).
+  ///
+  /// **Returns:** Non-zero on success, or zero on error.
+  pub fn Mix_SetDistance(channel: c_int, distance: Uint8) -> c_int;
+
+  /// Swaps the left and right speakers of a channel (a built-in effect).
+  ///
+  /// **Returns:** Non-zero on success, or zero on error.
+  pub fn Mix_SetReverseStereo(channel: c_int, flip: c_int) -> c_int;
+
+  /// Reserves the first `num` channels, so that playing on channel -1 won't
+  /// pick them.
+  ///
+  /// **Returns:** The number of channels reserved.
+  pub fn Mix_ReserveChannels(num: c_int) -> c_int;
+
+  /// Puts a channel into a group (-1 is the "no group" tag).
+  ///
+  /// **Returns:** Non-zero on success, or zero on error.
+  pub fn Mix_GroupChannel(which: c_int, tag: c_int) -> c_int;
+
+  /// Puts a range of channels (inclusive) into a group.
+  ///
+  /// **Returns:** The number of channels changed.
+  pub fn Mix_GroupChannels(from: c_int, to: c_int, tag: c_int) -> c_int;
+
+  /// Gets the first channel of a group that isn't playing.
+  ///
+  /// **Returns:** The channel, or -1 if none are free.
+  pub fn Mix_GroupAvailable(tag: c_int) -> c_int;
+
+  /// Gets the number of channels in a group (-1 for all channels).
+  pub fn Mix_GroupCount(tag: c_int) -> c_int;
+
+  /// Gets the channel of a group that's been playing the longest.
+  ///
+  /// **Returns:** The channel, or -1 if none are playing.
+  pub fn Mix_GroupOldest(tag: c_int) -> c_int;
+
+  /// Gets the channel of a group that started playing most recently.
+  ///
+  /// **Returns:** The channel, or -1 if none are playing.
+  pub fn Mix_GroupNewer(tag: c_int) -> c_int;
+
+  /// Plays a chunk on a channel.
+  ///
+  /// * `channel` the channel, or -1 for the first free unreserved channel.
+  /// * `loops` the number of extra times to play, or -1 to loop forever.
+  ///
+  /// **Returns:** The channel used, or -1 on error.
+  pub fn Mix_PlayChannel(
+    channel: c_int, chunk: *mut Mix_Chunk, loops: c_int,
+  ) -> c_int;
+
+  /// As [`Mix_PlayChannel`], but stops after at most `ticks` milliseconds
+  /// (-1 for no limit).
+  pub fn Mix_PlayChannelTimed(
+    channel: c_int, chunk: *mut Mix_Chunk, loops: c_int, ticks: c_int,
+  ) -> c_int;
+
+  /// Plays music, halting any music that's already playing.
+  ///
+  /// * `loops` the number of times to play, or -1 to loop forever. Note that
+  ///   unlike with chunks, 0 and 1 both mean to play once.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn Mix_PlayMusic(music: *mut Mix_Music, loops: c_int) -> c_int;
+
+  /// As [`Mix_PlayMusic`], but fades in over `ms` milliseconds.
+  pub fn Mix_FadeInMusic(
+    music: *mut Mix_Music, loops: c_int, ms: c_int,
+  ) -> c_int;
+
+  /// As [`Mix_FadeInMusic`], but starts from `position` (as with
+  /// [`Mix_SetMusicPosition`]).
+  pub fn Mix_FadeInMusicPos(
+    music: *mut Mix_Music, loops: c_int, ms: c_int, position: c_double,
+  ) -> c_int;
+
+  /// As [`Mix_PlayChannel`], but fades in over `ms` milliseconds.
+  pub fn Mix_FadeInChannel(
+    channel: c_int, chunk: *mut Mix_Chunk, loops: c_int, ms: c_int,
+  ) -> c_int;
+
+  /// As [`Mix_PlayChannelTimed`], but fades in over `ms` milliseconds.
+  pub fn Mix_FadeInChannelTimed(
+    channel: c_int, chunk: *mut Mix_Chunk, loops: c_int, ms: c_int,
+    ticks: c_int,
+  ) -> c_int;
+
+  /// Sets the volume of a channel (or all channels with -1).
+  ///
+  /// * `volume` from 0 to [`MIX_MAX_VOLUME`], or -1 to just get the volume.
+  ///
+  /// **Returns:** The previous volume (the average, if `channel` was -1).
+  pub fn Mix_Volume(channel: c_int, volume: c_int) -> c_int;
+
+  /// Sets the volume of a chunk.
+  ///
+  /// **Returns:** The previous volume.
+  pub fn Mix_VolumeChunk(chunk: *mut Mix_Chunk, volume: c_int) -> c_int;
+
+  /// Sets the music volume.
+  ///
+  /// **Returns:** The previous volume.
+  pub fn Mix_VolumeMusic(volume: c_int) -> c_int;
+
+  /// Gets the volume of some music (or of the playing music if null).
+  pub fn Mix_GetMusicVolume(music: *mut Mix_Music) -> c_int;
+
+  /// Sets the master volume, which scales all channels and the music.
+  ///
+  /// **Returns:** The previous master volume.
+  pub fn Mix_MasterVolume(volume: c_int) -> c_int;
+
+  /// Halts a channel (or all channels with -1).
+  ///
+  /// **Returns:** 0 (this can't fail).
+  pub fn Mix_HaltChannel(channel: c_int) -> c_int;
+
+  /// Halts all channels in a group.
+  pub fn Mix_HaltGroup(tag: c_int) -> c_int;
+
+  /// Halts the music.
+  pub fn Mix_HaltMusic() -> c_int;
+
+  /// Halts a channel (or all channels with -1) after `ticks` milliseconds.
+  ///
+  /// **Returns:** The number of channels changed.
+  pub fn Mix_ExpireChannel(channel: c_int, ticks: c_int) -> c_int;
+
+  /// Fades out a channel (or all channels with -1) over `ms` milliseconds.
+  ///
+  /// **Returns:** The number of channels that will fade out.
+  pub fn Mix_FadeOutChannel(which: c_int, ms: c_int) -> c_int;
+
+  /// Fades out all channels in a group over `ms` milliseconds.
+  ///
+  /// **Returns:** The number of channels that will fade out.
+  pub fn Mix_FadeOutGroup(tag: c_int, ms: c_int) -> c_int;
+
+  /// Fades out the music over `ms` milliseconds.
+  ///
+  /// **Returns:** Non-zero on success, or zero if there's no music playing.
+  pub fn Mix_FadeOutMusic(ms: c_int) -> c_int;
+
+  /// Gets the fading status of the music.
+  pub fn Mix_FadingMusic() -> Mix_Fading;
+
+  /// Gets the fading status of a channel.
+  pub fn Mix_FadingChannel(which: c_int) -> Mix_Fading;
+
+  /// Pauses a channel (or all channels with -1).
+  pub fn Mix_Pause(channel: c_int);
+
+  /// Resumes a channel (or all channels with -1).
+  pub fn Mix_Resume(channel: c_int);
+
+  /// Checks if a channel is paused.
+  ///
+  /// **Returns:** Non-zero if it's paused, or the number of paused channels
+  /// if `channel` was -1.
+  pub fn Mix_Paused(channel: c_int) -> c_int;
+
+  /// Pauses the music.
+  pub fn Mix_PauseMusic();
+
+  /// Resumes the music.
+  pub fn Mix_ResumeMusic();
+
+  /// Restarts the music from the beginning.
+  pub fn Mix_RewindMusic();
+
+  /// Checks if the music is paused.
+  pub fn Mix_PausedMusic() -> c_int;
+
+  /// Jumps to an order (pattern index) in MOD music.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn Mix_ModMusicJumpToOrder(order: c_int) -> c_int;
+
+  /// Starts a track in music that has more than one (eg: GME formats).
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn Mix_StartTrack(music: *mut Mix_Music, track: c_int) -> c_int;
+
+  /// Gets the number of tracks in some music.
+  ///
+  /// **Returns:** The number of tracks, or -1 on error.
+  pub fn Mix_GetNumTracks(music: *mut Mix_Music) -> c_int;
+
+  /// Sets the playing position of the music.
+  ///
+  /// The meaning of `position` depends on the format. For most formats it's
+  /// in seconds, for MOD music it's the pattern order.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn Mix_SetMusicPosition(position: c_double) -> c_int;
+
+  /// Gets the playing position of some music, in seconds.
+  ///
+  /// **Returns:** The position, or -1.0 if it's not known.
+  pub fn Mix_GetMusicPosition(music: *mut Mix_Music) -> c_double;
+
+  /// Gets the total length of some music (or of the playing music if null),
+  /// in seconds.
+  ///
+  /// **Returns:** The length, or -1.0 on error.
+  pub fn Mix_MusicDuration(music: *mut Mix_Music) -> c_double;
+
+  /// Gets the loop start time of some music, in seconds.
+  ///
+  /// **Returns:** The time, or -1.0 if there's no loop info.
+  pub fn Mix_GetMusicLoopStartTime(music: *mut Mix_Music) -> c_double;
+
+  /// Gets the loop end time of some music, in seconds.
+  ///
+  /// **Returns:** The time, or -1.0 if there's no loop info.
+  pub fn Mix_GetMusicLoopEndTime(music: *mut Mix_Music) -> c_double;
+
+  /// Gets the loop length of some music, in seconds.
+  ///
+  /// **Returns:** The length, or -1.0 if there's no loop info.
+  pub fn Mix_GetMusicLoopLengthTime(music: *mut Mix_Music) -> c_double;
+
+  /// Checks if a channel is playing.
+  ///
+  /// **Returns:** Non-zero if it's playing, or the number of playing
+  /// channels if `channel` was -1.
+  pub fn Mix_Playing(channel: c_int) -> c_int;
+
+  /// Checks if the music is playing.
+  pub fn Mix_PlayingMusic() -> c_int;
+
+  /// Sets an external command to use to play music, or null to use the
+  /// built-in player.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn Mix_SetMusicCMD(command: *const c_char) -> c_int;
+
+  /// Does nothing, kept for compatibility.
+  pub fn Mix_SetSynchroValue(value: c_int) -> c_int;
+
+  /// Does nothing, kept for compatibility.
+  pub fn Mix_GetSynchroValue() -> c_int;
+
+  /// Sets the sound font paths (separated by `;`) for MIDI playback.
+  ///
+  /// **Returns:** Non-zero on success, or zero on error.
+  pub fn Mix_SetSoundFonts(paths: *const c_char) -> c_int;
+
+  /// Gets the sound font paths, or null if none are set.
+  pub fn Mix_GetSoundFonts() -> *const c_char;
+
+  /// Calls `function` for each of the sound font paths.
+  ///
+  /// **Returns:** Non-zero if every call returned non-zero.
+  pub fn Mix_EachSoundFont(
+    function: Mix_EachSoundFontFunc, data: *mut c_void,
+  ) -> c_int;
+
+  /// Sets the path of the Timidity config file for MIDI playback.
+  ///
+  /// **Returns:** Non-zero on success, or zero on error.
+  pub fn Mix_SetTimidityCfg(path: *const c_char) -> c_int;
+
+  /// Gets the path of the Timidity config file, or null if none is set.
+  pub fn Mix_GetTimidityCfg() -> *const c_char;
+
+  /// Gets the chunk most recently played on a channel.
+  ///
+  /// The chunk might have been freed since, so check [`Mix_Playing`] first.
+  pub fn Mix_GetChunk(channel: c_int) -> *mut Mix_Chunk;
+
+  /// Closes the mixer, halting all playback.
+  ///
+  /// Call this as many times as the audio was opened, see [`Mix_QuerySpec`].
+  pub fn Mix_CloseAudio();
+}
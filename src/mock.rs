@@ -0,0 +1,399 @@
+//! A stand-in for SDL2, for unit testing code that calls into SDL.
+//!
+//! With the `mock` feature on, the build script doesn't link SDL2 at all.
+//! Instead this module defines its own versions of a core set of SDL2
+//! functions (exported under the real symbol names), so the normal bindings
+//! of the crate end up calling these. Each call is counted, and the results
+//! can be scripted with the functions in this module.
+//!
+//! The functions that the mock provides are listed in [`MockFn`]. Calling any
+//! other SDL function will be a link error, so the mock is only suitable for
+//! testing logic such as event handling and init sequencing, not for anything
+//! that actually puts things on screen.
+//!
+//! * Init functions track which subsystems are "initialized", and return the
+//!   value given to [`set_init_result`].
+//! * Events go in a small FIFO queue (up to [`MOCK_EVENT_QUEUE_LEN`] events).
+//!   Use [`push_event`] to script input for the code under test.
+//! * Time only moves when you call [`set_ticks`] or when the code under test
+//!   calls [`SDL_Delay`], so timing logic is deterministic.
+//! * The error string is whatever was given to [`set_error`].
+//!
+//! All of this state is global, so tests that use the mock should call
+//! [`reset`] first, and should not run at the same time as each other (eg: put
+//! them all in one `#[test]`, or use `--test-threads=1`).
+
+use core::{
+  ptr::addr_of_mut,
+  sync::atomic::{AtomicBool, AtomicU32, Ordering},
+};
+
+use crate::{c_char, c_int, events::*, stdinc::*, SDL_InitFlags};
+
+// makes rustdoc link properly!
+#[allow(unused)]
+use crate::{error::*, timer::*, *};
+
+/// The SDL functions that the mock provides.
+///
+/// Use with [`call_count`] to see how many times each was called.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[allow(missing_docs)]
+pub enum MockFn {
+  SDL_Init,
+  SDL_InitSubSystem,
+  SDL_QuitSubSystem,
+  SDL_WasInit,
+  SDL_Quit,
+  SDL_GetErrorMsg,
+  SDL_ClearError,
+  SDL_GetTicks,
+  SDL_GetPerformanceCounter,
+  SDL_GetPerformanceFrequency,
+  SDL_Delay,
+  SDL_PumpEvents,
+  SDL_PeepEvents,
+  SDL_HasEvent,
+  SDL_HasEvents,
+  SDL_FlushEvent,
+  SDL_FlushEvents,
+  SDL_PollEvent,
+  SDL_PushEvent,
+}
+const MOCK_FN_COUNT: usize = MockFn::SDL_PushEvent as usize + 1;
+
+/// How many events the mock's event queue can hold.
+pub const MOCK_EVENT_QUEUE_LEN: usize = 128;
+
+struct MockState {
+  inited: u32,
+  init_result: c_int,
+  ticks: u64,
+  error: &'static [u8],
+  events: [SDL_Event; MOCK_EVENT_QUEUE_LEN],
+  events_len: usize,
+}
+impl MockState {
+  const NEW: Self = Self {
+    inited: 0,
+    init_result: 0,
+    ticks: 0,
+    error: b"",
+    events: [SDL_Event { padding: [0; 56] }; MOCK_EVENT_QUEUE_LEN],
+    events_len: 0,
+  };
+
+  fn push(&mut self, event: SDL_Event) -> bool {
+    if self.events_len < MOCK_EVENT_QUEUE_LEN {
+      self.events[self.events_len] = event;
+      self.events_len += 1;
+      true
+    } else {
+      false
+    }
+  }
+
+  fn remove(&mut self, index: usize) -> SDL_Event {
+    let event = self.events[index];
+    self.events.copy_within(index + 1..self.events_len, index);
+    self.events_len -= 1;
+    event
+  }
+
+  fn in_range(
+    event: &SDL_Event, min: SDL_EventType, max: SDL_EventType,
+  ) -> bool {
+    let t = unsafe { event.type_ };
+    min <= t && t <= max
+  }
+}
+
+static mut STATE: MockState = MockState::NEW;
+static STATE_LOCKED: AtomicBool = AtomicBool::new(false);
+static CALL_COUNTS: [AtomicU32; MOCK_FN_COUNT] = {
+  #[allow(clippy::declare_interior_mutable_const)]
+  const ZERO: AtomicU32 = AtomicU32::new(0);
+  [ZERO; MOCK_FN_COUNT]
+};
+
+fn with_state<R>(f: impl FnOnce(&mut MockState) -> R) -> R {
+  while STATE_LOCKED
+    .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+    .is_err()
+  {
+    core::hint::spin_loop();
+  }
+  let r = f(unsafe { &mut *addr_of_mut!(STATE) });
+  STATE_LOCKED.store(false, Ordering::Release);
+  r
+}
+
+fn record(f: MockFn) {
+  CALL_COUNTS[f as usize].fetch_add(1, Ordering::Relaxed);
+}
+
+/// Puts the mock back to how it starts: nothing initialized, no events, no
+/// error, zero ticks, and all call counts at zero.
+pub fn reset() {
+  with_state(|s| *s = MockState::NEW);
+  for count in CALL_COUNTS.iter() {
+    count.store(0, Ordering::Relaxed);
+  }
+}
+
+/// How many times the given function has been called since the last [`reset`].
+#[must_use]
+pub fn call_count(f: MockFn) -> u32 {
+  CALL_COUNTS[f as usize].load(Ordering::Relaxed)
+}
+
+/// Sets what [`SDL_Init`] and [`SDL_InitSubSystem`] return.
+///
+/// A negative value makes them fail (without changing what's initialized).
+pub fn set_init_result(result: c_int) {
+  with_state(|s| s.init_result = result);
+}
+
+/// Sets the current time, in milliseconds.
+pub fn set_ticks(ms: u64) {
+  with_state(|s| s.ticks = ms);
+}
+
+/// Sets the text that [`SDL_GetErrorMsg`] gives (without a null terminator).
+pub fn set_error(msg: &'static [u8]) {
+  with_state(|s| s.error = msg);
+}
+
+/// Adds an event to the back of the event queue.
+///
+/// **Returns:** `false` if the queue was full.
+pub fn push_event(event: SDL_Event) -> bool {
+  with_state(|s| s.push(event))
+}
+
+/// How many events are currently in the event queue.
+#[must_use]
+pub fn pending_events() -> usize {
+  with_state(|s| s.events_len)
+}
+
+#[no_mangle]
+unsafe extern "C" fn SDL_Init(flags: SDL_InitFlags) -> c_int {
+  record(MockFn::SDL_Init);
+  with_state(|s| {
+    if s.init_result >= 0 {
+      s.inited |= flags.0;
+    }
+    s.init_result
+  })
+}
+
+#[no_mangle]
+unsafe extern "C" fn SDL_InitSubSystem(flags: SDL_InitFlags) -> c_int {
+  record(MockFn::SDL_InitSubSystem);
+  with_state(|s| {
+    if s.init_result >= 0 {
+      s.inited |= flags.0;
+    }
+    s.init_result
+  })
+}
+
+#[no_mangle]
+unsafe extern "C" fn SDL_QuitSubSystem(flags: SDL_InitFlags) -> c_int {
+  record(MockFn::SDL_QuitSubSystem);
+  with_state(|s| s.inited &= !flags.0);
+  0
+}
+
+#[no_mangle]
+unsafe extern "C" fn SDL_WasInit(flags: SDL_InitFlags) -> c_int {
+  record(MockFn::SDL_WasInit);
+  with_state(|s| {
+    if flags.0 == 0 {
+      s.inited as c_int
+    } else {
+      (s.inited & flags.0) as c_int
+    }
+  })
+}
+
+#[no_mangle]
+unsafe extern "C" fn SDL_Quit() {
+  record(MockFn::SDL_Quit);
+  with_state(|s| {
+    s.inited = 0;
+    s.events_len = 0;
+  });
+}
+
+#[no_mangle]
+unsafe extern "C" fn SDL_GetErrorMsg(
+  errstr: *mut c_char, maxlen: c_int,
+) -> *mut c_char {
+  record(MockFn::SDL_GetErrorMsg);
+  if !errstr.is_null() && maxlen > 0 {
+    let error = with_state(|s| s.error);
+    let len = error.len().min(maxlen as usize - 1);
+    errstr.cast::<u8>().copy_from_nonoverlapping(error.as_ptr(), len);
+    *errstr.add(len) = 0;
+  }
+  errstr
+}
+
+#[no_mangle]
+unsafe extern "C" fn SDL_ClearError() {
+  record(MockFn::SDL_ClearError);
+  with_state(|s| s.error = b"");
+}
+
+#[no_mangle]
+unsafe extern "C" fn SDL_GetTicks() -> Uint32 {
+  record(MockFn::SDL_GetTicks);
+  with_state(|s| s.ticks as Uint32)
+}
+
+#[no_mangle]
+unsafe extern "C" fn SDL_GetPerformanceCounter() -> Uint64 {
+  record(MockFn::SDL_GetPerformanceCounter);
+  with_state(|s| s.ticks)
+}
+
+#[no_mangle]
+unsafe extern "C" fn SDL_GetPerformanceFrequency() -> Uint64 {
+  record(MockFn::SDL_GetPerformanceFrequency);
+  1000
+}
+
+#[no_mangle]
+unsafe extern "C" fn SDL_Delay(ms: Uint32) {
+  record(MockFn::SDL_Delay);
+  with_state(|s| s.ticks += u64::from(ms));
+}
+
+#[no_mangle]
+unsafe extern "C" fn SDL_PumpEvents() {
+  record(MockFn::SDL_PumpEvents);
+}
+
+#[no_mangle]
+unsafe extern "C" fn SDL_PeepEvents(
+  events: *mut SDL_Event, numevents: c_int, action: SDL_eventaction,
+  minType: SDL_EventType, maxType: SDL_EventType,
+) -> c_int {
+  record(MockFn::SDL_PeepEvents);
+  let numevents = numevents.max(0) as usize;
+  with_state(|s| {
+    let mut stored = 0;
+    if events.is_null() {
+      // like SDL, count the matching events without changing the queue.
+      if action != SDL_ADDEVENT {
+        stored = s.events[..s.events_len]
+          .iter()
+          .filter(|e| MockState::in_range(e, minType, maxType))
+          .count();
+      }
+    } else if action == SDL_ADDEVENT {
+      while stored < numevents && s.push(*events.add(stored)) {
+        stored += 1;
+      }
+    } else {
+      let mut i = 0;
+      while i < s.events_len && stored < numevents {
+        if MockState::in_range(&s.events[i], minType, maxType) {
+          if action == SDL_GETEVENT {
+            *events.add(stored) = s.remove(i);
+          } else {
+            *events.add(stored) = s.events[i];
+            i += 1;
+          }
+          stored += 1;
+        } else {
+          i += 1;
+        }
+      }
+    }
+    stored as c_int
+  })
+}
+
+#[no_mangle]
+unsafe extern "C" fn SDL_HasEvent(type_: SDL_EventType) -> SDL_bool {
+  record(MockFn::SDL_HasEvent);
+  with_state(|s| {
+    SDL_bool::new(
+      s.events[..s.events_len]
+        .iter()
+        .any(|e| MockState::in_range(e, type_, type_)),
+    )
+  })
+}
+
+#[no_mangle]
+unsafe extern "C" fn SDL_HasEvents(
+  minType: SDL_EventType, maxType: SDL_EventType,
+) -> SDL_bool {
+  record(MockFn::SDL_HasEvents);
+  with_state(|s| {
+    SDL_bool::new(
+      s.events[..s.events_len]
+        .iter()
+        .any(|e| MockState::in_range(e, minType, maxType)),
+    )
+  })
+}
+
+#[no_mangle]
+unsafe extern "C" fn SDL_FlushEvent(type_: SDL_EventType) {
+  record(MockFn::SDL_FlushEvent);
+  flush_events(type_, type_);
+}
+
+#[no_mangle]
+unsafe extern "C" fn SDL_FlushEvents(
+  minType: SDL_EventType, maxType: SDL_EventType,
+) {
+  record(MockFn::SDL_FlushEvents);
+  flush_events(minType, maxType);
+}
+
+fn flush_events(min: SDL_EventType, max: SDL_EventType) {
+  with_state(|s| {
+    let mut i = 0;
+    while i < s.events_len {
+      if MockState::in_range(&s.events[i], min, max) {
+        s.remove(i);
+      } else {
+        i += 1;
+      }
+    }
+  });
+}
+
+#[no_mangle]
+unsafe extern "C" fn SDL_PollEvent(event: *mut SDL_Event) -> c_int {
+  record(MockFn::SDL_PollEvent);
+  with_state(|s| {
+    if s.events_len == 0 {
+      0
+    } else {
+      if !event.is_null() {
+        *event = s.remove(0);
+      }
+      1
+    }
+  })
+}
+
+#[no_mangle]
+unsafe extern "C" fn SDL_PushEvent(event: *mut SDL_Event) -> c_int {
+  record(MockFn::SDL_PushEvent);
+  with_state(|s| {
+    if s.push(*event) {
+      1
+    } else {
+      s.error = b"Event queue is full";
+      -1
+    }
+  })
+}
@@ -10,6 +10,7 @@ pub struct SDL_Cursor(c_void);
 
 /// Used with [`SDL_CreateSystemCursor`]
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_SystemCursor(pub i32);
 
@@ -43,6 +44,7 @@ pub const SDL_NUM_SYSTEM_CURSORS: usize = 12;
 
 /// Scroll direction types for the Scroll event
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_MouseWheelDirection(pub i32);
 
@@ -256,3 +258,71 @@ pub const SDL_BUTTON_RMASK: u32 = SDL_BUTTON(SDL_BUTTON_RIGHT);
 pub const SDL_BUTTON_X1MASK: u32 = SDL_BUTTON(SDL_BUTTON_X1);
 /// Mask for the extra button 2.
 pub const SDL_BUTTON_X2MASK: u32 = SDL_BUTTON(SDL_BUTTON_X2);
+
+/// A mouse position along with which buttons are held.
+///
+/// This is the info from [`SDL_GetMouseState`] (and friends), without the out
+/// pointers or the button mask.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct MouseState {
+  /// Horizontal position (or motion, for the relative state).
+  pub x: c_int,
+  /// Vertical position (or motion, for the relative state).
+  pub y: c_int,
+  /// If the left button is down.
+  pub left: bool,
+  /// If the middle button is down.
+  pub middle: bool,
+  /// If the right button is down.
+  pub right: bool,
+  /// If extra button 1 is down.
+  pub x1: bool,
+  /// If extra button 2 is down.
+  pub x2: bool,
+}
+impl MouseState {
+  /// Builds a state from a position and an `SDL_BUTTON_*MASK` button mask.
+  #[inline]
+  #[must_use]
+  pub const fn from_buttons(x: c_int, y: c_int, buttons: u32) -> Self {
+    Self {
+      x,
+      y,
+      left: buttons & SDL_BUTTON_LMASK != 0,
+      middle: buttons & SDL_BUTTON_MMASK != 0,
+      right: buttons & SDL_BUTTON_RMASK != 0,
+      x1: buttons & SDL_BUTTON_X1MASK != 0,
+      x2: buttons & SDL_BUTTON_X2MASK != 0,
+    }
+  }
+
+  /// Calls [`SDL_GetMouseState`]: the position is relative to the focus
+  /// window.
+  #[inline]
+  #[must_use]
+  pub unsafe fn get() -> Self {
+    let (mut x, mut y) = (0, 0);
+    let buttons = SDL_GetMouseState(&mut x, &mut y);
+    Self::from_buttons(x, y, buttons)
+  }
+
+  /// Calls [`SDL_GetRelativeMouseState`]: the position is the motion since
+  /// the last call.
+  #[inline]
+  #[must_use]
+  pub unsafe fn get_relative() -> Self {
+    let (mut x, mut y) = (0, 0);
+    let buttons = SDL_GetRelativeMouseState(&mut x, &mut y);
+    Self::from_buttons(x, y, buttons)
+  }
+
+  /// Calls [`SDL_GetGlobalMouseState`]: the position is in desktop
+  /// coordinates.
+  #[inline]
+  #[must_use]
+  pub unsafe fn get_global() -> Self {
+    let (mut x, mut y) = (0, 0);
+    let buttons = SDL_GetGlobalMouseState(&mut x, &mut y);
+    Self::from_buttons(x, y, buttons)
+  }
+}
@@ -0,0 +1,174 @@
+//! Mutexes, semaphores, and condition variables.
+
+use core::time::Duration;
+
+use crate::{c_int, c_void, stdinc::*, timer::duration_to_ms};
+
+// makes rustdoc link properly!
+#[allow(unused)]
+use crate::error::*;
+
+/// The `SDL_*WaitTimeout` functions return this when the time runs out.
+pub const SDL_MUTEX_TIMEDOUT: c_int = 1;
+
+/// Passed as a timeout, this waits forever.
+pub const SDL_MUTEX_MAXWAIT: Uint32 = !0;
+
+/// SDL's opaque mutex type.
+#[repr(transparent)]
+pub struct SDL_mutex(c_void);
+
+/// SDL's opaque semaphore type.
+#[repr(transparent)]
+pub struct SDL_sem(c_void);
+
+/// SDL's opaque condition variable type.
+#[repr(transparent)]
+pub struct SDL_cond(c_void);
+
+extern "C" {
+  /// Create a new mutex.
+  ///
+  /// All newly-created mutexes begin in the _unlocked_ state. SDL mutexes are
+  /// reentrant.
+  ///
+  /// **Returns:** The initialized and unlocked mutex, or NULL on failure (call
+  /// [`SDL_GetErrorMsg`] for more information).
+  pub fn SDL_CreateMutex() -> *mut SDL_mutex;
+
+  /// Lock the mutex.
+  ///
+  /// This will block until the mutex is available. It's legal for the owning
+  /// thread to lock an already-locked mutex, as long as it's unlocked the same
+  /// number of times.
+  ///
+  /// **Returns:** 0, or -1 on error.
+  pub fn SDL_LockMutex(mutex: *mut SDL_mutex) -> c_int;
+
+  /// Try to lock a mutex without blocking.
+  ///
+  /// **Returns:** 0, [`SDL_MUTEX_TIMEDOUT`] if the mutex would block, or -1
+  /// on error.
+  pub fn SDL_TryLockMutex(mutex: *mut SDL_mutex) -> c_int;
+
+  /// Unlock the mutex.
+  ///
+  /// It is an error to unlock a mutex that has not been locked by the current
+  /// thread, and doing so results in undefined behavior.
+  ///
+  /// **Returns:** 0, or -1 on error.
+  pub fn SDL_UnlockMutex(mutex: *mut SDL_mutex) -> c_int;
+
+  /// Destroy a mutex created with [`SDL_CreateMutex`].
+  ///
+  /// It is not safe to destroy a mutex that is locked.
+  pub fn SDL_DestroyMutex(mutex: *mut SDL_mutex);
+
+  /// Create a semaphore, with the given starting value.
+  ///
+  /// **Returns:** The new semaphore, or NULL on failure (call
+  /// [`SDL_GetErrorMsg`] for more information).
+  pub fn SDL_CreateSemaphore(initial_value: Uint32) -> *mut SDL_sem;
+
+  /// Destroy a semaphore.
+  ///
+  /// It is not safe to destroy a semaphore if there are threads currently
+  /// waiting on it.
+  pub fn SDL_DestroySemaphore(sem: *mut SDL_sem);
+
+  /// Wait until a semaphore has a positive value and then decrement it.
+  ///
+  /// **Returns:** 0 on success or a negative error code on failure.
+  pub fn SDL_SemWait(sem: *mut SDL_sem) -> c_int;
+
+  /// See if a semaphore has a positive value and decrement it if it does.
+  ///
+  /// **Returns:** 0 if the wait succeeds, [`SDL_MUTEX_TIMEDOUT`] if the wait
+  /// would block, or a negative error code on failure.
+  pub fn SDL_SemTryWait(sem: *mut SDL_sem) -> c_int;
+
+  /// Wait until a semaphore has a positive value and then decrement it, for
+  /// up to `ms` milliseconds.
+  ///
+  /// See [`sem_wait_timeout`] for a `Duration` version.
+  ///
+  /// **Returns:** 0 if the wait succeeds, [`SDL_MUTEX_TIMEDOUT`] if the wait
+  /// doesn't succeed in the allotted time, or a negative error code on
+  /// failure.
+  pub fn SDL_SemWaitTimeout(sem: *mut SDL_sem, ms: Uint32) -> c_int;
+
+  /// Atomically increment a semaphore's value and wake waiting threads.
+  ///
+  /// **Returns:** 0 on success or a negative error code on failure.
+  pub fn SDL_SemPost(sem: *mut SDL_sem) -> c_int;
+
+  /// Get the current value of a semaphore.
+  pub fn SDL_SemValue(sem: *mut SDL_sem) -> Uint32;
+
+  /// Create a condition variable.
+  ///
+  /// **Returns:** A new condition variable, or NULL on failure (call
+  /// [`SDL_GetErrorMsg`] for more information).
+  pub fn SDL_CreateCond() -> *mut SDL_cond;
+
+  /// Destroy a condition variable.
+  pub fn SDL_DestroyCond(cond: *mut SDL_cond);
+
+  /// Restart one of the threads that are waiting on the condition variable.
+  ///
+  /// **Returns:** 0 on success or a negative error code on failure.
+  pub fn SDL_CondSignal(cond: *mut SDL_cond) -> c_int;
+
+  /// Restart all threads that are waiting on the condition variable.
+  ///
+  /// **Returns:** 0 on success or a negative error code on failure.
+  pub fn SDL_CondBroadcast(cond: *mut SDL_cond) -> c_int;
+
+  /// Wait until a condition variable is signaled.
+  ///
+  /// This unlocks `mutex` and waits for another thread to call
+  /// [`SDL_CondSignal`] or [`SDL_CondBroadcast`] on `cond`. Once it's
+  /// signaled, the mutex is re-locked and the function returns. The mutex
+  /// must be locked before calling this.
+  ///
+  /// **Returns:** 0 when it is signaled or a negative error code on failure.
+  pub fn SDL_CondWait(cond: *mut SDL_cond, mutex: *mut SDL_mutex) -> c_int;
+
+  /// Like [`SDL_CondWait`], but gives up after `ms` milliseconds.
+  ///
+  /// See [`cond_wait_timeout`] for a `Duration` version.
+  ///
+  /// **Returns:** 0 if the condition variable is signaled,
+  /// [`SDL_MUTEX_TIMEDOUT`] if the condition is not signaled in the allotted
+  /// time, or a negative error code on failure.
+  pub fn SDL_CondWaitTimeout(
+    cond: *mut SDL_cond, mutex: *mut SDL_mutex, ms: Uint32,
+  ) -> c_int;
+}
+
+/// [`SDL_SemWaitTimeout`] for a `Duration` (see [`duration_to_ms`]).
+///
+/// A timeout that's too long to fit saturates to [`SDL_MUTEX_MAXWAIT`], which
+/// waits forever.
+///
+/// ## Safety
+/// * `sem` must be a live semaphore from [`SDL_CreateSemaphore`].
+#[inline]
+pub unsafe fn sem_wait_timeout(sem: *mut SDL_sem, timeout: Duration) -> c_int {
+  SDL_SemWaitTimeout(sem, duration_to_ms(timeout))
+}
+
+/// [`SDL_CondWaitTimeout`] for a `Duration` (see [`duration_to_ms`]).
+///
+/// A timeout that's too long to fit saturates to [`SDL_MUTEX_MAXWAIT`], which
+/// waits forever.
+///
+/// ## Safety
+/// * `cond` must be a live condition variable from [`SDL_CreateCond`].
+/// * `mutex` must be a live mutex, locked by this thread.
+#[inline]
+pub unsafe fn cond_wait_timeout(
+  cond: *mut SDL_cond, mutex: *mut SDL_mutex, timeout: Duration,
+) -> c_int {
+  SDL_CondWaitTimeout(cond, mutex, duration_to_ms(timeout))
+}
@@ -0,0 +1,438 @@
+//! Bindings to the SDL2_net library, for simple TCP and UDP networking.
+//!
+//! This is a separate library from SDL2 itself, so it must also be installed
+//! for your program to link and run. These bindings match SDL2_net 2.2.
+//!
+//! Socket handles are pointer newtypes (like [`SDL_GLContext`]), so check them
+//! with `is_null` after opening.
+//!
+//! [`SDL_GLContext`]: crate::video::SDL_GLContext
+
+use crate::{c_char, c_int, c_void, stdinc::*, version::*};
+
+/// The major version of SDL2_net that these bindings are for.
+pub const SDL_NET_MAJOR_VERSION: u8 = 2;
+/// The minor version of SDL2_net that these bindings are for.
+pub const SDL_NET_MINOR_VERSION: u8 = 2;
+/// The patch version of SDL2_net that these bindings are for.
+pub const SDL_NET_PATCHLEVEL: u8 = 0;
+
+/// Fills in the version of SDL2_net that these bindings are for.
+///
+/// To get the version of the library you're actually linked to, use
+/// [`SDLNet_Linked_Version`].
+#[inline]
+pub fn SDL_NET_VERSION(x: &mut SDL_version) {
+  x.major = SDL_NET_MAJOR_VERSION;
+  x.minor = SDL_NET_MINOR_VERSION;
+  x.patch = SDL_NET_PATCHLEVEL;
+}
+
+/// An IPv4 address and port.
+///
+/// Both fields are in network byte order (big-endian), so use
+/// [`SDLNet_Read32`]/[`SDLNet_Read16`] to get the values, or fill this in with
+/// [`SDLNet_ResolveHost`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct IPaddress {
+  /// The IPv4 host address, big-endian.
+  pub host: Uint32,
+  /// The port, big-endian.
+  pub port: Uint16,
+}
+
+/// Any address, for a server to listen on all interfaces.
+pub const INADDR_ANY: Uint32 = 0x00000000;
+/// Not a valid address.
+pub const INADDR_NONE: Uint32 = 0xFFFFFFFF;
+/// The loopback address, `127.0.0.1`.
+pub const INADDR_LOOPBACK: Uint32 = 0x7F000001;
+/// The broadcast address, `255.255.255.255`.
+pub const INADDR_BROADCAST: Uint32 = 0xFFFFFFFF;
+
+/// The number of channels a UDP socket has.
+pub const SDLNET_MAX_UDPCHANNELS: c_int = 32;
+/// The number of addresses that can be bound to one UDP channel.
+pub const SDLNET_MAX_UDPADDRESSES: c_int = 4;
+
+/// A newtype'd `c_void`.
+#[allow(unused)]
+#[repr(transparent)]
+pub struct _TCPsocket(c_void);
+
+/// A handle to a TCP socket.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct TCPsocket(pub *mut _TCPsocket);
+impl TCPsocket {
+  /// Checks if the socket pointer is null.
+  #[inline]
+  #[must_use]
+  pub fn is_null(self) -> bool {
+    self.0.is_null()
+  }
+}
+
+/// A newtype'd `c_void`.
+#[allow(unused)]
+#[repr(transparent)]
+pub struct _UDPsocket(c_void);
+
+/// A handle to a UDP socket.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct UDPsocket(pub *mut _UDPsocket);
+impl UDPsocket {
+  /// Checks if the socket pointer is null.
+  #[inline]
+  #[must_use]
+  pub fn is_null(self) -> bool {
+    self.0.is_null()
+  }
+}
+
+/// A UDP packet.
+///
+/// Allocate these with [`SDLNet_AllocPacket`].
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct UDPpacket {
+  /// The channel to send on, or the channel it was received on (-1 if it
+  /// wasn't from a bound address).
+  pub channel: c_int,
+  /// The packet data.
+  pub data: *mut Uint8,
+  /// The length of the packet data.
+  pub len: c_int,
+  /// The size of the `data` buffer.
+  pub maxlen: c_int,
+  /// After sending, the number of bytes sent (or -1 on error).
+  pub status: c_int,
+  /// The address to send to, or the address it was received from.
+  pub address: IPaddress,
+}
+impl Default for UDPpacket {
+  #[inline]
+  fn default() -> Self {
+    unsafe { core::mem::zeroed() }
+  }
+}
+
+/// A newtype'd `c_void`.
+#[allow(unused)]
+#[repr(transparent)]
+pub struct _SDLNet_SocketSet(c_void);
+
+/// A handle to a set of sockets that can be checked for activity together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct SDLNet_SocketSet(pub *mut _SDLNet_SocketSet);
+impl SDLNet_SocketSet {
+  /// Checks if the set pointer is null.
+  #[inline]
+  #[must_use]
+  pub fn is_null(self) -> bool {
+    self.0.is_null()
+  }
+}
+
+/// The part of every socket that's the same for TCP and UDP.
+#[derive(Debug, Clone, Copy, Default)]
+#[repr(C)]
+pub struct _SDLNet_GenericSocket {
+  /// Non-zero if the socket has data ready, after
+  /// [`SDLNet_CheckSockets`].
+  pub ready: c_int,
+}
+
+/// A handle to either a TCP or UDP socket.
+///
+/// Make this from either kind of socket with `From`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct SDLNet_GenericSocket(pub *mut _SDLNet_GenericSocket);
+impl From<TCPsocket> for SDLNet_GenericSocket {
+  #[inline]
+  fn from(sock: TCPsocket) -> Self {
+    Self(sock.0.cast())
+  }
+}
+impl From<UDPsocket> for SDLNet_GenericSocket {
+  #[inline]
+  fn from(sock: UDPsocket) -> Self {
+    Self(sock.0.cast())
+  }
+}
+
+extern "C" {
+  /// Gets the version of the SDL2_net library that's linked.
+  pub fn SDLNet_Linked_Version() -> *const SDL_version;
+
+  /// Initializes the library.
+  ///
+  /// This doesn't need SDL itself to be initialized.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn SDLNet_Init() -> c_int;
+
+  /// Shuts down the library.
+  pub fn SDLNet_Quit();
+
+  /// Gets the last SDL2_net error message.
+  pub fn SDLNet_GetError() -> *const c_char;
+
+  /// Resolves a host name and port into an address.
+  ///
+  /// * `host` the host name, or null to make an address for a server to
+  ///   listen on (with [`INADDR_ANY`]).
+  /// * `port` the port, in native byte order.
+  ///
+  /// **Returns:** 0 on success, or -1 if the host couldn't be resolved (and
+  /// `address.host` is set to [`INADDR_NONE`]).
+  pub fn SDLNet_ResolveHost(
+    address: *mut IPaddress, host: *const c_char, port: Uint16,
+  ) -> c_int;
+
+  /// Resolves an address into a host name.
+  ///
+  /// **Returns:** The host name (in a static buffer), or null on error.
+  pub fn SDLNet_ResolveIP(ip: *const IPaddress) -> *const c_char;
+
+  /// Gets the addresses of the local network interfaces.
+  ///
+  /// **Returns:** The number of addresses written, at most `maxcount`.
+  pub fn SDLNet_GetLocalAddresses(
+    addresses: *mut IPaddress, maxcount: c_int,
+  ) -> c_int;
+
+  /// Opens a TCP socket.
+  ///
+  /// If `ip.host` is [`INADDR_ANY`] or [`INADDR_NONE`], this makes a server
+  /// socket that listens on `ip.port`. Otherwise, it connects to the address.
+  ///
+  /// **Returns:** The new socket, or null on error.
+  pub fn SDLNet_TCP_Open(ip: *mut IPaddress) -> TCPsocket;
+
+  /// Accepts a connection on a server socket.
+  ///
+  /// This doesn't block.
+  ///
+  /// **Returns:** The new connection's socket, or null if there isn't one.
+  pub fn SDLNet_TCP_Accept(server: TCPsocket) -> TCPsocket;
+
+  /// Gets the address of the other end of a TCP connection.
+  ///
+  /// **Returns:** The address, or null for a server socket.
+  pub fn SDLNet_TCP_GetPeerAddress(sock: TCPsocket) -> *mut IPaddress;
+
+  /// Sends data on a TCP socket, blocking until it's all sent.
+  ///
+  /// **Returns:** The number of bytes sent. Less than `len` means an error.
+  pub fn SDLNet_TCP_Send(
+    sock: TCPsocket, data: *const c_void, len: c_int,
+  ) -> c_int;
+
+  /// Receives data on a TCP socket, blocking until some data arrives.
+  ///
+  /// **Returns:** The number of bytes received, or 0 or less if the
+  /// connection closed or there was an error.
+  pub fn SDLNet_TCP_Recv(
+    sock: TCPsocket, data: *mut c_void, maxlen: c_int,
+  ) -> c_int;
+
+  /// Closes a TCP socket.
+  pub fn SDLNet_TCP_Close(sock: TCPsocket);
+
+  /// Allocates a packet with a data buffer of `size` bytes.
+  ///
+  /// **Returns:** The new packet, or null on error.
+  pub fn SDLNet_AllocPacket(size: c_int) -> *mut UDPpacket;
+
+  /// Changes the size of a packet's data buffer.
+  ///
+  /// **Returns:** The new size, or the old size on error.
+  pub fn SDLNet_ResizePacket(packet: *mut UDPpacket, newsize: c_int) -> c_int;
+
+  /// Frees a packet.
+  pub fn SDLNet_FreePacket(packet: *mut UDPpacket);
+
+  /// Allocates a null terminated array of `howmany` packets.
+  ///
+  /// **Returns:** The array, or null on error.
+  pub fn SDLNet_AllocPacketV(
+    howmany: c_int, size: c_int,
+  ) -> *mut *mut UDPpacket;
+
+  /// Frees an array of packets from [`SDLNet_AllocPacketV`].
+  pub fn SDLNet_FreePacketV(packetV: *mut *mut UDPpacket);
+
+  /// Opens a UDP socket on a port (in native byte order).
+  ///
+  /// * `port` 0 to pick any available port.
+  ///
+  /// **Returns:** The new socket, or null on error.
+  pub fn SDLNet_UDP_Open(port: Uint16) -> UDPsocket;
+
+  /// Makes a UDP socket drop a percentage of packets, for testing.
+  pub fn SDLNet_UDP_SetPacketLoss(sock: UDPsocket, percent: c_int);
+
+  /// Binds an address to a channel of a UDP socket.
+  ///
+  /// * `channel` the channel, or -1 for the first free channel.
+  ///
+  /// **Returns:** The channel bound, or -1 on error.
+  pub fn SDLNet_UDP_Bind(
+    sock: UDPsocket, channel: c_int, address: *const IPaddress,
+  ) -> c_int;
+
+  /// Unbinds all addresses from a channel of a UDP socket.
+  pub fn SDLNet_UDP_Unbind(sock: UDPsocket, channel: c_int);
+
+  /// Gets the primary address bound to a channel of a UDP socket.
+  ///
+  /// * `channel` the channel, or -1 for the socket's own address.
+  ///
+  /// **Returns:** The address, or null if the channel isn't bound.
+  pub fn SDLNet_UDP_GetPeerAddress(
+    sock: UDPsocket, channel: c_int,
+  ) -> *mut IPaddress;
+
+  /// Sends several packets. Each goes to its channel's addresses, or to its
+  /// own `address` if its channel is -1.
+  ///
+  /// **Returns:** The number of packets sent.
+  pub fn SDLNet_UDP_SendV(
+    sock: UDPsocket, packets: *mut *mut UDPpacket, npackets: c_int,
+  ) -> c_int;
+
+  /// Sends a packet to the addresses bound to a channel, or to the packet's
+  /// own `address` if `channel` is -1.
+  ///
+  /// **Returns:** The number of destinations sent to, or 0 on error.
+  pub fn SDLNet_UDP_Send(
+    sock: UDPsocket, channel: c_int, packet: *mut UDPpacket,
+  ) -> c_int;
+
+  /// Receives as many packets as are waiting (up to the length of the null
+  /// terminated `packets` array).
+  ///
+  /// This doesn't block.
+  ///
+  /// **Returns:** The number of packets received, or -1 on error.
+  pub fn SDLNet_UDP_RecvV(
+    sock: UDPsocket, packets: *mut *mut UDPpacket,
+  ) -> c_int;
+
+  /// Receives a packet, if one is waiting.
+  ///
+  /// This doesn't block.
+  ///
+  /// **Returns:** 1 if a packet was received, 0 if not, or -1 on error.
+  pub fn SDLNet_UDP_Recv(sock: UDPsocket, packet: *mut UDPpacket) -> c_int;
+
+  /// Closes a UDP socket.
+  pub fn SDLNet_UDP_Close(sock: UDPsocket);
+
+  /// Allocates a socket set that can hold up to `maxsockets` sockets.
+  ///
+  /// **Returns:** The new set, or null on error.
+  pub fn SDLNet_AllocSocketSet(maxsockets: c_int) -> SDLNet_SocketSet;
+
+  /// Adds a socket to a set.
+  ///
+  /// **Returns:** The number of sockets in the set, or -1 if it's full.
+  pub fn SDLNet_AddSocket(
+    set: SDLNet_SocketSet, sock: SDLNet_GenericSocket,
+  ) -> c_int;
+
+  /// Removes a socket from a set.
+  ///
+  /// **Returns:** The number of sockets left in the set, or -1 if the socket
+  /// wasn't in the set.
+  pub fn SDLNet_DelSocket(
+    set: SDLNet_SocketSet, sock: SDLNet_GenericSocket,
+  ) -> c_int;
+
+  /// Checks which sockets of a set have data ready, waiting up to `timeout`
+  /// milliseconds.
+  ///
+  /// Use [`SDLNet_SocketReady`] on each socket afterwards.
+  ///
+  /// **Returns:** The number of sockets that are ready, or -1 on error.
+  pub fn SDLNet_CheckSockets(set: SDLNet_SocketSet, timeout: Uint32) -> c_int;
+
+  /// Frees a socket set. The sockets themselves aren't closed.
+  pub fn SDLNet_FreeSocketSet(set: SDLNet_SocketSet);
+}
+
+/// Adds a TCP socket to a set, see [`SDLNet_AddSocket`].
+#[inline]
+pub unsafe fn SDLNet_TCP_AddSocket(
+  set: SDLNet_SocketSet, sock: TCPsocket,
+) -> c_int {
+  SDLNet_AddSocket(set, sock.into())
+}
+
+/// Adds a UDP socket to a set, see [`SDLNet_AddSocket`].
+#[inline]
+pub unsafe fn SDLNet_UDP_AddSocket(
+  set: SDLNet_SocketSet, sock: UDPsocket,
+) -> c_int {
+  SDLNet_AddSocket(set, sock.into())
+}
+
+/// Removes a TCP socket from a set, see [`SDLNet_DelSocket`].
+#[inline]
+pub unsafe fn SDLNet_TCP_DelSocket(
+  set: SDLNet_SocketSet, sock: TCPsocket,
+) -> c_int {
+  SDLNet_DelSocket(set, sock.into())
+}
+
+/// Removes a UDP socket from a set, see [`SDLNet_DelSocket`].
+#[inline]
+pub unsafe fn SDLNet_UDP_DelSocket(
+  set: SDLNet_SocketSet, sock: UDPsocket,
+) -> c_int {
+  SDLNet_DelSocket(set, sock.into())
+}
+
+/// Checks if a socket had data ready during the last
+/// [`SDLNet_CheckSockets`].
+///
+/// A null socket is never ready.
+#[inline]
+#[must_use]
+pub unsafe fn SDLNet_SocketReady(
+  sock: impl Into<SDLNet_GenericSocket>,
+) -> bool {
+  let sock = sock.into();
+  !sock.0.is_null() && (*sock.0).ready != 0
+}
+
+/// Writes a `u16` to `area` in network byte order.
+#[inline]
+pub unsafe fn SDLNet_Write16(value: Uint16, area: *mut c_void) {
+  area.cast::<[u8; 2]>().write_unaligned(value.to_be_bytes())
+}
+
+/// Writes a `u32` to `area` in network byte order.
+#[inline]
+pub unsafe fn SDLNet_Write32(value: Uint32, area: *mut c_void) {
+  area.cast::<[u8; 4]>().write_unaligned(value.to_be_bytes())
+}
+
+/// Reads a `u16` from `area` in network byte order.
+#[inline]
+#[must_use]
+pub unsafe fn SDLNet_Read16(area: *const c_void) -> Uint16 {
+  Uint16::from_be_bytes(area.cast::<[u8; 2]>().read_unaligned())
+}
+
+/// Reads a `u32` from `area` in network byte order.
+#[inline]
+#[must_use]
+pub unsafe fn SDLNet_Read32(area: *const c_void) -> Uint32 {
+  Uint32::from_be_bytes(area.cast::<[u8; 4]>().read_unaligned())
+}
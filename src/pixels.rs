@@ -13,6 +13,7 @@ pub const SDL_ALPHA_TRANSPARENT: u8 = 0;
 ///
 /// Instances of this value are called `SDL_PIXELTYPE_*`
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_PixelType(pub u32);
 /// An unknown pixel type.
@@ -33,6 +34,7 @@ pub const SDL_PIXELTYPE_ARRAYF32: SDL_PixelType = SDL_PixelType(11);
 ///
 /// Instances of this value are called `SDL_BITMAPORDER_*`
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_BitmapOrder(pub u32);
 /// No bitmap ordering.
@@ -46,6 +48,7 @@ pub const SDL_BITMAPORDER_1234: SDL_BitmapOrder = SDL_BitmapOrder(2);
 ///
 /// Instances of this value are called `SDL_PACKEDORDER_*`
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_PackedOrder(pub u32);
 pub const SDL_PACKEDORDER_NONE: SDL_PackedOrder = SDL_PackedOrder(0);
@@ -62,6 +65,7 @@ pub const SDL_PACKEDORDER_BGRA: SDL_PackedOrder = SDL_PackedOrder(8);
 ///
 /// Instances of this value are called `SDL_ARRAYORDER_*`
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_ArrayOrder(pub u32);
 pub const SDL_ARRAYORDER_NONE: SDL_ArrayOrder = SDL_ArrayOrder(0);
@@ -76,6 +80,7 @@ pub const SDL_ARRAYORDER_ABGR: SDL_ArrayOrder = SDL_ArrayOrder(6);
 ///
 /// Instances of this value are called `SDL_PACKEDLAYOUT_*`
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_PackedLayout(pub u32);
 pub const SDL_PACKEDLAYOUT_NONE: SDL_PackedLayout = SDL_PackedLayout(0);
@@ -217,6 +222,7 @@ pub const fn SDL_ISPIXELFORMAT_FOURCC(format: SDL_PixelFormatEnum) -> bool {
 ///
 /// Instances of this value are called `SDL_PIXELFORMAT_*`
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_PixelFormatEnum(pub u32);
 pub const SDL_PIXELFORMAT_UNKNOWN: SDL_PixelFormatEnum = SDL_PixelFormatEnum(0);
@@ -488,6 +494,7 @@ pub const SDL_PIXELFORMAT_EXTERNAL_OES: SDL_PixelFormatEnum =
 
 /// An RGBA color value (8-bits per channel).
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 #[allow(missing_docs)]
 pub struct SDL_Color {
@@ -496,6 +503,7 @@ pub struct SDL_Color {
   pub b: Uint8,
   pub a: Uint8,
 }
+impl_pod!(SDL_Color);
 
 /// Info about a palette of colors.
 ///
@@ -617,3 +625,58 @@ extern "C" {
   /// Calculate a 256 entry gamma ramp for a gamma value.
   pub fn SDL_CalculateGammaRamp(gamma: f32, ramp: *mut Uint16);
 }
+
+impl SDL_PixelFormatEnum {
+  /// The name of this pixel format.
+  ///
+  /// This gives the same text as [`SDL_GetPixelFormatName`] (so formats with
+  /// more than one constant, like `SDL_PIXELFORMAT_RGBA32`, give SDL's main
+  /// name for them), but without calling into SDL. Values that aren't a known
+  /// format give `None`.
+  #[must_use]
+  pub const fn name(self) -> Option<&'static str> {
+    Some(match self {
+      SDL_PIXELFORMAT_UNKNOWN => "SDL_PIXELFORMAT_UNKNOWN",
+      SDL_PIXELFORMAT_INDEX1LSB => "SDL_PIXELFORMAT_INDEX1LSB",
+      SDL_PIXELFORMAT_INDEX1MSB => "SDL_PIXELFORMAT_INDEX1MSB",
+      SDL_PIXELFORMAT_INDEX4LSB => "SDL_PIXELFORMAT_INDEX4LSB",
+      SDL_PIXELFORMAT_INDEX4MSB => "SDL_PIXELFORMAT_INDEX4MSB",
+      SDL_PIXELFORMAT_INDEX8 => "SDL_PIXELFORMAT_INDEX8",
+      SDL_PIXELFORMAT_RGB332 => "SDL_PIXELFORMAT_RGB332",
+      SDL_PIXELFORMAT_RGB444 => "SDL_PIXELFORMAT_RGB444",
+      SDL_PIXELFORMAT_BGR444 => "SDL_PIXELFORMAT_BGR444",
+      SDL_PIXELFORMAT_RGB555 => "SDL_PIXELFORMAT_RGB555",
+      SDL_PIXELFORMAT_BGR555 => "SDL_PIXELFORMAT_BGR555",
+      SDL_PIXELFORMAT_ARGB4444 => "SDL_PIXELFORMAT_ARGB4444",
+      SDL_PIXELFORMAT_RGBA4444 => "SDL_PIXELFORMAT_RGBA4444",
+      SDL_PIXELFORMAT_ABGR4444 => "SDL_PIXELFORMAT_ABGR4444",
+      SDL_PIXELFORMAT_BGRA4444 => "SDL_PIXELFORMAT_BGRA4444",
+      SDL_PIXELFORMAT_ARGB1555 => "SDL_PIXELFORMAT_ARGB1555",
+      SDL_PIXELFORMAT_RGBA5551 => "SDL_PIXELFORMAT_RGBA5551",
+      SDL_PIXELFORMAT_ABGR1555 => "SDL_PIXELFORMAT_ABGR1555",
+      SDL_PIXELFORMAT_BGRA5551 => "SDL_PIXELFORMAT_BGRA5551",
+      SDL_PIXELFORMAT_RGB565 => "SDL_PIXELFORMAT_RGB565",
+      SDL_PIXELFORMAT_BGR565 => "SDL_PIXELFORMAT_BGR565",
+      SDL_PIXELFORMAT_RGB24 => "SDL_PIXELFORMAT_RGB24",
+      SDL_PIXELFORMAT_BGR24 => "SDL_PIXELFORMAT_BGR24",
+      SDL_PIXELFORMAT_RGB888 => "SDL_PIXELFORMAT_RGB888",
+      SDL_PIXELFORMAT_RGBX8888 => "SDL_PIXELFORMAT_RGBX8888",
+      SDL_PIXELFORMAT_BGR888 => "SDL_PIXELFORMAT_BGR888",
+      SDL_PIXELFORMAT_BGRX8888 => "SDL_PIXELFORMAT_BGRX8888",
+      SDL_PIXELFORMAT_ARGB8888 => "SDL_PIXELFORMAT_ARGB8888",
+      SDL_PIXELFORMAT_RGBA8888 => "SDL_PIXELFORMAT_RGBA8888",
+      SDL_PIXELFORMAT_ABGR8888 => "SDL_PIXELFORMAT_ABGR8888",
+      SDL_PIXELFORMAT_BGRA8888 => "SDL_PIXELFORMAT_BGRA8888",
+      SDL_PIXELFORMAT_ARGB2101010 => "SDL_PIXELFORMAT_ARGB2101010",
+      SDL_PIXELFORMAT_YV12 => "SDL_PIXELFORMAT_YV12",
+      SDL_PIXELFORMAT_IYUV => "SDL_PIXELFORMAT_IYUV",
+      SDL_PIXELFORMAT_YUY2 => "SDL_PIXELFORMAT_YUY2",
+      SDL_PIXELFORMAT_UYVY => "SDL_PIXELFORMAT_UYVY",
+      SDL_PIXELFORMAT_YVYU => "SDL_PIXELFORMAT_YVYU",
+      SDL_PIXELFORMAT_NV12 => "SDL_PIXELFORMAT_NV12",
+      SDL_PIXELFORMAT_NV21 => "SDL_PIXELFORMAT_NV21",
+      SDL_PIXELFORMAT_EXTERNAL_OES => "SDL_PIXELFORMAT_EXTERNAL_OES",
+      _ => return None,
+    })
+  }
+}
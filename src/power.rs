@@ -5,9 +5,47 @@ use crate::c_int;
 /// The basic state for the system's power supply.
 ///
 /// See the `SDL_POWERSTATE_*` constants.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_PowerState(pub u32);
+impl SDL_PowerState {
+  /// The name of the constant for this power state, if any.
+  #[must_use]
+  pub const fn name(self) -> Option<&'static str> {
+    Some(match self {
+      SDL_POWERSTATE_UNKNOWN => "SDL_POWERSTATE_UNKNOWN",
+      SDL_POWERSTATE_ON_BATTERY => "SDL_POWERSTATE_ON_BATTERY",
+      SDL_POWERSTATE_NO_BATTERY => "SDL_POWERSTATE_NO_BATTERY",
+      SDL_POWERSTATE_CHARGING => "SDL_POWERSTATE_CHARGING",
+      SDL_POWERSTATE_CHARGED => "SDL_POWERSTATE_CHARGED",
+      _ => return None,
+    })
+  }
+
+  /// If the system is running on its battery right now.
+  #[inline]
+  #[must_use]
+  pub const fn is_on_battery(self) -> bool {
+    self.0 == SDL_POWERSTATE_ON_BATTERY.0
+  }
+}
+/// Shows the name, like `SDL_POWERSTATE_CHARGING`.
+///
+/// Any unknown value shows as a number.
+impl core::fmt::Display for SDL_PowerState {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    match self.name() {
+      Some(name) => f.write_str(name),
+      None => write!(f, "SDL_PowerState({})", self.0),
+    }
+  }
+}
+impl core::fmt::Debug for SDL_PowerState {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    core::fmt::Display::fmt(self, f)
+  }
+}
 
 /// Cannot determine power status.
 pub const SDL_POWERSTATE_UNKNOWN: SDL_PowerState = SDL_PowerState(0);
@@ -34,3 +72,36 @@ extern "C" {
   /// **Returns:** The state of the battery (if any).
   pub fn SDL_GetPowerInfo(secs: *mut c_int, pct: *mut c_int) -> SDL_PowerState;
 }
+
+/// The system's power supply details, with `None` for unknown values.
+///
+/// This is the info from [`SDL_GetPowerInfo`], without the out pointers or
+/// the -1 sentinel values.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct PowerInfo {
+  /// The state of the battery (if any).
+  pub state: SDL_PowerState,
+  /// Seconds of battery life left, if known and running on a battery.
+  pub seconds_left: Option<u32>,
+  /// Percentage of battery life left (0 to 100), if known and running on a
+  /// battery.
+  pub percent_left: Option<u8>,
+}
+impl PowerInfo {
+  /// Calls [`SDL_GetPowerInfo`].
+  ///
+  /// This works without SDL being initialized, but depending on the platform
+  /// it can be slow (it might read files or ask a system service), so you
+  /// might not want to call it every frame.
+  #[inline]
+  #[must_use]
+  pub fn get() -> Self {
+    let (mut secs, mut pct) = (-1, -1);
+    let state = unsafe { SDL_GetPowerInfo(&mut secs, &mut pct) };
+    Self {
+      state,
+      seconds_left: u32::try_from(secs).ok(),
+      percent_left: u8::try_from(pct).ok(),
+    }
+  }
+}
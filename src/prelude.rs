@@ -6,14 +6,54 @@
 //! ```
 
 pub use crate::{
-  audio::*, blendmode::*, c_char, c_double, c_float, c_int, c_long, c_longlong,
-  c_schar, c_short, c_uchar, c_uint, c_ulong, c_ulonglong, c_ushort, c_void,
-  clipboard::*, cpuinfo::*, error::*, events::*, filesystem::*,
-  gamecontroller::*, gesture::*, hints::*, joystick::*, keyboard::*,
-  keycode::*, loadso::*, messagebox::*, mouse::*, pixels::*, platform::*,
-  power::*, quit::*, rect::*, renderer::*, rwops::*, scancode::*, sensor::*,
-  stdinc::*, surface::*, syswm::*, timer::*, touch::*, version::*, video::*, *,
+  assert::*, blendmode::*, c_char, c_double, c_float, c_int, c_long,
+  c_longlong, c_schar, c_short, c_uchar, c_uint, c_ulong, c_ulonglong,
+  c_ushort, c_void, clipboard::*, cpuinfo::*, error::*, events::*,
+  filesystem::*, gesture::*, guid::*, hints::*, keyboard::*, keycode::*,
+  loadso::*, locale::*, messagebox::*, metal::*, misc::*, mouse::*, mutex::*,
+  pixels::*, platform::*, power::*, quit::*, rect::*, rwops::*, scancode::*,
+  shape::*, stdinc::*, surface::*, system::*, syswm::*, timer::*, touch::*,
+  version::*, video::*, *,
 };
 
+#[cfg(feature = "audio")]
+pub use crate::audio::*;
+
+#[cfg(feature = "gamecontroller")]
+pub use crate::gamecontroller::*;
+
+#[cfg(feature = "gfx")]
+pub use crate::gfx::*;
+
+#[cfg(feature = "haptic")]
+pub use crate::haptic::*;
+
+#[cfg(feature = "sdl2_2_0_18")]
+pub use crate::hidapi::*;
+
+#[cfg(feature = "image")]
+pub use crate::image::*;
+
+#[cfg(feature = "joystick")]
+pub use crate::joystick::*;
+
+#[cfg(feature = "mixer")]
+pub use crate::mixer::*;
+
+#[cfg(feature = "net")]
+pub use crate::net::*;
+
+#[cfg(feature = "renderer")]
+pub use crate::renderer::*;
+
+#[cfg(feature = "sdltest")]
+pub use crate::sdltest::*;
+
+#[cfg(feature = "sensor")]
+pub use crate::sensor::*;
+
+#[cfg(feature = "ttf")]
+pub use crate::ttf::*;
+
 #[cfg(feature = "vulkan")]
 pub use crate::vulkan::*;
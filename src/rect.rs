@@ -4,6 +4,7 @@ use crate::{c_float, c_int, stdinc::*};
 
 /// The structure that defines a point (integer)
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 #[allow(missing_docs)]
 pub struct SDL_Point {
@@ -13,6 +14,7 @@ pub struct SDL_Point {
 
 /// The structure that defines a point (floating)
 #[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 #[allow(missing_docs)]
 pub struct SDL_FPoint {
@@ -22,6 +24,7 @@ pub struct SDL_FPoint {
 
 /// A rectangle, with the origin at the upper left (integer).
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 #[allow(missing_docs)]
 pub struct SDL_Rect {
@@ -33,6 +36,7 @@ pub struct SDL_Rect {
 
 /// A rectangle, with the origin at the upper left (floating).
 #[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 #[allow(missing_docs)]
 pub struct SDL_FRect {
@@ -41,6 +45,7 @@ pub struct SDL_FRect {
   pub w: f32,
   pub h: f32,
 }
+impl_pod!(SDL_Point, SDL_FPoint, SDL_Rect, SDL_FRect);
 
 /// Returns `true` if a point resides inside a rectangle.
 #[inline]
@@ -23,12 +23,19 @@ use crate::{
   blendmode::*, c_char, c_int, c_void, rect::*, stdinc::*, surface::*, video::*,
 };
 
+// makes rustdoc link properly!
+#[allow(unused)]
+use crate::{error::*, hints::*};
+
 // makes rustdoc link properly!
 #[allow(unused)]
 use crate::pixels::*;
 
 /// Flags used when creating a rendering context
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+///
+/// The `Debug` impl lists the names of the flags that are set.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_RendererFlags(pub u32);
 impl_bit_ops_for_tuple_newtype!(SDL_RendererFlags);
@@ -45,8 +52,26 @@ pub const SDL_RENDERER_PRESENTVSYNC: SDL_RendererFlags =
 pub const SDL_RENDERER_TARGETTEXTURE: SDL_RendererFlags =
   SDL_RendererFlags(0x00000008);
 
+impl core::fmt::Debug for SDL_RendererFlags {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    crate::fmt_flag_names(
+      f,
+      self.0,
+      &[
+        (SDL_RENDERER_SOFTWARE.0, "SDL_RENDERER_SOFTWARE"),
+        (SDL_RENDERER_ACCELERATED.0, "SDL_RENDERER_ACCELERATED"),
+        (SDL_RENDERER_PRESENTVSYNC.0, "SDL_RENDERER_PRESENTVSYNC"),
+        (SDL_RENDERER_TARGETTEXTURE.0, "SDL_RENDERER_TARGETTEXTURE"),
+      ],
+    )
+  }
+}
+
 /// Information on the capabilities of a render driver or context.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+///
+/// The `Debug` impl shows the name as text, the flags by name, and only the
+/// texture formats in use (also by name).
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(C)]
 #[allow(missing_docs)]
 pub struct SDL_RendererInfo {
@@ -70,11 +95,59 @@ impl Default for SDL_RendererInfo {
     unsafe { core::mem::zeroed() }
   }
 }
+impl SDL_RendererInfo {
+  /// The name of the renderer, or `None` if the pointer is null.
+  ///
+  /// ## Safety
+  /// * `name` must be null or point to a null-terminated string that lives at
+  ///   least as long as `self`. This holds for info filled in by
+  ///   [`SDL_GetRendererInfo`] or [`SDL_GetRenderDriverInfo`], where it's the
+  ///   driver's static name.
+  #[inline]
+  #[must_use]
+  pub unsafe fn name(&self) -> Option<&core::ffi::CStr> {
+    if self.name.is_null() {
+      None
+    } else {
+      Some(core::ffi::CStr::from_ptr(self.name))
+    }
+  }
+}
+impl core::fmt::Debug for SDL_RendererInfo {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    /// Shows a format by name (or in hex when it has no name).
+    struct Format(Uint32);
+    impl core::fmt::Debug for Format {
+      fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match SDL_PixelFormatEnum(self.0).name() {
+          Some(name) => f.write_str(name),
+          None => write!(f, "{:#X}", self.0),
+        }
+      }
+    }
+    struct Formats<'a>(&'a [Uint32]);
+    impl core::fmt::Debug for Formats<'_> {
+      fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.0.iter().map(|&x| Format(x))).finish()
+      }
+    }
+    let count =
+      (self.num_texture_formats as usize).min(self.texture_formats.len());
+    f.debug_struct("SDL_RendererInfo")
+      .field("name", &self.name)
+      .field("flags", &SDL_RendererFlags(self.flags))
+      .field("texture_formats", &Formats(&self.texture_formats[..count]))
+      .field("max_texture_width", &self.max_texture_width)
+      .field("max_texture_height", &self.max_texture_height)
+      .finish()
+  }
+}
 
 /// The scaling mode for a texture.
 ///
 /// See `SDL_ScaleMode*` constants.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_ScaleMode(pub i32);
 /// nearest pixel sampling
@@ -88,6 +161,7 @@ pub const SDL_ScaleModeBest: SDL_ScaleMode = SDL_ScaleMode(2);
 ///
 /// See `SDL_TEXTUREACCESS_*` constants.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_TextureAccess(pub i32);
 /// Changes rarely, not lockable.
@@ -101,6 +175,7 @@ pub const SDL_TEXTUREACCESS_TARGET: SDL_TextureAccess = SDL_TextureAccess(2);
 ///
 /// See `SDL_TEXTUREMODULATE_*` constants.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_TextureModulate(pub i32);
 /// No modulation.
@@ -117,6 +192,7 @@ pub const SDL_TEXTUREMODULATE_ALPHA: SDL_TextureModulate =
 ///
 /// See `SDL_FLIP_*` constants.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_RendererFlip(pub u32);
 impl_bit_ops_for_tuple_newtype!(SDL_RendererFlip);
@@ -127,6 +203,23 @@ pub const SDL_FLIP_HORIZONTAL: SDL_RendererFlip = SDL_RendererFlip(0x00000001);
 /// flip vertically
 pub const SDL_FLIP_VERTICAL: SDL_RendererFlip = SDL_RendererFlip(0x00000002);
 
+/// Vertex structure, used by [`SDL_RenderGeometry`].
+#[cfg(feature = "sdl2_2_0_18")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_18")))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct SDL_Vertex {
+  /// Vertex position, in [`SDL_Renderer`] coordinates
+  pub position: SDL_FPoint,
+  /// Vertex color
+  pub color: SDL_Color,
+  /// Normalized texture coordinates, if needed
+  pub tex_coord: SDL_FPoint,
+}
+#[cfg(feature = "sdl2_2_0_18")]
+impl_pod!(SDL_Vertex);
+
 /// An opaque structure representing rendering state.
 #[derive(Debug)]
 #[repr(transparent)]
@@ -462,7 +555,11 @@ extern "C" {
   /// See Also: [`SDL_LockTexture`], [`SDL_LockTextureToSurface`]
   pub fn SDL_UnlockTexture(texture: *mut SDL_Texture);
 
-  /// Determines whether a window supports the use of render targets
+  /// Determines whether a renderer supports the use of render targets.
+  ///
+  /// Check this before relying on [`SDL_SetRenderTarget`] (and textures made
+  /// with [`SDL_TEXTUREACCESS_TARGET`]), so that you can fall back to drawing
+  /// straight to the window when render-to-texture isn't available.
   ///
   /// * `renderer` The renderer that will be checked
   ///
@@ -632,6 +729,50 @@ extern "C" {
     renderer: *mut SDL_Renderer, scaleX: *mut f32, scaleY: *mut f32,
   );
 
+  /// Get logical coordinates of point in renderer when given real coordinates
+  /// of point in window.
+  ///
+  /// Logical coordinates will differ from real coordinates when render is
+  /// scaled and logical renderer size set.
+  ///
+  /// * `renderer` The renderer from which the logical coordinates should be
+  ///   calculated.
+  /// * `windowX` The real X coordinate in the window.
+  /// * `windowY` The real Y coordinate in the window.
+  /// * `logicalX` The pointer filled with the logical x coordinate.
+  /// * `logicalY` The pointer filled with the logical y coordinate.
+  ///
+  /// See Also: [`SDL_RenderGetScale`], [`SDL_RenderSetScale`],
+  /// [`SDL_RenderGetLogicalSize`], [`SDL_RenderSetLogicalSize`]
+  #[cfg(feature = "sdl2_2_0_18")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_18")))]
+  pub fn SDL_RenderWindowToLogical(
+    renderer: *mut SDL_Renderer, windowX: c_int, windowY: c_int,
+    logicalX: *mut f32, logicalY: *mut f32,
+  );
+
+  /// Get real coordinates of point in window when given logical coordinates
+  /// of point in renderer.
+  ///
+  /// Logical coordinates will differ from real coordinates when render is
+  /// scaled and logical renderer size set.
+  ///
+  /// * `renderer` The renderer from which the window coordinates should be
+  ///   calculated.
+  /// * `logicalX` The logical x coordinate.
+  /// * `logicalY` The logical y coordinate.
+  /// * `windowX` The pointer filled with the real X coordinate in the window.
+  /// * `windowY` The pointer filled with the real Y coordinate in the window.
+  ///
+  /// See Also: [`SDL_RenderGetScale`], [`SDL_RenderSetScale`],
+  /// [`SDL_RenderGetLogicalSize`], [`SDL_RenderSetLogicalSize`]
+  #[cfg(feature = "sdl2_2_0_18")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_18")))]
+  pub fn SDL_RenderLogicalToWindow(
+    renderer: *mut SDL_Renderer, logicalX: f32, logicalY: f32,
+    windowX: *mut c_int, windowY: *mut c_int,
+  );
+
   /// Set the color used for drawing operations (Rect, Line and Clear).
   ///
   /// * `renderer` The renderer for which drawing color should be set.
@@ -956,6 +1097,63 @@ extern "C" {
     center: *const SDL_FPoint, flip: SDL_RendererFlip,
   ) -> c_int;
 
+  /// Render a list of triangles, optionally using a texture and indices into
+  /// the vertex array.
+  ///
+  /// Color and alpha modulation is done per vertex ([`SDL_SetTextureColorMod`]
+  /// and [`SDL_SetTextureAlphaMod`] are ignored).
+  ///
+  /// * `renderer` The rendering context.
+  /// * `texture` (optional) The SDL texture to use.
+  /// * `vertices` Vertices.
+  /// * `num_vertices` Number of vertices.
+  /// * `indices` (optional) An array of integer indices into the `vertices`
+  ///   array, if NULL all vertices will be rendered in sequential order.
+  /// * `num_indices` Number of indices.
+  ///
+  /// **Returns:** 0 on success, or -1 if the operation is not supported
+  ///
+  /// See Also: [`SDL_RenderGeometryRaw`]
+  #[cfg(feature = "sdl2_2_0_18")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_18")))]
+  pub fn SDL_RenderGeometry(
+    renderer: *mut SDL_Renderer, texture: *mut SDL_Texture,
+    vertices: *const SDL_Vertex, num_vertices: c_int, indices: *const c_int,
+    num_indices: c_int,
+  ) -> c_int;
+
+  /// Render a list of triangles, optionally using a texture and indices into
+  /// the vertex arrays.
+  ///
+  /// Color and alpha modulation is done per vertex ([`SDL_SetTextureColorMod`]
+  /// and [`SDL_SetTextureAlphaMod`] are ignored).
+  ///
+  /// * `renderer` The rendering context.
+  /// * `texture` (optional) The SDL texture to use.
+  /// * `xy` Vertex positions
+  /// * `xy_stride` Byte size to move from one element to the next element
+  /// * `color` Vertex colors (as [`SDL_Color`])
+  /// * `color_stride` Byte size to move from one element to the next element
+  /// * `uv` Vertex normalized texture coordinates
+  /// * `uv_stride` Byte size to move from one element to the next element
+  /// * `num_vertices` Number of vertices.
+  /// * `indices` (optional) An array of indices into the vertex arrays, if
+  ///   NULL all vertices will be rendered in sequential order.
+  /// * `num_indices` Number of indices.
+  /// * `size_indices` Index size: 1 (byte), 2 (short), 4 (int)
+  ///
+  /// **Returns:** 0 on success, or -1 if the operation is not supported
+  ///
+  /// See Also: [`SDL_RenderGeometry`]
+  #[cfg(feature = "sdl2_2_0_18")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_18")))]
+  pub fn SDL_RenderGeometryRaw(
+    renderer: *mut SDL_Renderer, texture: *mut SDL_Texture, xy: *const f32,
+    xy_stride: c_int, color: *const SDL_Color, color_stride: c_int,
+    uv: *const f32, uv_stride: c_int, num_vertices: c_int,
+    indices: *const c_void, num_indices: c_int, size_indices: c_int,
+  ) -> c_int;
+
   /// Read pixels from the current rendering target.
   ///
   /// * `renderer` The renderer from which pixels should be read.
@@ -999,8 +1197,9 @@ extern "C" {
   /// you asked for a specific renderer backend (OpenGL, Direct3D, etc),
   /// you set SDL_HINT_RENDER_BATCHING to "1", and you plan to make
   /// OpenGL/D3D/whatever calls in addition to SDL render API calls. If all of
-  /// this applies, you should call SDL_RenderFlush() between calls to SDL's
-  /// render API and the low-level API you're using in cooperation.
+  /// this applies, you should call this between calls to SDL's render API and
+  /// the low-level API you're using in cooperation. That's the contract you
+  /// agree to when setting [`SDL_HINT_RENDER_BATCHING`].
   ///
   /// In all other cases, you can ignore this function. This is only here to
   /// get maximum performance out of a specific situation. In all other cases,
@@ -1009,6 +1208,8 @@ extern "C" {
   /// This function is first available in SDL 2.0.10, and is not needed in
   /// 2.0.9 and earlier, as earlier versions did not queue rendering commands
   /// at all, instead flushing them to the OS immediately.
+  ///
+  /// **Returns:** 0 on success or a negative error code on failure.
   pub fn SDL_RenderFlush(renderer: *mut SDL_Renderer) -> c_int;
 
   /// Bind the texture to the current OpenGL/ES/ES2 context for use with OpenGL
@@ -1052,6 +1253,17 @@ extern "C" {
     renderer: *mut SDL_Renderer,
   ) -> *mut c_void;
 
+  /// Toggle VSync of the given renderer.
+  ///
+  /// * `renderer` The renderer to toggle.
+  /// * `vsync` 1 for on, 0 for off. All other values are reserved.
+  ///
+  /// **Returns:** 0 on success, or non-zero on failure.
+  #[cfg(feature = "sdl2_2_0_18")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_18")))]
+  pub fn SDL_RenderSetVSync(renderer: *mut SDL_Renderer, vsync: c_int)
+    -> c_int;
+
   /// Update a rectangle within a planar NV12 or NV21 texture with new pixels.
   ///
   /// You can use [SDL_UpdateTexture] as long as your pixel data is a contiguous
@@ -1067,6 +1279,8 @@ extern "C" {
   /// * `UVpitch` the number of bytes between rows of pixel data for the UV
   ///   plane.
   /// * **Returns:** 0 on success, or -1 if the texture is not valid.
+  #[cfg(feature = "sdl2_2_0_16")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_16")))]
   pub fn SDL_UpdateNVTexture(
     texture: *mut SDL_Texture, rect: *const SDL_Rect, Yplane: *const Uint8,
     Ypitch: c_int, UVplane: *const Uint8, UVpitch: c_int,
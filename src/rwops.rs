@@ -6,14 +6,14 @@
 //! Only a minimal amount of this module is currently bound. If you want more
 //! added, PRs accepted.
 
-use crate::{c_char, c_void};
+use crate::{c_char, c_int, c_void};
 
 /// SDL's read/write abstraction.
 ///
 /// This is necessary for interaction with some of the other SDL systems, but
 /// I'm dubious as to its general value compared to using `std::fs` most of the
 /// time. So, currently, there's only bindings to allocate one from a file path
-/// and to free it later.
+/// or a block of memory, and to free it later.
 #[repr(transparent)]
 pub struct SDL_RWops(c_void);
 
@@ -29,6 +29,18 @@ extern "C" {
     file: *const c_char, mode: *const c_char,
   ) -> *mut SDL_RWops;
 
+  /// Creates a [`SDL_RWops`] that reads from and writes to a block of memory.
+  ///
+  /// * `mem` must stay valid (and `size` bytes long) for as long as the
+  ///   `SDL_RWops` is used. Writes past the end of the memory fail.
+  pub fn SDL_RWFromMem(mem: *mut c_void, size: c_int) -> *mut SDL_RWops;
+
+  /// Creates a read-only [`SDL_RWops`] from a block of memory.
+  ///
+  /// * `mem` must stay valid (and `size` bytes long) for as long as the
+  ///   `SDL_RWops` is used. Writes to the `SDL_RWops` fail.
+  pub fn SDL_RWFromConstMem(mem: *const c_void, size: c_int) -> *mut SDL_RWops;
+
   /// Free an [`SDL_RWops`] after use.
   ///
   /// Some functions already have a flag that they will free an `SDL_RWops`
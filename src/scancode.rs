@@ -16,6 +16,7 @@ use crate::keyboard::*;
 ///
 /// See all the constants named `SDL_SCANCODE_*`
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_Scancode(pub i32);
 
@@ -324,3 +325,336 @@ pub const SDL_SCANCODE_AUDIOFASTFORWARD: SDL_Scancode = SDL_Scancode(286);
 
 /// Marks the number of scancodes, for array sizes and such.
 pub const SDL_NUM_SCANCODES: usize = 512;
+
+#[cfg(feature = "keycode_names")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "keycode_names")))]
+impl SDL_Scancode {
+  /// The canonical name of this scancode.
+  ///
+  /// This gives the same text as [`SDL_GetScancodeName`], but it's looked up
+  /// in a table within the crate, so SDL doesn't need to be initialized for it
+  /// to work. Scancodes that don't have a name give `None` (instead of an empty
+  /// string).
+  #[inline]
+  #[must_use]
+  pub const fn name(self) -> Option<&'static str> {
+    if self.0 >= 0 && (self.0 as usize) < SDL_SCANCODE_NAMES.len() {
+      SDL_SCANCODE_NAMES[self.0 as usize]
+    } else {
+      None
+    }
+  }
+
+  /// Looks up a scancode by its canonical name.
+  ///
+  /// This works like [`SDL_GetScancodeFromName`] (including that the match is
+  /// case-insensitive), but it doesn't call into SDL.
+  #[must_use]
+  pub fn from_name(name: &str) -> Option<Self> {
+    SDL_SCANCODE_NAMES
+      .iter()
+      .position(|n| match n {
+        Some(n) => n.eq_ignore_ascii_case(name),
+        None => false,
+      })
+      .map(|i| SDL_Scancode(i as i32))
+  }
+}
+
+/// The names that SDL uses for each scancode, indexed by scancode value.
+///
+/// This is the same table as `SDL_scancode_names` in `SDL_keyboard.c`.
+#[cfg(feature = "keycode_names")]
+const SDL_SCANCODE_NAMES: [Option<&str>; 291] = [
+  /* 0 */ None,
+  /* 1 */ None,
+  /* 2 */ None,
+  /* 3 */ None,
+  /* 4 */ Some("A"),
+  /* 5 */ Some("B"),
+  /* 6 */ Some("C"),
+  /* 7 */ Some("D"),
+  /* 8 */ Some("E"),
+  /* 9 */ Some("F"),
+  /* 10 */ Some("G"),
+  /* 11 */ Some("H"),
+  /* 12 */ Some("I"),
+  /* 13 */ Some("J"),
+  /* 14 */ Some("K"),
+  /* 15 */ Some("L"),
+  /* 16 */ Some("M"),
+  /* 17 */ Some("N"),
+  /* 18 */ Some("O"),
+  /* 19 */ Some("P"),
+  /* 20 */ Some("Q"),
+  /* 21 */ Some("R"),
+  /* 22 */ Some("S"),
+  /* 23 */ Some("T"),
+  /* 24 */ Some("U"),
+  /* 25 */ Some("V"),
+  /* 26 */ Some("W"),
+  /* 27 */ Some("X"),
+  /* 28 */ Some("Y"),
+  /* 29 */ Some("Z"),
+  /* 30 */ Some("1"),
+  /* 31 */ Some("2"),
+  /* 32 */ Some("3"),
+  /* 33 */ Some("4"),
+  /* 34 */ Some("5"),
+  /* 35 */ Some("6"),
+  /* 36 */ Some("7"),
+  /* 37 */ Some("8"),
+  /* 38 */ Some("9"),
+  /* 39 */ Some("0"),
+  /* 40 */ Some("Return"),
+  /* 41 */ Some("Escape"),
+  /* 42 */ Some("Backspace"),
+  /* 43 */ Some("Tab"),
+  /* 44 */ Some("Space"),
+  /* 45 */ Some("-"),
+  /* 46 */ Some("="),
+  /* 47 */ Some("["),
+  /* 48 */ Some("]"),
+  /* 49 */ Some("\\"),
+  /* 50 */ Some("#"),
+  /* 51 */ Some(";"),
+  /* 52 */ Some("'"),
+  /* 53 */ Some("`"),
+  /* 54 */ Some(","),
+  /* 55 */ Some("."),
+  /* 56 */ Some("/"),
+  /* 57 */ Some("CapsLock"),
+  /* 58 */ Some("F1"),
+  /* 59 */ Some("F2"),
+  /* 60 */ Some("F3"),
+  /* 61 */ Some("F4"),
+  /* 62 */ Some("F5"),
+  /* 63 */ Some("F6"),
+  /* 64 */ Some("F7"),
+  /* 65 */ Some("F8"),
+  /* 66 */ Some("F9"),
+  /* 67 */ Some("F10"),
+  /* 68 */ Some("F11"),
+  /* 69 */ Some("F12"),
+  /* 70 */ Some("PrintScreen"),
+  /* 71 */ Some("ScrollLock"),
+  /* 72 */ Some("Pause"),
+  /* 73 */ Some("Insert"),
+  /* 74 */ Some("Home"),
+  /* 75 */ Some("PageUp"),
+  /* 76 */ Some("Delete"),
+  /* 77 */ Some("End"),
+  /* 78 */ Some("PageDown"),
+  /* 79 */ Some("Right"),
+  /* 80 */ Some("Left"),
+  /* 81 */ Some("Down"),
+  /* 82 */ Some("Up"),
+  /* 83 */ Some("Numlock"),
+  /* 84 */ Some("Keypad /"),
+  /* 85 */ Some("Keypad *"),
+  /* 86 */ Some("Keypad -"),
+  /* 87 */ Some("Keypad +"),
+  /* 88 */ Some("Keypad Enter"),
+  /* 89 */ Some("Keypad 1"),
+  /* 90 */ Some("Keypad 2"),
+  /* 91 */ Some("Keypad 3"),
+  /* 92 */ Some("Keypad 4"),
+  /* 93 */ Some("Keypad 5"),
+  /* 94 */ Some("Keypad 6"),
+  /* 95 */ Some("Keypad 7"),
+  /* 96 */ Some("Keypad 8"),
+  /* 97 */ Some("Keypad 9"),
+  /* 98 */ Some("Keypad 0"),
+  /* 99 */ Some("Keypad ."),
+  /* 100 */ None,
+  /* 101 */ Some("Application"),
+  /* 102 */ Some("Power"),
+  /* 103 */ Some("Keypad ="),
+  /* 104 */ Some("F13"),
+  /* 105 */ Some("F14"),
+  /* 106 */ Some("F15"),
+  /* 107 */ Some("F16"),
+  /* 108 */ Some("F17"),
+  /* 109 */ Some("F18"),
+  /* 110 */ Some("F19"),
+  /* 111 */ Some("F20"),
+  /* 112 */ Some("F21"),
+  /* 113 */ Some("F22"),
+  /* 114 */ Some("F23"),
+  /* 115 */ Some("F24"),
+  /* 116 */ Some("Execute"),
+  /* 117 */ Some("Help"),
+  /* 118 */ Some("Menu"),
+  /* 119 */ Some("Select"),
+  /* 120 */ Some("Stop"),
+  /* 121 */ Some("Again"),
+  /* 122 */ Some("Undo"),
+  /* 123 */ Some("Cut"),
+  /* 124 */ Some("Copy"),
+  /* 125 */ Some("Paste"),
+  /* 126 */ Some("Find"),
+  /* 127 */ Some("Mute"),
+  /* 128 */ Some("VolumeUp"),
+  /* 129 */ Some("VolumeDown"),
+  /* 130 */ None,
+  /* 131 */ None,
+  /* 132 */ None,
+  /* 133 */ Some("Keypad ,"),
+  /* 134 */ Some("Keypad = (AS400)"),
+  /* 135 */ None,
+  /* 136 */ None,
+  /* 137 */ None,
+  /* 138 */ None,
+  /* 139 */ None,
+  /* 140 */ None,
+  /* 141 */ None,
+  /* 142 */ None,
+  /* 143 */ None,
+  /* 144 */ None,
+  /* 145 */ None,
+  /* 146 */ None,
+  /* 147 */ None,
+  /* 148 */ None,
+  /* 149 */ None,
+  /* 150 */ None,
+  /* 151 */ None,
+  /* 152 */ None,
+  /* 153 */ Some("AltErase"),
+  /* 154 */ Some("SysReq"),
+  /* 155 */ Some("Cancel"),
+  /* 156 */ Some("Clear"),
+  /* 157 */ Some("Prior"),
+  /* 158 */ Some("Return"),
+  /* 159 */ Some("Separator"),
+  /* 160 */ Some("Out"),
+  /* 161 */ Some("Oper"),
+  /* 162 */ Some("Clear / Again"),
+  /* 163 */ Some("CrSel"),
+  /* 164 */ Some("ExSel"),
+  /* 165 */ None,
+  /* 166 */ None,
+  /* 167 */ None,
+  /* 168 */ None,
+  /* 169 */ None,
+  /* 170 */ None,
+  /* 171 */ None,
+  /* 172 */ None,
+  /* 173 */ None,
+  /* 174 */ None,
+  /* 175 */ None,
+  /* 176 */ Some("Keypad 00"),
+  /* 177 */ Some("Keypad 000"),
+  /* 178 */ Some("ThousandsSeparator"),
+  /* 179 */ Some("DecimalSeparator"),
+  /* 180 */ Some("CurrencyUnit"),
+  /* 181 */ Some("CurrencySubUnit"),
+  /* 182 */ Some("Keypad ("),
+  /* 183 */ Some("Keypad )"),
+  /* 184 */ Some("Keypad {"),
+  /* 185 */ Some("Keypad }"),
+  /* 186 */ Some("Keypad Tab"),
+  /* 187 */ Some("Keypad Backspace"),
+  /* 188 */ Some("Keypad A"),
+  /* 189 */ Some("Keypad B"),
+  /* 190 */ Some("Keypad C"),
+  /* 191 */ Some("Keypad D"),
+  /* 192 */ Some("Keypad E"),
+  /* 193 */ Some("Keypad F"),
+  /* 194 */ Some("Keypad XOR"),
+  /* 195 */ Some("Keypad ^"),
+  /* 196 */ Some("Keypad %"),
+  /* 197 */ Some("Keypad <"),
+  /* 198 */ Some("Keypad >"),
+  /* 199 */ Some("Keypad &"),
+  /* 200 */ Some("Keypad &&"),
+  /* 201 */ Some("Keypad |"),
+  /* 202 */ Some("Keypad ||"),
+  /* 203 */ Some("Keypad :"),
+  /* 204 */ Some("Keypad #"),
+  /* 205 */ Some("Keypad Space"),
+  /* 206 */ Some("Keypad @"),
+  /* 207 */ Some("Keypad !"),
+  /* 208 */ Some("Keypad MemStore"),
+  /* 209 */ Some("Keypad MemRecall"),
+  /* 210 */ Some("Keypad MemClear"),
+  /* 211 */ Some("Keypad MemAdd"),
+  /* 212 */ Some("Keypad MemSubtract"),
+  /* 213 */ Some("Keypad MemMultiply"),
+  /* 214 */ Some("Keypad MemDivide"),
+  /* 215 */ Some("Keypad +/-"),
+  /* 216 */ Some("Keypad Clear"),
+  /* 217 */ Some("Keypad ClearEntry"),
+  /* 218 */ Some("Keypad Binary"),
+  /* 219 */ Some("Keypad Octal"),
+  /* 220 */ Some("Keypad Decimal"),
+  /* 221 */ Some("Keypad Hexadecimal"),
+  /* 222 */ None,
+  /* 223 */ None,
+  /* 224 */ Some("Left Ctrl"),
+  /* 225 */ Some("Left Shift"),
+  /* 226 */ Some("Left Alt"),
+  /* 227 */ Some("Left GUI"),
+  /* 228 */ Some("Right Ctrl"),
+  /* 229 */ Some("Right Shift"),
+  /* 230 */ Some("Right Alt"),
+  /* 231 */ Some("Right GUI"),
+  /* 232 */ None,
+  /* 233 */ None,
+  /* 234 */ None,
+  /* 235 */ None,
+  /* 236 */ None,
+  /* 237 */ None,
+  /* 238 */ None,
+  /* 239 */ None,
+  /* 240 */ None,
+  /* 241 */ None,
+  /* 242 */ None,
+  /* 243 */ None,
+  /* 244 */ None,
+  /* 245 */ None,
+  /* 246 */ None,
+  /* 247 */ None,
+  /* 248 */ None,
+  /* 249 */ None,
+  /* 250 */ None,
+  /* 251 */ None,
+  /* 252 */ None,
+  /* 253 */ None,
+  /* 254 */ None,
+  /* 255 */ None,
+  /* 256 */ None,
+  /* 257 */ Some("ModeSwitch"),
+  /* 258 */ Some("AudioNext"),
+  /* 259 */ Some("AudioPrev"),
+  /* 260 */ Some("AudioStop"),
+  /* 261 */ Some("AudioPlay"),
+  /* 262 */ Some("AudioMute"),
+  /* 263 */ Some("MediaSelect"),
+  /* 264 */ Some("WWW"),
+  /* 265 */ Some("Mail"),
+  /* 266 */ Some("Calculator"),
+  /* 267 */ Some("Computer"),
+  /* 268 */ Some("AC Search"),
+  /* 269 */ Some("AC Home"),
+  /* 270 */ Some("AC Back"),
+  /* 271 */ Some("AC Forward"),
+  /* 272 */ Some("AC Stop"),
+  /* 273 */ Some("AC Refresh"),
+  /* 274 */ Some("AC Bookmarks"),
+  /* 275 */ Some("BrightnessDown"),
+  /* 276 */ Some("BrightnessUp"),
+  /* 277 */ Some("DisplaySwitch"),
+  /* 278 */ Some("KBDIllumToggle"),
+  /* 279 */ Some("KBDIllumDown"),
+  /* 280 */ Some("KBDIllumUp"),
+  /* 281 */ Some("Eject"),
+  /* 282 */ Some("Sleep"),
+  /* 283 */ Some("App1"),
+  /* 284 */ Some("App2"),
+  /* 285 */ Some("AudioRewind"),
+  /* 286 */ Some("AudioFastForward"),
+  /* 287 */ Some("SoftLeft"),
+  /* 288 */ Some("SoftRight"),
+  /* 289 */ Some("Call"),
+  /* 290 */ Some("EndCall"),
+];
@@ -0,0 +1,286 @@
+//! Audio functionality for the SDL3 library.
+//!
+//! SDL3 replaced the SDL2 callback model with audio *streams*: you create an
+//! [`SDL_AudioStream`], bind it to a device, and then put data into it (or
+//! pull data from it, for recording). SDL does any needed format conversion.
+//!
+//! The simplest way to get going is [`SDL_OpenAudioDeviceStream`], which opens
+//! a device and binds a new stream to it in a single call.
+
+use crate::{c_char, c_float, c_int, c_void};
+
+/// Audio format.
+///
+/// See the `SDL_AUDIO_*` constants.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct SDL_AudioFormat(pub u32);
+/// Unspecified audio format.
+pub const SDL_AUDIO_UNKNOWN: SDL_AudioFormat = SDL_AudioFormat(0x0000);
+/// Unsigned 8-bit samples
+pub const SDL_AUDIO_U8: SDL_AudioFormat = SDL_AudioFormat(0x0008);
+/// Signed 8-bit samples
+pub const SDL_AUDIO_S8: SDL_AudioFormat = SDL_AudioFormat(0x8008);
+/// Signed 16-bit samples (little-endian)
+pub const SDL_AUDIO_S16LE: SDL_AudioFormat = SDL_AudioFormat(0x8010);
+/// Signed 16-bit samples (big-endian)
+pub const SDL_AUDIO_S16BE: SDL_AudioFormat = SDL_AudioFormat(0x9010);
+/// 32-bit integer samples (little-endian)
+pub const SDL_AUDIO_S32LE: SDL_AudioFormat = SDL_AudioFormat(0x8020);
+/// 32-bit integer samples (big-endian)
+pub const SDL_AUDIO_S32BE: SDL_AudioFormat = SDL_AudioFormat(0x9020);
+/// 32-bit floating point samples (little-endian)
+pub const SDL_AUDIO_F32LE: SDL_AudioFormat = SDL_AudioFormat(0x8120);
+/// 32-bit floating point samples (big-endian)
+pub const SDL_AUDIO_F32BE: SDL_AudioFormat = SDL_AudioFormat(0x9120);
+/// Signed 16-bit samples (native endian)
+pub const SDL_AUDIO_S16: SDL_AudioFormat = if cfg!(target_endian = "little") {
+  SDL_AUDIO_S16LE
+} else {
+  SDL_AUDIO_S16BE
+};
+/// 32-bit integer samples (native endian)
+pub const SDL_AUDIO_S32: SDL_AudioFormat = if cfg!(target_endian = "little") {
+  SDL_AUDIO_S32LE
+} else {
+  SDL_AUDIO_S32BE
+};
+/// 32-bit floating point samples (native endian)
+pub const SDL_AUDIO_F32: SDL_AudioFormat = if cfg!(target_endian = "little") {
+  SDL_AUDIO_F32LE
+} else {
+  SDL_AUDIO_F32BE
+};
+
+/// SDL Audio Device instance IDs.
+///
+/// Zero is used to signify an invalid/null device.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct SDL_AudioDeviceID(pub u32);
+
+/// A value used to request a default playback audio device.
+pub const SDL_AUDIO_DEVICE_DEFAULT_PLAYBACK: SDL_AudioDeviceID =
+  SDL_AudioDeviceID(0xFFFFFFFF);
+/// A value used to request a default recording audio device.
+pub const SDL_AUDIO_DEVICE_DEFAULT_RECORDING: SDL_AudioDeviceID =
+  SDL_AudioDeviceID(0xFFFFFFFE);
+
+/// Format specifier for audio data.
+///
+/// Unlike SDL2, this only describes the data, there's no callback info here.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+pub struct SDL_AudioSpec {
+  /// Audio data format
+  pub format: SDL_AudioFormat,
+  /// Number of channels: 1 mono, 2 stereo, etc
+  pub channels: c_int,
+  /// sample rate: sample frames per second
+  pub freq: c_int,
+}
+
+/// The opaque handle that represents an audio stream.
+#[repr(transparent)]
+pub struct SDL_AudioStream(c_void);
+
+/// A callback that fires when data passes through an [`SDL_AudioStream`].
+///
+/// * `additional_amount` The amount of data, in bytes, that is needed right
+///   now.
+/// * `total_amount` The total amount of data requested, in bytes, that is
+///   requested or available.
+pub type SDL_AudioStreamCallback = Option<
+  unsafe extern "C" fn(
+    userdata: *mut c_void,
+    stream: *mut SDL_AudioStream,
+    additional_amount: c_int,
+    total_amount: c_int,
+  ),
+>;
+
+extern "C" {
+  /// Use this function to get the number of built-in audio drivers.
+  pub fn SDL_GetNumAudioDrivers() -> c_int;
+
+  /// Use this function to get the name of a built in audio driver.
+  pub fn SDL_GetAudioDriver(index: c_int) -> *const c_char;
+
+  /// Get the name of the current audio driver, or NULL if no driver has been
+  /// initialized.
+  pub fn SDL_GetCurrentAudioDriver() -> *const c_char;
+
+  /// Get a list of currently-connected audio playback devices.
+  ///
+  /// * `count` if non-NULL, gets the number of devices returned.
+  ///
+  /// **Returns:** a 0 terminated array of device instance IDs or NULL on
+  /// error. This should be freed with [`SDL_free`](crate::sdl3::stdinc::SDL_free)
+  /// when it is no longer needed.
+  pub fn SDL_GetAudioPlaybackDevices(
+    count: *mut c_int,
+  ) -> *mut SDL_AudioDeviceID;
+
+  /// Get a list of currently-connected audio recording devices.
+  ///
+  /// * `count` if non-NULL, gets the number of devices returned.
+  ///
+  /// **Returns:** a 0 terminated array of device instance IDs or NULL on
+  /// error. This should be freed with [`SDL_free`](crate::sdl3::stdinc::SDL_free)
+  /// when it is no longer needed.
+  pub fn SDL_GetAudioRecordingDevices(
+    count: *mut c_int,
+  ) -> *mut SDL_AudioDeviceID;
+
+  /// Get the human-readable name of a specific audio device.
+  pub fn SDL_GetAudioDeviceName(devid: SDL_AudioDeviceID) -> *const c_char;
+
+  /// Get the current audio format of a specific audio device.
+  ///
+  /// * `sample_frames` if non-NULL, gets the device buffer size, in sample
+  ///   frames.
+  pub fn SDL_GetAudioDeviceFormat(
+    devid: SDL_AudioDeviceID, spec: *mut SDL_AudioSpec,
+    sample_frames: *mut c_int,
+  ) -> bool;
+
+  /// Open a specific audio device.
+  ///
+  /// You can pass [`SDL_AUDIO_DEVICE_DEFAULT_PLAYBACK`] or
+  /// [`SDL_AUDIO_DEVICE_DEFAULT_RECORDING`] to get a reasonable default
+  /// device. The `spec` is only a hint for the hardware format, and can be
+  /// NULL.
+  ///
+  /// **Returns:** the device ID on success or 0 on failure.
+  pub fn SDL_OpenAudioDevice(
+    devid: SDL_AudioDeviceID, spec: *const SDL_AudioSpec,
+  ) -> SDL_AudioDeviceID;
+
+  /// Use this function to pause audio playback on a specified device.
+  pub fn SDL_PauseAudioDevice(dev: SDL_AudioDeviceID) -> bool;
+
+  /// Use this function to unpause audio playback on a specified device.
+  pub fn SDL_ResumeAudioDevice(dev: SDL_AudioDeviceID) -> bool;
+
+  /// Use this function to query if an audio device is paused.
+  pub fn SDL_AudioDevicePaused(dev: SDL_AudioDeviceID) -> bool;
+
+  /// Get the gain of an audio device.
+  pub fn SDL_GetAudioDeviceGain(devid: SDL_AudioDeviceID) -> c_float;
+
+  /// Change the gain of an audio device.
+  pub fn SDL_SetAudioDeviceGain(
+    devid: SDL_AudioDeviceID, gain: c_float,
+  ) -> bool;
+
+  /// Close a previously-opened audio device.
+  pub fn SDL_CloseAudioDevice(devid: SDL_AudioDeviceID);
+
+  /// Bind a single audio stream to an audio device.
+  pub fn SDL_BindAudioStream(
+    devid: SDL_AudioDeviceID, stream: *mut SDL_AudioStream,
+  ) -> bool;
+
+  /// Unbind a single audio stream from its audio device.
+  pub fn SDL_UnbindAudioStream(stream: *mut SDL_AudioStream);
+
+  /// Query an audio stream for its currently-bound device.
+  pub fn SDL_GetAudioStreamDevice(
+    stream: *mut SDL_AudioStream,
+  ) -> SDL_AudioDeviceID;
+
+  /// Create a new audio stream that converts from `src_spec` to `dst_spec`.
+  ///
+  /// **Returns:** a new audio stream on success or NULL on failure.
+  pub fn SDL_CreateAudioStream(
+    src_spec: *const SDL_AudioSpec, dst_spec: *const SDL_AudioSpec,
+  ) -> *mut SDL_AudioStream;
+
+  /// Query the current format of an audio stream.
+  pub fn SDL_GetAudioStreamFormat(
+    stream: *mut SDL_AudioStream, src_spec: *mut SDL_AudioSpec,
+    dst_spec: *mut SDL_AudioSpec,
+  ) -> bool;
+
+  /// Change the input and output formats of an audio stream.
+  ///
+  /// Either spec can be NULL to leave that side of the stream alone.
+  pub fn SDL_SetAudioStreamFormat(
+    stream: *mut SDL_AudioStream, src_spec: *const SDL_AudioSpec,
+    dst_spec: *const SDL_AudioSpec,
+  ) -> bool;
+
+  /// Add data to the stream.
+  ///
+  /// This data must match the format/channels/samplerate specified in the
+  /// latest call to [`SDL_SetAudioStreamFormat`], or the format specified
+  /// when creating the stream if it hasn't been changed.
+  pub fn SDL_PutAudioStreamData(
+    stream: *mut SDL_AudioStream, buf: *const c_void, len: c_int,
+  ) -> bool;
+
+  /// Get converted/resampled data from the stream.
+  ///
+  /// **Returns:** the number of bytes read from the stream or -1 on failure.
+  pub fn SDL_GetAudioStreamData(
+    stream: *mut SDL_AudioStream, buf: *mut c_void, len: c_int,
+  ) -> c_int;
+
+  /// Get the number of converted/resampled bytes available.
+  pub fn SDL_GetAudioStreamAvailable(stream: *mut SDL_AudioStream) -> c_int;
+
+  /// Get the number of bytes currently queued.
+  pub fn SDL_GetAudioStreamQueued(stream: *mut SDL_AudioStream) -> c_int;
+
+  /// Tell the stream that you're done sending data, and anything being
+  /// buffered should be converted/resampled and made available immediately.
+  pub fn SDL_FlushAudioStream(stream: *mut SDL_AudioStream) -> bool;
+
+  /// Clear any pending data in the stream.
+  pub fn SDL_ClearAudioStream(stream: *mut SDL_AudioStream) -> bool;
+
+  /// Use this function to pause audio playback on the audio device associated
+  /// with an audio stream.
+  pub fn SDL_PauseAudioStreamDevice(stream: *mut SDL_AudioStream) -> bool;
+
+  /// Use this function to unpause audio playback on the audio device
+  /// associated with an audio stream.
+  pub fn SDL_ResumeAudioStreamDevice(stream: *mut SDL_AudioStream) -> bool;
+
+  /// Set a callback that runs when data is requested from an audio stream.
+  pub fn SDL_SetAudioStreamGetCallback(
+    stream: *mut SDL_AudioStream, callback: SDL_AudioStreamCallback,
+    userdata: *mut c_void,
+  ) -> bool;
+
+  /// Set a callback that runs when data is added to an audio stream.
+  pub fn SDL_SetAudioStreamPutCallback(
+    stream: *mut SDL_AudioStream, callback: SDL_AudioStreamCallback,
+    userdata: *mut c_void,
+  ) -> bool;
+
+  /// Free an audio stream.
+  ///
+  /// If this stream was bound to an audio device, it is unbound during this
+  /// call. If this stream was created with [`SDL_OpenAudioDeviceStream`], the
+  /// audio device that was opened alongside this stream's creation will be
+  /// closed, too.
+  pub fn SDL_DestroyAudioStream(stream: *mut SDL_AudioStream);
+
+  /// Convenience function for straightforward audio init for the common case.
+  ///
+  /// This opens the device, creates a stream with the given `spec` as the app
+  /// side format, and binds the stream to the device. If `callback` is
+  /// provided it's used as the stream's get callback (for playback) or put
+  /// callback (for recording).
+  ///
+  /// The device starts out *paused*, use [`SDL_ResumeAudioStreamDevice`] to
+  /// start it.
+  ///
+  /// **Returns:** an audio stream on success, ready to use, or NULL on
+  /// failure. Destroying the stream also closes the device.
+  pub fn SDL_OpenAudioDeviceStream(
+    devid: SDL_AudioDeviceID, spec: *const SDL_AudioSpec,
+    callback: SDL_AudioStreamCallback, userdata: *mut c_void,
+  ) -> *mut SDL_AudioStream;
+}
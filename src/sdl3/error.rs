@@ -0,0 +1,32 @@
+//! SDL uses a thread-local buffer to store the current error string.
+
+use crate::c_char;
+
+extern "C" {
+  /// Set the SDL error message for the current thread.
+  ///
+  /// The `fmt` is a `printf` style format string.
+  ///
+  /// **Returns:** always `false`.
+  pub fn SDL_SetError(fmt: *const c_char, ...) -> bool;
+
+  /// Set an error indicating that memory allocation failed.
+  ///
+  /// **Returns:** always `false`.
+  pub fn SDL_OutOfMemory() -> bool;
+
+  /// Retrieve a message about the last error that occurred on the current
+  /// thread.
+  ///
+  /// SDL API functions may set error messages and then succeed, so you should
+  /// only use the error value if a function fails.
+  ///
+  /// The returned string is owned by SDL and stays valid until the next SDL
+  /// call on the same thread.
+  pub fn SDL_GetError() -> *const c_char;
+
+  /// Clear any previous error message for this thread.
+  ///
+  /// **Returns:** always `true`.
+  pub fn SDL_ClearError() -> bool;
+}
@@ -0,0 +1,389 @@
+//! Event handling.
+//!
+//! SDL3 keeps the SDL2 idea of one big [`SDL_Event`] union, but the layout is
+//! different: every event has a 64-bit nanosecond `timestamp`, window events
+//! are now their own event types (instead of being sub-events of one
+//! `SDL_WINDOWEVENT`), and mouse positions are `f32`.
+//!
+//! Only the most common event structs are declared so far. Use
+//! [`SDL_Event::type_`] to tell which one is active, and read anything else
+//! out of the `padding` bytes.
+
+use crate::{
+  c_char, c_int, c_void,
+  sdl3::{audio::*, video::*},
+};
+
+/// The types of events that can be delivered.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct SDL_EventType(pub u32);
+/// Unused (do not remove)
+pub const SDL_EVENT_FIRST: SDL_EventType = SDL_EventType(0);
+/// User-requested quit
+pub const SDL_EVENT_QUIT: SDL_EventType = SDL_EventType(0x100);
+/// The application is being terminated by the OS.
+pub const SDL_EVENT_TERMINATING: SDL_EventType = SDL_EventType(0x101);
+/// The application is low on memory, free memory if possible.
+pub const SDL_EVENT_LOW_MEMORY: SDL_EventType = SDL_EventType(0x102);
+/// The application is about to enter the background.
+pub const SDL_EVENT_WILL_ENTER_BACKGROUND: SDL_EventType = SDL_EventType(0x103);
+/// The application did enter the background and may not get CPU for some time.
+pub const SDL_EVENT_DID_ENTER_BACKGROUND: SDL_EventType = SDL_EventType(0x104);
+/// The application is about to enter the foreground.
+pub const SDL_EVENT_WILL_ENTER_FOREGROUND: SDL_EventType = SDL_EventType(0x105);
+/// The application is now interactive.
+pub const SDL_EVENT_DID_ENTER_FOREGROUND: SDL_EventType = SDL_EventType(0x106);
+/// The user's locale preferences have changed.
+pub const SDL_EVENT_LOCALE_CHANGED: SDL_EventType = SDL_EventType(0x107);
+/// The system theme changed
+pub const SDL_EVENT_SYSTEM_THEME_CHANGED: SDL_EventType = SDL_EventType(0x108);
+/// Window has been shown
+pub const SDL_EVENT_WINDOW_SHOWN: SDL_EventType = SDL_EventType(0x202);
+/// Window has been hidden
+pub const SDL_EVENT_WINDOW_HIDDEN: SDL_EventType = SDL_EventType(0x203);
+/// Window has been exposed and should be redrawn
+pub const SDL_EVENT_WINDOW_EXPOSED: SDL_EventType = SDL_EventType(0x204);
+/// Window has been moved to `data1`, `data2`
+pub const SDL_EVENT_WINDOW_MOVED: SDL_EventType = SDL_EventType(0x205);
+/// Window has been resized to `data1` x `data2`
+pub const SDL_EVENT_WINDOW_RESIZED: SDL_EventType = SDL_EventType(0x206);
+/// The pixel size of the window has changed to `data1` x `data2`
+pub const SDL_EVENT_WINDOW_PIXEL_SIZE_CHANGED: SDL_EventType =
+  SDL_EventType(0x207);
+/// The pixel size of a Metal view associated with the window has changed
+pub const SDL_EVENT_WINDOW_METAL_VIEW_RESIZED: SDL_EventType =
+  SDL_EventType(0x208);
+/// Window has been minimized
+pub const SDL_EVENT_WINDOW_MINIMIZED: SDL_EventType = SDL_EventType(0x209);
+/// Window has been maximized
+pub const SDL_EVENT_WINDOW_MAXIMIZED: SDL_EventType = SDL_EventType(0x20A);
+/// Window has been restored to normal size and position
+pub const SDL_EVENT_WINDOW_RESTORED: SDL_EventType = SDL_EventType(0x20B);
+/// Window has gained mouse focus
+pub const SDL_EVENT_WINDOW_MOUSE_ENTER: SDL_EventType = SDL_EventType(0x20C);
+/// Window has lost mouse focus
+pub const SDL_EVENT_WINDOW_MOUSE_LEAVE: SDL_EventType = SDL_EventType(0x20D);
+/// Window has gained keyboard focus
+pub const SDL_EVENT_WINDOW_FOCUS_GAINED: SDL_EventType = SDL_EventType(0x20E);
+/// Window has lost keyboard focus
+pub const SDL_EVENT_WINDOW_FOCUS_LOST: SDL_EventType = SDL_EventType(0x20F);
+/// The window manager requests that the window be closed
+pub const SDL_EVENT_WINDOW_CLOSE_REQUESTED: SDL_EventType =
+  SDL_EventType(0x210);
+/// Key pressed
+pub const SDL_EVENT_KEY_DOWN: SDL_EventType = SDL_EventType(0x300);
+/// Key released
+pub const SDL_EVENT_KEY_UP: SDL_EventType = SDL_EventType(0x301);
+/// Keyboard text editing (composition)
+pub const SDL_EVENT_TEXT_EDITING: SDL_EventType = SDL_EventType(0x302);
+/// Keyboard text input
+pub const SDL_EVENT_TEXT_INPUT: SDL_EventType = SDL_EventType(0x303);
+/// Mouse moved
+pub const SDL_EVENT_MOUSE_MOTION: SDL_EventType = SDL_EventType(0x400);
+/// Mouse button pressed
+pub const SDL_EVENT_MOUSE_BUTTON_DOWN: SDL_EventType = SDL_EventType(0x401);
+/// Mouse button released
+pub const SDL_EVENT_MOUSE_BUTTON_UP: SDL_EventType = SDL_EventType(0x402);
+/// Mouse wheel motion
+pub const SDL_EVENT_MOUSE_WHEEL: SDL_EventType = SDL_EventType(0x403);
+/// A new joystick has been inserted into the system
+pub const SDL_EVENT_JOYSTICK_ADDED: SDL_EventType = SDL_EventType(0x605);
+/// An opened joystick has been removed
+pub const SDL_EVENT_JOYSTICK_REMOVED: SDL_EventType = SDL_EventType(0x606);
+/// A new gamepad has been inserted into the system
+pub const SDL_EVENT_GAMEPAD_ADDED: SDL_EventType = SDL_EventType(0x653);
+/// A gamepad has been removed
+pub const SDL_EVENT_GAMEPAD_REMOVED: SDL_EventType = SDL_EventType(0x654);
+/// The clipboard changed
+pub const SDL_EVENT_CLIPBOARD_UPDATE: SDL_EventType = SDL_EventType(0x900);
+/// The system requests a file open
+pub const SDL_EVENT_DROP_FILE: SDL_EventType = SDL_EventType(0x1000);
+/// text/plain drag-and-drop event
+pub const SDL_EVENT_DROP_TEXT: SDL_EventType = SDL_EventType(0x1001);
+/// A new set of drops is beginning
+pub const SDL_EVENT_DROP_BEGIN: SDL_EventType = SDL_EventType(0x1002);
+/// Current set of drops is now complete
+pub const SDL_EVENT_DROP_COMPLETE: SDL_EventType = SDL_EventType(0x1003);
+/// A new audio device is available
+pub const SDL_EVENT_AUDIO_DEVICE_ADDED: SDL_EventType = SDL_EventType(0x1100);
+/// An audio device has been removed
+pub const SDL_EVENT_AUDIO_DEVICE_REMOVED: SDL_EventType = SDL_EventType(0x1101);
+/// An audio device's format has been changed by the system
+pub const SDL_EVENT_AUDIO_DEVICE_FORMAT_CHANGED: SDL_EventType =
+  SDL_EventType(0x1102);
+/// The render targets have been reset and their contents need to be updated
+pub const SDL_EVENT_RENDER_TARGETS_RESET: SDL_EventType = SDL_EventType(0x2000);
+/// The device has been reset and all textures need to be recreated
+pub const SDL_EVENT_RENDER_DEVICE_RESET: SDL_EventType = SDL_EventType(0x2001);
+/// The first event type available for your own use, see
+/// [`SDL_RegisterEvents`].
+pub const SDL_EVENT_USER: SDL_EventType = SDL_EventType(0x8000);
+/// The last possible event type.
+pub const SDL_EVENT_LAST: SDL_EventType = SDL_EventType(0xFFFF);
+
+/// Fields shared by every event.
+#[derive(Debug, Clone, Copy, Default)]
+#[repr(C)]
+pub struct SDL_CommonEvent {
+  /// Event type, shared with all events
+  pub type_: SDL_EventType,
+  #[allow(missing_docs)]
+  pub reserved: u32,
+  /// In nanoseconds, populated using [`SDL_GetTicksNS`]
+  ///
+  /// [`SDL_GetTicksNS`]: crate::sdl3::timer::SDL_GetTicksNS
+  pub timestamp: u64,
+}
+
+/// The "quit requested" event.
+pub type SDL_QuitEvent = SDL_CommonEvent;
+
+/// Window state change event data (`SDL_EVENT_WINDOW_*`).
+#[derive(Debug, Clone, Copy, Default)]
+#[repr(C)]
+pub struct SDL_WindowEvent {
+  /// `SDL_EVENT_WINDOW_*`
+  pub type_: SDL_EventType,
+  #[allow(missing_docs)]
+  pub reserved: u32,
+  /// In nanoseconds
+  pub timestamp: u64,
+  /// The associated window
+  pub windowID: SDL_WindowID,
+  /// event dependent data
+  pub data1: i32,
+  /// event dependent data
+  pub data2: i32,
+}
+
+/// Keyboard button event structure (`SDL_EVENT_KEY_DOWN`/`SDL_EVENT_KEY_UP`).
+#[derive(Debug, Clone, Copy, Default)]
+#[repr(C)]
+pub struct SDL_KeyboardEvent {
+  /// `SDL_EVENT_KEY_DOWN` or `SDL_EVENT_KEY_UP`
+  pub type_: SDL_EventType,
+  #[allow(missing_docs)]
+  pub reserved: u32,
+  /// In nanoseconds
+  pub timestamp: u64,
+  /// The window with keyboard focus, if any
+  pub windowID: SDL_WindowID,
+  /// The keyboard instance id, or 0 if unknown or virtual
+  pub which: u32,
+  /// SDL physical key code (an `SDL_Scancode`)
+  pub scancode: c_int,
+  /// SDL virtual key code (an `SDL_Keycode`)
+  pub key: u32,
+  /// current key modifiers (an `SDL_Keymod`)
+  pub mod_: u16,
+  /// The platform dependent scancode for this event
+  pub raw: u16,
+  /// true if the key is pressed
+  pub down: bool,
+  /// true if this is a key repeat
+  pub repeat: bool,
+}
+
+/// Keyboard text input event structure (`SDL_EVENT_TEXT_INPUT`).
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct SDL_TextInputEvent {
+  /// `SDL_EVENT_TEXT_INPUT`
+  pub type_: SDL_EventType,
+  #[allow(missing_docs)]
+  pub reserved: u32,
+  /// In nanoseconds
+  pub timestamp: u64,
+  /// The window with keyboard focus, if any
+  pub windowID: SDL_WindowID,
+  /// The input text, UTF-8 encoded. SDL owns this, so copy it if you need to
+  /// keep it.
+  pub text: *const c_char,
+}
+
+/// Mouse motion event structure (`SDL_EVENT_MOUSE_MOTION`).
+#[derive(Debug, Clone, Copy, Default)]
+#[repr(C)]
+pub struct SDL_MouseMotionEvent {
+  /// `SDL_EVENT_MOUSE_MOTION`
+  pub type_: SDL_EventType,
+  #[allow(missing_docs)]
+  pub reserved: u32,
+  /// In nanoseconds
+  pub timestamp: u64,
+  /// The window with mouse focus, if any
+  pub windowID: SDL_WindowID,
+  /// The mouse instance id, or 0 for touch input
+  pub which: u32,
+  /// The current button state (an `SDL_MouseButtonFlags`)
+  pub state: u32,
+  /// X coordinate, relative to window
+  pub x: f32,
+  /// Y coordinate, relative to window
+  pub y: f32,
+  /// The relative motion in the X direction
+  pub xrel: f32,
+  /// The relative motion in the Y direction
+  pub yrel: f32,
+}
+
+/// Mouse button event structure (`SDL_EVENT_MOUSE_BUTTON_DOWN`/`UP`).
+#[derive(Debug, Clone, Copy, Default)]
+#[repr(C)]
+pub struct SDL_MouseButtonEvent {
+  /// `SDL_EVENT_MOUSE_BUTTON_DOWN` or `SDL_EVENT_MOUSE_BUTTON_UP`
+  pub type_: SDL_EventType,
+  #[allow(missing_docs)]
+  pub reserved: u32,
+  /// In nanoseconds
+  pub timestamp: u64,
+  /// The window with mouse focus, if any
+  pub windowID: SDL_WindowID,
+  /// The mouse instance id, or 0 for touch input
+  pub which: u32,
+  /// The mouse button index
+  pub button: u8,
+  /// true if the button is pressed
+  pub down: bool,
+  /// 1 for single-click, 2 for double-click, etc.
+  pub clicks: u8,
+  #[allow(missing_docs)]
+  pub padding: u8,
+  /// X coordinate, relative to window
+  pub x: f32,
+  /// Y coordinate, relative to window
+  pub y: f32,
+}
+
+/// Audio device event structure (`SDL_EVENT_AUDIO_DEVICE_*`).
+#[derive(Debug, Clone, Copy, Default)]
+#[repr(C)]
+pub struct SDL_AudioDeviceEvent {
+  /// `SDL_EVENT_AUDIO_DEVICE_*`
+  pub type_: SDL_EventType,
+  #[allow(missing_docs)]
+  pub reserved: u32,
+  /// In nanoseconds
+  pub timestamp: u64,
+  /// The audio device's id
+  pub which: SDL_AudioDeviceID,
+  /// false if a playback device, true if a recording device.
+  pub recording: bool,
+  #[allow(missing_docs)]
+  pub padding1: u8,
+  #[allow(missing_docs)]
+  pub padding2: u8,
+  #[allow(missing_docs)]
+  pub padding3: u8,
+}
+
+/// A user-defined event type (`SDL_EVENT_USER` through `SDL_EVENT_LAST`).
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct SDL_UserEvent {
+  /// A value from [`SDL_RegisterEvents`]
+  pub type_: SDL_EventType,
+  #[allow(missing_docs)]
+  pub reserved: u32,
+  /// In nanoseconds
+  pub timestamp: u64,
+  /// The associated window if any
+  pub windowID: SDL_WindowID,
+  /// User defined event code
+  pub code: i32,
+  /// User defined data pointer
+  pub data1: *mut c_void,
+  /// User defined data pointer
+  pub data2: *mut c_void,
+}
+
+/// The structure for all events in SDL3.
+///
+/// As with SDL2, this is a C union padded to a fixed size (128 bytes), so
+/// check `type_` before reading any other field.
+#[derive(Clone, Copy)]
+#[repr(C)]
+#[allow(missing_docs)]
+pub union SDL_Event {
+  pub type_: SDL_EventType,
+  pub common: SDL_CommonEvent,
+  pub quit: SDL_QuitEvent,
+  pub window: SDL_WindowEvent,
+  pub key: SDL_KeyboardEvent,
+  pub text: SDL_TextInputEvent,
+  pub motion: SDL_MouseMotionEvent,
+  pub button: SDL_MouseButtonEvent,
+  pub adevice: SDL_AudioDeviceEvent,
+  pub user: SDL_UserEvent,
+  pub padding: [u8; 128],
+}
+impl Default for SDL_Event {
+  #[inline]
+  fn default() -> Self {
+    unsafe { core::mem::zeroed() }
+  }
+}
+impl core::fmt::Debug for SDL_Event {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    f.debug_struct("SDL_Event")
+      .field("type_", unsafe { &self.type_ })
+      .finish_non_exhaustive()
+  }
+}
+// Same as the C header, this checks that the union didn't change size.
+const _: () = assert!(core::mem::size_of::<SDL_Event>() == 128);
+
+extern "C" {
+  /// Pump the event loop, gathering events from the input devices.
+  ///
+  /// [`SDL_PollEvent`] and [`SDL_WaitEvent`] do this for you.
+  pub fn SDL_PumpEvents();
+
+  /// Poll for currently pending events.
+  ///
+  /// * `event` the next event is removed from the queue and stored here. If
+  ///   this is null, the event is left in the queue.
+  ///
+  /// **Returns:** `true` if there was an event, `false` if the queue is
+  /// empty.
+  pub fn SDL_PollEvent(event: *mut SDL_Event) -> bool;
+
+  /// Wait indefinitely for the next available event.
+  ///
+  /// **Returns:** `true` on success or `false` if there was an error while
+  /// waiting for events.
+  pub fn SDL_WaitEvent(event: *mut SDL_Event) -> bool;
+
+  /// Wait until the timeout (in milliseconds, -1 for no timeout) for the next
+  /// available event.
+  ///
+  /// **Returns:** `true` if an event was stored, or `false` on timeout or
+  /// error.
+  pub fn SDL_WaitEventTimeout(event: *mut SDL_Event, timeoutMS: i32) -> bool;
+
+  /// Add an event to the event queue.
+  ///
+  /// **Returns:** `true` on success, or `false` if the event was filtered or
+  /// on failure.
+  pub fn SDL_PushEvent(event: *mut SDL_Event) -> bool;
+
+  /// Allocate a set of user-defined events, and return the beginning event
+  /// number for that set of events.
+  ///
+  /// **Returns:** The first event type, or 0 if there aren't enough user
+  /// events left.
+  pub fn SDL_RegisterEvents(numevents: c_int) -> u32;
+
+  /// Check for the existence of a certain event type in the event queue.
+  pub fn SDL_HasEvent(type_: u32) -> bool;
+
+  /// Clear events of a specific type from the event queue.
+  pub fn SDL_FlushEvent(type_: u32);
+
+  /// Clear events of a range of types (inclusive) from the event queue.
+  pub fn SDL_FlushEvents(minType: u32, maxType: u32);
+
+  /// Get the window that an event is associated with, if any.
+  pub fn SDL_GetWindowFromEvent(event: *const SDL_Event) -> *mut SDL_Window;
+}
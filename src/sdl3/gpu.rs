@@ -0,0 +1,365 @@
+//! The GPU API offers a cross-platform way for apps to talk to modern
+//! graphics hardware.
+//!
+//! It offers both 3D graphics and compute support, in the style of Metal,
+//! Vulkan, and Direct3D 12.
+//!
+//! Only the basics are bound so far: creating a device, claiming a window,
+//! acquiring and submitting command buffers, getting the swapchain texture,
+//! and beginning/ending a render pass. That's enough to clear the screen each
+//! frame, and more of the API will be added over time.
+
+use crate::{
+  c_char, c_float, c_int, c_void,
+  sdl3::{pixels::*, video::*},
+};
+
+/// An opaque handle representing the SDL_GPU context.
+#[repr(transparent)]
+pub struct SDL_GPUDevice(c_void);
+
+/// An opaque handle representing a texture.
+#[repr(transparent)]
+pub struct SDL_GPUTexture(c_void);
+
+/// An opaque handle representing a command buffer.
+///
+/// Most state is managed via command buffers. When setting state using a
+/// command buffer, that state is local to the command buffer.
+#[repr(transparent)]
+pub struct SDL_GPUCommandBuffer(c_void);
+
+/// An opaque handle representing a render pass.
+///
+/// This handle is transient and should not be held or referenced after
+/// [`SDL_EndGPURenderPass`] is called.
+#[repr(transparent)]
+pub struct SDL_GPURenderPass(c_void);
+
+/// Specifies the format of shader code.
+///
+/// Each format corresponds to a specific backend that accepts it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct SDL_GPUShaderFormat(pub u32);
+impl_bit_ops_for_tuple_newtype!(SDL_GPUShaderFormat);
+#[allow(missing_docs)]
+pub const SDL_GPU_SHADERFORMAT_INVALID: SDL_GPUShaderFormat =
+  SDL_GPUShaderFormat(0);
+/// Shaders for NDA'd platforms.
+pub const SDL_GPU_SHADERFORMAT_PRIVATE: SDL_GPUShaderFormat =
+  SDL_GPUShaderFormat(1 << 0);
+/// SPIR-V shaders for Vulkan.
+pub const SDL_GPU_SHADERFORMAT_SPIRV: SDL_GPUShaderFormat =
+  SDL_GPUShaderFormat(1 << 1);
+/// DXBC SM5_1 shaders for D3D12.
+pub const SDL_GPU_SHADERFORMAT_DXBC: SDL_GPUShaderFormat =
+  SDL_GPUShaderFormat(1 << 2);
+/// DXIL SM6_0 shaders for D3D12.
+pub const SDL_GPU_SHADERFORMAT_DXIL: SDL_GPUShaderFormat =
+  SDL_GPUShaderFormat(1 << 3);
+/// MSL shaders for Metal.
+pub const SDL_GPU_SHADERFORMAT_MSL: SDL_GPUShaderFormat =
+  SDL_GPUShaderFormat(1 << 4);
+/// Precompiled metallib shaders for Metal.
+pub const SDL_GPU_SHADERFORMAT_METALLIB: SDL_GPUShaderFormat =
+  SDL_GPUShaderFormat(1 << 5);
+
+/// Specifies how the contents of a texture attached to a render pass are
+/// treated at the beginning of the render pass.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct SDL_GPULoadOp(pub i32);
+/// The previous contents of the texture will be preserved.
+pub const SDL_GPU_LOADOP_LOAD: SDL_GPULoadOp = SDL_GPULoadOp(0);
+/// The contents of the texture will be cleared to a color.
+pub const SDL_GPU_LOADOP_CLEAR: SDL_GPULoadOp = SDL_GPULoadOp(1);
+/// The previous contents of the texture need not be preserved.
+pub const SDL_GPU_LOADOP_DONT_CARE: SDL_GPULoadOp = SDL_GPULoadOp(2);
+
+/// Specifies how the contents of a texture attached to a render pass are
+/// treated at the end of the render pass.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct SDL_GPUStoreOp(pub i32);
+/// The contents generated during the render pass will be written to memory.
+pub const SDL_GPU_STOREOP_STORE: SDL_GPUStoreOp = SDL_GPUStoreOp(0);
+/// The contents generated during the render pass are not needed.
+pub const SDL_GPU_STOREOP_DONT_CARE: SDL_GPUStoreOp = SDL_GPUStoreOp(1);
+/// The multisample contents are resolved and the multisample contents are
+/// discarded.
+pub const SDL_GPU_STOREOP_RESOLVE: SDL_GPUStoreOp = SDL_GPUStoreOp(2);
+/// The multisample contents are resolved and also stored.
+pub const SDL_GPU_STOREOP_RESOLVE_AND_STORE: SDL_GPUStoreOp = SDL_GPUStoreOp(3);
+
+/// Specifies the texture format and colorspace of the swapchain textures.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct SDL_GPUSwapchainComposition(pub i32);
+/// B8G8R8A8 or R8G8B8A8 swapchain. Pixel values are in sRGB encoding.
+pub const SDL_GPU_SWAPCHAINCOMPOSITION_SDR: SDL_GPUSwapchainComposition =
+  SDL_GPUSwapchainComposition(0);
+/// B8G8R8A8_SRGB or R8G8B8A8_SRGB swapchain. Pixel values are stored in memory
+/// in sRGB encoding but accessed in shaders in "linear sRGB" encoding.
+pub const SDL_GPU_SWAPCHAINCOMPOSITION_SDR_LINEAR: SDL_GPUSwapchainComposition =
+  SDL_GPUSwapchainComposition(1);
+/// R16G16B16A16_FLOAT swapchain. Pixel values are in extended linear sRGB
+/// encoding.
+pub const SDL_GPU_SWAPCHAINCOMPOSITION_HDR_EXTENDED_LINEAR:
+  SDL_GPUSwapchainComposition = SDL_GPUSwapchainComposition(2);
+/// A2R10G10B10 or A2B10G10R10 swapchain. Pixel values are in BT.2020 ST2084
+/// (PQ) encoding.
+pub const SDL_GPU_SWAPCHAINCOMPOSITION_HDR10_ST2084:
+  SDL_GPUSwapchainComposition = SDL_GPUSwapchainComposition(3);
+
+/// Specifies the timing that will be used to present swapchain textures to
+/// the OS.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct SDL_GPUPresentMode(pub i32);
+/// Waits for vblank before presenting. This is always supported.
+pub const SDL_GPU_PRESENTMODE_VSYNC: SDL_GPUPresentMode = SDL_GPUPresentMode(0);
+/// Immediately presents. Lowest latency option, but tearing may occur.
+pub const SDL_GPU_PRESENTMODE_IMMEDIATE: SDL_GPUPresentMode =
+  SDL_GPUPresentMode(1);
+/// Waits for vblank, but replaces any pending frame with the newest one.
+pub const SDL_GPU_PRESENTMODE_MAILBOX: SDL_GPUPresentMode =
+  SDL_GPUPresentMode(2);
+
+/// A structure specifying the parameters of a color target used by a render
+/// pass.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct SDL_GPUColorTargetInfo {
+  /// The texture that will be used as a color target by a render pass.
+  pub texture: *mut SDL_GPUTexture,
+  /// The mip level to use as a color target.
+  pub mip_level: u32,
+  /// The layer index or depth plane to use as a color target.
+  pub layer_or_depth_plane: u32,
+  /// The color to clear the color target to at the start of the render pass.
+  /// Ignored if [`SDL_GPU_LOADOP_CLEAR`] is not used.
+  pub clear_color: SDL_FColor,
+  /// What is done with the contents of the color target at the beginning of
+  /// the render pass.
+  pub load_op: SDL_GPULoadOp,
+  /// What is done with the results of the render pass.
+  pub store_op: SDL_GPUStoreOp,
+  /// The texture that will receive the results of a multisample resolve
+  /// operation.
+  pub resolve_texture: *mut SDL_GPUTexture,
+  /// The mip level of the resolve texture to use for the resolve operation.
+  pub resolve_mip_level: u32,
+  /// The layer index of the resolve texture to use for the resolve operation.
+  pub resolve_layer: u32,
+  /// true cycles the texture if the texture is bound and `load_op` is not
+  /// LOAD
+  pub cycle: bool,
+  /// true cycles the resolve texture if the resolve texture is bound.
+  pub cycle_resolve_texture: bool,
+  #[allow(missing_docs)]
+  pub padding1: u8,
+  #[allow(missing_docs)]
+  pub padding2: u8,
+}
+impl Default for SDL_GPUColorTargetInfo {
+  #[inline]
+  fn default() -> Self {
+    unsafe { core::mem::zeroed() }
+  }
+}
+
+/// A structure specifying the parameters of a depth-stencil target used by a
+/// render pass.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub struct SDL_GPUDepthStencilTargetInfo {
+  /// The texture that will be used as the depth stencil target by the render
+  /// pass.
+  pub texture: *mut SDL_GPUTexture,
+  /// The value to clear the depth component to at the beginning of the render
+  /// pass. Ignored if [`SDL_GPU_LOADOP_CLEAR`] is not used.
+  pub clear_depth: c_float,
+  /// What is done with the depth contents at the beginning of the render
+  /// pass.
+  pub load_op: SDL_GPULoadOp,
+  /// What is done with the depth results of the render pass.
+  pub store_op: SDL_GPUStoreOp,
+  /// What is done with the stencil contents at the beginning of the render
+  /// pass.
+  pub stencil_load_op: SDL_GPULoadOp,
+  /// What is done with the stencil results of the render pass.
+  pub stencil_store_op: SDL_GPUStoreOp,
+  /// true cycles the texture if the texture is bound and any load ops are not
+  /// LOAD
+  pub cycle: bool,
+  /// The value to clear the stencil component to at the beginning of the
+  /// render pass. Ignored if [`SDL_GPU_LOADOP_CLEAR`] is not used.
+  pub clear_stencil: u8,
+  #[allow(missing_docs)]
+  pub padding1: u8,
+  #[allow(missing_docs)]
+  pub padding2: u8,
+}
+impl Default for SDL_GPUDepthStencilTargetInfo {
+  #[inline]
+  fn default() -> Self {
+    unsafe { core::mem::zeroed() }
+  }
+}
+
+extern "C" {
+  /// Checks for GPU runtime support.
+  ///
+  /// * `format_flags` a bitflag indicating which shader formats the app is
+  ///   able to provide.
+  /// * `name` the preferred GPU driver, or NULL to let SDL pick the optimal
+  ///   driver.
+  pub fn SDL_GPUSupportsShaderFormats(
+    format_flags: SDL_GPUShaderFormat, name: *const c_char,
+  ) -> bool;
+
+  /// Creates a GPU context.
+  ///
+  /// * `format_flags` a bitflag indicating which shader formats the app is
+  ///   able to provide.
+  /// * `debug_mode` enable debug mode properties and validations.
+  /// * `name` the preferred GPU driver, or NULL to let SDL pick the optimal
+  ///   driver.
+  ///
+  /// **Returns:** a GPU context on success or NULL on failure.
+  pub fn SDL_CreateGPUDevice(
+    format_flags: SDL_GPUShaderFormat, debug_mode: bool, name: *const c_char,
+  ) -> *mut SDL_GPUDevice;
+
+  /// Destroys a GPU context previously returned by [`SDL_CreateGPUDevice`].
+  pub fn SDL_DestroyGPUDevice(device: *mut SDL_GPUDevice);
+
+  /// Get the number of GPU drivers compiled into SDL.
+  pub fn SDL_GetNumGPUDrivers() -> c_int;
+
+  /// Get the name of a built in GPU driver.
+  pub fn SDL_GetGPUDriver(index: c_int) -> *const c_char;
+
+  /// Returns the name of the backend used to create this GPU context.
+  pub fn SDL_GetGPUDeviceDriver(device: *mut SDL_GPUDevice) -> *const c_char;
+
+  /// Returns the supported shader formats for this GPU context.
+  pub fn SDL_GetGPUShaderFormats(
+    device: *mut SDL_GPUDevice,
+  ) -> SDL_GPUShaderFormat;
+
+  /// Claims a window, creating a swapchain structure for it.
+  ///
+  /// This must be called before [`SDL_AcquireGPUSwapchainTexture`] is called
+  /// using the window. You should only call this function from the thread
+  /// that created the window.
+  pub fn SDL_ClaimWindowForGPUDevice(
+    device: *mut SDL_GPUDevice, window: *mut SDL_Window,
+  ) -> bool;
+
+  /// Unclaims a window, destroying its swapchain structure.
+  pub fn SDL_ReleaseWindowFromGPUDevice(
+    device: *mut SDL_GPUDevice, window: *mut SDL_Window,
+  );
+
+  /// Determines whether a swapchain composition is supported by the window.
+  ///
+  /// The window must be claimed before calling this function.
+  pub fn SDL_WindowSupportsGPUSwapchainComposition(
+    device: *mut SDL_GPUDevice, window: *mut SDL_Window,
+    swapchain_composition: SDL_GPUSwapchainComposition,
+  ) -> bool;
+
+  /// Determines whether a presentation mode is supported by the window.
+  ///
+  /// The window must be claimed before calling this function.
+  pub fn SDL_WindowSupportsGPUPresentMode(
+    device: *mut SDL_GPUDevice, window: *mut SDL_Window,
+    present_mode: SDL_GPUPresentMode,
+  ) -> bool;
+
+  /// Changes the swapchain parameters for the given claimed window.
+  ///
+  /// The default is [`SDL_GPU_SWAPCHAINCOMPOSITION_SDR`] and
+  /// [`SDL_GPU_PRESENTMODE_VSYNC`].
+  pub fn SDL_SetGPUSwapchainParameters(
+    device: *mut SDL_GPUDevice, window: *mut SDL_Window,
+    swapchain_composition: SDL_GPUSwapchainComposition,
+    present_mode: SDL_GPUPresentMode,
+  ) -> bool;
+
+  /// Acquire a command buffer.
+  ///
+  /// This command buffer is managed by the implementation and should not be
+  /// freed by the user. The command buffer may only be used on the thread it
+  /// was acquired on.
+  ///
+  /// **Returns:** a command buffer, or NULL on failure.
+  pub fn SDL_AcquireGPUCommandBuffer(
+    device: *mut SDL_GPUDevice,
+  ) -> *mut SDL_GPUCommandBuffer;
+
+  /// Acquire a texture to use in presentation.
+  ///
+  /// When a swapchain texture is acquired on a command buffer, it will
+  /// automatically be submitted for presentation when the command buffer is
+  /// submitted. The swapchain texture should only be referenced by the command
+  /// buffer used to acquire it.
+  ///
+  /// This may fill the swapchain texture handle with NULL under certain
+  /// conditions (eg: the window is minimized), and that's *not* an error. You
+  /// should always check the handle before using it.
+  pub fn SDL_AcquireGPUSwapchainTexture(
+    command_buffer: *mut SDL_GPUCommandBuffer, window: *mut SDL_Window,
+    swapchain_texture: *mut *mut SDL_GPUTexture,
+    swapchain_texture_width: *mut u32, swapchain_texture_height: *mut u32,
+  ) -> bool;
+
+  /// Blocks the thread until a swapchain texture is available to be acquired,
+  /// and then acquires it.
+  ///
+  /// Otherwise this works like [`SDL_AcquireGPUSwapchainTexture`].
+  pub fn SDL_WaitAndAcquireGPUSwapchainTexture(
+    command_buffer: *mut SDL_GPUCommandBuffer, window: *mut SDL_Window,
+    swapchain_texture: *mut *mut SDL_GPUTexture,
+    swapchain_texture_width: *mut u32, swapchain_texture_height: *mut u32,
+  ) -> bool;
+
+  /// Submits a command buffer so its commands can be processed on the GPU.
+  ///
+  /// It is invalid to use the command buffer after this is called.
+  pub fn SDL_SubmitGPUCommandBuffer(
+    command_buffer: *mut SDL_GPUCommandBuffer,
+  ) -> bool;
+
+  /// Cancels a command buffer.
+  ///
+  /// None of the enqueued commands are executed. It is an error to call this
+  /// after a swapchain texture has been acquired.
+  pub fn SDL_CancelGPUCommandBuffer(
+    command_buffer: *mut SDL_GPUCommandBuffer,
+  ) -> bool;
+
+  /// Begins a render pass on a command buffer.
+  ///
+  /// A render pass consists of a set of texture subresources (or depth
+  /// slices in the 3D texture case) which will be rendered to during the
+  /// render pass, along with corresponding clear values and load/store
+  /// operations. It is an error to call this while another pass is active on
+  /// the same command buffer.
+  pub fn SDL_BeginGPURenderPass(
+    command_buffer: *mut SDL_GPUCommandBuffer,
+    color_target_infos: *const SDL_GPUColorTargetInfo, num_color_targets: u32,
+    depth_stencil_target_info: *const SDL_GPUDepthStencilTargetInfo,
+  ) -> *mut SDL_GPURenderPass;
+
+  /// Ends the given render pass.
+  ///
+  /// All bound graphics state on the render pass command buffer is unset.
+  /// The render pass handle is now invalid.
+  pub fn SDL_EndGPURenderPass(render_pass: *mut SDL_GPURenderPass);
+
+  /// Blocks the thread until the GPU is completely idle.
+  pub fn SDL_WaitForGPUIdle(device: *mut SDL_GPUDevice) -> bool;
+}
@@ -0,0 +1,105 @@
+//! Bindings to the SDL3 C library.
+//!
+//! This module tree is only available with the `sdl3` cargo feature, and
+//! it's intended for prototyping a migration to SDL3 within the same crate.
+//!
+//! SDL3 uses the same symbol names as SDL2 for many functions (eg:
+//! `SDL_Init`), so the two libraries can't be linked into one program. When
+//! the `sdl3` feature is enabled the build script links to SDL3 *instead* of
+//! SDL2, and so **none of the SDL2 items at the crate root may be called** in
+//! that configuration. Only use the declarations within this module.
+//!
+//! The layout follows the same rule as the rest of the crate: a header like
+//! `include/SDL3/SDL_foo.h` becomes a module named `foo`. Also there is a
+//! `prelude` module which lets you grab all of the SDL3 exports in a single use
+//! statement.
+//!
+//! Coverage is currently partial: init, errors, versions, properties, window
+//! creation, the event queue and the most common event types, the
+//! stream-based audio API, and the basics of the GPU API.
+
+use crate::c_char;
+
+pub mod prelude;
+
+pub mod audio;
+pub mod error;
+pub mod events;
+pub mod gpu;
+pub mod pixels;
+pub mod properties;
+pub mod stdinc;
+pub mod timer;
+pub mod version;
+pub mod video;
+
+/// SDL3's initialization flags.
+///
+/// These are the flags which may be passed to [`SDL_Init`]. You should specify
+/// the subsystems which you will be using in your application.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct SDL_InitFlags(pub u32);
+impl_bit_ops_for_tuple_newtype!(SDL_InitFlags);
+/// `SDL_INIT_AUDIO` implies [`SDL_INIT_EVENTS`]
+pub const SDL_INIT_AUDIO: SDL_InitFlags = SDL_InitFlags(0x00000010);
+/// `SDL_INIT_VIDEO` implies [`SDL_INIT_EVENTS`], should be initialized on the
+/// main thread
+pub const SDL_INIT_VIDEO: SDL_InitFlags = SDL_InitFlags(0x00000020);
+/// `SDL_INIT_JOYSTICK` implies [`SDL_INIT_EVENTS`]
+pub const SDL_INIT_JOYSTICK: SDL_InitFlags = SDL_InitFlags(0x00000200);
+#[allow(missing_docs)]
+pub const SDL_INIT_HAPTIC: SDL_InitFlags = SDL_InitFlags(0x00001000);
+/// `SDL_INIT_GAMEPAD` implies [`SDL_INIT_JOYSTICK`]
+pub const SDL_INIT_GAMEPAD: SDL_InitFlags = SDL_InitFlags(0x00002000);
+#[allow(missing_docs)]
+pub const SDL_INIT_EVENTS: SDL_InitFlags = SDL_InitFlags(0x00004000);
+/// `SDL_INIT_SENSOR` implies [`SDL_INIT_EVENTS`]
+pub const SDL_INIT_SENSOR: SDL_InitFlags = SDL_InitFlags(0x00008000);
+/// `SDL_INIT_CAMERA` implies [`SDL_INIT_EVENTS`]
+pub const SDL_INIT_CAMERA: SDL_InitFlags = SDL_InitFlags(0x00010000);
+
+extern "C" {
+  /// Initialize the SDL library.
+  ///
+  /// **Returns:** `true` on success or `false` on failure; call
+  /// [`SDL_GetError`](error::SDL_GetError) for more information.
+  pub fn SDL_Init(flags: SDL_InitFlags) -> bool;
+
+  /// Compatibility function to initialize the SDL library.
+  ///
+  /// This function and [`SDL_Init`] are interchangeable.
+  pub fn SDL_InitSubSystem(flags: SDL_InitFlags) -> bool;
+
+  /// Shut down specific SDL subsystems.
+  ///
+  /// You still need to call [`SDL_Quit`] even if you close all open
+  /// subsystems with this.
+  pub fn SDL_QuitSubSystem(flags: SDL_InitFlags);
+
+  /// Get a mask of the specified subsystems which are currently initialized.
+  ///
+  /// If `flags` is 0, it returns a mask of all initialized subsystems.
+  pub fn SDL_WasInit(flags: SDL_InitFlags) -> SDL_InitFlags;
+
+  /// Clean up all initialized subsystems.
+  ///
+  /// You should call this function even if you have already shutdown each
+  /// initialized subsystem with [`SDL_QuitSubSystem`].
+  pub fn SDL_Quit();
+
+  /// Specify basic metadata about your app.
+  ///
+  /// * `appname` The name of the application ("My Game 2: Bad Guy's
+  ///   Revenge!").
+  /// * `appversion` The version of the application ("1.0.0beta5").
+  /// * `appidentifier` A unique string in reverse-domain format that
+  ///   identifies this app ("com.example.mygame2").
+  ///
+  /// All strings may be NULL. This should be called as early as possible,
+  /// before [`SDL_Init`].
+  pub fn SDL_SetAppMetadata(
+    appname: *const c_char, appversion: *const c_char,
+    appidentifier: *const c_char,
+  ) -> bool;
+}
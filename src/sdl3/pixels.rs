@@ -0,0 +1,27 @@
+//! Pixel and color related types.
+
+use crate::c_float;
+
+/// A structure that represents a color as RGBA components.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(C)]
+#[allow(missing_docs)]
+pub struct SDL_Color {
+  pub r: u8,
+  pub g: u8,
+  pub b: u8,
+  pub a: u8,
+}
+
+/// A structure that represents a color as floating point RGBA components.
+///
+/// The range is normally 0.0 to 1.0, but HDR colors can go higher.
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+#[repr(C)]
+#[allow(missing_docs)]
+pub struct SDL_FColor {
+  pub r: c_float,
+  pub g: c_float,
+  pub b: c_float,
+  pub a: c_float,
+}
@@ -0,0 +1,16 @@
+//! This is a module that re-exports every sub-module of the SDL3 tree.
+//!
+//! This is separate from the crate's main prelude, because many SDL3 names
+//! are the same as SDL2 names.
+//! ```rust
+//! use fermium::sdl3::prelude::*;
+//! ```
+
+pub use crate::{
+  c_char, c_double, c_float, c_int, c_long, c_longlong, c_schar, c_short,
+  c_uchar, c_uint, c_ulong, c_ulonglong, c_ushort, c_void,
+  sdl3::{
+    audio::*, error::*, events::*, gpu::*, pixels::*, properties::*, stdinc::*,
+    timer::*, version::*, video::*, *,
+  },
+};
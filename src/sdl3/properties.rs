@@ -0,0 +1,190 @@
+//! A property is a variable that can be created and retrieved by name at
+//! runtime.
+//!
+//! All properties are part of a property group ([`SDL_PropertiesID`]). A
+//! property group can be created with [`SDL_CreateProperties`] and destroyed
+//! with [`SDL_DestroyProperties`].
+//!
+//! Many SDL3 functions take a property group instead of a long list of
+//! arguments (eg: `SDL_CreateWindowWithProperties`), and the property names
+//! they look for are given as `SDL_PROP_*` constants in the relevant module.
+
+use crate::{c_char, c_float, c_void};
+
+/// SDL properties ID.
+///
+/// An ID of 0 is an invalid ID.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct SDL_PropertiesID(pub u32);
+
+/// SDL property type.
+///
+/// See the `SDL_PROPERTY_TYPE_*` constants.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct SDL_PropertyType(pub i32);
+#[allow(missing_docs)]
+pub const SDL_PROPERTY_TYPE_INVALID: SDL_PropertyType = SDL_PropertyType(0);
+#[allow(missing_docs)]
+pub const SDL_PROPERTY_TYPE_POINTER: SDL_PropertyType = SDL_PropertyType(1);
+#[allow(missing_docs)]
+pub const SDL_PROPERTY_TYPE_STRING: SDL_PropertyType = SDL_PropertyType(2);
+#[allow(missing_docs)]
+pub const SDL_PROPERTY_TYPE_NUMBER: SDL_PropertyType = SDL_PropertyType(3);
+#[allow(missing_docs)]
+pub const SDL_PROPERTY_TYPE_FLOAT: SDL_PropertyType = SDL_PropertyType(4);
+#[allow(missing_docs)]
+pub const SDL_PROPERTY_TYPE_BOOLEAN: SDL_PropertyType = SDL_PropertyType(5);
+
+/// A callback used to free resources when a property is deleted.
+///
+/// This is called when the property is cleared, replaced, or the property
+/// group is destroyed.
+pub type SDL_CleanupPropertyCallback =
+  Option<unsafe extern "C" fn(userdata: *mut c_void, value: *mut c_void)>;
+
+/// A callback used to enumerate all the properties in a group of properties.
+pub type SDL_EnumeratePropertiesCallback = Option<
+  unsafe extern "C" fn(
+    userdata: *mut c_void,
+    props: SDL_PropertiesID,
+    name: *const c_char,
+  ),
+>;
+
+extern "C" {
+  /// Get the global SDL properties.
+  ///
+  /// **Returns:** a valid property ID on success or 0 on failure.
+  pub fn SDL_GetGlobalProperties() -> SDL_PropertiesID;
+
+  /// Create a group of properties.
+  ///
+  /// All properties are automatically destroyed when [`SDL_Quit`] is called.
+  ///
+  /// **Returns:** an ID for a new group of properties, or 0 on failure.
+  ///
+  /// [`SDL_Quit`]: super::SDL_Quit
+  pub fn SDL_CreateProperties() -> SDL_PropertiesID;
+
+  /// Copy a group of properties.
+  ///
+  /// Pointer properties with a cleanup function are not copied.
+  pub fn SDL_CopyProperties(
+    src: SDL_PropertiesID, dst: SDL_PropertiesID,
+  ) -> bool;
+
+  /// Lock a group of properties.
+  ///
+  /// Obtain a multi-threaded lock for these properties. Other threads will
+  /// wait while trying to lock these properties until they are unlocked.
+  /// Properties must be unlocked before they are destroyed.
+  pub fn SDL_LockProperties(props: SDL_PropertiesID) -> bool;
+
+  /// Unlock a group of properties.
+  pub fn SDL_UnlockProperties(props: SDL_PropertiesID);
+
+  /// Set a pointer property in a group of properties with a cleanup function
+  /// that is called when the property is deleted.
+  ///
+  /// The cleanup function is also called if setting the property fails for
+  /// any reason.
+  pub fn SDL_SetPointerPropertyWithCleanup(
+    props: SDL_PropertiesID, name: *const c_char, value: *mut c_void,
+    cleanup: SDL_CleanupPropertyCallback, userdata: *mut c_void,
+  ) -> bool;
+
+  /// Set a pointer property in a group of properties.
+  pub fn SDL_SetPointerProperty(
+    props: SDL_PropertiesID, name: *const c_char, value: *mut c_void,
+  ) -> bool;
+
+  /// Set a string property in a group of properties.
+  ///
+  /// This function makes a copy of the string; the caller does not have to
+  /// preserve the data after this call completes.
+  pub fn SDL_SetStringProperty(
+    props: SDL_PropertiesID, name: *const c_char, value: *const c_char,
+  ) -> bool;
+
+  /// Set an integer property in a group of properties.
+  pub fn SDL_SetNumberProperty(
+    props: SDL_PropertiesID, name: *const c_char, value: i64,
+  ) -> bool;
+
+  /// Set a floating point property in a group of properties.
+  pub fn SDL_SetFloatProperty(
+    props: SDL_PropertiesID, name: *const c_char, value: c_float,
+  ) -> bool;
+
+  /// Set a boolean property in a group of properties.
+  pub fn SDL_SetBooleanProperty(
+    props: SDL_PropertiesID, name: *const c_char, value: bool,
+  ) -> bool;
+
+  /// Return whether a property exists in a group of properties.
+  pub fn SDL_HasProperty(props: SDL_PropertiesID, name: *const c_char) -> bool;
+
+  /// Get the type of a property in a group of properties.
+  ///
+  /// **Returns:** the type of the property, or [`SDL_PROPERTY_TYPE_INVALID`]
+  /// if it is not set.
+  pub fn SDL_GetPropertyType(
+    props: SDL_PropertiesID, name: *const c_char,
+  ) -> SDL_PropertyType;
+
+  /// Get a pointer property from a group of properties.
+  ///
+  /// **Returns:** the value of the property, or `default_value` if it is not
+  /// set or not a pointer property.
+  pub fn SDL_GetPointerProperty(
+    props: SDL_PropertiesID, name: *const c_char, default_value: *mut c_void,
+  ) -> *mut c_void;
+
+  /// Get a string property from a group of properties.
+  ///
+  /// **Returns:** the value of the property, or `default_value` if it is not
+  /// set or not a string property.
+  pub fn SDL_GetStringProperty(
+    props: SDL_PropertiesID, name: *const c_char, default_value: *const c_char,
+  ) -> *const c_char;
+
+  /// Get a number property from a group of properties.
+  ///
+  /// You can use [`SDL_GetPropertyType`] to query whether the property exists
+  /// and is a number property.
+  pub fn SDL_GetNumberProperty(
+    props: SDL_PropertiesID, name: *const c_char, default_value: i64,
+  ) -> i64;
+
+  /// Get a floating point property from a group of properties.
+  pub fn SDL_GetFloatProperty(
+    props: SDL_PropertiesID, name: *const c_char, default_value: c_float,
+  ) -> c_float;
+
+  /// Get a boolean property from a group of properties.
+  pub fn SDL_GetBooleanProperty(
+    props: SDL_PropertiesID, name: *const c_char, default_value: bool,
+  ) -> bool;
+
+  /// Clear a property from a group of properties.
+  pub fn SDL_ClearProperty(
+    props: SDL_PropertiesID, name: *const c_char,
+  ) -> bool;
+
+  /// Enumerate the properties contained in a group of properties.
+  ///
+  /// The callback function is called for each property in the group of
+  /// properties. The properties are locked during enumeration.
+  pub fn SDL_EnumerateProperties(
+    props: SDL_PropertiesID, callback: SDL_EnumeratePropertiesCallback,
+    userdata: *mut c_void,
+  ) -> bool;
+
+  /// Destroy a group of properties.
+  ///
+  /// All properties are deleted and their cleanup functions will be called, if
+  /// any.
+  pub fn SDL_DestroyProperties(props: SDL_PropertiesID);
+}
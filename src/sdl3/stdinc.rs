@@ -0,0 +1,19 @@
+//! The "standard include" which almost every other include tends to use.
+//!
+//! SDL3 uses the C `bool` type directly, so there's no `SDL_bool` here.
+
+use crate::c_void;
+
+extern "C" {
+  /// Works like [`malloc`](https://man7.org/linux/man-pages/man3/malloc.3.html)
+  pub fn SDL_malloc(size: usize) -> *mut c_void;
+
+  /// Works like [`calloc`](https://man7.org/linux/man-pages/man3/calloc.3p.html)
+  pub fn SDL_calloc(nmemb: usize, size: usize) -> *mut c_void;
+
+  /// Works like [`realloc`](https://man7.org/linux/man-pages/man3/realloc.3p.html)
+  pub fn SDL_realloc(mem: *mut c_void, size: usize) -> *mut c_void;
+
+  /// Works like [`free`](https://man7.org/linux/man-pages/man3/free.3p.html)
+  pub fn SDL_free(mem: *mut c_void);
+}
@@ -0,0 +1,23 @@
+//! Timer related functionality.
+//!
+//! SDL3 ticks are 64-bit, so they don't wrap like the SDL2 ticks did.
+
+extern "C" {
+  /// Get the number of milliseconds since SDL library initialization.
+  pub fn SDL_GetTicks() -> u64;
+
+  /// Get the number of nanoseconds since SDL library initialization.
+  pub fn SDL_GetTicksNS() -> u64;
+
+  /// Get the current value of the high resolution counter.
+  pub fn SDL_GetPerformanceCounter() -> u64;
+
+  /// Get the count per second of the high resolution counter.
+  pub fn SDL_GetPerformanceFrequency() -> u64;
+
+  /// Wait a specified number of milliseconds before returning.
+  pub fn SDL_Delay(ms: u32);
+
+  /// Wait a specified number of nanoseconds before returning.
+  pub fn SDL_DelayNS(ns: u64);
+}
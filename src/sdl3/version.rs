@@ -0,0 +1,59 @@
+//! Lets you get info about the runtime SDL version.
+//!
+//! SDL3 packs the version into a single `c_int`, see [`SDL_VERSIONNUM`].
+
+use crate::{c_char, c_int};
+
+/// SDL Major version that these bindings were written against.
+pub const SDL_MAJOR_VERSION: c_int = 3;
+/// SDL Minor version that these bindings were written against.
+pub const SDL_MINOR_VERSION: c_int = 2;
+/// SDL Micro version that these bindings were written against.
+pub const SDL_MICRO_VERSION: c_int = 0;
+
+/// Turns the three version parts into a single version number.
+#[inline]
+#[must_use]
+pub const fn SDL_VERSIONNUM(major: c_int, minor: c_int, patch: c_int) -> c_int {
+  major * 1000000 + minor * 1000 + patch
+}
+
+/// Extracts the major version from a version number.
+#[inline]
+#[must_use]
+pub const fn SDL_VERSIONNUM_MAJOR(version: c_int) -> c_int {
+  version / 1000000
+}
+
+/// Extracts the minor version from a version number.
+#[inline]
+#[must_use]
+pub const fn SDL_VERSIONNUM_MINOR(version: c_int) -> c_int {
+  (version / 1000) % 1000
+}
+
+/// Extracts the micro version from a version number.
+#[inline]
+#[must_use]
+pub const fn SDL_VERSIONNUM_MICRO(version: c_int) -> c_int {
+  version % 1000
+}
+
+/// The version number of SDL that these bindings were written against.
+pub const SDL_VERSION: c_int =
+  SDL_VERSIONNUM(SDL_MAJOR_VERSION, SDL_MINOR_VERSION, SDL_MICRO_VERSION);
+
+extern "C" {
+  /// Get the version of SDL that is linked against your program.
+  ///
+  /// This function may be called safely at any time, even before
+  /// [`SDL_Init`](super::SDL_Init).
+  pub fn SDL_GetVersion() -> c_int;
+
+  /// Get the code revision of SDL that is linked against your program.
+  ///
+  /// This is an arbitrary string (a hash value) uniquely identifying the exact
+  /// revision of the SDL library in use. This is a static string, do not free
+  /// it.
+  pub fn SDL_GetRevision() -> *const c_char;
+}
@@ -0,0 +1,162 @@
+//! Module for interacting with the video subsystem.
+
+use crate::{c_char, c_int, c_void, sdl3::properties::*};
+
+/// The type used to identify a window (newtype'd `c_void`).
+#[repr(transparent)]
+pub struct SDL_Window(c_void);
+
+/// This is a unique ID for a window.
+///
+/// The value 0 is an invalid ID.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct SDL_WindowID(pub u32);
+
+/// The flags on a window.
+///
+/// Unlike SDL2, these are 64 bits.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct SDL_WindowFlags(pub u64);
+impl_bit_ops_for_tuple_newtype!(SDL_WindowFlags);
+/// window is in fullscreen mode
+pub const SDL_WINDOW_FULLSCREEN: SDL_WindowFlags = SDL_WindowFlags(0x1);
+/// window usable with OpenGL context
+pub const SDL_WINDOW_OPENGL: SDL_WindowFlags = SDL_WindowFlags(0x2);
+/// window is occluded
+pub const SDL_WINDOW_OCCLUDED: SDL_WindowFlags = SDL_WindowFlags(0x4);
+/// window is neither mapped onto the desktop nor shown in the taskbar/dock
+pub const SDL_WINDOW_HIDDEN: SDL_WindowFlags = SDL_WindowFlags(0x8);
+/// no window decoration
+pub const SDL_WINDOW_BORDERLESS: SDL_WindowFlags = SDL_WindowFlags(0x10);
+/// window can be resized
+pub const SDL_WINDOW_RESIZABLE: SDL_WindowFlags = SDL_WindowFlags(0x20);
+/// window is minimized
+pub const SDL_WINDOW_MINIMIZED: SDL_WindowFlags = SDL_WindowFlags(0x40);
+/// window is maximized
+pub const SDL_WINDOW_MAXIMIZED: SDL_WindowFlags = SDL_WindowFlags(0x80);
+/// window has grabbed mouse input
+pub const SDL_WINDOW_MOUSE_GRABBED: SDL_WindowFlags = SDL_WindowFlags(0x100);
+/// window has input focus
+pub const SDL_WINDOW_INPUT_FOCUS: SDL_WindowFlags = SDL_WindowFlags(0x200);
+/// window has mouse focus
+pub const SDL_WINDOW_MOUSE_FOCUS: SDL_WindowFlags = SDL_WindowFlags(0x400);
+/// window not created by SDL
+pub const SDL_WINDOW_EXTERNAL: SDL_WindowFlags = SDL_WindowFlags(0x800);
+/// window is modal
+pub const SDL_WINDOW_MODAL: SDL_WindowFlags = SDL_WindowFlags(0x1000);
+/// window uses high pixel density back buffer if possible
+pub const SDL_WINDOW_HIGH_PIXEL_DENSITY: SDL_WindowFlags =
+  SDL_WindowFlags(0x2000);
+/// window has mouse captured (unrelated to `MOUSE_GRABBED`)
+pub const SDL_WINDOW_MOUSE_CAPTURE: SDL_WindowFlags = SDL_WindowFlags(0x4000);
+/// window has relative mode enabled
+pub const SDL_WINDOW_MOUSE_RELATIVE_MODE: SDL_WindowFlags =
+  SDL_WindowFlags(0x8000);
+/// window should always be above others
+pub const SDL_WINDOW_ALWAYS_ON_TOP: SDL_WindowFlags = SDL_WindowFlags(0x10000);
+/// window should be treated as a utility window, not showing in the taskbar
+/// and window list
+pub const SDL_WINDOW_UTILITY: SDL_WindowFlags = SDL_WindowFlags(0x20000);
+/// window should be treated as a tooltip and does not get mouse or keyboard
+/// focus, requires a parent window
+pub const SDL_WINDOW_TOOLTIP: SDL_WindowFlags = SDL_WindowFlags(0x40000);
+/// window should be treated as a popup menu, requires a parent window
+pub const SDL_WINDOW_POPUP_MENU: SDL_WindowFlags = SDL_WindowFlags(0x80000);
+/// window has grabbed keyboard input
+pub const SDL_WINDOW_KEYBOARD_GRABBED: SDL_WindowFlags =
+  SDL_WindowFlags(0x100000);
+/// window usable for Vulkan surface
+pub const SDL_WINDOW_VULKAN: SDL_WindowFlags = SDL_WindowFlags(0x10000000);
+/// window usable for Metal view
+pub const SDL_WINDOW_METAL: SDL_WindowFlags = SDL_WindowFlags(0x20000000);
+/// window with transparent buffer
+pub const SDL_WINDOW_TRANSPARENT: SDL_WindowFlags = SDL_WindowFlags(0x40000000);
+/// window should not be focusable
+pub const SDL_WINDOW_NOT_FOCUSABLE: SDL_WindowFlags =
+  SDL_WindowFlags(0x80000000);
+
+/// Used to indicate that you don't care what the window position is.
+pub const SDL_WINDOWPOS_UNDEFINED: c_int = 0x1FFF0000;
+/// Used to indicate that the window position should be centered.
+pub const SDL_WINDOWPOS_CENTERED: c_int = 0x2FFF0000;
+
+/// Property for [`SDL_CreateWindowWithProperties`]: the window title (string).
+pub const SDL_PROP_WINDOW_CREATE_TITLE_STRING: &[u8] =
+  b"SDL.window.create.title\0";
+/// Property for [`SDL_CreateWindowWithProperties`]: the window width (number).
+pub const SDL_PROP_WINDOW_CREATE_WIDTH_NUMBER: &[u8] =
+  b"SDL.window.create.width\0";
+/// Property for [`SDL_CreateWindowWithProperties`]: the window height
+/// (number).
+pub const SDL_PROP_WINDOW_CREATE_HEIGHT_NUMBER: &[u8] =
+  b"SDL.window.create.height\0";
+/// Property for [`SDL_CreateWindowWithProperties`]: the window x position
+/// (number, or `SDL_WINDOWPOS_*`).
+pub const SDL_PROP_WINDOW_CREATE_X_NUMBER: &[u8] = b"SDL.window.create.x\0";
+/// Property for [`SDL_CreateWindowWithProperties`]: the window y position
+/// (number, or `SDL_WINDOWPOS_*`).
+pub const SDL_PROP_WINDOW_CREATE_Y_NUMBER: &[u8] = b"SDL.window.create.y\0";
+/// Property for [`SDL_CreateWindowWithProperties`]: the window flags (number,
+/// an [`SDL_WindowFlags`] value).
+pub const SDL_PROP_WINDOW_CREATE_FLAGS_NUMBER: &[u8] =
+  b"SDL.window.create.flags\0";
+/// Property for [`SDL_CreateWindowWithProperties`]: if the window is
+/// resizable (boolean).
+pub const SDL_PROP_WINDOW_CREATE_RESIZABLE_BOOLEAN: &[u8] =
+  b"SDL.window.create.resizable\0";
+/// Property for [`SDL_CreateWindowWithProperties`]: if the window starts
+/// hidden (boolean).
+pub const SDL_PROP_WINDOW_CREATE_HIDDEN_BOOLEAN: &[u8] =
+  b"SDL.window.create.hidden\0";
+/// Property for [`SDL_CreateWindowWithProperties`]: if the window uses a high
+/// pixel density buffer (boolean).
+pub const SDL_PROP_WINDOW_CREATE_HIGH_PIXEL_DENSITY_BOOLEAN: &[u8] =
+  b"SDL.window.create.high_pixel_density\0";
+
+extern "C" {
+  /// Create a window with the specified dimensions and flags.
+  ///
+  /// Unlike SDL2, there's no position argument: the window is centered, or
+  /// you can use [`SDL_CreateWindowWithProperties`] to give a position.
+  ///
+  /// **Returns:** the window that was created or NULL on failure.
+  pub fn SDL_CreateWindow(
+    title: *const c_char, w: c_int, h: c_int, flags: SDL_WindowFlags,
+  ) -> *mut SDL_Window;
+
+  /// Create a window with the specified properties.
+  ///
+  /// See the `SDL_PROP_WINDOW_CREATE_*` constants.
+  ///
+  /// **Returns:** the window that was created or NULL on failure.
+  pub fn SDL_CreateWindowWithProperties(
+    props: SDL_PropertiesID,
+  ) -> *mut SDL_Window;
+
+  /// Destroy a window.
+  pub fn SDL_DestroyWindow(window: *mut SDL_Window);
+
+  /// Get the numeric ID of a window.
+  ///
+  /// **Returns:** the ID of the window on success or 0 on failure.
+  pub fn SDL_GetWindowID(window: *mut SDL_Window) -> SDL_WindowID;
+
+  /// Get a window from a stored ID.
+  ///
+  /// **Returns:** the window associated with `id` or NULL if it doesn't
+  /// exist.
+  pub fn SDL_GetWindowFromID(id: SDL_WindowID) -> *mut SDL_Window;
+
+  /// Get the properties associated with a window.
+  pub fn SDL_GetWindowProperties(window: *mut SDL_Window) -> SDL_PropertiesID;
+
+  /// Get the window flags.
+  pub fn SDL_GetWindowFlags(window: *mut SDL_Window) -> SDL_WindowFlags;
+
+  /// Get the size of a window's client area, in pixels.
+  pub fn SDL_GetWindowSizeInPixels(
+    window: *mut SDL_Window, w: *mut c_int, h: *mut c_int,
+  ) -> bool;
+}
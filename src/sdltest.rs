@@ -0,0 +1,439 @@
+//! Bindings to parts of SDL's test library (`SDL2_test`).
+//!
+//! This is the small static library that SDL's own test programs use. It has
+//! the common command line handling and window setup of those programs, a
+//! seeded fuzzer for generating test data, a surface comparison, and a tiny
+//! built-in debug font that can draw text with just a renderer.
+//!
+//! `SDL2_test` comes with SDL's development files (and the bundled build makes
+//! it too), but it's always a static library. It isn't covered by SDL's ABI
+//! promises, so the [`SDLTest_CommonState`] here matches the headers of the
+//! SDL2 version that the crate is for.
+
+use crate::{
+  audio::*, c_char, c_double, c_float, c_int, events::*, rect::*, renderer::*,
+  stdinc::*, surface::*, video::*,
+};
+
+// makes rustdoc link properly!
+#[allow(unused)]
+use crate::{error::*, *};
+
+/// [`SDLTest_CommonState::verbose`] flag: log info about the video setup.
+pub const VERBOSE_VIDEO: Uint32 = 0x00000001;
+/// [`SDLTest_CommonState::verbose`] flag: log the available display modes.
+pub const VERBOSE_MODES: Uint32 = 0x00000002;
+/// [`SDLTest_CommonState::verbose`] flag: log info about the renderers.
+pub const VERBOSE_RENDER: Uint32 = 0x00000004;
+/// [`SDLTest_CommonState::verbose`] flag: log the events that are handled.
+pub const VERBOSE_EVENT: Uint32 = 0x00000008;
+/// [`SDLTest_CommonState::verbose`] flag: log info about the audio setup.
+pub const VERBOSE_AUDIO: Uint32 = 0x00000010;
+/// [`SDLTest_CommonState::verbose`] flag: also log mouse motion events.
+pub const VERBOSE_MOTION: Uint32 = 0x00000020;
+
+/// The settings of a test program, as parsed from its command line.
+///
+/// Make this with [`SDLTest_CommonCreateState`], then adjust any fields
+/// before calling [`SDLTest_CommonInit`], which opens the windows (and
+/// renderers, and audio) that it describes.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+#[allow(missing_docs)]
+pub struct SDLTest_CommonState {
+  // SDL init flags
+  pub argv: *mut *mut c_char,
+  pub flags: Uint32,
+  pub verbose: Uint32,
+
+  // Video info
+  pub videodriver: *const c_char,
+  pub display: c_int,
+  pub window_title: *const c_char,
+  pub window_icon: *const c_char,
+  pub window_flags: Uint32,
+  pub flash_on_focus_loss: SDL_bool,
+  pub window_x: c_int,
+  pub window_y: c_int,
+  pub window_w: c_int,
+  pub window_h: c_int,
+  pub window_minW: c_int,
+  pub window_minH: c_int,
+  pub window_maxW: c_int,
+  pub window_maxH: c_int,
+  pub logical_w: c_int,
+  pub logical_h: c_int,
+  pub scale: c_float,
+  pub depth: c_int,
+  pub refresh_rate: c_int,
+  pub num_windows: c_int,
+  pub windows: *mut *mut SDL_Window,
+
+  // Renderer info
+  pub renderdriver: *const c_char,
+  pub render_flags: Uint32,
+  pub skip_renderer: SDL_bool,
+  pub renderers: *mut *mut SDL_Renderer,
+  pub targets: *mut *mut SDL_Texture,
+
+  // Audio info
+  pub audiodriver: *const c_char,
+  pub audiospec: SDL_AudioSpec,
+
+  // GL settings
+  pub gl_red_size: c_int,
+  pub gl_green_size: c_int,
+  pub gl_blue_size: c_int,
+  pub gl_alpha_size: c_int,
+  pub gl_buffer_size: c_int,
+  pub gl_depth_size: c_int,
+  pub gl_stencil_size: c_int,
+  pub gl_double_buffer: c_int,
+  pub gl_accum_red_size: c_int,
+  pub gl_accum_green_size: c_int,
+  pub gl_accum_blue_size: c_int,
+  pub gl_accum_alpha_size: c_int,
+  pub gl_stereo: c_int,
+  pub gl_multisamplebuffers: c_int,
+  pub gl_multisamplesamples: c_int,
+  pub gl_retained_backing: c_int,
+  pub gl_accelerated: c_int,
+  pub gl_major_version: c_int,
+  pub gl_minor_version: c_int,
+  pub gl_debug: c_int,
+  pub gl_profile_mask: c_int,
+
+  // Additional fields added in 2.0.18
+  pub confine: SDL_Rect,
+}
+
+/// The width and height (in pixels) of one character of the debug font.
+pub const FONT_CHARACTER_SIZE: c_int = 8;
+/// The line height (in pixels) used by [`SDLTest_TextWindow`].
+pub const FONT_LINE_HEIGHT: c_int = FONT_CHARACTER_SIZE + 2;
+
+/// A scrolling window of text, drawn with the debug font.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+#[allow(missing_docs)]
+pub struct SDLTest_TextWindow {
+  pub rect: SDL_Rect,
+  pub current: c_int,
+  pub numlines: c_int,
+  pub lines: *mut *mut c_char,
+}
+
+// The sizes that a C compiler gives these on 64-bit targets.
+#[cfg(target_pointer_width = "64")]
+const _: () = {
+  use core::mem::size_of;
+  assert!(size_of::<SDLTest_CommonState>() == 296);
+  assert!(size_of::<SDLTest_TextWindow>() == 32);
+};
+
+extern "C" {
+  /// Parse the `SDL_Init` flags and create a common state.
+  ///
+  /// * `argv` The program's null-terminated argument list.
+  /// * `flags` The [`SDL_InitFlags`] of the subsystems the program uses
+  ///   (as a plain `Uint32`).
+  ///
+  /// **Returns:** the new state, or NULL on an allocation failure.
+  pub fn SDLTest_CommonCreateState(
+    argv: *mut *mut c_char, flags: Uint32,
+  ) -> *mut SDLTest_CommonState;
+
+  /// Process one common argument.
+  ///
+  /// * `state` The common state describing the test window to create.
+  /// * `index` The index of the argument to process in `argv`.
+  ///
+  /// **Returns:** the number of arguments processed (ie: 1 for `--fullscreen`
+  /// or 2 for `--video x11`), or -1 if the argument wasn't recognized.
+  pub fn SDLTest_CommonArg(
+    state: *mut SDLTest_CommonState, index: c_int,
+  ) -> c_int;
+
+  /// Logs the usage of the program, and all of the common options.
+  ///
+  /// * `state` The common state.
+  /// * `argv0` The name of the program (ie: `argv[0]`).
+  /// * `options` A NULL-terminated list of the program's own options, or
+  ///   NULL.
+  pub fn SDLTest_CommonLogUsage(
+    state: *mut SDLTest_CommonState, argv0: *const c_char,
+    options: *mut *const c_char,
+  );
+
+  /// Returns the common usage information.
+  ///
+  /// You should (probably) be using [`SDLTest_CommonLogUsage`] instead, but
+  /// this function remains for binary compatibility. Strings returned from
+  /// this function are valid until [`SDLTest_CommonQuit`] is called, in which
+  /// case those strings' memory is freed and can no longer be used.
+  pub fn SDLTest_CommonUsage(state: *mut SDLTest_CommonState) -> *const c_char;
+
+  /// Open the test windows (and renderers, and audio) of the common state.
+  ///
+  /// **Returns:** [`SDL_TRUE`] if the initialization succeeded, or
+  /// [`SDL_FALSE`] otherwise (call [`SDL_GetErrorMsg`] for more information).
+  pub fn SDLTest_CommonInit(state: *mut SDLTest_CommonState) -> SDL_bool;
+
+  /// Easy argument handling when the program doesn't have options of its own.
+  ///
+  /// This parses every argument with [`SDLTest_CommonArg`], and logs the
+  /// usage if any of them aren't recognized.
+  ///
+  /// * `state` The common state.
+  /// * `argc` The number of arguments in `argv`.
+  /// * `argv` The program's argument list.
+  ///
+  /// **Returns:** [`SDL_FALSE`] if the program should exit (for a bad
+  /// argument), otherwise [`SDL_TRUE`].
+  pub fn SDLTest_CommonDefaultArgs(
+    state: *mut SDLTest_CommonState, argc: c_int, argv: *mut *mut c_char,
+  ) -> SDL_bool;
+
+  /// Handle the common events (window close, the debug hotkeys, etc).
+  ///
+  /// * `state` The common state.
+  /// * `event` The event to handle.
+  /// * `done` Set to 1 if the program should quit.
+  pub fn SDLTest_CommonEvent(
+    state: *mut SDLTest_CommonState, event: *mut SDL_Event, done: *mut c_int,
+  );
+
+  /// Close the test windows, shut down SDL, and free the common state.
+  pub fn SDLTest_CommonQuit(state: *mut SDLTest_CommonState);
+
+  /// Draws various window information (position, size, etc) to the
+  /// renderer.
+  ///
+  /// * `renderer` The renderer to draw to.
+  /// * `window` The window whose information should be displayed.
+  /// * `usedHeight` Gets the height (in pixels) of the text that was drawn.
+  pub fn SDLTest_CommonDrawWindowInfo(
+    renderer: *mut SDL_Renderer, window: *mut SDL_Window,
+    usedHeight: *mut c_int,
+  );
+}
+
+extern "C" {
+  /// Initializes the fuzzer for a test.
+  ///
+  /// The fuzzer keeps its random state in a global, so it isn't thread safe.
+  ///
+  /// * `execKey` Execution "key" that seeds the random number generator
+  ///   uniquely for the test.
+  pub fn SDLTest_FuzzerInit(execKey: Uint64);
+
+  /// Returns a random `Uint8`.
+  pub fn SDLTest_RandomUint8() -> Uint8;
+  /// Returns a random `Sint8`.
+  pub fn SDLTest_RandomSint8() -> Sint8;
+  /// Returns a random `Uint16`.
+  pub fn SDLTest_RandomUint16() -> Uint16;
+  /// Returns a random `Sint16`.
+  pub fn SDLTest_RandomSint16() -> Sint16;
+  /// Returns a random `Sint32`.
+  pub fn SDLTest_RandomSint32() -> Sint32;
+  /// Returns a random positive `Uint32`.
+  pub fn SDLTest_RandomUint32() -> Uint32;
+  /// Returns a random `Uint64`.
+  pub fn SDLTest_RandomUint64() -> Uint64;
+  /// Returns a random `Sint64`.
+  pub fn SDLTest_RandomSint64() -> Sint64;
+
+  /// Returns a random float in the range `[0.0, 1.0]`.
+  pub fn SDLTest_RandomUnitFloat() -> c_float;
+  /// Returns a random double in the range `[0.0, 1.0]`.
+  pub fn SDLTest_RandomUnitDouble() -> c_double;
+  /// Returns a random float.
+  pub fn SDLTest_RandomFloat() -> c_float;
+  /// Returns a random double.
+  pub fn SDLTest_RandomDouble() -> c_double;
+
+  /// Returns a random boundary value for `Uint8` within the given boundaries.
+  ///
+  /// Boundaries are inclusive, and it's fine to give them in either order.
+  /// If `validDomain` is [`SDL_TRUE`], the result is one of `boundary1`,
+  /// `boundary2`, or the values right next to them (inside the range).
+  /// Otherwise it's a value just outside of the range (or 0 with an error
+  /// set, if the range covers the whole type).
+  ///
+  /// For example, `SDLTest_RandomUint8BoundaryValue(10, 20, SDL_TRUE)` gives
+  /// 10, 11, 19, or 20, and `(1, 20, SDL_FALSE)` gives 0 or 21.
+  pub fn SDLTest_RandomUint8BoundaryValue(
+    boundary1: Uint8, boundary2: Uint8, validDomain: SDL_bool,
+  ) -> Uint8;
+  /// Returns a random boundary value for `Uint16`, see
+  /// [`SDLTest_RandomUint8BoundaryValue`].
+  pub fn SDLTest_RandomUint16BoundaryValue(
+    boundary1: Uint16, boundary2: Uint16, validDomain: SDL_bool,
+  ) -> Uint16;
+  /// Returns a random boundary value for `Uint32`, see
+  /// [`SDLTest_RandomUint8BoundaryValue`].
+  pub fn SDLTest_RandomUint32BoundaryValue(
+    boundary1: Uint32, boundary2: Uint32, validDomain: SDL_bool,
+  ) -> Uint32;
+  /// Returns a random boundary value for `Uint64`, see
+  /// [`SDLTest_RandomUint8BoundaryValue`].
+  pub fn SDLTest_RandomUint64BoundaryValue(
+    boundary1: Uint64, boundary2: Uint64, validDomain: SDL_bool,
+  ) -> Uint64;
+  /// Returns a random boundary value for `Sint8`, see
+  /// [`SDLTest_RandomUint8BoundaryValue`].
+  pub fn SDLTest_RandomSint8BoundaryValue(
+    boundary1: Sint8, boundary2: Sint8, validDomain: SDL_bool,
+  ) -> Sint8;
+  /// Returns a random boundary value for `Sint16`, see
+  /// [`SDLTest_RandomUint8BoundaryValue`].
+  pub fn SDLTest_RandomSint16BoundaryValue(
+    boundary1: Sint16, boundary2: Sint16, validDomain: SDL_bool,
+  ) -> Sint16;
+  /// Returns a random boundary value for `Sint32`, see
+  /// [`SDLTest_RandomUint8BoundaryValue`].
+  pub fn SDLTest_RandomSint32BoundaryValue(
+    boundary1: Sint32, boundary2: Sint32, validDomain: SDL_bool,
+  ) -> Sint32;
+  /// Returns a random boundary value for `Sint64`, see
+  /// [`SDLTest_RandomUint8BoundaryValue`].
+  pub fn SDLTest_RandomSint64BoundaryValue(
+    boundary1: Sint64, boundary2: Sint64, validDomain: SDL_bool,
+  ) -> Sint64;
+
+  /// Returns an integer in the range `[min, max]` (inclusive).
+  ///
+  /// `min` and `max` may be given in either order.
+  pub fn SDLTest_RandomIntegerInRange(min: Sint32, max: Sint32) -> Sint32;
+
+  /// Generates a random string of printable ASCII characters, with a length
+  /// from 1 to 255.
+  ///
+  /// **Returns:** the new string (free it with [`SDL_free`]), or NULL if
+  /// something went wrong.
+  pub fn SDLTest_RandomAsciiString() -> *mut c_char;
+  /// Generates a random string of printable ASCII characters, with a length
+  /// from 1 to `maxLength`.
+  ///
+  /// **Returns:** the new string (free it with [`SDL_free`]), or NULL if
+  /// `maxLength` was less than 1 or something else went wrong.
+  pub fn SDLTest_RandomAsciiStringWithMaximumLength(
+    maxLength: c_int,
+  ) -> *mut c_char;
+  /// Generates a random string of printable ASCII characters, of exactly
+  /// `size` characters.
+  ///
+  /// **Returns:** the new string (free it with [`SDL_free`]), or NULL if
+  /// `size` was less than 1 or something else went wrong.
+  pub fn SDLTest_RandomAsciiStringOfSize(size: c_int) -> *mut c_char;
+
+  /// Get the number of fuzzer calls since the last [`SDLTest_FuzzerInit`].
+  pub fn SDLTest_GetFuzzerInvocationCount() -> c_int;
+}
+
+extern "C" {
+  /// Compares a surface and a reference surface for equality.
+  ///
+  /// * `surface` The surface to test.
+  /// * `referenceSurface` The reference surface to compare against.
+  /// * `allowable_error` How much each pixel's color is allowed to differ
+  ///   (as a squared distance in RGB space).
+  ///
+  /// **Returns:** 0 if the surfaces are equal, -1 on an error (such as the
+  /// surfaces being different sizes), or the number of pixels that were too
+  /// different.
+  pub fn SDLTest_CompareSurfaces(
+    surface: *mut SDL_Surface, referenceSurface: *mut SDL_Surface,
+    allowable_error: c_int,
+  ) -> c_int;
+}
+
+extern "C" {
+  /// Draw a single character with the debug font, using the renderer's
+  /// current draw color.
+  ///
+  /// * `renderer` The renderer to draw on.
+  /// * `x` The X coordinate of the upper left corner of the character.
+  /// * `y` The Y coordinate of the upper left corner of the character.
+  /// * `c` The character to draw (a Unicode code point).
+  ///
+  /// **Returns:** 0 on success, or -1 on failure.
+  pub fn SDLTest_DrawCharacter(
+    renderer: *mut SDL_Renderer, x: c_int, y: c_int, c: Uint32,
+  ) -> c_int;
+
+  /// Draw a UTF-8 string with the debug font, using the renderer's current
+  /// draw color.
+  ///
+  /// * `renderer` The renderer to draw on.
+  /// * `x` The X coordinate of the upper left corner of the string.
+  /// * `y` The Y coordinate of the upper left corner of the string.
+  /// * `s` The null-terminated string to draw.
+  ///
+  /// **Returns:** 0 on success, or -1 on failure.
+  pub fn SDLTest_DrawString(
+    renderer: *mut SDL_Renderer, x: c_int, y: c_int, s: *const c_char,
+  ) -> c_int;
+
+  /// Create a text window.
+  ///
+  /// * `x` The X coordinate of the text window.
+  /// * `y` The Y coordinate of the text window.
+  /// * `w` The width of the text window.
+  /// * `h` The height of the text window.
+  ///
+  /// **Returns:** the new window, or NULL on failure. Free it with
+  /// [`SDLTest_TextWindowDestroy`].
+  #[cfg(feature = "sdl2_2_24")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_24")))]
+  pub fn SDLTest_TextWindowCreate(
+    x: c_int, y: c_int, w: c_int, h: c_int,
+  ) -> *mut SDLTest_TextWindow;
+
+  /// Display a text window.
+  ///
+  /// This function should be called every frame to display the text.
+  #[cfg(feature = "sdl2_2_24")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_24")))]
+  pub fn SDLTest_TextWindowDisplay(
+    textwin: *mut SDLTest_TextWindow, renderer: *mut SDL_Renderer,
+  );
+
+  /// Add text to a text window.
+  ///
+  /// This is a printf-style format string. Adding `\n` starts a new line,
+  /// and `\b` erases the last character.
+  #[cfg(feature = "sdl2_2_24")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_24")))]
+  pub fn SDLTest_TextWindowAddText(
+    textwin: *mut SDLTest_TextWindow, fmt: *const c_char, ...
+  );
+
+  /// Add `len` bytes of text to a text window.
+  ///
+  /// The text doesn't need to be null-terminated. Like with
+  /// [`SDLTest_TextWindowAddText`], `\n` and `\b` are handled.
+  #[cfg(feature = "sdl2_2_24")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_24")))]
+  pub fn SDLTest_TextWindowAddTextWithLength(
+    textwin: *mut SDLTest_TextWindow, text: *const c_char, len: usize,
+  );
+
+  /// Clear the text in a text window.
+  #[cfg(feature = "sdl2_2_24")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_24")))]
+  pub fn SDLTest_TextWindowClear(textwin: *mut SDLTest_TextWindow);
+
+  /// Free the storage associated with a text window.
+  #[cfg(feature = "sdl2_2_24")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_24")))]
+  pub fn SDLTest_TextWindowDestroy(textwin: *mut SDLTest_TextWindow);
+
+  /// Cleanup the textures used by the debug font.
+  ///
+  /// Call this before destroying the renderers that the font was drawn with.
+  #[cfg(feature = "sdl2_2_24")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_24")))]
+  pub fn SDLTest_CleanupTextDrawing();
+}
@@ -32,6 +32,7 @@ pub struct SDL_SensorID(pub Sint32);
 /// Hare are the additional Android sensors:
 /// <https://developer.android.com/reference/android/hardware/SensorEvent.html#values>
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_SensorType(pub i32);
 
@@ -43,8 +44,18 @@ pub const SDL_SENSOR_UNKNOWN: SDL_SensorType = SDL_SensorType(0);
 pub const SDL_SENSOR_ACCEL: SDL_SensorType = SDL_SensorType(1);
 /// Gyroscope
 pub const SDL_SENSOR_GYRO: SDL_SensorType = SDL_SensorType(2);
+/// Accelerometer for left Joy-Con controller and Wii nunchuk
+pub const SDL_SENSOR_ACCEL_L: SDL_SensorType = SDL_SensorType(3);
+/// Gyroscope for left Joy-Con controller
+pub const SDL_SENSOR_GYRO_L: SDL_SensorType = SDL_SensorType(4);
+/// Accelerometer for right Joy-Con controller
+pub const SDL_SENSOR_ACCEL_R: SDL_SensorType = SDL_SensorType(5);
+/// Gyroscope for right Joy-Con controller
+pub const SDL_SENSOR_GYRO_R: SDL_SensorType = SDL_SensorType(6);
 
-/// Accelerometer sensor
+/// Standard gravity, in meters per second squared.
+///
+/// ## Accelerometer sensor
 ///
 /// The accelerometer returns the current acceleration in SI meters per
 /// second squared. This measurement includes the force of gravity, so
@@ -165,6 +176,25 @@ extern "C" {
     sensor: *mut SDL_Sensor, data: *mut c_float, num_values: c_int,
   ) -> c_int;
 
+  /// Get the current state of an opened sensor with the timestamp of the last
+  /// update.
+  ///
+  /// The number of values and interpretation of the data is sensor dependent.
+  ///
+  /// * `sensor` The sensor to query
+  /// * `timestamp` A pointer filled with the timestamp in microseconds of the
+  ///   current sensor reading if available, or 0 if not
+  /// * `data` A pointer filled with the current sensor state
+  /// * `num_values` The number of values to write to data
+  ///
+  /// **Returns:** 0 or -1 if an error occurred.
+  #[cfg(feature = "sdl2_2_26")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_26")))]
+  pub fn SDL_SensorGetDataWithTimestamp(
+    sensor: *mut SDL_Sensor, timestamp: *mut Uint64, data: *mut c_float,
+    num_values: c_int,
+  ) -> c_int;
+
   /// Close a sensor previously opened with [`SDL_SensorOpen`]
   pub fn SDL_SensorClose(sensor: *mut SDL_Sensor);
 
@@ -177,3 +207,51 @@ extern "C" {
   /// subsystem.
   pub fn SDL_SensorUpdate();
 }
+
+/// Lists the sensors attached to the system right now.
+///
+/// Each item is the sensor's instance ID, type, and name, for the sensors at
+/// device index 0 up to [`SDL_NumSensors`]. The index of an item is its device
+/// index, so use `enumerate` if you want to pass it to [`SDL_SensorOpen`].
+/// The list is read while holding [`SDL_LockSensors`], and the names are
+/// copied (any invalid UTF-8 is replaced with `U+FFFD`), so the result stays
+/// valid after the sensor list changes.
+///
+/// ```no_run
+/// # use fermium::prelude::*;
+/// unsafe {
+///   for (index, (_id, ty, _name)) in sensor_list().into_iter().enumerate() {
+///     if ty == SDL_SENSOR_GYRO {
+///       SDL_SensorOpen(index as _);
+///     }
+///   }
+/// }
+/// ```
+///
+/// ## Safety
+/// * The sensor subsystem must be initialized.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "alloc")))]
+pub unsafe fn sensor_list(
+) -> alloc::vec::Vec<(SDL_SensorID, SDL_SensorType, alloc::string::String)> {
+  SDL_LockSensors();
+  let list = (0..SDL_NumSensors().max(0))
+    .map(|i| {
+      let name = SDL_SensorGetDeviceName(i);
+      (
+        SDL_SensorGetDeviceInstanceID(i),
+        SDL_SensorGetDeviceType(i),
+        if name.is_null() {
+          alloc::string::String::new()
+        } else {
+          alloc::string::String::from_utf8_lossy(
+            core::ffi::CStr::from_ptr(name).to_bytes(),
+          )
+          .into_owned()
+        },
+      )
+    })
+    .collect();
+  SDL_UnlockSensors();
+  list
+}
@@ -0,0 +1,149 @@
+//! Shaped windows, which have a non-rectangular outline.
+//!
+//! Create the window with [`SDL_CreateShapedWindow`], then give it a shape
+//! with [`SDL_SetWindowShape`]. The shape is a surface, and the
+//! [`SDL_WindowShapeMode`] picks which of its pixels are part of the window
+//! (by alpha or by color key).
+
+use crate::{
+  c_char, c_int, c_uint, pixels::*, stdinc::*, surface::*, video::*,
+};
+
+// makes rustdoc link properly!
+#[allow(unused)]
+use crate::*;
+
+/// The window is not a shaped window.
+pub const SDL_NONSHAPEABLE_WINDOW: c_int = -1;
+/// The shape argument was invalid.
+pub const SDL_INVALID_SHAPE_ARGUMENT: c_int = -2;
+/// The shaped window doesn't have a shape yet.
+pub const SDL_WINDOW_LACKS_SHAPE: c_int = -3;
+
+/// The specific type of contents present in an [`SDL_WindowShapeParams`]
+/// union.
+///
+/// See the `ShapeMode*` constants.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct WindowShapeMode(pub i32);
+/// The default mode, a binarized alpha cutoff of 1.
+pub const ShapeModeDefault: WindowShapeMode = WindowShapeMode(0);
+/// A binarized alpha cutoff with a given integer value.
+pub const ShapeModeBinarizeAlpha: WindowShapeMode = WindowShapeMode(1);
+/// A binarized alpha cutoff with a given integer value, but with the opposite
+/// comparison.
+pub const ShapeModeReverseBinarizeAlpha: WindowShapeMode = WindowShapeMode(2);
+/// A color key is applied.
+pub const ShapeModeColorKey: WindowShapeMode = WindowShapeMode(3);
+
+/// If the mode uses the alpha channel (`binarizationCutoff`) rather than a
+/// color key.
+#[inline]
+#[must_use]
+pub const fn SDL_SHAPEMODEALPHA(mode: WindowShapeMode) -> bool {
+  mode.0 == ShapeModeDefault.0
+    || mode.0 == ShapeModeBinarizeAlpha.0
+    || mode.0 == ShapeModeReverseBinarizeAlpha.0
+}
+
+/// A union containing parameters for shaped windows.
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub union SDL_WindowShapeParams {
+  /// A cutoff alpha value for binarization of the window shape's alpha
+  /// channel.
+  pub binarizationCutoff: Uint8,
+  /// The color key, for [`ShapeModeColorKey`].
+  pub colorKey: SDL_Color,
+}
+impl Default for SDL_WindowShapeParams {
+  #[inline]
+  fn default() -> Self {
+    Self { colorKey: SDL_Color::default() }
+  }
+}
+impl core::fmt::Debug for SDL_WindowShapeParams {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    // Every field is plain bytes, so the color key view is always readable.
+    f.debug_struct("SDL_WindowShapeParams")
+      .field("colorKey", unsafe { &self.colorKey })
+      .finish()
+  }
+}
+
+/// Tags the [`SDL_WindowShapeParams`] union with the type of its contents.
+#[derive(Debug, Clone, Copy, Default)]
+#[repr(C)]
+pub struct SDL_WindowShapeMode {
+  /// The mode of these window-shape parameters.
+  pub mode: WindowShapeMode,
+  /// Window-shape parameters.
+  pub parameters: SDL_WindowShapeParams,
+}
+
+extern "C" {
+  /// Create a window that can be shaped with the specified position,
+  /// dimensions, and flags.
+  ///
+  /// * `title` The title of the window, in UTF-8 encoding.
+  /// * `x` The x position of the window, [`SDL_WINDOWPOS_CENTERED`], or
+  ///   [`SDL_WINDOWPOS_UNDEFINED`].
+  /// * `y` The y position of the window, [`SDL_WINDOWPOS_CENTERED`], or
+  ///   [`SDL_WINDOWPOS_UNDEFINED`].
+  /// * `w` The width of the window.
+  /// * `h` The height of the window.
+  /// * `flags` The flags for the window, any of [`SDL_WINDOW_OPENGL`],
+  ///   [`SDL_WINDOW_INPUT_GRABBED`], [`SDL_WINDOW_HIDDEN`],
+  ///   [`SDL_WINDOW_RESIZABLE`], [`SDL_WINDOW_MAXIMIZED`], or
+  ///   [`SDL_WINDOW_MINIMIZED`]. [`SDL_WINDOW_BORDERLESS`] is always set, and
+  ///   [`SDL_WINDOW_FULLSCREEN`] is always unset.
+  ///
+  /// **Returns:** the window created, or NULL if window creation failed.
+  ///
+  /// See Also: [`SDL_DestroyWindow`]
+  pub fn SDL_CreateShapedWindow(
+    title: *const c_char, x: c_uint, y: c_uint, w: c_uint, h: c_uint,
+    flags: Uint32,
+  ) -> *mut SDL_Window;
+
+  /// Return whether the given window is a shaped window.
+  ///
+  /// **Returns:** [`SDL_TRUE`] if the window is a window that can be shaped,
+  /// [`SDL_FALSE`] if the window is unshaped or NULL.
+  pub fn SDL_IsShapedWindow(window: *const SDL_Window) -> SDL_bool;
+
+  /// Set the shape and parameters of a shaped window.
+  ///
+  /// * `window` The shaped window whose parameters should be set.
+  /// * `shape` A surface encoding the desired shape for the window.
+  /// * `shape_mode` The parameters to set for the shaped window.
+  ///
+  /// **Returns:** 0 on success, [`SDL_INVALID_SHAPE_ARGUMENT`] on an invalid
+  /// shape argument, or [`SDL_NONSHAPEABLE_WINDOW`] if the window given does
+  /// not reference a valid shaped window.
+  ///
+  /// See Also: [`SDL_GetShapedWindowMode`]
+  pub fn SDL_SetWindowShape(
+    window: *mut SDL_Window, shape: *mut SDL_Surface,
+    shape_mode: *mut SDL_WindowShapeMode,
+  ) -> c_int;
+
+  /// Get the shape parameters of a shaped window.
+  ///
+  /// * `window` The shaped window whose parameters should be retrieved.
+  /// * `shape_mode` An empty shape-mode structure to fill, or NULL to check
+  ///   whether the window has a shape.
+  ///
+  /// **Returns:** 0 if the window has a shape and, provided `shape_mode` was
+  /// not NULL, `shape_mode` has been filled with the mode data,
+  /// [`SDL_NONSHAPEABLE_WINDOW`] if the window given is not a shaped window,
+  /// or [`SDL_WINDOW_LACKS_SHAPE`] if the window given is a shapeable window
+  /// currently lacking a shape.
+  ///
+  /// See Also: [`SDL_SetWindowShape`]
+  pub fn SDL_GetShapedWindowMode(
+    window: *mut SDL_Window, shape_mode: *mut SDL_WindowShapeMode,
+  ) -> c_int;
+}
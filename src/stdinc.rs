@@ -2,7 +2,7 @@
 
 //! The "standard include" which almost every other include tends to use.
 
-use crate::{c_int, c_void};
+use crate::{c_char, c_int, c_void};
 
 // Note(Lokathor): We want to be able to use these in the definitions for easier
 // interaction with the SDL headers and bindgen, but they're dumb to expose to
@@ -27,12 +27,32 @@ pub const fn SDL_FOURCC(a: u8, b: u8, c: u8, d: u8) -> u32 {
 }
 
 /// SDL's `bool` approximation.
+///
+/// This is a [`Bool32`](bool32::Bool32), which already converts to and from
+/// `bool` with `From`/`Into`. The [`SdlBoolExt`] trait adds a method for the
+/// spots where type inference can't pick the `Into` target.
 pub type SDL_bool = bool32::Bool32;
 /// `false` value
 pub const SDL_FALSE: SDL_bool = SDL_bool::new(false);
 /// `true` value
 pub const SDL_TRUE: SDL_bool = SDL_bool::new(true);
 
+/// Extra methods for [`SDL_bool`].
+///
+/// `SDL_bool` is a type from another crate, so these have to be in a trait.
+/// The trait is in the prelude.
+pub trait SdlBoolExt {
+  /// Converts to a Rust `bool`. Any non-zero value is `true`, just like C.
+  #[must_use]
+  fn into_bool(self) -> bool;
+}
+impl SdlBoolExt for SDL_bool {
+  #[inline]
+  fn into_bool(self) -> bool {
+    self != SDL_FALSE
+  }
+}
+
 extern "C" {
   /// Works like [`malloc`](https://man7.org/linux/man-pages/man3/malloc.3.html)
   pub fn SDL_malloc(size: usize) -> *mut c_void;
@@ -65,6 +85,70 @@ extern "C" {
   pub fn SDL_GetNumAllocations() -> c_int;
 }
 
+/// Records [`SDL_GetNumAllocations`] so that you can see how much it changes.
+///
+/// Make one of these before some code that you think leaks, then check
+/// [`delta`](Self::delta) afterwards. When the snapshot drops, the final delta
+/// is passed to the reporter function (if one was given), and with the
+/// `tracing` feature it's also recorded as a `debug` event.
+///
+/// The count includes everything allocated with `SDL_malloc` and friends
+/// (and freed with `SDL_free`) on any thread. Memory that SDL gets some other
+/// way (eg: from the system's graphics or audio libraries) isn't counted, so
+/// treat the numbers as hints.
+#[derive(Debug)]
+pub struct AllocSnapshot {
+  start: c_int,
+  report: Option<fn(c_int)>,
+}
+impl AllocSnapshot {
+  /// Records the current allocation count.
+  #[inline]
+  #[must_use]
+  pub fn new() -> Self {
+    Self { start: unsafe { SDL_GetNumAllocations() }, report: None }
+  }
+
+  /// Records the current allocation count, and gives the final delta to
+  /// `report` when the snapshot drops.
+  #[inline]
+  #[must_use]
+  pub fn with_reporter(report: fn(c_int)) -> Self {
+    Self { report: Some(report), ..Self::new() }
+  }
+
+  /// The allocation count when this snapshot was made.
+  #[inline]
+  #[must_use]
+  pub const fn start(&self) -> c_int {
+    self.start
+  }
+
+  /// The number of allocations made since the snapshot (minus the number
+  /// freed).
+  #[inline]
+  #[must_use]
+  pub fn delta(&self) -> c_int {
+    unsafe { SDL_GetNumAllocations() }.wrapping_sub(self.start)
+  }
+}
+impl Default for AllocSnapshot {
+  #[inline]
+  fn default() -> Self {
+    Self::new()
+  }
+}
+impl Drop for AllocSnapshot {
+  fn drop(&mut self) {
+    let delta = self.delta();
+    #[cfg(feature = "tracing")]
+    tracing::debug!(start = self.start, delta, "SDL allocation count changed");
+    if let Some(report) = self.report {
+      report(delta);
+    }
+  }
+}
+
 /// Works like [`malloc`](https://man7.org/linux/man-pages/man3/malloc.3.html)
 pub type SDL_malloc_func =
   Option<unsafe extern "C" fn(size: usize) -> *mut c_void>;
@@ -79,3 +163,20 @@ pub type SDL_realloc_func =
 
 /// Works like [`free`](https://man7.org/linux/man-pages/man3/free.3p.html)
 pub type SDL_free_func = Option<unsafe extern "C" fn(mem: *mut c_void)>;
+
+/// Passes `name` to `f` as a null terminated C string.
+///
+/// The copy is made on the stack, so this is only for short strings like key
+/// and button names. Anything too long, or with a null byte inside, gives
+/// `None` without calling `f`.
+pub(crate) fn with_short_c_str<T>(
+  name: &str, f: impl FnOnce(*const c_char) -> T,
+) -> Option<T> {
+  let mut buf = [0_u8; 64];
+  let bytes = name.as_bytes();
+  if bytes.len() >= buf.len() || bytes.contains(&0) {
+    return None;
+  }
+  buf[..bytes.len()].copy_from_slice(bytes);
+  Some(f(buf.as_ptr().cast()))
+}
@@ -30,6 +30,152 @@ pub unsafe fn SDL_MUSTLOCK(s: *const SDL_Surface) -> bool {
 #[repr(transparent)]
 pub struct SDL_BlitMap(c_void);
 
+/// A checked view of the pixel data of an [`SDL_Surface`].
+///
+/// Each row of a surface is `pitch` bytes apart, which is often more than the
+/// `w * bytes_per_pixel` bytes of actual pixel data in the row. This type
+/// handles that for you: [`rows_mut`](Self::rows_mut) gives one slice per row
+/// that covers only the pixel bytes of that row, and
+/// [`as_flat_mut`](Self::as_flat_mut) gives the whole image as one slice when
+/// there's no padding between rows.
+///
+/// The view borrows the surface's pixel memory, so don't unlock, free, or
+/// otherwise touch the surface while a view exists.
+#[derive(Debug)]
+pub struct SurfacePixels<'a> {
+  data: &'a mut [u8],
+  pitch: usize,
+  row_len: usize,
+  height: usize,
+}
+impl<'a> SurfacePixels<'a> {
+  /// Makes a view of the surface's pixels.
+  ///
+  /// The surface must already be locked with [`SDL_LockSurface`] if
+  /// [`SDL_MUSTLOCK`] says it needs to be.
+  ///
+  /// **Returns:** `None` if the surface pointer is null, has no pixels, must
+  /// be locked but isn't, or has a `pitch` that's too small for its width.
+  ///
+  /// ## Safety
+  /// * `surface` must be null or point to a valid surface.
+  /// * The surface's pixels must stay valid and must not be accessed by
+  ///   anything else for all of `'a`.
+  #[must_use]
+  pub unsafe fn new(surface: *mut SDL_Surface) -> Option<Self> {
+    let s = surface.as_ref()?;
+    if s.pixels.is_null() || s.format.is_null() {
+      return None;
+    }
+    if SDL_MUSTLOCK(surface) && s.locked == 0 {
+      return None;
+    }
+    let width = usize::try_from(s.w).ok()?;
+    let height = usize::try_from(s.h).ok()?;
+    let pitch = usize::try_from(s.pitch).ok()?;
+    // bits rather than bytes, so that packed formats under 8bpp work too
+    let bits_per_pixel = usize::from((*s.format).BitsPerPixel);
+    let row_len = width.checked_mul(bits_per_pixel)?.checked_add(7)? / 8;
+    if pitch < row_len {
+      return None;
+    }
+    // the final row doesn't need to include any padding after the pixels
+    let len = match height {
+      0 => 0,
+      h => pitch.checked_mul(h - 1)?.checked_add(row_len)?,
+    };
+    let data = core::slice::from_raw_parts_mut(s.pixels.cast::<u8>(), len);
+    Some(Self { data, pitch, row_len, height })
+  }
+
+  /// The number of bytes between the start of one row and the next.
+  #[inline]
+  #[must_use]
+  pub const fn pitch(&self) -> usize {
+    self.pitch
+  }
+
+  /// The number of bytes of pixel data in each row.
+  #[inline]
+  #[must_use]
+  pub const fn row_len(&self) -> usize {
+    self.row_len
+  }
+
+  /// The number of rows.
+  #[inline]
+  #[must_use]
+  pub const fn height(&self) -> usize {
+    self.height
+  }
+
+  /// Gets row `y`, or `None` if it's out of bounds.
+  #[inline]
+  #[must_use]
+  pub fn row(&self, y: usize) -> Option<&[u8]> {
+    if y < self.height {
+      let start = y * self.pitch;
+      Some(&self.data[start..start + self.row_len])
+    } else {
+      None
+    }
+  }
+
+  /// Gets row `y` mutably, or `None` if it's out of bounds.
+  #[inline]
+  #[must_use]
+  pub fn row_mut(&mut self, y: usize) -> Option<&mut [u8]> {
+    if y < self.height {
+      let start = y * self.pitch;
+      Some(&mut self.data[start..start + self.row_len])
+    } else {
+      None
+    }
+  }
+
+  /// Iterates over the rows, top to bottom.
+  #[inline]
+  pub fn rows(&self) -> impl Iterator<Item = &[u8]> + '_ {
+    let row_len = self.row_len;
+    self.data.chunks(self.pitch.max(1)).map(move |row| &row[..row_len])
+  }
+
+  /// Iterates mutably over the rows, top to bottom.
+  #[inline]
+  pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [u8]> + '_ {
+    let row_len = self.row_len;
+    self.data.chunks_mut(self.pitch.max(1)).map(move |row| &mut row[..row_len])
+  }
+
+  /// Gets all the pixel data as one slice.
+  ///
+  /// **Returns:** `None` if there's padding between the rows (when `pitch`
+  /// isn't equal to the row length).
+  #[inline]
+  #[must_use]
+  pub fn as_flat(&self) -> Option<&[u8]> {
+    if self.pitch == self.row_len {
+      Some(self.data)
+    } else {
+      None
+    }
+  }
+
+  /// Gets all the pixel data as one mutable slice.
+  ///
+  /// **Returns:** `None` if there's padding between the rows (when `pitch`
+  /// isn't equal to the row length).
+  #[inline]
+  #[must_use]
+  pub fn as_flat_mut(&mut self) -> Option<&mut [u8]> {
+    if self.pitch == self.row_len {
+      Some(self.data)
+    } else {
+      None
+    }
+  }
+}
+
 /// Represents a bitmap image in CPU memory.
 ///
 /// Generally, you should not touch these fields yourself.
@@ -65,6 +211,7 @@ pub struct SDL_Surface {
 
 /// The formula used for converting between YUV and RGB.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_YUV_CONVERSION_MODE(pub u32);
 /// Full range JPEG
@@ -365,6 +512,23 @@ extern "C" {
     src_pitch: c_int, dst_format: Uint32, dst: *mut c_void, dst_pitch: c_int,
   ) -> c_int;
 
+  /// Premultiply the alpha on a block of pixels.
+  ///
+  /// This is safe to use with `src == dst`, but not for other overlapping
+  /// areas.
+  ///
+  /// This function is currently only implemented for
+  /// `SDL_PIXELFORMAT_ARGB8888`.
+  ///
+  /// **Return:** 0 on success, or a negative error code on failure (call
+  /// [`SDL_GetErrorMsg`] for more information).
+  #[cfg(feature = "sdl2_2_0_18")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_18")))]
+  pub fn SDL_PremultiplyAlpha(
+    width: c_int, height: c_int, src_format: Uint32, src: *const c_void,
+    src_pitch: c_int, dst_format: Uint32, dst: *mut c_void, dst_pitch: c_int,
+  ) -> c_int;
+
   /// Performs a fast fill of the given rectangle with `color`.
   ///
   /// If `rect` is NULL, the whole surface will be filled with `color`.
@@ -464,7 +628,18 @@ extern "C" {
     width: c_int, height: c_int,
   ) -> SDL_YUV_CONVERSION_MODE;
 
+  /// Perform a fast, low quality, stretch blit between two surfaces of the
+  /// same format.
+  ///
+  /// Please use [`SDL_BlitScaled`] instead.
+  pub fn SDL_SoftStretch(
+    src: *mut SDL_Surface, srcrect: *const SDL_Rect, dst: *mut SDL_Surface,
+    dstrect: *const SDL_Rect,
+  ) -> c_int;
+
   /// Perform bilinear scaling between two surfaces of the same format, 32BPP.
+  #[cfg(feature = "sdl2_2_0_16")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_16")))]
   pub fn SDL_SoftStretchLinear(
     src: *mut SDL_Surface, srcrect: *const SDL_Rect, dst: *mut SDL_Surface,
     dstrect: *const SDL_Rect,
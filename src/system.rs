@@ -0,0 +1,743 @@
+//! Platform specific functions.
+//!
+//! Most of these only exist on one platform, and they're only declared when
+//! building for that platform (the docs show all of them).
+
+// Each platform only uses some of these.
+#[cfg(feature = "renderer")]
+#[allow(unused_imports)]
+use crate::renderer::SDL_Renderer;
+#[allow(unused_imports)]
+use crate::{
+  c_char, c_int, c_uint, c_void, stdinc::*, video::*, SDL_main_func,
+};
+
+// makes rustdoc link properly!
+#[allow(unused)]
+use crate::{error::*, events::*};
+
+/// A pointer to the Java native interface (`JNIEnv*`) of the current thread.
+#[cfg(not(all(target_os = "android", feature = "jni")))]
+pub type SDL_AndroidJNIEnv = *mut c_void;
+/// A pointer to the Java native interface (`JNIEnv*`) of the current thread.
+#[cfg(all(target_os = "android", feature = "jni"))]
+pub type SDL_AndroidJNIEnv = *mut jni_sys::JNIEnv;
+
+/// A Java object reference (`jobject`).
+#[cfg(not(all(target_os = "android", feature = "jni")))]
+pub type SDL_AndroidJObject = *mut c_void;
+/// A Java object reference (`jobject`).
+#[cfg(all(target_os = "android", feature = "jni"))]
+pub type SDL_AndroidJObject = jni_sys::jobject;
+
+/// Bit for [`SDL_AndroidGetExternalStorageState`]: external storage can be
+/// read.
+#[cfg(any(target_os = "android", doc))]
+#[cfg_attr(docs_rs, doc(cfg(target_os = "android")))]
+pub const SDL_ANDROID_EXTERNAL_STORAGE_READ: c_int = 0x01;
+/// Bit for [`SDL_AndroidGetExternalStorageState`]: external storage can be
+/// written.
+#[cfg(any(target_os = "android", doc))]
+#[cfg_attr(docs_rs, doc(cfg(target_os = "android")))]
+pub const SDL_ANDROID_EXTERNAL_STORAGE_WRITE: c_int = 0x02;
+
+#[cfg(any(target_os = "android", doc))]
+extern "C" {
+  /// Get the Android Java Native Interface Environment of the current thread.
+  ///
+  /// This is the `JNIEnv` one needs to access the Java virtual machine from
+  /// native code, and is needed for many Android APIs to be usable from C.
+  ///
+  /// **Returns:** the `JNIEnv` to which the current thread is attached, or
+  /// null on error.
+  ///
+  /// See Also: [`SDL_AndroidGetActivity`]
+  #[cfg_attr(docs_rs, doc(cfg(target_os = "android")))]
+  pub fn SDL_AndroidGetJNIEnv() -> SDL_AndroidJNIEnv;
+
+  /// Retrieve the Java instance of the Android activity class.
+  ///
+  /// The `jobject` returned by the function is a local reference and must be
+  /// released by the caller. See the `PushLocalFrame()` and `PopLocalFrame()`
+  /// or `DeleteLocalRef()` functions of the Java native interface.
+  ///
+  /// **Returns:** the `jobject` representing the instance of the Activity
+  /// class of the Android application, or null on error.
+  ///
+  /// See Also: [`SDL_AndroidGetJNIEnv`]
+  #[cfg_attr(docs_rs, doc(cfg(target_os = "android")))]
+  pub fn SDL_AndroidGetActivity() -> SDL_AndroidJObject;
+
+  /// Query Android API level of the current device.
+  ///
+  /// (eg: API level 31 is Android 12, and API level 21 is Android 5.0)
+  #[cfg_attr(docs_rs, doc(cfg(target_os = "android")))]
+  pub fn SDL_GetAndroidSDKVersion() -> c_int;
+
+  /// Query if the application is running on Android TV.
+  #[cfg_attr(docs_rs, doc(cfg(target_os = "android")))]
+  pub fn SDL_IsAndroidTV() -> SDL_bool;
+
+  /// Query if the application is running on a Chromebook.
+  #[cfg_attr(docs_rs, doc(cfg(target_os = "android")))]
+  pub fn SDL_IsChromebook() -> SDL_bool;
+
+  /// Query if the application is running on a Samsung DeX docking station.
+  #[cfg_attr(docs_rs, doc(cfg(target_os = "android")))]
+  pub fn SDL_IsDeXMode() -> SDL_bool;
+
+  /// Trigger the Android system back button behavior.
+  #[cfg_attr(docs_rs, doc(cfg(target_os = "android")))]
+  pub fn SDL_AndroidBackButton();
+
+  /// Get the path used for internal storage for this application.
+  ///
+  /// This path is unique to your application and cannot be written to by
+  /// other applications. It's typically `/data/data/your.app.package/files`.
+  ///
+  /// **Returns:** the path, or null on failure (call [`SDL_GetErrorMsg`] for
+  /// more information).
+  #[cfg_attr(docs_rs, doc(cfg(target_os = "android")))]
+  pub fn SDL_AndroidGetInternalStoragePath() -> *const c_char;
+
+  /// Get the current state of external storage.
+  ///
+  /// **Returns:** a bitmask of [`SDL_ANDROID_EXTERNAL_STORAGE_READ`] and
+  /// [`SDL_ANDROID_EXTERNAL_STORAGE_WRITE`], or 0 if external storage is
+  /// currently unavailable or on failure (call [`SDL_GetErrorMsg`] for more
+  /// information).
+  ///
+  /// See Also: [`SDL_AndroidGetExternalStoragePath`]
+  #[cfg_attr(docs_rs, doc(cfg(target_os = "android")))]
+  pub fn SDL_AndroidGetExternalStorageState() -> c_int;
+
+  /// Get the path used for external storage for this application.
+  ///
+  /// This path is unique to your application, but is public and can be
+  /// written to by other applications. It's typically
+  /// `/storage/sdcard0/Android/data/your.app.package/files`.
+  ///
+  /// **Returns:** the path, or null on failure (call [`SDL_GetErrorMsg`] for
+  /// more information).
+  ///
+  /// See Also: [`SDL_AndroidGetExternalStorageState`]
+  #[cfg_attr(docs_rs, doc(cfg(target_os = "android")))]
+  pub fn SDL_AndroidGetExternalStoragePath() -> *const c_char;
+
+  /// Request permissions at runtime.
+  ///
+  /// This blocks the calling thread until the permission is granted or
+  /// denied.
+  ///
+  /// * `permission` The permission to request (eg:
+  ///   `android.permission.RECORD_AUDIO`).
+  ///
+  /// **Returns:** [`SDL_TRUE`] if the permission was granted.
+  #[cfg_attr(docs_rs, doc(cfg(target_os = "android")))]
+  pub fn SDL_AndroidRequestPermission(permission: *const c_char) -> SDL_bool;
+
+  /// Shows an Android toast notification (in the UI thread).
+  ///
+  /// * `message` text message to be shown
+  /// * `duration` 0=short, 1=long
+  /// * `gravity` where the notification should appear on the screen (an
+  ///   `android.view.Gravity` value), or -1 if you don't have a preference.
+  /// * `xoffset` set this parameter only when gravity >=0
+  /// * `yoffset` set this parameter only when gravity >=0
+  ///
+  /// **Returns:** 0 if success, -1 if any error occurs.
+  #[cfg(feature = "sdl2_2_0_16")]
+  #[cfg_attr(
+    docs_rs,
+    doc(cfg(all(target_os = "android", feature = "sdl2_2_0_16")))
+  )]
+  pub fn SDL_AndroidShowToast(
+    message: *const c_char, duration: c_int, gravity: c_int, xoffset: c_int,
+    yoffset: c_int,
+  ) -> c_int;
+
+  /// Send a user command to `SDLActivity`.
+  ///
+  /// Override `boolean onUnhandledMessage(Message msg)` to handle the
+  /// message.
+  ///
+  /// * `command` user command that must be greater or equal to 0x8000
+  /// * `param` user parameter
+  ///
+  /// **Returns:** 0 if success, -1 if any error occurs.
+  #[cfg(feature = "sdl2_2_0_22")]
+  #[cfg_attr(
+    docs_rs,
+    doc(cfg(all(target_os = "android", feature = "sdl2_2_0_22")))
+  )]
+  pub fn SDL_AndroidSendMessage(command: Uint32, param: c_int) -> c_int;
+}
+
+#[cfg(any(target_os = "ios", target_os = "tvos", doc))]
+extern "C" {
+  /// Use this function to set the animation callback on Apple iOS.
+  ///
+  /// The `callback` is called with `callbackParam` every `interval` frames.
+  /// For more information see SDL's
+  /// [README-ios](https://github.com/libsdl-org/SDL/blob/SDL2/docs/README-ios.md).
+  ///
+  /// To use a Rust closure instead, see [`set_ios_animation_callback`].
+  ///
+  /// * `window` the window for which the animation callback should be set
+  /// * `interval` the number of frames after which `callback` will be called
+  /// * `callback` the function to call for every frame.
+  /// * `callbackParam` a pointer that is passed to `callback`.
+  ///
+  /// **Returns:** 0 on success or a negative error code on failure (call
+  /// [`SDL_GetErrorMsg`] for more information).
+  ///
+  /// See Also: [`SDL_iPhoneSetEventPump`]
+  #[cfg_attr(docs_rs, doc(cfg(any(target_os = "ios", target_os = "tvos"))))]
+  pub fn SDL_iPhoneSetAnimationCallback(
+    window: *mut SDL_Window, interval: c_int,
+    callback: Option<unsafe extern "C" fn(*mut c_void)>,
+    callbackParam: *mut c_void,
+  ) -> c_int;
+
+  /// Use this function to enable or disable the SDL event pump on Apple iOS.
+  ///
+  /// * `enabled` [`SDL_TRUE`] to enable the event pump, [`SDL_FALSE`] to
+  ///   disable it
+  ///
+  /// See Also: [`SDL_iPhoneSetAnimationCallback`]
+  #[cfg_attr(docs_rs, doc(cfg(any(target_os = "ios", target_os = "tvos"))))]
+  pub fn SDL_iPhoneSetEventPump(enabled: SDL_bool);
+
+  /// Notifies SDL that the status bar orientation changed, for an iOS
+  /// application delegate that's not SDL's own.
+  #[cfg_attr(docs_rs, doc(cfg(any(target_os = "ios", target_os = "tvos"))))]
+  pub fn SDL_OnApplicationDidChangeStatusBarOrientation();
+}
+
+#[cfg(any(target_os = "ios", target_os = "tvos", doc))]
+#[cfg_attr(docs_rs, doc(cfg(any(target_os = "ios", target_os = "tvos"))))]
+pub use self::{
+  SDL_iPhoneSetAnimationCallback as SDL_iOSSetAnimationCallback,
+  SDL_iPhoneSetEventPump as SDL_iOSSetEventPump,
+};
+
+/// Sets a closure as the per-frame animation callback of a window on iOS.
+///
+/// This is [`SDL_iPhoneSetAnimationCallback`] with the closure passed along
+/// through the `callbackParam` pointer. SDL keeps calling the closure until
+/// the callback is changed or the window is destroyed, so the closure must
+/// live for the rest of the program. An easy way to get that is to leak a
+/// `Box`:
+///
+/// ```ignore
+/// let frame = Box::leak(Box::new(move || {
+///   // update and draw one frame
+/// }));
+/// unsafe { set_ios_animation_callback(window, 1, frame) };
+/// ```
+///
+/// If the closure panics the program will abort, since the panic can't unwind
+/// back through SDL.
+///
+/// **Returns:** 0 on success or a negative error code on failure (call
+/// [`SDL_GetErrorMsg`] for more information).
+///
+/// ## Safety
+/// * The window must be valid.
+/// * The closure must not be set on more than one window, since SDL would
+///   then call it from each window's display link while it's already in use.
+#[cfg(any(target_os = "ios", target_os = "tvos", doc))]
+#[cfg_attr(docs_rs, doc(cfg(any(target_os = "ios", target_os = "tvos"))))]
+#[inline]
+pub unsafe fn set_ios_animation_callback<F: FnMut() + 'static>(
+  window: *mut SDL_Window, interval: c_int, callback: &'static mut F,
+) -> c_int {
+  unsafe extern "C" fn trampoline<F: FnMut()>(callbackParam: *mut c_void) {
+    let callback = &mut *callbackParam.cast::<F>();
+    crate::abort_on_unwind(
+      "the `set_ios_animation_callback` closure",
+      callback,
+    );
+  }
+  SDL_iPhoneSetAnimationCallback(
+    window,
+    interval,
+    Some(trampoline::<F>),
+    (callback as *mut F).cast::<c_void>(),
+  )
+}
+
+/// A callback that sees every Windows message, see
+/// [`SDL_SetWindowsMessageHook`].
+///
+/// * `userdata` what was passed as `userdata` to [`SDL_SetWindowsMessageHook`]
+/// * `hWnd` the `HWND` that the message is for
+/// * `message` the message type (eg: `WM_INPUT`)
+/// * `wParam` the message's `WPARAM`
+/// * `lParam` the message's `LPARAM`
+#[cfg(any(target_os = "windows", doc))]
+#[cfg_attr(docs_rs, doc(cfg(target_os = "windows")))]
+pub type SDL_WindowsMessageHook = Option<
+  unsafe extern "C" fn(
+    userdata: *mut c_void,
+    hWnd: *mut c_void,
+    message: c_uint,
+    wParam: Uint64,
+    lParam: Sint64,
+  ),
+>;
+
+#[cfg(any(target_os = "windows", doc))]
+extern "C" {
+  /// Set a callback for every Windows message, run before
+  /// `TranslateMessage()`.
+  ///
+  /// The hook only sees the message, it can't stop SDL from also handling it.
+  /// Pass `None` to remove the hook.
+  ///
+  /// * `callback` The hook function to call.
+  /// * `userdata` a pointer to pass to every call of `callback`
+  #[cfg_attr(docs_rs, doc(cfg(target_os = "windows")))]
+  pub fn SDL_SetWindowsMessageHook(
+    callback: SDL_WindowsMessageHook, userdata: *mut c_void,
+  );
+
+  /// Get the D3D9 adapter index that matches the specified display index.
+  ///
+  /// The returned adapter index can be passed to `IDirect3D9::CreateDevice`
+  /// and controls on which monitor a full screen application will appear.
+  ///
+  /// * `displayIndex` the display index for which to get the D3D9 adapter
+  ///   index
+  ///
+  /// **Returns:** the D3D9 adapter index on success or a negative error code
+  /// on failure (call [`SDL_GetErrorMsg`] for more information).
+  #[cfg_attr(docs_rs, doc(cfg(target_os = "windows")))]
+  pub fn SDL_Direct3D9GetAdapterIndex(displayIndex: c_int) -> c_int;
+
+  /// Get the DXGI Adapter and Output indices for the specified display index.
+  ///
+  /// The DXGI Adapter and Output indices can be passed to `EnumAdapters` and
+  /// `EnumOutputs` respectively to get the objects required to create a DX10
+  /// or DX11 device and swap chain.
+  ///
+  /// * `displayIndex` the display index for which to get both indices
+  /// * `adapterIndex` a pointer to be filled in with the adapter index
+  /// * `outputIndex` a pointer to be filled in with the output index
+  ///
+  /// **Returns:** [`SDL_TRUE`] on success or [`SDL_FALSE`] on failure (call
+  /// [`SDL_GetErrorMsg`] for more information).
+  #[cfg_attr(docs_rs, doc(cfg(target_os = "windows")))]
+  pub fn SDL_DXGIGetOutputInfo(
+    displayIndex: c_int, adapterIndex: *mut c_int, outputIndex: *mut c_int,
+  ) -> SDL_bool;
+}
+
+#[cfg(all(any(target_os = "windows", doc), feature = "renderer"))]
+extern "C" {
+  /// Get the D3D9 device associated with a renderer.
+  ///
+  /// SDL adds a reference to the device before returning it, so once you are
+  /// done using the device you should `Release` it to avoid a resource leak.
+  /// The `d3d9_device` helper (with the `windows_interop` feature) does
+  /// this for you.
+  ///
+  /// * `renderer` The renderer to query
+  ///
+  /// **Returns:** The device, or `NULL` if the renderer isn't a D3D9 renderer
+  /// (call [`SDL_GetErrorMsg`] for more information).
+  #[cfg_attr(
+    docs_rs,
+    doc(cfg(all(target_os = "windows", feature = "renderer")))
+  )]
+  pub fn SDL_RenderGetD3D9Device(
+    renderer: *mut SDL_Renderer,
+  ) -> *mut IDirect3DDevice9;
+
+  /// Get the D3D11 device associated with a renderer.
+  ///
+  /// SDL adds a reference to the device before returning it, so once you are
+  /// done using the device you should `Release` it to avoid a resource leak.
+  /// The `d3d11_device` helper (with the `windows_interop` feature) does
+  /// this for you.
+  ///
+  /// * `renderer` The renderer to query
+  ///
+  /// **Returns:** The device, or `NULL` if the renderer isn't a D3D11
+  /// renderer (call [`SDL_GetErrorMsg`] for more information).
+  #[cfg(feature = "sdl2_2_0_16")]
+  #[cfg_attr(
+    docs_rs,
+    doc(cfg(all(
+      target_os = "windows",
+      feature = "renderer",
+      feature = "sdl2_2_0_16"
+    )))
+  )]
+  pub fn SDL_RenderGetD3D11Device(
+    renderer: *mut SDL_Renderer,
+  ) -> *mut ID3D11Device;
+
+  /// Get the D3D12 device associated with a renderer.
+  ///
+  /// SDL adds a reference to the device before returning it, so once you are
+  /// done using the device you should `Release` it to avoid a resource leak.
+  /// The `d3d12_device` helper (with the `windows_interop` feature) does
+  /// this for you.
+  ///
+  /// * `renderer` The renderer to query
+  ///
+  /// **Returns:** The device, or `NULL` if the renderer isn't a D3D12
+  /// renderer (call [`SDL_GetErrorMsg`] for more information).
+  #[cfg(feature = "sdl2_2_24")]
+  #[cfg_attr(
+    docs_rs,
+    doc(cfg(all(
+      target_os = "windows",
+      feature = "renderer",
+      feature = "sdl2_2_24"
+    )))
+  )]
+  pub fn SDL_RenderGetD3D12Device(
+    renderer: *mut SDL_Renderer,
+  ) -> *mut ID3D12Device;
+}
+
+/// Direct3D 9 device (`IDirect3DDevice9`), as an opaque type.
+///
+/// See [`SDL_RenderGetD3D9Device`]
+#[cfg(all(any(target_os = "windows", doc), feature = "renderer"))]
+#[cfg_attr(docs_rs, doc(cfg(all(target_os = "windows", feature = "renderer"))))]
+#[repr(transparent)]
+pub struct IDirect3DDevice9(c_void);
+
+/// Direct3D 11 device (`ID3D11Device`), as an opaque type.
+///
+/// See [`SDL_RenderGetD3D11Device`]
+#[cfg(all(any(target_os = "windows", doc), feature = "renderer"))]
+#[cfg_attr(docs_rs, doc(cfg(all(target_os = "windows", feature = "renderer"))))]
+#[repr(transparent)]
+pub struct ID3D11Device(c_void);
+
+/// Direct3D 12 device (`ID3D12Device`), as an opaque type.
+///
+/// See [`SDL_RenderGetD3D12Device`]
+#[cfg(all(any(target_os = "windows", doc), feature = "renderer"))]
+#[cfg_attr(docs_rs, doc(cfg(all(target_os = "windows", feature = "renderer"))))]
+#[repr(transparent)]
+pub struct ID3D12Device(c_void);
+
+/// Gets the renderer's D3D9 device as a `windows` crate interface.
+///
+/// The interface owns the reference that SDL added, and releases it when
+/// dropped.
+///
+/// **Returns:** `None` if the renderer isn't a D3D9 renderer.
+///
+/// ## Safety
+/// * The renderer must be valid.
+#[cfg(all(target_os = "windows", feature = "windows_interop"))]
+#[cfg_attr(docs_rs, doc(cfg(feature = "windows_interop")))]
+#[inline]
+#[must_use]
+pub unsafe fn d3d9_device(
+  renderer: *mut SDL_Renderer,
+) -> Option<::windows::Win32::Graphics::Direct3D9::IDirect3DDevice9> {
+  use ::windows::core::Interface;
+  let device = SDL_RenderGetD3D9Device(renderer);
+  if device.is_null() {
+    None
+  } else {
+    Some(Interface::from_raw(device.cast()))
+  }
+}
+
+/// Gets the renderer's D3D11 device as a `windows` crate interface.
+///
+/// The interface owns the reference that SDL added, and releases it when
+/// dropped.
+///
+/// **Returns:** `None` if the renderer isn't a D3D11 renderer.
+///
+/// ## Safety
+/// * The renderer must be valid.
+#[cfg(all(
+  target_os = "windows",
+  feature = "windows_interop",
+  feature = "sdl2_2_0_16"
+))]
+#[cfg_attr(
+  docs_rs,
+  doc(cfg(all(feature = "windows_interop", feature = "sdl2_2_0_16")))
+)]
+#[inline]
+#[must_use]
+pub unsafe fn d3d11_device(
+  renderer: *mut SDL_Renderer,
+) -> Option<::windows::Win32::Graphics::Direct3D11::ID3D11Device> {
+  use ::windows::core::Interface;
+  let device = SDL_RenderGetD3D11Device(renderer);
+  if device.is_null() {
+    None
+  } else {
+    Some(Interface::from_raw(device.cast()))
+  }
+}
+
+/// Gets the renderer's D3D12 device as a `windows` crate interface.
+///
+/// The interface owns the reference that SDL added, and releases it when
+/// dropped.
+///
+/// **Returns:** `None` if the renderer isn't a D3D12 renderer.
+///
+/// ## Safety
+/// * The renderer must be valid.
+#[cfg(all(
+  target_os = "windows",
+  feature = "windows_interop",
+  feature = "sdl2_2_24"
+))]
+#[cfg_attr(
+  docs_rs,
+  doc(cfg(all(feature = "windows_interop", feature = "sdl2_2_24")))
+)]
+#[inline]
+#[must_use]
+pub unsafe fn d3d12_device(
+  renderer: *mut SDL_Renderer,
+) -> Option<::windows::Win32::Graphics::Direct3D12::ID3D12Device> {
+  use ::windows::core::Interface;
+  let device = SDL_RenderGetD3D12Device(renderer);
+  if device.is_null() {
+    None
+  } else {
+    Some(Interface::from_raw(device.cast()))
+  }
+}
+
+#[cfg(any(target_os = "linux", doc))]
+extern "C" {
+  /// Sets the UNIX nice value for a thread.
+  ///
+  /// This uses `setpriority()` if possible, and RealtimeKit if available.
+  ///
+  /// * `threadID` the Unix thread ID to change priority of.
+  /// * `priority` The new, Unix-specific, priority value.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  #[cfg_attr(docs_rs, doc(cfg(target_os = "linux")))]
+  pub fn SDL_LinuxSetThreadPriority(threadID: Sint64, priority: c_int)
+    -> c_int;
+
+  /// Sets the priority (not nice level) and scheduling policy for a thread.
+  ///
+  /// This uses `setpriority()` if possible, and RealtimeKit if available.
+  ///
+  /// * `threadID` The Unix thread ID to change priority of.
+  /// * `sdlPriority` The new `SDL_ThreadPriority` value: 0 (low), 1
+  ///   (normal), 2 (high), or 3 (time critical).
+  /// * `schedPolicy` The new scheduling policy (`SCHED_FIFO`, `SCHED_RR`,
+  ///   `SCHED_OTHER`, etc).
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  #[cfg(feature = "sdl2_2_0_18")]
+  #[cfg_attr(
+    docs_rs,
+    doc(cfg(all(target_os = "linux", feature = "sdl2_2_0_18")))
+  )]
+  pub fn SDL_LinuxSetThreadPriorityAndPolicy(
+    threadID: Sint64, sdlPriority: c_int, schedPolicy: c_int,
+  ) -> c_int;
+}
+
+/// The kinds of WinRT (UWP) path that [`SDL_WinRTGetFSPathUTF8`] can look up.
+///
+/// Not all paths are available on all versions of Windows, especially on
+/// Windows Phone.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+#[cfg(any(all(target_os = "windows", target_vendor = "uwp"), doc))]
+#[cfg_attr(
+  docs_rs,
+  doc(cfg(all(target_os = "windows", target_vendor = "uwp")))
+)]
+pub struct SDL_WinRT_Path(pub i32);
+/// The installed app's root directory. Files here are likely to be
+/// read-only.
+#[cfg(any(all(target_os = "windows", target_vendor = "uwp"), doc))]
+#[cfg_attr(
+  docs_rs,
+  doc(cfg(all(target_os = "windows", target_vendor = "uwp")))
+)]
+pub const SDL_WINRT_PATH_INSTALLED_LOCATION: SDL_WinRT_Path = SDL_WinRT_Path(0);
+/// The app's local data store. Files may be written here.
+#[cfg(any(all(target_os = "windows", target_vendor = "uwp"), doc))]
+#[cfg_attr(
+  docs_rs,
+  doc(cfg(all(target_os = "windows", target_vendor = "uwp")))
+)]
+pub const SDL_WINRT_PATH_LOCAL_FOLDER: SDL_WinRT_Path = SDL_WinRT_Path(1);
+/// The app's roaming data store. Files written here may be copied to other
+/// machines via a network connection. Unsupported on Windows Phone.
+#[cfg(any(all(target_os = "windows", target_vendor = "uwp"), doc))]
+#[cfg_attr(
+  docs_rs,
+  doc(cfg(all(target_os = "windows", target_vendor = "uwp")))
+)]
+pub const SDL_WINRT_PATH_ROAMING_FOLDER: SDL_WinRT_Path = SDL_WinRT_Path(2);
+/// The app's temporary data store. Files written here may be deleted at any
+/// time. Unsupported on Windows Phone.
+#[cfg(any(all(target_os = "windows", target_vendor = "uwp"), doc))]
+#[cfg_attr(
+  docs_rs,
+  doc(cfg(all(target_os = "windows", target_vendor = "uwp")))
+)]
+pub const SDL_WINRT_PATH_TEMP_FOLDER: SDL_WinRT_Path = SDL_WinRT_Path(3);
+
+/// The WinRT device family, from [`SDL_WinRTGetDeviceFamily`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+#[cfg(any(all(target_os = "windows", target_vendor = "uwp"), doc))]
+#[cfg_attr(
+  docs_rs,
+  doc(cfg(all(target_os = "windows", target_vendor = "uwp")))
+)]
+pub struct SDL_WinRT_DeviceFamily(pub i32);
+/// Unknown family.
+#[cfg(any(all(target_os = "windows", target_vendor = "uwp"), doc))]
+#[cfg_attr(
+  docs_rs,
+  doc(cfg(all(target_os = "windows", target_vendor = "uwp")))
+)]
+pub const SDL_WINRT_DEVICEFAMILY_UNKNOWN: SDL_WinRT_DeviceFamily =
+  SDL_WinRT_DeviceFamily(0);
+/// Desktop family.
+#[cfg(any(all(target_os = "windows", target_vendor = "uwp"), doc))]
+#[cfg_attr(
+  docs_rs,
+  doc(cfg(all(target_os = "windows", target_vendor = "uwp")))
+)]
+pub const SDL_WINRT_DEVICEFAMILY_DESKTOP: SDL_WinRT_DeviceFamily =
+  SDL_WinRT_DeviceFamily(1);
+/// Mobile family (eg: a smartphone).
+#[cfg(any(all(target_os = "windows", target_vendor = "uwp"), doc))]
+#[cfg_attr(
+  docs_rs,
+  doc(cfg(all(target_os = "windows", target_vendor = "uwp")))
+)]
+pub const SDL_WINRT_DEVICEFAMILY_MOBILE: SDL_WinRT_DeviceFamily =
+  SDL_WinRT_DeviceFamily(2);
+/// Xbox family.
+#[cfg(any(all(target_os = "windows", target_vendor = "uwp"), doc))]
+#[cfg_attr(
+  docs_rs,
+  doc(cfg(all(target_os = "windows", target_vendor = "uwp")))
+)]
+pub const SDL_WINRT_DEVICEFAMILY_XBOX: SDL_WinRT_DeviceFamily =
+  SDL_WinRT_DeviceFamily(3);
+
+#[cfg(any(all(target_os = "windows", target_vendor = "uwp"), doc))]
+extern "C" {
+  /// Retrieve a WinRT defined path on the local file system.
+  ///
+  /// Not all paths are available on all versions of Windows. This is
+  /// especially true on Windows Phone. Check the docs of the given
+  /// [`SDL_WinRT_Path`] value for where it's supported.
+  ///
+  /// * `pathType` the type of path to retrieve
+  ///
+  /// **Returns:** a UCS-2 string (16-bit, wide-char) containing the path, or
+  /// null if the path is not available for any reason (call
+  /// [`SDL_GetErrorMsg`] for more information).
+  ///
+  /// See Also: [`SDL_WinRTGetFSPathUTF8`]
+  #[cfg_attr(
+    docs_rs,
+    doc(cfg(all(target_os = "windows", target_vendor = "uwp")))
+  )]
+  pub fn SDL_WinRTGetFSPathUNICODE(pathType: SDL_WinRT_Path) -> *const u16;
+
+  /// Retrieve a WinRT defined path on the local file system.
+  ///
+  /// Not all paths are available on all versions of Windows. This is
+  /// especially true on Windows Phone. Check the docs of the given
+  /// [`SDL_WinRT_Path`] value for where it's supported.
+  ///
+  /// * `pathType` the type of path to retrieve
+  ///
+  /// **Returns:** a UTF-8 string containing the path, or null if the path is
+  /// not available for any reason (call [`SDL_GetErrorMsg`] for more
+  /// information).
+  ///
+  /// See Also: [`SDL_WinRTGetFSPathUNICODE`]
+  #[cfg_attr(
+    docs_rs,
+    doc(cfg(all(target_os = "windows", target_vendor = "uwp")))
+  )]
+  pub fn SDL_WinRTGetFSPathUTF8(pathType: SDL_WinRT_Path) -> *const c_char;
+
+  /// Detects the device family of WinRT platform at runtime.
+  #[cfg_attr(
+    docs_rs,
+    doc(cfg(all(target_os = "windows", target_vendor = "uwp")))
+  )]
+  pub fn SDL_WinRTGetDeviceFamily() -> SDL_WinRT_DeviceFamily;
+
+  /// Initialize and launch an SDL/WinRT application.
+  ///
+  /// XAML apps call this from their own entry point. Non-XAML apps instead
+  /// compile in SDL's `SDL_WinRT_main_NonXAML.cpp` (or a copy of it), which
+  /// calls this for them.
+  ///
+  /// * `mainFunction` the app's C-style `main`, which SDL calls once the
+  ///   `CoreApplication` is running.
+  /// * `reserved` reserved for future use, should be null.
+  ///
+  /// **Returns:** 0 on success or -1 on failure (call [`SDL_GetErrorMsg`] for
+  /// more information).
+  #[cfg_attr(
+    docs_rs,
+    doc(cfg(all(target_os = "windows", target_vendor = "uwp")))
+  )]
+  pub fn SDL_WinRTRunApp(
+    mainFunction: SDL_main_func, reserved: *mut c_void,
+  ) -> c_int;
+}
+
+extern "C" {
+  /// Notifies SDL that the application will terminate.
+  ///
+  /// The `SDL_OnApplication*` functions are for an application delegate that
+  /// isn't SDL's own (eg: when SDL is embedded in a larger iOS app) to tell
+  /// SDL about state changes. SDL then sends the matching events (eg:
+  /// [`SDL_APP_TERMINATING`]).
+  pub fn SDL_OnApplicationWillTerminate();
+
+  /// Notifies SDL that the application received a memory warning.
+  ///
+  /// SDL then sends [`SDL_APP_LOWMEMORY`].
+  pub fn SDL_OnApplicationDidReceiveMemoryWarning();
+
+  /// Notifies SDL that the application will resign being active.
+  ///
+  /// SDL then sends [`SDL_APP_WILLENTERBACKGROUND`].
+  pub fn SDL_OnApplicationWillResignActive();
+
+  /// Notifies SDL that the application entered the background.
+  ///
+  /// SDL then sends [`SDL_APP_DIDENTERBACKGROUND`].
+  pub fn SDL_OnApplicationDidEnterBackground();
+
+  /// Notifies SDL that the application will enter the foreground.
+  ///
+  /// SDL then sends [`SDL_APP_WILLENTERFOREGROUND`].
+  pub fn SDL_OnApplicationWillEnterForeground();
+
+  /// Notifies SDL that the application became active.
+  ///
+  /// SDL then sends [`SDL_APP_DIDENTERFOREGROUND`].
+  pub fn SDL_OnApplicationDidBecomeActive();
+}
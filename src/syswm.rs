@@ -3,12 +3,13 @@
 //! This is mostly needed to make support for the
 //! [`raw-window-handle`](https://docs.rs/raw-window-handle) crate possible.
 
-use crate::{c_uint, c_ulong, c_void, stdinc::*, version::*, video::*};
+use crate::{c_int, c_uint, c_ulong, c_void, stdinc::*, version::*, video::*};
 
 /// These are the various supported windowing subsystems.
 ///
 /// See `SDL_SYSWM_*` constants
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_SYSWM_TYPE(pub i32);
 
@@ -38,6 +39,10 @@ pub const SDL_SYSWM_VIVANTE: SDL_SYSWM_TYPE = SDL_SYSWM_TYPE(10);
 pub const SDL_SYSWM_OS2: SDL_SYSWM_TYPE = SDL_SYSWM_TYPE(11);
 #[allow(missing_docs)]
 pub const SDL_SYSWM_HAIKU: SDL_SYSWM_TYPE = SDL_SYSWM_TYPE(12);
+#[allow(missing_docs)]
+pub const SDL_SYSWM_KMSDRM: SDL_SYSWM_TYPE = SDL_SYSWM_TYPE(13);
+#[allow(missing_docs)]
+pub const SDL_SYSWM_RISCOS: SDL_SYSWM_TYPE = SDL_SYSWM_TYPE(14);
 
 #[derive(Clone, Copy)]
 #[repr(C)]
@@ -114,8 +119,19 @@ pub struct SDL_SysWMinfo_wayland {
   pub display: *mut c_void,
   /// Wayland surface (`wl_surface*`)
   pub surface: *mut c_void,
-  /// Wayland shell_surface (window manager handle) (`wl_shell_surface*`)
+  /// DEPRECATED Wayland shell_surface (window manager handle)
+  /// (`wl_shell_surface*`)
   pub shell_surface: *mut c_void,
+  /// Wayland EGL window (native window) (`wl_egl_window*`)
+  pub egl_window: *mut c_void,
+  /// Wayland xdg surface (window manager handle) (`xdg_surface*`)
+  pub xdg_surface: *mut c_void,
+  /// Wayland xdg toplevel role (`xdg_toplevel*`)
+  pub xdg_toplevel: *mut c_void,
+  /// Wayland xdg popup role (`xdg_popup*`)
+  pub xdg_popup: *mut c_void,
+  /// Wayland xdg positioner, for popup (`xdg_positioner*`)
+  pub xdg_positioner: *mut c_void,
 }
 
 #[derive(Clone, Copy)]
@@ -138,6 +154,23 @@ pub struct SDL_SysWMinfo_vivante {
   pub window: *mut c_void,
 }
 
+#[derive(Clone, Copy)]
+#[repr(C)]
+#[allow(missing_docs)]
+pub struct SDL_SysWMinfo_kmsdrm {
+  /// Device index (ex: the X in `/dev/dri/cardX`)
+  pub dev_index: c_int,
+  /// DRM FD (unavailable on Vulkan windows)
+  pub drm_fd: c_int,
+  /// GBM device (unavailable on Vulkan windows) (`gbm_device*`)
+  pub gbm_dev: *mut c_void,
+}
+#[cfg(target_pointer_width = "64")]
+const _: () = {
+  use core::mem::size_of;
+  assert!(size_of::<SDL_SysWMinfo_kmsdrm>() == 16);
+};
+
 #[derive(Clone, Copy)]
 #[repr(C)]
 #[allow(missing_docs)]
@@ -153,10 +186,14 @@ pub union SDL_SysWMinfo_union {
   pub android: SDL_SysWMinfo_android,
   pub vivante: SDL_SysWMinfo_vivante,
   /* os2 support skipped, file an issue if you care. */
+  pub kmsdrm: SDL_SysWMinfo_kmsdrm,
   /// Dummy field to ensure that the union is always at least 64 bytes.
   pub dummy: [u8; 64],
 }
 
+// Same as the C header, every platform's info must fit in the 64 bytes.
+const _: () = assert!(core::mem::size_of::<SDL_SysWMinfo_union>() == 64);
+
 /// The custom window manager information structure.
 ///
 /// When this structure is returned, it holds information about which low level
@@ -176,6 +213,28 @@ impl Default for SDL_SysWMinfo {
     unsafe { core::mem::zeroed() }
   }
 }
+impl SDL_SysWMinfo {
+  /// Gets the info for a window.
+  ///
+  /// This fills in the `version` field before calling [`SDL_GetWindowWMInfo`],
+  /// which is the step that's easy to forget.
+  ///
+  /// **Returns:** The info, or `None` if SDL couldn't provide it.
+  ///
+  /// ## Safety
+  /// * The window must be valid.
+  #[inline]
+  #[must_use]
+  pub unsafe fn get(window: *mut SDL_Window) -> Option<Self> {
+    let mut info = Self::default();
+    SDL_VERSION(&mut info.version);
+    if SDL_GetWindowWMInfo(window, &mut info).into_bool() {
+      Some(info)
+    } else {
+      None
+    }
+  }
+}
 
 #[cfg(feature = "raw-window-handle")]
 #[cfg_attr(docs_rs, doc(cfg(feature = "raw-window-handle")))]
@@ -185,6 +244,8 @@ impl SDL_SysWMinfo {
   /// ## Safety
   /// * The `subsystem` must be correct for the `info` data, or bad things
   ///   happen.
+  ///
+  /// [RawWindowHandle]: raw_window_handle::RawWindowHandle
   pub unsafe fn try_into(self) -> Option<raw_window_handle::RawWindowHandle> {
     use raw_window_handle::*;
     Some(match self.subsystem {
@@ -228,10 +289,93 @@ impl SDL_SysWMinfo {
       }
       /* I don't think RWH supports any of these? */
       SDL_SYSWM_MIR | SDL_SYSWM_VIVANTE | SDL_SYSWM_OS2 | SDL_SYSWM_HAIKU
-      | SDL_SYSWM_DIRECTFB => return None,
+      | SDL_SYSWM_DIRECTFB | SDL_SYSWM_KMSDRM | SDL_SYSWM_RISCOS => {
+        return None
+      }
       _ => return /*We shouldn't be in this case! Oh well!*/ None,
     })
   }
+
+  /// Attempts to convert the info into the correct [RawDisplayHandle] value
+  ///
+  /// ## Safety
+  /// * The `subsystem` must be correct for the `info` data, or bad things
+  ///   happen.
+  ///
+  /// [RawDisplayHandle]: raw_window_handle::RawDisplayHandle
+  pub unsafe fn try_into_display(
+    self,
+  ) -> Option<raw_window_handle::RawDisplayHandle> {
+    use raw_window_handle::*;
+    Some(match self.subsystem {
+      SDL_SYSWM_WINDOWS | SDL_SYSWM_WINRT => {
+        RawDisplayHandle::Windows(WindowsDisplayHandle::empty())
+      }
+      SDL_SYSWM_X11 => {
+        // SDL doesn't tell us the screen, so this leaves it as screen 0.
+        let mut xlib_handle = XlibDisplayHandle::empty();
+        xlib_handle.display = self.info.x11.display;
+        RawDisplayHandle::Xlib(xlib_handle)
+      }
+      SDL_SYSWM_COCOA => RawDisplayHandle::AppKit(AppKitDisplayHandle::empty()),
+      SDL_SYSWM_UIKIT => RawDisplayHandle::UiKit(UiKitDisplayHandle::empty()),
+      SDL_SYSWM_WAYLAND => {
+        let mut wayland_handle = WaylandDisplayHandle::empty();
+        wayland_handle.display = self.info.wl.display;
+        RawDisplayHandle::Wayland(wayland_handle)
+      }
+      SDL_SYSWM_ANDROID => {
+        RawDisplayHandle::Android(AndroidDisplayHandle::empty())
+      }
+      SDL_SYSWM_KMSDRM if self.info.kmsdrm.drm_fd >= 0 => {
+        let mut drm_handle = DrmDisplayHandle::empty();
+        drm_handle.fd = self.info.kmsdrm.drm_fd;
+        RawDisplayHandle::Drm(drm_handle)
+      }
+      _ => return None,
+    })
+  }
+}
+
+/// Gets the [RawWindowHandle] of a window.
+///
+/// This is the handle that graphics crates such as `wgpu`, `ash`, and
+/// `softbuffer` want, along with [`get_raw_display_handle`].
+///
+/// **Returns:** `None` if SDL can't provide the window info, or if the window
+/// system isn't one that `raw-window-handle` supports.
+///
+/// ## Safety
+/// * The window must be valid.
+///
+/// [RawWindowHandle]: raw_window_handle::RawWindowHandle
+#[cfg(feature = "raw-window-handle")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "raw-window-handle")))]
+#[inline]
+#[must_use]
+pub unsafe fn get_raw_window_handle(
+  window: *mut SDL_Window,
+) -> Option<raw_window_handle::RawWindowHandle> {
+  SDL_SysWMinfo::get(window)?.try_into()
+}
+
+/// Gets the [RawDisplayHandle] of the display connection that a window uses.
+///
+/// **Returns:** `None` if SDL can't provide the window info, or if the window
+/// system isn't one that `raw-window-handle` supports.
+///
+/// ## Safety
+/// * The window must be valid.
+///
+/// [RawDisplayHandle]: raw_window_handle::RawDisplayHandle
+#[cfg(feature = "raw-window-handle")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "raw-window-handle")))]
+#[inline]
+#[must_use]
+pub unsafe fn get_raw_display_handle(
+  window: *mut SDL_Window,
+) -> Option<raw_window_handle::RawDisplayHandle> {
+  SDL_SysWMinfo::get(window)?.try_into_display()
 }
 
 extern "C" {
@@ -250,7 +394,7 @@ extern "C" {
   /// let window = unimplemented!("make the window");
   /// let mut info = SDL_SysWMinfo::default();
   /// SDL_VERSION(&mut info.version);
-  /// if SDL_TRUE == unsafe { SDL_GetWindowWMInfo(window, &mut info) } {
+  /// if unsafe { SDL_GetWindowWMInfo(window, &mut info) }.into_bool() {
   ///   unimplemented!("now you have your info");
   /// }
   /// ```
@@ -258,3 +402,153 @@ extern "C" {
     window: *mut SDL_Window, info: *mut SDL_SysWMinfo,
   ) -> SDL_bool;
 }
+
+/// Gets the info for a window, only if it uses the given subsystem.
+#[allow(dead_code)]
+unsafe fn wm_info_for(
+  window: *mut SDL_Window, subsystem: SDL_SYSWM_TYPE,
+) -> Option<SDL_SysWMinfo_union> {
+  SDL_SysWMinfo::get(window)
+    .filter(|info| info.subsystem == subsystem)
+    .map(|info| info.info)
+}
+
+/// The window's `HWND`.
+///
+/// **Returns:** `None` if the window info isn't available.
+///
+/// ## Safety
+/// * The window must be valid.
+#[cfg(any(target_os = "windows", doc))]
+#[cfg_attr(docs_rs, doc(cfg(target_os = "windows")))]
+#[inline]
+#[must_use]
+pub unsafe fn hwnd(window: *mut SDL_Window) -> Option<*mut c_void> {
+  wm_info_for(window, SDL_SYSWM_WINDOWS).map(|info| info.win.window)
+}
+
+/// The window's device context (`HDC`).
+///
+/// **Returns:** `None` if the window info isn't available.
+///
+/// ## Safety
+/// * The window must be valid.
+#[cfg(any(target_os = "windows", doc))]
+#[cfg_attr(docs_rs, doc(cfg(target_os = "windows")))]
+#[inline]
+#[must_use]
+pub unsafe fn hdc(window: *mut SDL_Window) -> Option<*mut c_void> {
+  wm_info_for(window, SDL_SYSWM_WINDOWS).map(|info| info.win.hdc)
+}
+
+/// The `HINSTANCE` that the window's class was registered with.
+///
+/// **Returns:** `None` if the window info isn't available.
+///
+/// ## Safety
+/// * The window must be valid.
+#[cfg(any(target_os = "windows", doc))]
+#[cfg_attr(docs_rs, doc(cfg(target_os = "windows")))]
+#[inline]
+#[must_use]
+pub unsafe fn hinstance(window: *mut SDL_Window) -> Option<*mut c_void> {
+  wm_info_for(window, SDL_SYSWM_WINDOWS).map(|info| info.win.hinstance)
+}
+
+/// The window's X11 `Window` id.
+///
+/// **Returns:** `None` if the window isn't using the X11 video driver.
+///
+/// ## Safety
+/// * The window must be valid.
+#[cfg(any(
+  target_os = "linux",
+  target_os = "freebsd",
+  target_os = "dragonfly",
+  target_os = "netbsd",
+  target_os = "openbsd",
+  doc
+))]
+#[cfg_attr(docs_rs, doc(cfg(unix)))]
+#[inline]
+#[must_use]
+pub unsafe fn x11_window(window: *mut SDL_Window) -> Option<c_ulong> {
+  wm_info_for(window, SDL_SYSWM_X11).map(|info| info.x11.window)
+}
+
+/// The X11 `Display*` that the window is on.
+///
+/// **Returns:** `None` if the window isn't using the X11 video driver.
+///
+/// ## Safety
+/// * The window must be valid.
+#[cfg(any(
+  target_os = "linux",
+  target_os = "freebsd",
+  target_os = "dragonfly",
+  target_os = "netbsd",
+  target_os = "openbsd",
+  doc
+))]
+#[cfg_attr(docs_rs, doc(cfg(unix)))]
+#[inline]
+#[must_use]
+pub unsafe fn x11_display(window: *mut SDL_Window) -> Option<*mut c_void> {
+  wm_info_for(window, SDL_SYSWM_X11).map(|info| info.x11.display)
+}
+
+/// The window's Wayland `wl_surface*`.
+///
+/// **Returns:** `None` if the window isn't using the Wayland video driver.
+///
+/// ## Safety
+/// * The window must be valid.
+#[cfg(any(
+  target_os = "linux",
+  target_os = "freebsd",
+  target_os = "dragonfly",
+  target_os = "netbsd",
+  target_os = "openbsd",
+  doc
+))]
+#[cfg_attr(docs_rs, doc(cfg(unix)))]
+#[inline]
+#[must_use]
+pub unsafe fn wayland_surface(window: *mut SDL_Window) -> Option<*mut c_void> {
+  wm_info_for(window, SDL_SYSWM_WAYLAND).map(|info| info.wl.surface)
+}
+
+/// The Wayland `wl_display*` that the window is on.
+///
+/// **Returns:** `None` if the window isn't using the Wayland video driver.
+///
+/// ## Safety
+/// * The window must be valid.
+#[cfg(any(
+  target_os = "linux",
+  target_os = "freebsd",
+  target_os = "dragonfly",
+  target_os = "netbsd",
+  target_os = "openbsd",
+  doc
+))]
+#[cfg_attr(docs_rs, doc(cfg(unix)))]
+#[inline]
+#[must_use]
+pub unsafe fn wayland_display(window: *mut SDL_Window) -> Option<*mut c_void> {
+  wm_info_for(window, SDL_SYSWM_WAYLAND).map(|info| info.wl.display)
+}
+
+/// The window's `NSWindow*`.
+///
+/// **Returns:** `None` if the window info isn't available.
+///
+/// ## Safety
+/// * The window must be valid.
+#[cfg(any(target_os = "macos", doc))]
+#[cfg_attr(docs_rs, doc(cfg(target_os = "macos")))]
+#[inline]
+#[must_use]
+pub unsafe fn ns_window(window: *mut SDL_Window) -> Option<*mut c_void> {
+  wm_info_for(window, SDL_SYSWM_COCOA).map(|info| info.cocoa.window)
+}
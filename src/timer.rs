@@ -1,5 +1,7 @@
 //! Timer related functionality.
 
+use core::time::Duration;
+
 use crate::{c_int, c_void, stdinc::*};
 
 /// Function prototype for the timer callback function.
@@ -20,8 +22,17 @@ extern "C" {
   /// Get the number of milliseconds since the SDL library initialization.
   ///
   /// **Note:** This value wraps if the program runs for more than ~49 days.
+  /// With SDL 2.0.18 or later, use [`SDL_GetTicks64`] instead.
   pub fn SDL_GetTicks() -> Uint32;
 
+  /// Get the number of milliseconds since the SDL library initialization.
+  ///
+  /// Unlike [`SDL_GetTicks`] this doesn't wrap after ~49 days, so values
+  /// from this function can be safely compared directly.
+  #[cfg(feature = "sdl2_2_0_18")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_18")))]
+  pub fn SDL_GetTicks64() -> Uint64;
+
   /// Get the current value of the high resolution counter.
   pub fn SDL_GetPerformanceCounter() -> Uint64;
 
@@ -51,3 +62,44 @@ extern "C" {
   /// **Warning:** It is not safe to remove a timer multiple times.
   pub fn SDL_RemoveTimer(id: SDL_TimerID) -> SDL_bool;
 }
+
+/// Converts a `Duration` to the milliseconds that SDL's timeouts use.
+///
+/// Any fraction of a millisecond rounds up, so a short but non-zero duration
+/// doesn't become "don't wait at all". Durations too long for a `u32` saturate
+/// to `u32::MAX` (about 49 days).
+#[inline]
+#[must_use]
+pub const fn duration_to_ms(duration: Duration) -> u32 {
+  let ms = duration.as_millis();
+  let ms = if duration.subsec_nanos() % 1_000_000 != 0 { ms + 1 } else { ms };
+  if ms > u32::MAX as u128 {
+    u32::MAX
+  } else {
+    ms as u32
+  }
+}
+
+/// [`SDL_Delay`] for a `Duration` (see [`duration_to_ms`]).
+#[inline]
+pub fn delay(duration: Duration) {
+  unsafe { SDL_Delay(duration_to_ms(duration)) }
+}
+
+/// The milliseconds since the SDL library initialization, as a `u64`.
+///
+/// This uses [`SDL_GetTicks64`] when the `sdl2_2_0_18` feature is on, so it
+/// won't wrap. Without that feature it falls back to [`SDL_GetTicks`], which
+/// still wraps after ~49 days.
+#[inline]
+#[must_use]
+pub fn ticks_ms_u64() -> u64 {
+  #[cfg(feature = "sdl2_2_0_18")]
+  unsafe {
+    SDL_GetTicks64()
+  }
+  #[cfg(not(feature = "sdl2_2_0_18"))]
+  unsafe {
+    u64::from(SDL_GetTicks())
+  }
+}
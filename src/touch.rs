@@ -2,6 +2,13 @@
 
 use crate::{c_float, c_int, stdinc::*};
 
+#[cfg(feature = "sdl2_2_0_22")]
+use crate::c_char;
+
+// makes rustdoc link properly!
+#[allow(unused)]
+use crate::error::*;
+
 /// Used with touch events.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
@@ -16,6 +23,7 @@ pub struct SDL_FingerID(pub Sint64);
 ///
 /// See `SDL_TOUCH_DEVICE_*` constants.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_TouchDeviceType(pub i32);
 
@@ -41,6 +49,7 @@ pub struct SDL_Finger {
   pub y: c_float,
   pub pressure: c_float,
 }
+impl_zeroable!(SDL_Finger);
 
 /// Used as the device ID for *mouse events* simulated with touch input.
 pub const SDL_TOUCH_MOUSEID: u32 = -1_i32 as u32;
@@ -50,18 +59,46 @@ pub const SDL_MOUSE_TOUCHID: SDL_TouchID = SDL_TouchID(-1);
 
 extern "C" {
   /// Get the number of registered touch devices.
-  pub fn SDL_GetNumTouchDevices();
+  ///
+  /// On some platforms SDL first sees a touch device when it's used, so this
+  /// may be 0 until then.
+  ///
+  /// See Also: [`SDL_GetTouchDevice`]
+  pub fn SDL_GetNumTouchDevices() -> c_int;
 
-  /// Get the touch ID with the given index, or 0 if the index is invalid.
+  /// Get the touch ID with the given index.
+  ///
+  /// * `index` the touch device index, from 0 up to
+  ///   [`SDL_GetNumTouchDevices`].
+  ///
+  /// **Returns:** the touch ID, or 0 if the index is invalid (call
+  /// [`SDL_GetErrorMsg`] for more information).
   pub fn SDL_GetTouchDevice(index: c_int) -> SDL_TouchID;
 
+  /// Get the touch device name as reported from the driver, or NULL if the
+  /// index is invalid.
+  #[cfg(feature = "sdl2_2_0_22")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_22")))]
+  pub fn SDL_GetTouchName(index: c_int) -> *const c_char;
+
   /// Get the type of the given touch device.
   pub fn SDL_GetTouchDeviceType(touchID: SDL_TouchID) -> SDL_TouchDeviceType;
 
   /// Get the number of active fingers for a given touch device.
+  ///
+  /// **Returns:** the number of active fingers for a given touch device on
+  /// success or 0 on failure (call [`SDL_GetErrorMsg`] for more
+  /// information).
+  ///
+  /// See Also: [`SDL_GetTouchFinger`]
   pub fn SDL_GetNumTouchFingers(touchID: SDL_TouchID) -> c_int;
 
-  /// Get the finger object of the given touch, with the given index.
+  /// Get the finger object for specified touch device ID and finger index.
+  ///
+  /// The returned finger is owned by SDL and should not be freed.
+  ///
+  /// **Returns:** a pointer to the finger, or NULL if no object at the given
+  /// ID and index could be found.
   pub fn SDL_GetTouchFinger(
     touchID: SDL_TouchID, index: c_int,
   ) -> *mut SDL_Finger;
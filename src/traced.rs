@@ -0,0 +1,214 @@
+//! Versions of some key SDL2 calls that record [`tracing`] spans.
+//!
+//! Each function here has the same name and signature as the normal binding,
+//! and just calls that binding inside of a span named after the function. When
+//! the call reports failure, the SDL error message is also recorded as an
+//! `error` event. Swap a `use fermium::prelude::*;` for specific imports from
+//! this module where you want the extra info.
+//!
+//! One-time setup calls (init, window/renderer/context creation, opening audio)
+//! use `DEBUG` level spans. Calls that usually happen every frame (texture
+//! updates, presenting) use `TRACE` level spans, so that they're easy to filter
+//! out.
+//!
+//! This module isn't part of the prelude, since its names overlap with the rest
+//! of the crate.
+
+use core::ffi::CStr;
+
+use crate::{c_char, c_int, error::*, video::*, *};
+
+#[cfg(feature = "audio")]
+use crate::audio::*;
+#[cfg(feature = "renderer")]
+use crate::{c_void, rect::*, renderer::*, surface::*};
+
+/// Records SDL's current error message as an `error` event.
+fn trace_sdl_error(call: &str) {
+  let mut buf = [0 as c_char; 256];
+  let msg = unsafe {
+    CStr::from_ptr(SDL_GetErrorMsg(buf.as_mut_ptr(), buf.len() as c_int))
+  };
+  tracing::error!(error = ?msg, "{call} failed");
+}
+
+/// Shows a C string pointer in span fields (null shows as `None`).
+unsafe fn c_str_field<'a>(p: *const c_char) -> Option<&'a CStr> {
+  if p.is_null() {
+    None
+  } else {
+    Some(CStr::from_ptr(p))
+  }
+}
+
+/// As [`SDL_Init`](crate::SDL_Init)
+#[inline]
+pub unsafe fn SDL_Init(flags: SDL_InitFlags) -> c_int {
+  let _span = tracing::debug_span!("SDL_Init", ?flags).entered();
+  let ret = crate::SDL_Init(flags);
+  if ret < 0 {
+    trace_sdl_error("SDL_Init");
+  }
+  ret
+}
+
+/// As [`SDL_InitSubSystem`](crate::SDL_InitSubSystem)
+#[inline]
+pub unsafe fn SDL_InitSubSystem(flags: SDL_InitFlags) -> c_int {
+  let _span = tracing::debug_span!("SDL_InitSubSystem", ?flags).entered();
+  let ret = crate::SDL_InitSubSystem(flags);
+  if ret < 0 {
+    trace_sdl_error("SDL_InitSubSystem");
+  }
+  ret
+}
+
+/// As [`SDL_CreateWindow`](crate::video::SDL_CreateWindow)
+#[inline]
+pub unsafe fn SDL_CreateWindow(
+  title: *const c_char, x: c_int, y: c_int, w: c_int, h: c_int, flags: u32,
+) -> *mut SDL_Window {
+  let _span = tracing::debug_span!(
+    "SDL_CreateWindow",
+    title = ?c_str_field(title),
+    x,
+    y,
+    w,
+    h,
+    ?flags
+  )
+  .entered();
+  let ret = crate::video::SDL_CreateWindow(title, x, y, w, h, flags);
+  if ret.is_null() {
+    trace_sdl_error("SDL_CreateWindow");
+  }
+  ret
+}
+
+/// As [`SDL_GL_CreateContext`](crate::video::SDL_GL_CreateContext)
+#[inline]
+pub unsafe fn SDL_GL_CreateContext(window: *mut SDL_Window) -> SDL_GLContext {
+  let _span = tracing::debug_span!("SDL_GL_CreateContext", ?window).entered();
+  let ret = crate::video::SDL_GL_CreateContext(window);
+  if ret.is_null() {
+    trace_sdl_error("SDL_GL_CreateContext");
+  }
+  ret
+}
+
+/// As [`SDL_GL_SwapWindow`](crate::video::SDL_GL_SwapWindow)
+#[inline]
+pub unsafe fn SDL_GL_SwapWindow(window: *mut SDL_Window) {
+  let _span = tracing::trace_span!("SDL_GL_SwapWindow", ?window).entered();
+  crate::video::SDL_GL_SwapWindow(window)
+}
+
+/// As [`SDL_CreateRenderer`](crate::renderer::SDL_CreateRenderer)
+#[cfg(feature = "renderer")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "renderer")))]
+#[inline]
+pub unsafe fn SDL_CreateRenderer(
+  window: *mut SDL_Window, index: c_int, flags: u32,
+) -> *mut SDL_Renderer {
+  let _span =
+    tracing::debug_span!("SDL_CreateRenderer", ?window, index, ?flags)
+      .entered();
+  let ret = crate::renderer::SDL_CreateRenderer(window, index, flags);
+  if ret.is_null() {
+    trace_sdl_error("SDL_CreateRenderer");
+  }
+  ret
+}
+
+/// As [`SDL_CreateTexture`](crate::renderer::SDL_CreateTexture)
+#[cfg(feature = "renderer")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "renderer")))]
+#[inline]
+pub unsafe fn SDL_CreateTexture(
+  renderer: *mut SDL_Renderer, format: u32, access: c_int, w: c_int, h: c_int,
+) -> *mut SDL_Texture {
+  let _span =
+    tracing::debug_span!("SDL_CreateTexture", ?renderer, format, access, w, h)
+      .entered();
+  let ret = crate::renderer::SDL_CreateTexture(renderer, format, access, w, h);
+  if ret.is_null() {
+    trace_sdl_error("SDL_CreateTexture");
+  }
+  ret
+}
+
+/// As [`SDL_CreateTextureFromSurface`](crate::renderer::SDL_CreateTextureFromSurface)
+#[cfg(feature = "renderer")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "renderer")))]
+#[inline]
+pub unsafe fn SDL_CreateTextureFromSurface(
+  renderer: *mut SDL_Renderer, surface: *mut SDL_Surface,
+) -> *mut SDL_Texture {
+  let _span =
+    tracing::debug_span!("SDL_CreateTextureFromSurface", ?renderer, ?surface)
+      .entered();
+  let ret = crate::renderer::SDL_CreateTextureFromSurface(renderer, surface);
+  if ret.is_null() {
+    trace_sdl_error("SDL_CreateTextureFromSurface");
+  }
+  ret
+}
+
+/// As [`SDL_UpdateTexture`](crate::renderer::SDL_UpdateTexture)
+#[cfg(feature = "renderer")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "renderer")))]
+#[inline]
+pub unsafe fn SDL_UpdateTexture(
+  texture: *mut SDL_Texture, rect: *const SDL_Rect, pixels: *const c_void,
+  pitch: c_int,
+) -> c_int {
+  let _span = tracing::trace_span!(
+    "SDL_UpdateTexture",
+    ?texture,
+    rect = ?rect.as_ref(),
+    pitch
+  )
+  .entered();
+  let ret = crate::renderer::SDL_UpdateTexture(texture, rect, pixels, pitch);
+  if ret < 0 {
+    trace_sdl_error("SDL_UpdateTexture");
+  }
+  ret
+}
+
+/// As [`SDL_RenderPresent`](crate::renderer::SDL_RenderPresent)
+#[cfg(feature = "renderer")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "renderer")))]
+#[inline]
+pub unsafe fn SDL_RenderPresent(renderer: *mut SDL_Renderer) {
+  let _span = tracing::trace_span!("SDL_RenderPresent", ?renderer).entered();
+  crate::renderer::SDL_RenderPresent(renderer)
+}
+
+/// As [`SDL_OpenAudioDevice`](crate::audio::SDL_OpenAudioDevice)
+#[cfg(feature = "audio")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "audio")))]
+#[inline]
+pub unsafe fn SDL_OpenAudioDevice(
+  device: *const c_char, iscapture: c_int, desired: *const SDL_AudioSpec,
+  obtained: *mut SDL_AudioSpec, allowed_changes: c_int,
+) -> SDL_AudioDeviceID {
+  let _span = tracing::debug_span!(
+    "SDL_OpenAudioDevice",
+    device = ?c_str_field(device),
+    iscapture,
+    allowed_changes
+  )
+  .entered();
+  let ret = crate::audio::SDL_OpenAudioDevice(
+    device,
+    iscapture,
+    desired,
+    obtained,
+    allowed_changes,
+  );
+  if ret.0 == 0 {
+    trace_sdl_error("SDL_OpenAudioDevice");
+  }
+  ret
+}
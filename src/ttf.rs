@@ -0,0 +1,586 @@
+//! Bindings to the SDL2_ttf library, for rendering TrueType fonts.
+//!
+//! This is a separate library from SDL2 itself, so it must also be installed
+//! for your program to link and run. These bindings match SDL2_ttf 2.20.
+//!
+//! The `TTF_Render*` functions come in five text flavors: `Text` (Latin-1),
+//! `UTF8`, `UNICODE` (UCS-2), and `Glyph`/`Glyph32` for single characters. Each
+//! of those comes in four quality modes (`Solid`, `Shaded`, `Blended`, and
+//! `LCD`), and the text versions also have a `_Wrapped` form. The docs for
+//! the `UTF8` version of each mode explain that mode.
+
+use crate::{
+  c_char, c_int, c_long, c_uint, c_void, pixels::*, rwops::*, stdinc::*,
+  surface::*, version::*,
+};
+
+// makes rustdoc link properly!
+#[allow(unused)]
+use crate::error::*;
+
+/// The major version of SDL2_ttf that these bindings are for.
+pub const SDL_TTF_MAJOR_VERSION: u8 = 2;
+/// The minor version of SDL2_ttf that these bindings are for.
+pub const SDL_TTF_MINOR_VERSION: u8 = 20;
+/// The patch version of SDL2_ttf that these bindings are for.
+pub const SDL_TTF_PATCHLEVEL: u8 = 2;
+
+/// Fills in the version of SDL2_ttf that these bindings are for.
+///
+/// To get the version of the library you're actually linked to, use
+/// [`TTF_Linked_Version`].
+#[inline]
+pub fn SDL_TTF_VERSION(x: &mut SDL_version) {
+  x.major = SDL_TTF_MAJOR_VERSION;
+  x.minor = SDL_TTF_MINOR_VERSION;
+  x.patch = SDL_TTF_PATCHLEVEL;
+}
+
+/// A byte order mark for native-endian UCS-2 text.
+pub const UNICODE_BOM_NATIVE: Uint16 = 0xFEFF;
+/// A byte order mark for byte-swapped UCS-2 text.
+pub const UNICODE_BOM_SWAPPED: Uint16 = 0xFFFE;
+
+/// Font style flags, see [`TTF_SetFontStyle`].
+pub const TTF_STYLE_NORMAL: c_int = 0x00;
+#[allow(missing_docs)]
+pub const TTF_STYLE_BOLD: c_int = 0x01;
+#[allow(missing_docs)]
+pub const TTF_STYLE_ITALIC: c_int = 0x02;
+#[allow(missing_docs)]
+pub const TTF_STYLE_UNDERLINE: c_int = 0x04;
+#[allow(missing_docs)]
+pub const TTF_STYLE_STRIKETHROUGH: c_int = 0x08;
+
+/// Font hinting modes, see [`TTF_SetFontHinting`].
+pub const TTF_HINTING_NORMAL: c_int = 0;
+#[allow(missing_docs)]
+pub const TTF_HINTING_LIGHT: c_int = 1;
+#[allow(missing_docs)]
+pub const TTF_HINTING_MONO: c_int = 2;
+#[allow(missing_docs)]
+pub const TTF_HINTING_NONE: c_int = 3;
+#[allow(missing_docs)]
+pub const TTF_HINTING_LIGHT_SUBPIXEL: c_int = 4;
+
+/// Line alignment for wrapped text, see [`TTF_SetFontWrappedAlign`].
+pub const TTF_WRAPPED_ALIGN_LEFT: c_int = 0;
+#[allow(missing_docs)]
+pub const TTF_WRAPPED_ALIGN_CENTER: c_int = 1;
+#[allow(missing_docs)]
+pub const TTF_WRAPPED_ALIGN_RIGHT: c_int = 2;
+
+/// The direction that text is laid out in.
+///
+/// This only has an effect when SDL2_ttf is built with HarfBuzz.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+pub struct TTF_Direction(pub u32);
+/// Left to right.
+pub const TTF_DIRECTION_LTR: TTF_Direction = TTF_Direction(0);
+/// Right to left.
+pub const TTF_DIRECTION_RTL: TTF_Direction = TTF_Direction(1);
+/// Top to bottom.
+pub const TTF_DIRECTION_TTB: TTF_Direction = TTF_Direction(2);
+/// Bottom to top.
+pub const TTF_DIRECTION_BTT: TTF_Direction = TTF_Direction(3);
+
+/// An opaque type for a loaded font.
+///
+/// Free this with [`TTF_CloseFont`].
+#[repr(transparent)]
+pub struct TTF_Font(c_void);
+
+extern "C" {
+  /// Gets the version of the SDL2_ttf library that's linked.
+  pub fn TTF_Linked_Version() -> *const SDL_version;
+
+  /// Gets the version of FreeType that SDL2_ttf uses.
+  pub fn TTF_GetFreeTypeVersion(
+    major: *mut c_int, minor: *mut c_int, patch: *mut c_int,
+  );
+
+  /// Gets the version of HarfBuzz that SDL2_ttf uses.
+  ///
+  /// This is all zeroes if SDL2_ttf was built without HarfBuzz.
+  pub fn TTF_GetHarfBuzzVersion(
+    major: *mut c_int, minor: *mut c_int, patch: *mut c_int,
+  );
+
+  /// Sets if UCS-2 text without a byte order mark is byte-swapped.
+  pub fn TTF_ByteSwappedUNICODE(swapped: SDL_bool);
+
+  /// Initializes the library.
+  ///
+  /// This can be called more than once, with each call matched by a call to
+  /// [`TTF_Quit`].
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn TTF_Init() -> c_int;
+
+  /// Opens a font file at the size given (in points, at 72 DPI).
+  ///
+  /// **Returns:** The new font, or null on error.
+  pub fn TTF_OpenFont(file: *const c_char, ptsize: c_int) -> *mut TTF_Font;
+
+  /// Opens a face of a font file that has more than one face.
+  ///
+  /// See [`TTF_OpenFont`].
+  pub fn TTF_OpenFontIndex(
+    file: *const c_char, ptsize: c_int, index: c_long,
+  ) -> *mut TTF_Font;
+
+  /// Opens a font from an [`SDL_RWops`].
+  ///
+  /// * `freesrc` non-zero to have the `SDL_RWops` closed/freed when the font
+  ///   is closed. Otherwise you must keep it open until the font is closed.
+  ///
+  /// **Returns:** The new font, or null on error.
+  pub fn TTF_OpenFontRW(
+    src: *mut SDL_RWops, freesrc: c_int, ptsize: c_int,
+  ) -> *mut TTF_Font;
+
+  /// Opens a face of a font from an [`SDL_RWops`].
+  ///
+  /// See [`TTF_OpenFontRW`].
+  pub fn TTF_OpenFontIndexRW(
+    src: *mut SDL_RWops, freesrc: c_int, ptsize: c_int, index: c_long,
+  ) -> *mut TTF_Font;
+
+  /// As [`TTF_OpenFont`], but for a screen with the DPI given.
+  pub fn TTF_OpenFontDPI(
+    file: *const c_char, ptsize: c_int, hdpi: c_uint, vdpi: c_uint,
+  ) -> *mut TTF_Font;
+
+  /// As [`TTF_OpenFontIndex`], but for a screen with the DPI given.
+  pub fn TTF_OpenFontIndexDPI(
+    file: *const c_char, ptsize: c_int, index: c_long, hdpi: c_uint,
+    vdpi: c_uint,
+  ) -> *mut TTF_Font;
+
+  /// As [`TTF_OpenFontRW`], but for a screen with the DPI given.
+  pub fn TTF_OpenFontDPIRW(
+    src: *mut SDL_RWops, freesrc: c_int, ptsize: c_int, hdpi: c_uint,
+    vdpi: c_uint,
+  ) -> *mut TTF_Font;
+
+  /// As [`TTF_OpenFontIndexRW`], but for a screen with the DPI given.
+  pub fn TTF_OpenFontIndexDPIRW(
+    src: *mut SDL_RWops, freesrc: c_int, ptsize: c_int, index: c_long,
+    hdpi: c_uint, vdpi: c_uint,
+  ) -> *mut TTF_Font;
+
+  /// Changes the size of an open font.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn TTF_SetFontSize(font: *mut TTF_Font, ptsize: c_int) -> c_int;
+
+  /// Changes the size and DPI of an open font.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn TTF_SetFontSizeDPI(
+    font: *mut TTF_Font, ptsize: c_int, hdpi: c_uint, vdpi: c_uint,
+  ) -> c_int;
+
+  /// Gets the `TTF_STYLE_*` flags of a font.
+  pub fn TTF_GetFontStyle(font: *const TTF_Font) -> c_int;
+
+  /// Sets the `TTF_STYLE_*` flags of a font.
+  ///
+  /// This clears the glyph cache, so don't change it every frame.
+  pub fn TTF_SetFontStyle(font: *mut TTF_Font, style: c_int);
+
+  /// Gets the outline width of a font, in pixels.
+  pub fn TTF_GetFontOutline(font: *const TTF_Font) -> c_int;
+
+  /// Sets the outline width of a font, in pixels (0 for no outline).
+  pub fn TTF_SetFontOutline(font: *mut TTF_Font, outline: c_int);
+
+  /// Gets the `TTF_HINTING_*` mode of a font.
+  pub fn TTF_GetFontHinting(font: *const TTF_Font) -> c_int;
+
+  /// Sets the `TTF_HINTING_*` mode of a font.
+  pub fn TTF_SetFontHinting(font: *mut TTF_Font, hinting: c_int);
+
+  /// Gets the `TTF_WRAPPED_ALIGN_*` mode of a font.
+  pub fn TTF_GetFontWrappedAlign(font: *const TTF_Font) -> c_int;
+
+  /// Sets the `TTF_WRAPPED_ALIGN_*` mode of a font.
+  pub fn TTF_SetFontWrappedAlign(font: *mut TTF_Font, align: c_int);
+
+  /// Gets the maximum pixel height of all glyphs in a font.
+  pub fn TTF_FontHeight(font: *const TTF_Font) -> c_int;
+
+  /// Gets the pixel offset from the baseline to the top of the font.
+  pub fn TTF_FontAscent(font: *const TTF_Font) -> c_int;
+
+  /// Gets the pixel offset from the baseline to the bottom of the font (this
+  /// is negative).
+  pub fn TTF_FontDescent(font: *const TTF_Font) -> c_int;
+
+  /// Gets the recommended pixel spacing between lines of text.
+  pub fn TTF_FontLineSkip(font: *const TTF_Font) -> c_int;
+
+  /// Checks if kerning is enabled for a font.
+  pub fn TTF_GetFontKerning(font: *const TTF_Font) -> c_int;
+
+  /// Enables (non-zero) or disables (zero) kerning for a font.
+  pub fn TTF_SetFontKerning(font: *mut TTF_Font, allowed: c_int);
+
+  /// Gets the number of faces in a font.
+  pub fn TTF_FontFaces(font: *const TTF_Font) -> c_long;
+
+  /// Checks if the font's current face is fixed width.
+  pub fn TTF_FontFaceIsFixedWidth(font: *const TTF_Font) -> c_int;
+
+  /// Gets the family name of the font's current face, or null.
+  pub fn TTF_FontFaceFamilyName(font: *const TTF_Font) -> *const c_char;
+
+  /// Gets the style name of the font's current face, or null.
+  pub fn TTF_FontFaceStyleName(font: *const TTF_Font) -> *const c_char;
+
+  /// Checks if a font has a glyph for a UCS-2 character.
+  ///
+  /// **Returns:** The glyph index, or 0 if there's no glyph.
+  pub fn TTF_GlyphIsProvided(font: *mut TTF_Font, ch: Uint16) -> c_int;
+
+  /// Checks if a font has a glyph for a character.
+  ///
+  /// **Returns:** The glyph index, or 0 if there's no glyph.
+  pub fn TTF_GlyphIsProvided32(font: *mut TTF_Font, ch: Uint32) -> c_int;
+
+  /// Gets the metrics of a UCS-2 character's glyph.
+  ///
+  /// Any of the out pointers can be null.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn TTF_GlyphMetrics(
+    font: *mut TTF_Font, ch: Uint16, minx: *mut c_int, maxx: *mut c_int,
+    miny: *mut c_int, maxy: *mut c_int, advance: *mut c_int,
+  ) -> c_int;
+
+  /// Gets the metrics of a character's glyph.
+  ///
+  /// Any of the out pointers can be null.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn TTF_GlyphMetrics32(
+    font: *mut TTF_Font, ch: Uint32, minx: *mut c_int, maxx: *mut c_int,
+    miny: *mut c_int, maxy: *mut c_int, advance: *mut c_int,
+  ) -> c_int;
+
+  /// Gets the size that Latin-1 text would render at.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn TTF_SizeText(
+    font: *mut TTF_Font, text: *const c_char, w: *mut c_int, h: *mut c_int,
+  ) -> c_int;
+
+  /// Gets the size that UTF-8 text would render at.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn TTF_SizeUTF8(
+    font: *mut TTF_Font, text: *const c_char, w: *mut c_int, h: *mut c_int,
+  ) -> c_int;
+
+  /// Gets the size that UCS-2 text would render at.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn TTF_SizeUNICODE(
+    font: *mut TTF_Font, text: *const Uint16, w: *mut c_int, h: *mut c_int,
+  ) -> c_int;
+
+  /// Gets how much Latin-1 text fits within a width, without rendering it.
+  ///
+  /// See [`TTF_MeasureUTF8`].
+  pub fn TTF_MeasureText(
+    font: *mut TTF_Font, text: *const c_char, measure_width: c_int,
+    extent: *mut c_int, count: *mut c_int,
+  ) -> c_int;
+
+  /// Gets how much UTF-8 text fits within a width, without rendering it.
+  ///
+  /// * `measure_width` the width in pixels.
+  /// * `extent` gets the width of the text that fits, in pixels.
+  /// * `count` gets the number of characters that fit.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn TTF_MeasureUTF8(
+    font: *mut TTF_Font, text: *const c_char, measure_width: c_int,
+    extent: *mut c_int, count: *mut c_int,
+  ) -> c_int;
+
+  /// Gets how much UCS-2 text fits within a width, without rendering it.
+  ///
+  /// See [`TTF_MeasureUTF8`].
+  pub fn TTF_MeasureUNICODE(
+    font: *mut TTF_Font, text: *const Uint16, measure_width: c_int,
+    extent: *mut c_int, count: *mut c_int,
+  ) -> c_int;
+
+  /// Renders Latin-1 text in solid mode, see [`TTF_RenderUTF8_Solid`].
+  pub fn TTF_RenderText_Solid(
+    font: *mut TTF_Font, text: *const c_char, fg: SDL_Color,
+  ) -> *mut SDL_Surface;
+
+  /// Renders Latin-1 text in solid mode, wrapped into lines.
+  pub fn TTF_RenderText_Solid_Wrapped(
+    font: *mut TTF_Font, text: *const c_char, fg: SDL_Color, wrapLength: Uint32,
+  ) -> *mut SDL_Surface;
+
+  /// Renders UTF-8 text in solid mode.
+  ///
+  /// Solid mode is the fastest, but the lowest quality. The surface is 8-bit
+  /// palettized, with index 0 as the (transparent) background and index 1 as
+  /// `fg`. There's no antialiasing.
+  ///
+  /// **Returns:** The new surface, or null on error.
+  pub fn TTF_RenderUTF8_Solid(
+    font: *mut TTF_Font, text: *const c_char, fg: SDL_Color,
+  ) -> *mut SDL_Surface;
+
+  /// Renders UTF-8 text in solid mode, wrapped into lines.
+  ///
+  /// Lines are broken at word boundaries so that they're at most `wrapLength`
+  /// pixels wide, and at any newlines in the text. A `wrapLength` of 0 only
+  /// breaks at newlines.
+  pub fn TTF_RenderUTF8_Solid_Wrapped(
+    font: *mut TTF_Font, text: *const c_char, fg: SDL_Color, wrapLength: Uint32,
+  ) -> *mut SDL_Surface;
+
+  /// Renders UCS-2 text in solid mode, see [`TTF_RenderUTF8_Solid`].
+  pub fn TTF_RenderUNICODE_Solid(
+    font: *mut TTF_Font, text: *const Uint16, fg: SDL_Color,
+  ) -> *mut SDL_Surface;
+
+  /// Renders UCS-2 text in solid mode, wrapped into lines.
+  pub fn TTF_RenderUNICODE_Solid_Wrapped(
+    font: *mut TTF_Font, text: *const Uint16, fg: SDL_Color, wrapLength: Uint32,
+  ) -> *mut SDL_Surface;
+
+  /// Renders a single UCS-2 glyph in solid mode, see [`TTF_RenderUTF8_Solid`].
+  pub fn TTF_RenderGlyph_Solid(
+    font: *mut TTF_Font, ch: Uint16, fg: SDL_Color,
+  ) -> *mut SDL_Surface;
+
+  /// Renders a single glyph in solid mode, see [`TTF_RenderUTF8_Solid`].
+  pub fn TTF_RenderGlyph32_Solid(
+    font: *mut TTF_Font, ch: Uint32, fg: SDL_Color,
+  ) -> *mut SDL_Surface;
+
+  /// Renders Latin-1 text in shaded mode, see [`TTF_RenderUTF8_Shaded`].
+  pub fn TTF_RenderText_Shaded(
+    font: *mut TTF_Font, text: *const c_char, fg: SDL_Color, bg: SDL_Color,
+  ) -> *mut SDL_Surface;
+
+  /// Renders Latin-1 text in shaded mode, wrapped into lines.
+  pub fn TTF_RenderText_Shaded_Wrapped(
+    font: *mut TTF_Font, text: *const c_char, fg: SDL_Color, bg: SDL_Color,
+    wrapLength: Uint32,
+  ) -> *mut SDL_Surface;
+
+  /// Renders UTF-8 text in shaded mode.
+  ///
+  /// Shaded mode is antialiased onto a solid `bg` color. The surface is 8-bit
+  /// palettized, with index 0 as `bg`, so it's not transparent.
+  ///
+  /// **Returns:** The new surface, or null on error.
+  pub fn TTF_RenderUTF8_Shaded(
+    font: *mut TTF_Font, text: *const c_char, fg: SDL_Color, bg: SDL_Color,
+  ) -> *mut SDL_Surface;
+
+  /// Renders UTF-8 text in shaded mode, wrapped into lines.
+  ///
+  /// Lines are broken at word boundaries so that they're at most `wrapLength`
+  /// pixels wide, and at any newlines in the text. A `wrapLength` of 0 only
+  /// breaks at newlines.
+  pub fn TTF_RenderUTF8_Shaded_Wrapped(
+    font: *mut TTF_Font, text: *const c_char, fg: SDL_Color, bg: SDL_Color,
+    wrapLength: Uint32,
+  ) -> *mut SDL_Surface;
+
+  /// Renders UCS-2 text in shaded mode, see [`TTF_RenderUTF8_Shaded`].
+  pub fn TTF_RenderUNICODE_Shaded(
+    font: *mut TTF_Font, text: *const Uint16, fg: SDL_Color, bg: SDL_Color,
+  ) -> *mut SDL_Surface;
+
+  /// Renders UCS-2 text in shaded mode, wrapped into lines.
+  pub fn TTF_RenderUNICODE_Shaded_Wrapped(
+    font: *mut TTF_Font, text: *const Uint16, fg: SDL_Color, bg: SDL_Color,
+    wrapLength: Uint32,
+  ) -> *mut SDL_Surface;
+
+  /// Renders a single UCS-2 glyph in shaded mode, see [`TTF_RenderUTF8_Shaded`].
+  pub fn TTF_RenderGlyph_Shaded(
+    font: *mut TTF_Font, ch: Uint16, fg: SDL_Color, bg: SDL_Color,
+  ) -> *mut SDL_Surface;
+
+  /// Renders a single glyph in shaded mode, see [`TTF_RenderUTF8_Shaded`].
+  pub fn TTF_RenderGlyph32_Shaded(
+    font: *mut TTF_Font, ch: Uint32, fg: SDL_Color, bg: SDL_Color,
+  ) -> *mut SDL_Surface;
+
+  /// Renders Latin-1 text in blended mode, see [`TTF_RenderUTF8_Blended`].
+  pub fn TTF_RenderText_Blended(
+    font: *mut TTF_Font, text: *const c_char, fg: SDL_Color,
+  ) -> *mut SDL_Surface;
+
+  /// Renders Latin-1 text in blended mode, wrapped into lines.
+  pub fn TTF_RenderText_Blended_Wrapped(
+    font: *mut TTF_Font, text: *const c_char, fg: SDL_Color, wrapLength: Uint32,
+  ) -> *mut SDL_Surface;
+
+  /// Renders UTF-8 text in blended mode.
+  ///
+  /// Blended mode is the slowest, but the highest quality. The text is
+  /// antialiased onto a transparent 32-bit ARGB surface, so it can be blended
+  /// over anything.
+  ///
+  /// **Returns:** The new surface, or null on error.
+  pub fn TTF_RenderUTF8_Blended(
+    font: *mut TTF_Font, text: *const c_char, fg: SDL_Color,
+  ) -> *mut SDL_Surface;
+
+  /// Renders UTF-8 text in blended mode, wrapped into lines.
+  ///
+  /// Lines are broken at word boundaries so that they're at most `wrapLength`
+  /// pixels wide, and at any newlines in the text. A `wrapLength` of 0 only
+  /// breaks at newlines.
+  pub fn TTF_RenderUTF8_Blended_Wrapped(
+    font: *mut TTF_Font, text: *const c_char, fg: SDL_Color, wrapLength: Uint32,
+  ) -> *mut SDL_Surface;
+
+  /// Renders UCS-2 text in blended mode, see [`TTF_RenderUTF8_Blended`].
+  pub fn TTF_RenderUNICODE_Blended(
+    font: *mut TTF_Font, text: *const Uint16, fg: SDL_Color,
+  ) -> *mut SDL_Surface;
+
+  /// Renders UCS-2 text in blended mode, wrapped into lines.
+  pub fn TTF_RenderUNICODE_Blended_Wrapped(
+    font: *mut TTF_Font, text: *const Uint16, fg: SDL_Color, wrapLength: Uint32,
+  ) -> *mut SDL_Surface;
+
+  /// Renders a single UCS-2 glyph in blended mode, see [`TTF_RenderUTF8_Blended`].
+  pub fn TTF_RenderGlyph_Blended(
+    font: *mut TTF_Font, ch: Uint16, fg: SDL_Color,
+  ) -> *mut SDL_Surface;
+
+  /// Renders a single glyph in blended mode, see [`TTF_RenderUTF8_Blended`].
+  pub fn TTF_RenderGlyph32_Blended(
+    font: *mut TTF_Font, ch: Uint32, fg: SDL_Color,
+  ) -> *mut SDL_Surface;
+
+  /// Renders Latin-1 text in lcd mode, see [`TTF_RenderUTF8_LCD`].
+  pub fn TTF_RenderText_LCD(
+    font: *mut TTF_Font, text: *const c_char, fg: SDL_Color, bg: SDL_Color,
+  ) -> *mut SDL_Surface;
+
+  /// Renders Latin-1 text in lcd mode, wrapped into lines.
+  pub fn TTF_RenderText_LCD_Wrapped(
+    font: *mut TTF_Font, text: *const c_char, fg: SDL_Color, bg: SDL_Color,
+    wrapLength: Uint32,
+  ) -> *mut SDL_Surface;
+
+  /// Renders UTF-8 text in lcd mode.
+  ///
+  /// LCD mode uses subpixel rendering onto a solid `bg` color, for LCD screens.
+  /// The surface is 32-bit ARGB.
+  ///
+  /// **Returns:** The new surface, or null on error.
+  pub fn TTF_RenderUTF8_LCD(
+    font: *mut TTF_Font, text: *const c_char, fg: SDL_Color, bg: SDL_Color,
+  ) -> *mut SDL_Surface;
+
+  /// Renders UTF-8 text in lcd mode, wrapped into lines.
+  ///
+  /// Lines are broken at word boundaries so that they're at most `wrapLength`
+  /// pixels wide, and at any newlines in the text. A `wrapLength` of 0 only
+  /// breaks at newlines.
+  pub fn TTF_RenderUTF8_LCD_Wrapped(
+    font: *mut TTF_Font, text: *const c_char, fg: SDL_Color, bg: SDL_Color,
+    wrapLength: Uint32,
+  ) -> *mut SDL_Surface;
+
+  /// Renders UCS-2 text in lcd mode, see [`TTF_RenderUTF8_LCD`].
+  pub fn TTF_RenderUNICODE_LCD(
+    font: *mut TTF_Font, text: *const Uint16, fg: SDL_Color, bg: SDL_Color,
+  ) -> *mut SDL_Surface;
+
+  /// Renders UCS-2 text in lcd mode, wrapped into lines.
+  pub fn TTF_RenderUNICODE_LCD_Wrapped(
+    font: *mut TTF_Font, text: *const Uint16, fg: SDL_Color, bg: SDL_Color,
+    wrapLength: Uint32,
+  ) -> *mut SDL_Surface;
+
+  /// Renders a single UCS-2 glyph in lcd mode, see [`TTF_RenderUTF8_LCD`].
+  pub fn TTF_RenderGlyph_LCD(
+    font: *mut TTF_Font, ch: Uint16, fg: SDL_Color, bg: SDL_Color,
+  ) -> *mut SDL_Surface;
+
+  /// Renders a single glyph in lcd mode, see [`TTF_RenderUTF8_LCD`].
+  pub fn TTF_RenderGlyph32_LCD(
+    font: *mut TTF_Font, ch: Uint32, fg: SDL_Color, bg: SDL_Color,
+  ) -> *mut SDL_Surface;
+
+  /// Closes a font.
+  pub fn TTF_CloseFont(font: *mut TTF_Font);
+
+  /// Shuts down the library, once it's been called once for each call to
+  /// [`TTF_Init`].
+  pub fn TTF_Quit();
+
+  /// Gets the number of times [`TTF_Init`] has been called, minus the number
+  /// of times [`TTF_Quit`] has been called.
+  pub fn TTF_WasInit() -> c_int;
+
+  /// Gets the kerning between two glyph indexes.
+  ///
+  /// Deprecated, use [`TTF_GetFontKerningSizeGlyphs32`].
+  pub fn TTF_GetFontKerningSize(
+    font: *mut TTF_Font, prev_index: c_int, index: c_int,
+  ) -> c_int;
+
+  /// Gets the kerning between two UCS-2 characters, in pixels.
+  pub fn TTF_GetFontKerningSizeGlyphs(
+    font: *mut TTF_Font, previous_ch: Uint16, ch: Uint16,
+  ) -> c_int;
+
+  /// Gets the kerning between two characters, in pixels.
+  pub fn TTF_GetFontKerningSizeGlyphs32(
+    font: *mut TTF_Font, previous_ch: Uint32, ch: Uint32,
+  ) -> c_int;
+
+  /// Enables or disables signed distance field rendering for a font.
+  ///
+  /// **Returns:** 0 on success, or -1 on error.
+  pub fn TTF_SetFontSDF(font: *mut TTF_Font, on_off: SDL_bool) -> c_int;
+
+  /// Checks if signed distance field rendering is enabled for a font.
+  pub fn TTF_GetFontSDF(font: *const TTF_Font) -> SDL_bool;
+
+  /// Sets the default text direction for all fonts (an `hb_direction_t`).
+  ///
+  /// Deprecated, use [`TTF_SetFontDirection`].
+  pub fn TTF_SetDirection(direction: c_int) -> c_int;
+
+  /// Sets the default script for all fonts (an `hb_script_t`).
+  ///
+  /// Deprecated, use [`TTF_SetFontScriptName`].
+  pub fn TTF_SetScript(script: c_int) -> c_int;
+
+  /// Sets the text direction of a font.
+  ///
+  /// **Returns:** 0 on success, or -1 if HarfBuzz isn't available.
+  pub fn TTF_SetFontDirection(
+    font: *mut TTF_Font, direction: TTF_Direction,
+  ) -> c_int;
+
+  /// Sets the script of a font, as an ISO 15924 code (eg: `"Arab"`).
+  ///
+  /// **Returns:** 0 on success, or -1 if HarfBuzz isn't available or the
+  /// script name isn't valid.
+  pub fn TTF_SetFontScriptName(
+    font: *mut TTF_Font, script: *const c_char,
+  ) -> c_int;
+}
@@ -18,6 +18,7 @@ use crate::*;
 ///
 /// **To Be Clear: The SDL library doesn't subscribe to SemVer.**
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 #[allow(missing_docs)]
 pub struct SDL_version {
@@ -28,6 +29,7 @@ pub struct SDL_version {
   /// update version
   pub patch: Uint8,
 }
+impl_pod!(SDL_version);
 
 /// SDL Major version that this lib was compiled against.
 pub const SDL_MAJOR_VERSION: u8 = 2;
@@ -1,16 +1,16 @@
 //! Module for interacting with the video subsystem.
 
-use crate::{c_char, c_int, c_void, rect::*, stdinc::*, surface::*};
-
-// makes rustdoc link properly!
-#[allow(unused)]
-use crate::error::*;
 #[allow(unused)]
 #[cfg(feature = "vulkan")]
 use crate::vulkan::*;
+use crate::{
+  c_char, c_int, c_void, error::*, hints::*, pixels::*, rect::*, stdinc::*,
+  surface::*, SDL_InitSubSystem, SDL_QuitSubSystem, SDL_INIT_VIDEO,
+};
 
 /// The structure that defines a display mode
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct SDL_DisplayMode {
   /// pixel format
@@ -22,16 +22,33 @@ pub struct SDL_DisplayMode {
   /// refresh rate (or zero for unspecified)
   pub refresh_rate: c_int,
   /// driver-specific data, initialize to 0
+  ///
+  /// (This isn't serialized, and is null when deserialized.)
+  #[cfg_attr(feature = "serde", serde(skip, default = "core::ptr::null_mut"))]
   pub driverdata: *mut c_void,
 }
+impl_zeroable!(SDL_DisplayMode);
 
 /// The type used to identify a window (newtype'd `c_void`).
 #[repr(transparent)]
 pub struct SDL_Window(c_void);
 
-/// The flags on a window
+/// The ID of a window.
+///
+/// This is what window related events use to say which window they're about.
+/// Use [`SDL_GetWindowFromID`] to go from an ID back to a window. Valid IDs are
+/// non-zero.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
+pub struct SDL_WindowID(pub u32);
+
+/// The flags on a window
+///
+/// The `Debug` and `Display` impls list the names of the flags that are set,
+/// like `SDL_WINDOW_SHOWN | SDL_WINDOW_RESIZABLE`.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
 pub struct SDL_WindowFlags(pub u32);
 impl_bit_ops_for_tuple_newtype!(SDL_WindowFlags);
 #[allow(missing_docs)]
@@ -80,11 +97,57 @@ pub const SDL_WINDOW_UTILITY: SDL_WindowFlags = SDL_WindowFlags(0x00020000);
 pub const SDL_WINDOW_TOOLTIP: SDL_WindowFlags = SDL_WindowFlags(0x00040000);
 #[allow(missing_docs)]
 pub const SDL_WINDOW_POPUP_MENU: SDL_WindowFlags = SDL_WindowFlags(0x00080000);
+/// The window has grabbed keyboard input
+#[cfg(feature = "sdl2_2_0_16")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_16")))]
+pub const SDL_WINDOW_KEYBOARD_GRABBED: SDL_WindowFlags =
+  SDL_WindowFlags(0x00100000);
 #[allow(missing_docs)]
 pub const SDL_WINDOW_VULKAN: SDL_WindowFlags = SDL_WindowFlags(0x10000000);
 #[allow(missing_docs)]
 pub const SDL_WINDOW_METAL: SDL_WindowFlags = SDL_WindowFlags(0x20000000);
 
+impl core::fmt::Display for SDL_WindowFlags {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    // `FULLSCREEN_DESKTOP` includes the `FULLSCREEN` bit, so it goes first.
+    crate::fmt_flag_names(
+      f,
+      self.0,
+      &[
+        (SDL_WINDOW_FULLSCREEN_DESKTOP.0, "SDL_WINDOW_FULLSCREEN_DESKTOP"),
+        (SDL_WINDOW_FULLSCREEN.0, "SDL_WINDOW_FULLSCREEN"),
+        (SDL_WINDOW_OPENGL.0, "SDL_WINDOW_OPENGL"),
+        (SDL_WINDOW_SHOWN.0, "SDL_WINDOW_SHOWN"),
+        (SDL_WINDOW_HIDDEN.0, "SDL_WINDOW_HIDDEN"),
+        (SDL_WINDOW_BORDERLESS.0, "SDL_WINDOW_BORDERLESS"),
+        (SDL_WINDOW_RESIZABLE.0, "SDL_WINDOW_RESIZABLE"),
+        (SDL_WINDOW_MINIMIZED.0, "SDL_WINDOW_MINIMIZED"),
+        (SDL_WINDOW_MAXIMIZED.0, "SDL_WINDOW_MAXIMIZED"),
+        (SDL_WINDOW_INPUT_GRABBED.0, "SDL_WINDOW_INPUT_GRABBED"),
+        (SDL_WINDOW_INPUT_FOCUS.0, "SDL_WINDOW_INPUT_FOCUS"),
+        (SDL_WINDOW_MOUSE_FOCUS.0, "SDL_WINDOW_MOUSE_FOCUS"),
+        (SDL_WINDOW_FOREIGN.0, "SDL_WINDOW_FOREIGN"),
+        (SDL_WINDOW_ALLOW_HIGHDPI.0, "SDL_WINDOW_ALLOW_HIGHDPI"),
+        (SDL_WINDOW_MOUSE_CAPTURE.0, "SDL_WINDOW_MOUSE_CAPTURE"),
+        (SDL_WINDOW_ALWAYS_ON_TOP.0, "SDL_WINDOW_ALWAYS_ON_TOP"),
+        (SDL_WINDOW_SKIP_TASKBAR.0, "SDL_WINDOW_SKIP_TASKBAR"),
+        (SDL_WINDOW_UTILITY.0, "SDL_WINDOW_UTILITY"),
+        (SDL_WINDOW_TOOLTIP.0, "SDL_WINDOW_TOOLTIP"),
+        (SDL_WINDOW_POPUP_MENU.0, "SDL_WINDOW_POPUP_MENU"),
+        #[cfg(feature = "sdl2_2_0_16")]
+        (SDL_WINDOW_KEYBOARD_GRABBED.0, "SDL_WINDOW_KEYBOARD_GRABBED"),
+        (SDL_WINDOW_VULKAN.0, "SDL_WINDOW_VULKAN"),
+        (SDL_WINDOW_METAL.0, "SDL_WINDOW_METAL"),
+      ],
+    )
+  }
+}
+impl core::fmt::Debug for SDL_WindowFlags {
+  fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+    core::fmt::Display::fmt(self, f)
+  }
+}
+
 /// Specifies that you don't care about the window position.
 pub const SDL_WINDOWPOS_UNDEFINED: i32 = 0x1FFF0000;
 
@@ -96,6 +159,7 @@ pub const SDL_WINDOWPOS_CENTERED: i32 = 0x2FFF0000;
 /// Technically a `u32`, altered to be `u8` to better fit with the usage within
 /// the API.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_WindowEventID(pub u8);
 #[allow(missing_docs)]
@@ -134,12 +198,18 @@ pub const SDL_WINDOWEVENT_CLOSE: SDL_WindowEventID = SDL_WindowEventID(14);
 pub const SDL_WINDOWEVENT_TAKE_FOCUS: SDL_WindowEventID = SDL_WindowEventID(15);
 #[allow(missing_docs)]
 pub const SDL_WINDOWEVENT_HIT_TEST: SDL_WindowEventID = SDL_WindowEventID(16);
+/// The ICC profile of the window's display has changed.
+#[cfg(feature = "sdl2_2_0_18")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_18")))]
+pub const SDL_WINDOWEVENT_ICCPROF_CHANGED: SDL_WindowEventID =
+  SDL_WindowEventID(17);
 
 /// Event subtype for display events.
 ///
 /// Technically a `u32`, altered to be `u8` to better fit with the usage within
 /// the API.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_DisplayEventID(pub u8);
 /// Never used
@@ -156,6 +226,7 @@ pub const SDL_DISPLAYEVENT_DISCONNECTED: SDL_DisplayEventID =
 
 /// Orientations a display can have.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_DisplayOrientation(pub u32);
 #[allow(missing_docs)]
@@ -188,6 +259,7 @@ impl SDL_GLContext {
 
 /// OpenGL configuration attributes
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_GLattr(pub u32);
 #[allow(missing_docs)]
@@ -249,6 +321,7 @@ pub const SDL_GL_CONTEXT_NO_ERROR: SDL_GLattr = SDL_GLattr(26);
 ///
 /// See the `SDL_GL_CONTEXT_PROFILE_*` constants.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_GLprofile(pub u32);
 #[allow(missing_docs)]
@@ -263,6 +336,7 @@ pub const SDL_GL_CONTEXT_PROFILE_ES: SDL_GLprofile = SDL_GLprofile(0x0004);
 ///
 /// See the `SDL_GL_CONTEXT_*` constants.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_GLcontextFlag(pub u32);
 impl_bit_ops_for_tuple_newtype!(SDL_GLcontextFlag);
@@ -283,6 +357,7 @@ pub const SDL_GL_CONTEXT_RESET_ISOLATION_FLAG: SDL_GLcontextFlag =
 ///
 /// See `SDL_GL_CONTEXT_RELEASE_BEHAVIOR_*`
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_GLcontextReleaseFlag(pub u32);
 #[allow(missing_docs)]
@@ -296,6 +371,7 @@ pub const SDL_GL_CONTEXT_RELEASE_BEHAVIOR_FLUSH: SDL_GLcontextReleaseFlag =
 ///
 /// See `SDL_GL_CONTEXT_RESET_*`
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_GLContextResetNotification(pub u32);
 #[allow(missing_docs)]
@@ -309,6 +385,7 @@ pub const SDL_GL_CONTEXT_RESET_LOSE_CONTEXT: SDL_GLContextResetNotification =
 ///
 /// See `SDL_HITTEST_*`
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_HitTestResult(pub u32);
 #[allow(missing_docs)]
@@ -346,14 +423,23 @@ pub type SDL_HitTest = Option<
 /// Window flash operation
 ///
 /// See [SDL_FlashWindow]
+#[cfg(feature = "sdl2_2_0_16")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_16")))]
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(transparent)]
 pub struct SDL_FlashOperation(pub u32);
 /// Cancel any window flash state
+#[cfg(feature = "sdl2_2_0_16")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_16")))]
 pub const SDL_FLASH_CANCEL: SDL_FlashOperation = SDL_FlashOperation(0);
 /// Flash the window briefly to get attention
+#[cfg(feature = "sdl2_2_0_16")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_16")))]
 pub const SDL_FLASH_BRIEFLY: SDL_FlashOperation = SDL_FlashOperation(1);
 /// Flash the window until it gets focus
+#[cfg(feature = "sdl2_2_0_16")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_16")))]
 pub const SDL_FLASH_UNTIL_FOCUSED: SDL_FlashOperation = SDL_FlashOperation(2);
 
 extern "C" {
@@ -464,7 +550,7 @@ extern "C" {
   pub fn SDL_GetWindowDisplayIndex(window: *mut SDL_Window) -> c_int;
 
   /// Get a window from a stored ID, or NULL if it doesn't exist.
-  pub fn SDL_GetWindowFromID(id: u32) -> *mut SDL_Window;
+  pub fn SDL_GetWindowFromID(id: SDL_WindowID) -> *mut SDL_Window;
 
   /// Sets the display mode to use when the given window is visible and
   /// fullscreen.
@@ -484,6 +570,23 @@ extern "C" {
     window: *mut SDL_Window, mode: *mut SDL_DisplayMode,
   ) -> c_int;
 
+  /// Get the raw ICC profile data for the screen the window is currently on.
+  ///
+  /// Data returned should be freed with [`SDL_free`].
+  ///
+  /// * `window` the window to query
+  /// * `size` the size of the ICC profile
+  ///
+  /// **Returns:** the raw ICC profile data on success or NULL on failure. Call
+  /// [`SDL_GetErrorMsg`] for more.
+  ///
+  /// See Also: [`window_icc_profile`]
+  #[cfg(feature = "sdl2_2_0_18")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_18")))]
+  pub fn SDL_GetWindowICCProfile(
+    window: *mut SDL_Window, size: *mut usize,
+  ) -> *mut c_void;
+
   /// Gets the pixel format of the window.
   ///
   /// **Return:** the pixel format on success, or `SDL_PIXELFORMAT_UNKNOWN` on
@@ -546,7 +649,7 @@ extern "C" {
   pub fn SDL_CreateWindowFrom(data: *const c_void) -> *mut SDL_Window;
 
   /// Gets the window's ID, or 0 on failure.
-  pub fn SDL_GetWindowID(window: *mut SDL_Window) -> Uint32;
+  pub fn SDL_GetWindowID(window: *mut SDL_Window) -> SDL_WindowID;
 
   /// Gets the flags of the window.
   pub fn SDL_GetWindowFlags(window: *mut SDL_Window) -> Uint32;
@@ -596,6 +699,22 @@ extern "C" {
     window: *mut SDL_Window, w: *mut c_int, h: *mut c_int,
   );
 
+  /// Gets the window's client area size in pixels.
+  ///
+  /// This may differ from [`SDL_GetWindowSize`] if we're rendering to a
+  /// high-DPI drawable, ie: the window was created with
+  /// [`SDL_WINDOW_ALLOW_HIGHDPI`] on a platform with high-DPI support (Apple
+  /// calls this "Retina"), and not disabled by the
+  /// [`SDL_HINT_VIDEO_HIGHDPI_DISABLED`] hint. On Windows it differs when
+  /// [`SDL_HINT_WINDOWS_DPI_SCALING`] is on.
+  ///
+  /// Either pointer may safely be null.
+  #[cfg(feature = "sdl2_2_26")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_26")))]
+  pub fn SDL_GetWindowSizeInPixels(
+    window: *mut SDL_Window, w: *mut c_int, h: *mut c_int,
+  );
+
   /// Gets the size of the border decoration around the client area.
   ///
   /// Any pointer may safely be null.
@@ -883,6 +1002,8 @@ extern "C" {
   /// * `operation` the flash operation
   /// * **Returns:** 0 on success, or a negative error code on failure. Call
   ///   [SDL_GetError] for more information.
+  #[cfg(feature = "sdl2_2_0_16")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_16")))]
   pub fn SDL_FlashWindow(
     window: *mut SDL_Window, operation: SDL_FlashOperation,
   ) -> c_int;
@@ -898,6 +1019,8 @@ extern "C" {
   ///   disable.
   ///
   /// See Also: [SDL_SetWindowAlwaysOnTop]
+  #[cfg(feature = "sdl2_2_0_16")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_16")))]
   pub fn SDL_SetWindowAlwaysOnTop(window: *mut SDL_Window, on_top: SDL_bool);
 
   /// Set a window's keyboard grab mode.
@@ -910,6 +1033,8 @@ extern "C" {
   ///
   /// See Also: [SDL_GetWindowKeyboardGrab], [SDL_SetWindowMouseGrab],
   /// [SDL_SetWindowGrab]
+  #[cfg(feature = "sdl2_2_0_16")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_16")))]
   pub fn SDL_SetWindowKeyboardGrab(window: *mut SDL_Window, grabbed: SDL_bool);
 
   /// Set a window's mouse grab mode.
@@ -918,6 +1043,8 @@ extern "C" {
   ///
   /// See Also: [SDL_GetWindowMouseGrab], [SDL_SetWindowKeyboardGrab],
   /// [SDL_SetWindowGrab]
+  #[cfg(feature = "sdl2_2_0_16")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_16")))]
   pub fn SDL_SetWindowMouseGrab(window: *mut SDL_Window, grabbed: SDL_bool);
 
   /// Get a window's keyboard grab mode.
@@ -927,6 +1054,8 @@ extern "C" {
   ///   otherwise.
   ///
   /// See Also: [SDL_SetWindowKeyboardGrab], [SDL_GetWindowGrab]
+  #[cfg(feature = "sdl2_2_0_16")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_16")))]
   pub fn SDL_GetWindowKeyboardGrab(window: *mut SDL_Window) -> SDL_bool;
 
   /// Get a window's mouse grab mode.
@@ -935,5 +1064,234 @@ extern "C" {
   /// * **Returns:** `SDL_TRUE` if mouse is grabbed, and `SDL_FALSE` otherwise.
   ///
   /// See Also: [SDL_SetWindowKeyboardGrab], [SDL_GetWindowGrab]
+  #[cfg(feature = "sdl2_2_0_16")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_16")))]
   pub fn SDL_GetWindowMouseGrab(window: *mut SDL_Window) -> SDL_bool;
+
+  /// Confines the cursor to the specified area of a window.
+  ///
+  /// Note that this does NOT grab the cursor, it only defines the area a
+  /// cursor is restricted to when the window has mouse focus.
+  ///
+  /// * `window` The window that will be associated with the barrier.
+  /// * `rect` A rectangle area in window-relative coordinates. If NULL the
+  ///   barrier for the specified window will be destroyed.
+  ///
+  /// **Returns:** 0 on success or a negative error code on failure. Call
+  /// [`SDL_GetErrorMsg`] for more information.
+  ///
+  /// See Also: [`SDL_GetWindowMouseRect`], [`SDL_SetWindowMouseGrab`]
+  #[cfg(feature = "sdl2_2_0_18")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_18")))]
+  pub fn SDL_SetWindowMouseRect(
+    window: *mut SDL_Window, rect: *const SDL_Rect,
+  ) -> c_int;
+
+  /// Get the mouse confinement rectangle of a window.
+  ///
+  /// * `window` The window to query
+  ///
+  /// **Returns:** A pointer to the mouse confinement rectangle of a window, or
+  /// NULL if there isn't one.
+  ///
+  /// See Also: [`SDL_SetWindowMouseRect`]
+  #[cfg(feature = "sdl2_2_0_18")]
+  #[cfg_attr(docs_rs, doc(cfg(feature = "sdl2_2_0_18")))]
+  pub fn SDL_GetWindowMouseRect(window: *mut SDL_Window) -> *const SDL_Rect;
+}
+
+/// Starts the video subsystem with a driver that doesn't need a display.
+///
+/// This is meant for running tests on a CI machine. It sets
+/// [`SDL_HINT_VIDEODRIVER`] to the "offscreen" driver and initializes
+/// [`SDL_INIT_VIDEO`]. If that fails it sets the hint to the "dummy" driver
+/// and tries again, and then checks that SDL really did pick one of those two
+/// drivers. Windows can still be created, but nothing is shown. (Only
+/// "offscreen" can make OpenGL contexts, when EGL is available.)
+///
+/// The hint is only read by SDL 2.0.22 or later. With an older SDL, set the
+/// `SDL_VIDEODRIVER` environment variable to "dummy" instead, and this will
+/// still do the check. Likewise, if the environment variable is set to some
+/// other driver it overrides the hint, and this will fail.
+///
+/// **Returns:** 0 on success, or negative on error (call [`SDL_GetErrorMsg`]).
+pub unsafe fn init_headless_video() -> c_int {
+  SDL_SetHint(
+    SDL_HINT_VIDEODRIVER.as_ptr().cast(),
+    b"offscreen\0".as_ptr().cast(),
+  );
+  if SDL_InitSubSystem(SDL_INIT_VIDEO) < 0 {
+    SDL_SetHint(
+      SDL_HINT_VIDEODRIVER.as_ptr().cast(),
+      b"dummy\0".as_ptr().cast(),
+    );
+    if SDL_InitSubSystem(SDL_INIT_VIDEO) < 0 {
+      return -1;
+    }
+  }
+  let current = SDL_GetCurrentVideoDriver();
+  if !current.is_null() {
+    let current = core::ffi::CStr::from_ptr(current).to_bytes();
+    if current == b"offscreen" || current == b"dummy" {
+      return 0;
+    }
+  }
+  SDL_QuitSubSystem(SDL_INIT_VIDEO);
+  SDL_SetError(b"Couldn't start a headless video driver\0".as_ptr().cast())
+}
+
+/// Sets a window's icon from RGBA pixel data.
+///
+/// The pixels are 4 bytes each, in R, G, B, A order, with rows top to bottom
+/// and no padding between rows. This is the layout you get from most image
+/// decoders (eg: an `RgbaImage` from the `image` crate). A temporary surface
+/// borrows the pixels, is passed to [`SDL_SetWindowIcon`] (which makes its own
+/// copy), and is then freed.
+///
+/// **Returns:** 0 on success, or negative on error (call [`SDL_GetErrorMsg`]).
+/// It's an error if `pixels` isn't exactly `width * height * 4` bytes long.
+pub unsafe fn set_window_icon_rgba(
+  window: *mut SDL_Window, pixels: &[u8], width: u32, height: u32,
+) -> c_int {
+  let expected = (width as usize)
+    .checked_mul(height as usize)
+    .and_then(|px| px.checked_mul(4));
+  if expected != Some(pixels.len())
+    || width > (c_int::MAX / 4) as u32
+    || height > c_int::MAX as u32
+  {
+    return SDL_SetError(
+      b"Icon pixels don't match the size given\0".as_ptr().cast(),
+    );
+  }
+  // SDL doesn't write to the pixels, it just has no const version of this.
+  let icon = SDL_CreateRGBSurfaceWithFormatFrom(
+    pixels.as_ptr() as *mut c_void,
+    width as c_int,
+    height as c_int,
+    32,
+    (width * 4) as c_int,
+    SDL_PIXELFORMAT_RGBA32.0,
+  );
+  if icon.is_null() {
+    return -1;
+  }
+  SDL_SetWindowIcon(window, icon);
+  SDL_FreeSurface(icon);
+  0
+}
+
+/// The common set of OpenGL attributes to ask for before making a GL window.
+///
+/// Fill this out (starting from `Default` is usually easiest) and call
+/// [`apply`](Self::apply) after initializing video but before making the
+/// window with [`SDL_WINDOW_OPENGL`].
+///
+/// ```no_run
+/// # use fermium::prelude::*;
+/// let config = GlContextConfig { debug: true, ..Default::default() };
+/// unsafe { config.apply() }.unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GlContextConfig {
+  /// Core, compatibility, or ES.
+  pub profile: SDL_GLprofile,
+  /// The context's major version.
+  pub major_version: c_int,
+  /// The context's minor version.
+  pub minor_version: c_int,
+  /// Minimum bits in the depth buffer.
+  pub depth_bits: c_int,
+  /// Minimum bits in the stencil buffer.
+  pub stencil_bits: c_int,
+  /// Request an sRGB capable default framebuffer.
+  pub srgb: bool,
+  /// Request a debug context ([`SDL_GL_CONTEXT_DEBUG_FLAG`]).
+  pub debug: bool,
+  /// Request a forward compatible context
+  /// ([`SDL_GL_CONTEXT_FORWARD_COMPATIBLE_FLAG`]). macOS needs this for any
+  /// core profile above 3.1.
+  pub forward_compatible: bool,
+  /// Samples per pixel for multisampling, or 0 for none.
+  pub multisample_samples: c_int,
+}
+impl Default for GlContextConfig {
+  /// OpenGL 3.3 core, 24 bit depth, 8 bit stencil, and nothing else.
+  #[inline]
+  fn default() -> Self {
+    Self {
+      profile: SDL_GL_CONTEXT_PROFILE_CORE,
+      major_version: 3,
+      minor_version: 3,
+      depth_bits: 24,
+      stencil_bits: 8,
+      srgb: false,
+      debug: false,
+      forward_compatible: cfg!(target_os = "macos"),
+      multisample_samples: 0,
+    }
+  }
+}
+impl GlContextConfig {
+  /// Sets all of the attributes with [`SDL_GL_SetAttribute`].
+  ///
+  /// The context attributes (profile, version, flags) are set first, then the
+  /// framebuffer attributes. Attributes that this struct doesn't cover are
+  /// left as they are.
+  ///
+  /// ## Safety
+  /// * The video subsystem must be initialized.
+  /// * Call this before creating the window (and its context). The attributes
+  ///   are only read when a window or context is created.
+  ///
+  /// ## Failure
+  /// * Stops at the first attribute that SDL won't set.
+  pub unsafe fn apply(&self) -> Result<(), SdlError> {
+    let mut flags = SDL_GLcontextFlag(0);
+    if self.debug {
+      flags |= SDL_GL_CONTEXT_DEBUG_FLAG;
+    }
+    if self.forward_compatible {
+      flags |= SDL_GL_CONTEXT_FORWARD_COMPATIBLE_FLAG;
+    }
+    let multisample = self.multisample_samples > 0;
+    let attributes = [
+      (SDL_GL_CONTEXT_PROFILE_MASK, self.profile.0 as c_int),
+      (SDL_GL_CONTEXT_MAJOR_VERSION, self.major_version),
+      (SDL_GL_CONTEXT_MINOR_VERSION, self.minor_version),
+      (SDL_GL_CONTEXT_FLAGS, flags.0 as c_int),
+      (SDL_GL_DEPTH_SIZE, self.depth_bits),
+      (SDL_GL_STENCIL_SIZE, self.stencil_bits),
+      (SDL_GL_FRAMEBUFFER_SRGB_CAPABLE, self.srgb as c_int),
+      (SDL_GL_MULTISAMPLEBUFFERS, multisample as c_int),
+      (SDL_GL_MULTISAMPLESAMPLES, self.multisample_samples.max(0)),
+    ];
+    for (attr, value) in attributes {
+      if SDL_GL_SetAttribute(attr, value) < 0 {
+        return Err(SdlError::get());
+      }
+    }
+    Ok(())
+  }
+}
+
+/// Copies the ICC profile of the window's current display into a `Vec`.
+///
+/// This calls [`SDL_GetWindowICCProfile`] and frees SDL's buffer for you.
+///
+/// ## Safety
+/// * `window` must be a valid window.
+#[cfg(all(feature = "alloc", feature = "sdl2_2_0_18"))]
+#[cfg_attr(docs_rs, doc(cfg(all(feature = "alloc", feature = "sdl2_2_0_18"))))]
+pub unsafe fn window_icc_profile(
+  window: *mut SDL_Window,
+) -> Result<alloc::vec::Vec<u8>, SdlError> {
+  let mut size = 0_usize;
+  let data = SDL_GetWindowICCProfile(window, &mut size);
+  if data.is_null() {
+    return Err(SdlError::get());
+  }
+  let profile = core::slice::from_raw_parts(data.cast::<u8>(), size).to_vec();
+  SDL_free(data);
+  Ok(profile)
 }
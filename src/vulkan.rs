@@ -3,18 +3,54 @@
 //! This is only a basic level of functionality that lets you "turn it on". For
 //! a full Vulkan experience you'll need a vulkan bindings crate such as
 //! [ash](https:docs.rs/ash), or similar.
+//!
+//! With the `ash_types` feature, the Vulkan handles in these signatures are
+//! ash's own types ([`ash::vk::Instance`], [`ash::vk::SurfaceKHR`], and
+//! [`ash::vk::PFN_vkGetInstanceProcAddr`]), so they can be passed straight to
+//! and from ash without any casting.
+
+use crate::{c_char, c_int, c_uint, stdinc::*, video::*};
+
+#[cfg(not(feature = "ash_types"))]
+use crate::c_void;
 
-use crate::{c_char, c_int, c_uint, c_void, stdinc::*, video::*};
+#[cfg(feature = "alloc")]
+use crate::error::*;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::ffi::CStr;
 
 // makes rustdoc link properly!
 #[allow(unused)]
 use crate::hints::*;
 
 /// Alternate type name in some docs.
+#[cfg(not(feature = "ash_types"))]
 pub type SDL_vulkanInstance = raw_vulkan_handle::VkInstance;
+/// Alternate type name in some docs.
+#[cfg(feature = "ash_types")]
+pub type SDL_vulkanInstance = ash::vk::Instance;
 
 /// Alternate type name in some docs.
+#[cfg(not(feature = "ash_types"))]
 pub type SDL_vulkanSurface = raw_vulkan_handle::VkSurfaceKHR;
+/// Alternate type name in some docs.
+#[cfg(feature = "ash_types")]
+pub type SDL_vulkanSurface = ash::vk::SurfaceKHR;
+
+/// The `vkGetInstanceProcAddr` pointer from
+/// [`SDL_Vulkan_GetVkGetInstanceProcAddr`].
+///
+/// Null if the Vulkan library isn't loaded.
+#[cfg(not(feature = "ash_types"))]
+pub type SDL_vkGetInstanceProcAddr = *mut c_void;
+/// The `vkGetInstanceProcAddr` pointer from
+/// [`SDL_Vulkan_GetVkGetInstanceProcAddr`].
+///
+/// `None` if the Vulkan library isn't loaded.
+#[cfg(feature = "ash_types")]
+pub type SDL_vkGetInstanceProcAddr = Option<ash::vk::PFN_vkGetInstanceProcAddr>;
 
 extern "C" {
   /// Dynamically load a Vulkan loader library.
@@ -69,7 +105,7 @@ extern "C" {
   ///
   /// This should be called after either calling [`SDL_Vulkan_LoadLibrary`]
   /// or creating an [`SDL_Window`] with the [`SDL_WINDOW_VULKAN`] flag.
-  pub fn SDL_Vulkan_GetVkGetInstanceProcAddr() -> *mut c_void;
+  pub fn SDL_Vulkan_GetVkGetInstanceProcAddr() -> SDL_vkGetInstanceProcAddr;
 
   /// Unload the Vulkan loader library previously loaded by
   /// [`SDL_Vulkan_LoadLibrary`].
@@ -149,11 +185,14 @@ extern "C" {
   /// free(names);
   /// ```
   ///
+  /// With the `alloc` feature, `vulkan_instance_extensions` does the two calls
+  /// for you. The names are already the `*const c_char` that ash's
+  /// `InstanceCreateInfo` wants.
+  ///
   /// See Also: [`SDL_Vulkan_CreateSurface`]
   pub fn SDL_Vulkan_GetInstanceExtensions(
     window: *mut SDL_Window, pCount: *mut c_uint, pNames: *mut *const c_char,
   ) -> SDL_bool;
-  // TODO: convert the above example to rust
 
   /// Create a Vulkan rendering surface for a window.
   ///
@@ -183,8 +222,8 @@ extern "C" {
   ///
   /// See Also: [`SDL_Vulkan_GetInstanceExtensions`]
   pub fn SDL_Vulkan_CreateSurface(
-    window: *mut SDL_Window, instance: raw_vulkan_handle::VkInstance,
-    surface: *mut raw_vulkan_handle::VkSurfaceKHR,
+    window: *mut SDL_Window, instance: SDL_vulkanInstance,
+    surface: *mut SDL_vulkanSurface,
   ) -> SDL_bool;
 
   /// Get the size of a window's underlying drawable in pixels (for use with
@@ -209,3 +248,40 @@ extern "C" {
     window: *mut SDL_Window, w: *mut c_int, h: *mut c_int,
   );
 }
+
+/// Gets the Vulkan instance extensions that SDL needs, as a `Vec`.
+///
+/// This does the "get the count, then get the names" calls to
+/// [`SDL_Vulkan_GetInstanceExtensions`]. The names are SDL's static strings.
+/// Add any other extensions you want, then pass the pointers to instance
+/// creation:
+/// ```no_run
+/// # use fermium::prelude::*;
+/// # let window: *mut SDL_Window = core::ptr::null_mut();
+/// let exts = unsafe { vulkan_instance_extensions(window) }.unwrap();
+/// let ptrs: Vec<*const c_char> = exts.iter().map(|e| e.as_ptr()).collect();
+/// // use `ptrs.len()` and `ptrs.as_ptr()` in your `VkInstanceCreateInfo`
+/// ```
+///
+/// ## Safety
+/// * `window` must be null or a window made with [`SDL_WINDOW_VULKAN`].
+/// * The Vulkan library must be loaded (by [`SDL_Vulkan_LoadLibrary`] or by
+///   making a Vulkan window).
+#[cfg(feature = "alloc")]
+#[cfg_attr(docs_rs, doc(cfg(feature = "alloc")))]
+pub unsafe fn vulkan_instance_extensions(
+  window: *mut SDL_Window,
+) -> Result<Vec<&'static CStr>, SdlError> {
+  let mut count: c_uint = 0;
+  let p = core::ptr::null_mut();
+  if !SDL_Vulkan_GetInstanceExtensions(window, &mut count, p).into_bool() {
+    return Err(SdlError::get());
+  }
+  let mut names: Vec<*const c_char> = Vec::with_capacity(count as usize);
+  let p = names.as_mut_ptr();
+  if !SDL_Vulkan_GetInstanceExtensions(window, &mut count, p).into_bool() {
+    return Err(SdlError::get());
+  }
+  names.set_len(count as usize);
+  Ok(names.into_iter().map(|name| CStr::from_ptr(name)).collect())
+}
@@ -0,0 +1,18 @@
+use fermium::prelude::*;
+
+#[test]
+fn hint_names_round_trip() {
+  assert_eq!(Hint::RenderDriver.name(), "SDL_RENDER_DRIVER");
+  assert_eq!(Hint::RenderDriver.as_bytes(), SDL_HINT_RENDER_DRIVER);
+  assert_eq!(Hint::from_name("SDL_VIDEODRIVER"), Some(Hint::Videodriver));
+  assert_eq!(Hint::from_name("SDL_NOT_A_HINT"), None);
+  assert_eq!(Hint::RenderOpenglShaders.name(), "SDL_RENDER_OPENGL_SHADERS");
+  assert_eq!(
+    Hint::from_name("SDL_RENDER_OPENGL_SHADERS"),
+    Some(Hint::RenderOpenglShaders)
+  );
+  for &hint in Hint::ALL {
+    assert_eq!(hint.as_bytes().last(), Some(&0));
+    assert_eq!(Hint::from_name(hint.name()), Some(hint));
+  }
+}
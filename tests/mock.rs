@@ -0,0 +1,40 @@
+#![cfg(feature = "mock")]
+
+use fermium::{mock::*, prelude::*};
+
+// The mock's state is global, so everything is checked in a single test.
+#[test]
+fn mock_init_events_and_ticks() {
+  reset();
+  unsafe {
+    assert_eq!(SDL_Init(SDL_INIT_EVENTS | SDL_INIT_TIMER), 0);
+    assert_eq!(SDL_WasInit(SDL_INIT_EVENTS), SDL_INIT_EVENTS.0 as i32);
+    assert!(!SDL_QuitRequested());
+
+    let mut event = SDL_Event::default();
+    event.type_ = SDL_KEYDOWN;
+    assert!(push_event(event));
+    event.type_ = SDL_QUIT;
+    assert!(push_event(event));
+    assert!(SDL_QuitRequested());
+
+    let mut events = [SDL_Event::default(); 4];
+    assert_eq!(poll_events(&mut events), 2);
+    assert_eq!(events[0].type_, SDL_KEYDOWN);
+    assert_eq!(events[1].type_, SDL_QUIT);
+    assert_eq!(SDL_PollEvent(&mut event), 0);
+
+    set_ticks(100);
+    SDL_Delay(16);
+    assert_eq!(SDL_GetTicks(), 116);
+
+    SDL_Quit();
+    assert_eq!(SDL_WasInit(SDL_InitFlags(0)), 0);
+
+    set_init_result(-1);
+    assert!(SDL_Init(SDL_INIT_VIDEO) < 0);
+    assert_eq!(SDL_WasInit(SDL_INIT_VIDEO), 0);
+  }
+  assert_eq!(call_count(MockFn::SDL_Init), 2);
+  assert_eq!(call_count(MockFn::SDL_PumpEvents), 3);
+}
@@ -0,0 +1,39 @@
+#![cfg(feature = "keycode_names")]
+
+use fermium::prelude::*;
+
+#[test]
+fn scancode_names_round_trip() {
+  assert_eq!(SDL_SCANCODE_A.name(), Some("A"));
+  assert_eq!(SDL_SCANCODE_KP_ENTER.name(), Some("Keypad Enter"));
+  assert_eq!(SDL_Scancode(0).name(), None);
+  assert_eq!(SDL_Scancode(-1).name(), None);
+  assert_eq!(SDL_Scancode::from_name("left shift"), Some(SDL_SCANCODE_LSHIFT));
+  assert_eq!(SDL_Scancode::from_name("not a key"), None);
+  for i in 0..SDL_NUM_SCANCODES as i32 {
+    let scancode = SDL_Scancode(i);
+    if let Some(name) = scancode.name() {
+      // SDL_SCANCODE_RETURN2 shares a name with SDL_SCANCODE_RETURN.
+      if scancode != SDL_SCANCODE_RETURN2 {
+        assert_eq!(SDL_Scancode::from_name(name), Some(scancode));
+      }
+    }
+  }
+}
+
+#[test]
+fn keycode_names_round_trip() {
+  assert_eq!(SDLK_a.name(), Some("A"));
+  assert_eq!(SDLK_SPACE.name(), Some("Space"));
+  assert_eq!(SDLK_DELETE.name(), Some("Delete"));
+  assert_eq!(SDLK_F5.name(), Some("F5"));
+  assert_eq!(SDLK_LEFTBRACKET.name(), Some("["));
+  assert_eq!(SDL_Keycode::from_name("A"), Some(SDLK_a));
+  assert_eq!(SDL_Keycode::from_name("return"), Some(SDLK_RETURN));
+  assert_eq!(SDL_Keycode::from_name("Keypad 7"), Some(SDLK_KP_7));
+  for key in [SDLK_ESCAPE, SDLK_z, SDLK_9, SDLK_UP, SDLK_RGUI, SDLK_SLASH] {
+    assert_eq!(SDL_Keycode::from_name(key.name().unwrap()), Some(key));
+  }
+  assert_eq!("Left Shift".parse::<SDL_Keycode>(), Ok(SDLK_LSHIFT));
+  assert!("".parse::<SDL_Keycode>().is_err());
+}